@@ -187,6 +187,7 @@ impl Into<MinifyOptions> for TransformOptions {
         Default::default()
       },
       unused_symbols,
+      ..MinifyOptions::default()
     }
   }
 }
@@ -289,6 +290,7 @@ pub extern "C" fn lightningcss_stylesheet_parse(
     error_recovery: options.error_recovery,
     source_index: 0,
     warnings: Some(warnings.clone()),
+    ..ParserOptions::default()
   };
 
   let stylesheet = unwrap!(StyleSheet::parse(code, opts), error, std::ptr::null_mut());
@@ -349,6 +351,7 @@ pub extern "C" fn lightningcss_stylesheet_to_css(
     } else {
       None
     },
+    ..PrinterOptions::default()
   };
 
   let res = unwrap!(wrapper.stylesheet.to_css(opts), error, ToCssResult::default());
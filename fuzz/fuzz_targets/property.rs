@@ -0,0 +1,42 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use lightningcss::properties::{Property, PropertyId};
+use lightningcss::stylesheet::{ParserOptions, PrinterOptions};
+
+/// Properties to fuzz. The first byte of the input selects one of these.
+const PROPERTIES: &[&str] = &[
+  "animation",
+  "animation-name",
+  "animation-timing-function",
+  "animation-timeline",
+  "transition",
+  "background",
+  "border",
+  "font",
+  "grid",
+  "grid-template-areas",
+  "color",
+  "width",
+  "transform",
+  "mask",
+  "text-shadow",
+];
+
+fuzz_target!(|data: &[u8]| {
+  let Some((first, rest)) = data.split_first() else {
+    return;
+  };
+  let Ok(input) = std::str::from_utf8(rest) else {
+    return;
+  };
+
+  let property_id = PropertyId::from(PROPERTIES[*first as usize % PROPERTIES.len()]);
+  if let Ok(property) = Property::parse_value_string(property_id, input, ParserOptions::default()) {
+    let _ = property.value_to_css_string(PrinterOptions::default());
+    let _ = property.value_to_css_string(PrinterOptions {
+      minify: true,
+      ..PrinterOptions::default()
+    });
+  }
+});
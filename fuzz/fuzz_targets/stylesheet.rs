@@ -0,0 +1,24 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use lightningcss::stylesheet::{MinifyOptions, ParserOptions, PrinterOptions, StyleSheet};
+
+fuzz_target!(|data: &[u8]| {
+  let Ok(input) = std::str::from_utf8(data) else {
+    return;
+  };
+
+  let options = ParserOptions {
+    error_recovery: true,
+    ..ParserOptions::default()
+  };
+  if let Ok(mut stylesheet) = StyleSheet::parse(input, options) {
+    let _ = stylesheet.to_css(PrinterOptions::default());
+    if stylesheet.minify(MinifyOptions::default()).is_ok() {
+      let _ = stylesheet.to_css(PrinterOptions {
+        minify: true,
+        ..PrinterOptions::default()
+      });
+    }
+  }
+});
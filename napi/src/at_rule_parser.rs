@@ -183,10 +183,10 @@ impl<'i> ToCss for AtRule<'i> {
         AtRuleBody::RuleList(rules) => {
           dest.whitespace()?;
           dest.write_char('{')?;
-          dest.indent();
+          dest.increase_indent();
           dest.newline()?;
           rules.to_css(dest)?;
-          dest.dedent();
+          dest.decrease_indent();
           dest.newline()?;
           dest.write_char('}')?;
         }
@@ -7,12 +7,13 @@ use lightningcss::bundler::{Bundler, SourceProvider};
 use lightningcss::css_modules::{CssModuleExports, CssModuleReferences, PatternParseError};
 use lightningcss::dependencies::{Dependency, DependencyOptions};
 use lightningcss::error::{Error, ErrorLocation, MinifyErrorKind, ParserError, PrinterErrorKind};
+use lightningcss::printer::{Indent, LineEnding};
 use lightningcss::stylesheet::{
   MinifyOptions, ParserFlags, ParserOptions, PrinterOptions, PseudoClasses, StyleAttribute, StyleSheet,
 };
 use lightningcss::targets::{Browsers, Features, Targets};
 use napi::bindgen_prelude::{FromNapiValue, ToNapiValue};
-use napi::{CallContext, Env, JsObject, JsUnknown};
+use napi::{CallContext, Env, JsObject, JsString, JsUnknown};
 use parcel_sourcemap::SourceMap;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
@@ -74,6 +75,35 @@ impl<'i> TransformResult<'i> {
   }
 }
 
+/// The result of [transform_json], where the code and source map are strings rather than buffers.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TransformJsonResult<'i> {
+  code: String,
+  map: Option<String>,
+  exports: Option<CssModuleExports>,
+  references: Option<CssModuleReferences>,
+  dependencies: Option<Vec<Dependency>>,
+  warnings: Vec<Warning<'i>>,
+}
+
+impl<'i> TransformResult<'i> {
+  fn into_json(self) -> napi::Result<String> {
+    let result = TransformJsonResult {
+      code: String::from_utf8(self.code).map_err(|e| napi::Error::from_reason(e.to_string()))?,
+      map: match self.map {
+        Some(map) => Some(String::from_utf8(map).map_err(|e| napi::Error::from_reason(e.to_string()))?),
+        None => None,
+      },
+      exports: self.exports,
+      references: self.references,
+      dependencies: self.dependencies,
+      warnings: self.warnings,
+    };
+    serde_json::to_string(&result).map_err(|e| napi::Error::from_reason(e.to_string()))
+  }
+}
+
 #[cfg(feature = "visitor")]
 fn get_visitor(env: Env, opts: &JsObject) -> Option<JsVisitor> {
   if let Ok(visitor) = get_named_property::<JsObject>(opts, "visitor") {
@@ -102,6 +132,23 @@ pub fn transform(ctx: CallContext) -> napi::Result<JsUnknown> {
   }
 }
 
+/// Like [transform], but accepts the options as a JSON string and returns the result as a JSON string.
+/// The `code` option is a string, and the `code` and `map` fields of the result are strings as well.
+/// Since functions cannot be serialized, visitors are not supported.
+pub fn transform_json(ctx: CallContext) -> napi::Result<JsString> {
+  let json = ctx.get::<JsString>(0)?.into_utf8()?;
+  let config: Config =
+    serde_json::from_str(json.as_str()?).map_err(|e| napi::Error::new(napi::Status::InvalidArg, e.to_string()))?;
+  let code =
+    std::str::from_utf8(&config.code).map_err(|e| napi::Error::new(napi::Status::InvalidArg, e.to_string()))?;
+  let res = compile(code, &config, &mut None);
+
+  match res {
+    Ok(res) => ctx.env.create_string(&res.into_json()?),
+    Err(err) => Err(err.into_js_error(*ctx.env, Some(code))?),
+  }
+}
+
 pub fn transform_style_attribute(ctx: CallContext) -> napi::Result<JsUnknown> {
   let opts = ctx.get::<JsObject>(0)?;
   let mut visitor = get_visitor(*ctx.env, &opts);
@@ -568,7 +615,11 @@ struct Config {
   #[serde(default)]
   pub exclude: u32,
   pub minify: Option<bool>,
+  pub indent: Option<IndentOption>,
+  pub line_ending: Option<LineEndingOption>,
+  pub newline_between_rules: Option<bool>,
   pub source_map: Option<bool>,
+  pub source_root: Option<String>,
   pub input_source_map: Option<String>,
   pub drafts: Option<Drafts>,
   pub non_standard: Option<NonStandard>,
@@ -580,6 +631,44 @@ struct Config {
   pub custom_at_rules: Option<HashMap<String, CustomAtRuleConfig>>,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum IndentOption {
+  Spaces(u8),
+  Keyword(IndentKeyword),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum IndentKeyword {
+  Tab,
+}
+
+impl From<&IndentOption> for Indent {
+  fn from(indent: &IndentOption) -> Indent {
+    match indent {
+      IndentOption::Spaces(width) => Indent::Spaces(*width),
+      IndentOption::Keyword(IndentKeyword::Tab) => Indent::Tab,
+    }
+  }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum LineEndingOption {
+  Lf,
+  Crlf,
+}
+
+impl From<&LineEndingOption> for LineEnding {
+  fn from(line_ending: &LineEndingOption) -> LineEnding {
+    match line_ending {
+      LineEndingOption::Lf => LineEnding::Lf,
+      LineEndingOption::Crlf => LineEnding::Crlf,
+    }
+  }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
 enum AnalyzeDependenciesOption {
@@ -624,7 +713,11 @@ struct BundleConfig {
   #[serde(default)]
   pub exclude: u32,
   pub minify: Option<bool>,
+  pub indent: Option<IndentOption>,
+  pub line_ending: Option<LineEndingOption>,
+  pub newline_between_rules: Option<bool>,
   pub source_map: Option<bool>,
+  pub source_root: Option<String>,
   pub drafts: Option<Drafts>,
   pub non_standard: Option<NonStandard>,
   pub css_modules: Option<CssModulesOption>,
@@ -731,6 +824,7 @@ fn compile<'i>(
         source_index: 0,
         error_recovery: config.error_recovery.unwrap_or_default(),
         warnings: warnings.clone(),
+        ..ParserOptions::default()
       },
       &mut CustomAtRuleParser {
         configs: config.custom_at_rules.clone().unwrap_or_default(),
@@ -751,10 +845,14 @@ fn compile<'i>(
     stylesheet.minify(MinifyOptions {
       targets,
       unused_symbols: config.unused_symbols.clone().unwrap_or_default(),
+      ..MinifyOptions::default()
     })?;
 
     stylesheet.to_css(PrinterOptions {
       minify: config.minify.unwrap_or_default(),
+      indent: config.indent.as_ref().map_or_else(Indent::default, |i| i.into()),
+      line_ending: config.line_ending.as_ref().map_or_else(LineEnding::default, |l| l.into()),
+      newline_between_rules: config.newline_between_rules.unwrap_or_default(),
       source_map: source_map.as_mut(),
       project_root,
       targets,
@@ -770,6 +868,7 @@ fn compile<'i>(
         None
       },
       pseudo_classes: config.pseudo_classes.as_ref().map(|p| p.into()),
+      ..PrinterOptions::default()
     })?
   };
 
@@ -780,7 +879,7 @@ fn compile<'i>(
       }
     }
 
-    source_map.to_json(None).ok()
+    source_map.to_json(config.source_root.as_deref()).ok()
   } else {
     None
   };
@@ -864,6 +963,7 @@ fn compile_bundle<
       warnings: warnings.clone(),
       filename: String::new(),
       source_index: 0,
+      ..ParserOptions::default()
     };
 
     let mut at_rule_parser = CustomAtRuleParser {
@@ -887,10 +987,14 @@ fn compile_bundle<
     stylesheet.minify(MinifyOptions {
       targets,
       unused_symbols: config.unused_symbols.clone().unwrap_or_default(),
+      ..MinifyOptions::default()
     })?;
 
     stylesheet.to_css(PrinterOptions {
       minify: config.minify.unwrap_or_default(),
+      indent: config.indent.as_ref().map_or_else(Indent::default, |i| i.into()),
+      line_ending: config.line_ending.as_ref().map_or_else(LineEnding::default, |l| l.into()),
+      newline_between_rules: config.newline_between_rules.unwrap_or_default(),
       source_map: source_map.as_mut(),
       project_root,
       targets,
@@ -906,11 +1010,12 @@ fn compile_bundle<
         None
       },
       pseudo_classes: config.pseudo_classes.as_ref().map(|p| p.into()),
+      ..PrinterOptions::default()
     })?
   };
 
   let map = if let Some(source_map) = &mut source_map {
-    source_map.to_json(None).ok()
+    source_map.to_json(config.source_root.as_deref()).ok()
   } else {
     None
   };
@@ -1022,6 +1127,7 @@ fn compile_attr<'i>(
         None
       },
       pseudo_classes: None,
+      ..PrinterOptions::default()
     })?
   };
   Ok(AttrResult {
@@ -47,7 +47,7 @@ export type Rule<D = Declaration, M = MediaQuery> = | {
   }
 | {
     type: "counter-style";
-    value: CounterStyleRule<D>;
+    value: CounterStyleRule;
   }
 | {
     type: "namespace";
@@ -1341,6 +1341,25 @@ export type PropertyId =
       property: "text-overflow";
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "line-clamp";
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "overscroll-behavior";
+    }
+  | {
+      property: "overscroll-behavior-x";
+    }
+  | {
+      property: "overscroll-behavior-y";
+    }
+  | {
+      property: "overscroll-behavior-inline";
+    }
+  | {
+      property: "overscroll-behavior-block";
+    }
   | {
       property: "position";
     }
@@ -1934,6 +1953,9 @@ export type PropertyId =
       property: "transition-timing-function";
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "transition-behavior";
+    }
   | {
       property: "transition";
       vendorPrefix: VendorPrefix;
@@ -2025,9 +2047,24 @@ export type PropertyId =
   | {
       property: "scale";
     }
+  | {
+      property: "offset-path";
+    }
   | {
       property: "text-transform";
     }
+  | {
+      property: "white-space-collapse";
+    }
+  | {
+      property: "text-wrap-mode";
+    }
+  | {
+      property: "text-wrap-style";
+    }
+  | {
+      property: "text-wrap";
+    }
   | {
       property: "white-space";
     }
@@ -2233,6 +2270,9 @@ export type PropertyId =
   | {
       property: "image-rendering";
     }
+  | {
+      property: "d";
+    }
   | {
       property: "clip-path";
       vendorPrefix: VendorPrefix;
@@ -2335,6 +2375,9 @@ export type PropertyId =
       property: "mask-box-image-repeat";
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "shape-outside";
+    }
   | {
       property: "filter";
       vendorPrefix: VendorPrefix;
@@ -2346,6 +2389,9 @@ export type PropertyId =
   | {
       property: "z-index";
     }
+  | {
+      property: "overlay";
+    }
   | {
       property: "container-type";
     }
@@ -2355,6 +2401,12 @@ export type PropertyId =
   | {
       property: "container";
     }
+  | {
+      property: "contain";
+    }
+  | {
+      property: "content-visibility";
+    }
   | {
       property: "view-transition-name";
     }
@@ -2520,6 +2572,31 @@ export type Declaration =
       value: TextOverflow;
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "line-clamp";
+      value: LineClamp;
+      vendorPrefix: VendorPrefix;
+    }
+  | {
+      property: "overscroll-behavior";
+      value: OverscrollBehavior;
+    }
+  | {
+      property: "overscroll-behavior-x";
+      value: OverscrollBehaviorKeyword;
+    }
+  | {
+      property: "overscroll-behavior-y";
+      value: OverscrollBehaviorKeyword;
+    }
+  | {
+      property: "overscroll-behavior-inline";
+      value: OverscrollBehaviorKeyword;
+    }
+  | {
+      property: "overscroll-behavior-block";
+      value: OverscrollBehaviorKeyword;
+    }
   | {
       property: "position";
       value: Position2;
@@ -3298,6 +3375,10 @@ export type Declaration =
       value: EasingFunction[];
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "transition-behavior";
+      value: TransitionBehavior[];
+    }
   | {
       property: "transition";
       value: Transition[];
@@ -3414,10 +3495,30 @@ export type Declaration =
       property: "scale";
       value: Scale;
     }
+  | {
+      property: "offset-path";
+      value: OffsetPath;
+    }
   | {
       property: "text-transform";
       value: TextTransform;
     }
+  | {
+      property: "white-space-collapse";
+      value: WhiteSpaceCollapse;
+    }
+  | {
+      property: "text-wrap-mode";
+      value: TextWrapMode;
+    }
+  | {
+      property: "text-wrap-style";
+      value: TextWrapStyle;
+    }
+  | {
+      property: "text-wrap";
+      value: TextWrap;
+    }
   | {
       property: "white-space";
       value: WhiteSpace;
@@ -3686,6 +3787,10 @@ export type Declaration =
       property: "image-rendering";
       value: ImageRendering;
     }
+  | {
+      property: "d";
+      value: SVGPath;
+    }
   | {
       property: "clip-path";
       value: ClipPath;
@@ -3817,6 +3922,10 @@ export type Declaration =
       value: BorderImageRepeat;
       vendorPrefix: VendorPrefix;
     }
+  | {
+      property: "shape-outside";
+      value: ShapeOutside;
+    }
   | {
       property: "filter";
       value: FilterList;
@@ -3831,6 +3940,10 @@ export type Declaration =
       property: "z-index";
       value: ZIndex;
     }
+  | {
+      property: "overlay";
+      value: Overlay;
+    }
   | {
       property: "container-type";
       value: ContainerType;
@@ -3843,6 +3956,14 @@ export type Declaration =
       property: "container";
       value: Container;
     }
+  | {
+      property: "contain";
+      value: Contain;
+    }
+  | {
+      property: "content-visibility";
+      value: ContentVisibility;
+    }
   | {
       property: "view-transition-name";
       value: ViewTransitionName;
@@ -3876,6 +3997,355 @@ export type Declaration =
       property: "custom";
       value: CustomProperty;
     };
+/**
+ * A value for the [line-clamp](https://drafts.csswg.org/css-overflow-4/#line-clamp) property.
+ */
+export type LineClamp =
+  | {
+      type: "none";
+    }
+  | {
+      type: "integer";
+      value: number;
+    };
+/**
+ * A value for the [overscroll-behavior](https://drafts.csswg.org/css-overscroll-1/#propdef-overscroll-behavior) shorthand property.
+ */
+export interface OverscrollBehavior {
+  /**
+   * The overscroll behavior for the x direction.
+   */
+  x: OverscrollBehaviorKeyword;
+  /**
+   * The overscroll behavior for the y direction.
+   */
+  y: OverscrollBehaviorKeyword;
+}
+/**
+ * An [overscroll behavior](https://drafts.csswg.org/css-overscroll-1/#overscroll-behavior-properties) keyword, as used in the `overscroll-behavior` property and its longhands.
+ */
+export type OverscrollBehaviorKeyword = "auto" | "contain" | "none";
+/**
+ * A value for the [transition-behavior](https://drafts.csswg.org/css-transitions-2/#transition-behavior-property) property.
+ */
+export type TransitionBehavior = "normal" | "allow-discrete";
+/**
+ * A value for the [offset-path](https://drafts.fxtf.org/motion-1/#offset-path-property) property.
+ */
+export type OffsetPath =
+  | {
+      type: "none";
+    }
+  | {
+      type: "url";
+      value: Url;
+    }
+  | {
+      /**
+       * A reference box that the shape is positioned according to.
+       */
+      referenceBox: GeometryBox;
+      /**
+       * A basic shape.
+       */
+      shape: BasicShape;
+      type: "shape";
+    }
+  | {
+      type: "box";
+      value: GeometryBox;
+    };
+/**
+ * A value for the [white-space-collapse](https://drafts.csswg.org/css-text-4/#white-space-collapsing) property.
+ */
+export type WhiteSpaceCollapse =
+  | "collapse"
+  | "discard"
+  | "preserve"
+  | "preserve-breaks"
+  | "preserve-spaces"
+  | "break-spaces";
+/**
+ * A value for the [text-wrap-mode](https://drafts.csswg.org/css-text-4/#text-wrap-mode) property.
+ */
+export type TextWrapMode = "wrap" | "nowrap";
+/**
+ * A value for the [text-wrap-style](https://drafts.csswg.org/css-text-4/#text-wrap-style) property.
+ */
+export type TextWrapStyle = "auto" | "balance" | "stable" | "pretty";
+/**
+ * A value for the [text-wrap](https://drafts.csswg.org/css-text-4/#text-wrap) shorthand property.
+ */
+export interface TextWrap {
+  /**
+   * Whether lines may wrap.
+   */
+  mode: TextWrapMode;
+  /**
+   * How lines are wrapped.
+   */
+  style: TextWrapStyle;
+}
+/**
+ * A value for the [d](https://www.w3.org/TR/SVG2/paths.html#TheDProperty) property.
+ */
+export type SVGPath =
+  | {
+      type: "none";
+    }
+  | {
+      type: "path";
+      value: PathData;
+    };
+/**
+ * SVG [path data](https://www.w3.org/TR/SVG2/paths.html#PathData), as used in the `path()` function and the `d` property.
+ *
+ * When minifying, path data is compacted by removing redundant whitespace and command letters, and choosing the shortest of the absolute and relative forms of each command.
+ */
+export interface PathData {
+  /**
+   * The commands that make up the path.
+   */
+  commands: PathCommand[];
+}
+/**
+ * A command within [PathData](PathData).
+ */
+export type PathCommand =
+  | {
+      /**
+       * The start point of the subpath.
+       */
+      point: PathPoint;
+      /**
+       * Whether the coordinates are relative to the current point.
+       */
+      relative: boolean;
+      type: "move-to";
+    }
+  | {
+      /**
+       * The end point of the line.
+       */
+      point: PathPoint;
+      /**
+       * Whether the coordinates are relative to the current point.
+       */
+      relative: boolean;
+      type: "line-to";
+    }
+  | {
+      /**
+       * Whether the coordinate is relative to the current point.
+       */
+      relative: boolean;
+      type: "horizontal-line-to";
+      /**
+       * The x coordinate of the end of the line.
+       */
+      x: number;
+    }
+  | {
+      /**
+       * Whether the coordinate is relative to the current point.
+       */
+      relative: boolean;
+      type: "vertical-line-to";
+      /**
+       * The y coordinate of the end of the line.
+       */
+      y: number;
+    }
+  | {
+      /**
+       * The control point at the start of the curve.
+       */
+      control1: PathPoint;
+      /**
+       * The control point at the end of the curve.
+       */
+      control2: PathPoint;
+      /**
+       * The end point of the curve.
+       */
+      point: PathPoint;
+      /**
+       * Whether the coordinates are relative to the current point.
+       */
+      relative: boolean;
+      type: "curve-to";
+    }
+  | {
+      /**
+       * The control point at the end of the curve.
+       */
+      control2: PathPoint;
+      /**
+       * The end point of the curve.
+       */
+      point: PathPoint;
+      /**
+       * Whether the coordinates are relative to the current point.
+       */
+      relative: boolean;
+      type: "smooth-curve-to";
+    }
+  | {
+      /**
+       * The control point of the curve.
+       */
+      control1: PathPoint;
+      /**
+       * The end point of the curve.
+       */
+      point: PathPoint;
+      /**
+       * Whether the coordinates are relative to the current point.
+       */
+      relative: boolean;
+      type: "quadratic-curve-to";
+    }
+  | {
+      /**
+       * The end point of the curve.
+       */
+      point: PathPoint;
+      /**
+       * Whether the coordinates are relative to the current point.
+       */
+      relative: boolean;
+      type: "smooth-quadratic-curve-to";
+    }
+  | {
+      /**
+       * The rotation of the ellipse's x axis, in degrees.
+       */
+      angle: number;
+      /**
+       * Whether the larger of the two possible arcs is drawn.
+       */
+      largeArc: boolean;
+      /**
+       * The end point of the arc.
+       */
+      point: PathPoint;
+      /**
+       * The x and y radii of the ellipse.
+       */
+      radii: PathPoint;
+      /**
+       * Whether the end point is relative to the current point.
+       */
+      relative: boolean;
+      /**
+       * Whether the arc is drawn in the positive-angle direction.
+       */
+      sweep: boolean;
+      type: "arc";
+    }
+  | {
+      type: "close-path";
+    };
+/**
+ * A coordinate pair within [PathData](PathData).
+ */
+export interface PathPoint {
+  /**
+   * The x coordinate.
+   */
+  x: number;
+  /**
+   * The y coordinate.
+   */
+  y: number;
+}
+/**
+ * A value for the [shape-outside](https://www.w3.org/TR/css-shapes-1/#shape-outside-property) property.
+ */
+export type ShapeOutside =
+  | {
+      type: "none";
+    }
+  | {
+      /**
+       * A reference box that the shape is positioned according to.
+       */
+      referenceBox: ShapeBox;
+      /**
+       * A basic shape.
+       */
+      shape: BasicShape;
+      type: "shape";
+    }
+  | {
+      type: "box";
+      value: ShapeBox;
+    }
+  | {
+      type: "image";
+      value: Image;
+    };
+/**
+ * A [`<shape-box>`](https://www.w3.org/TR/css-shapes-1/#typedef-shape-box) value as used in the `shape-outside` property.
+ */
+export type ShapeBox = "margin-box" | "border-box" | "padding-box" | "content-box";
+/**
+ * A value for the [overlay](https://drafts.csswg.org/css-position-4/#overlay) property.
+ */
+export type Overlay = "none" | "auto";
+export interface Contain {
+  inlineSize: boolean;
+  layout: boolean;
+  paint: boolean;
+  size: boolean;
+  style: boolean;
+}
+/**
+ * A value for the [content-visibility](https://drafts.csswg.org/css-contain-2/#content-visibility) property.
+ */
+export type ContentVisibility = "visible" | "auto" | "hidden";
+/**
+ * The value of the [size](https://www.w3.org/TR/css-page-3/#page-size-prop) descriptor in an `@page` rule.
+ */
+export type PageSizeDescriptor =
+  | {
+      type: "size";
+      value: PageSize;
+    }
+  | {
+      type: "unparsed";
+      value: TokenOrValue[];
+    };
+/**
+ * A value for the [size](https://www.w3.org/TR/css-page-3/#page-size-prop) descriptor in an `@page` rule.
+ */
+export type PageSize =
+  | {
+      type: "auto";
+    }
+  | {
+      type: "length";
+      value: Size2DFor_Length;
+    }
+  | {
+      /**
+       * The page orientation.
+       */
+      orientation?: PageOrientation | null;
+      /**
+       * A named page size.
+       */
+      size?: PageSizeKeyword | null;
+      type: "keyword";
+    };
+/**
+ * A page orientation keyword in the `size` descriptor of an `@page` rule.
+ */
+export type PageOrientation = "portrait" | "landscape";
+/**
+ * A [page size](https://www.w3.org/TR/css-page-3/#typedef-page-size-page-size) keyword in the `size` descriptor of an `@page` rule.
+ */
+export type PageSizeKeyword = "a5" | "a4" | "a3" | "b5" | "b4" | "jis-b5" | "jis-b4" | "letter" | "legal" | "ledger";
 /**
  * A CSS [`<image>`](https://www.w3.org/TR/css-images-3/#image-values) value.
  */
@@ -5150,6 +5620,13 @@ export type TrackSizing =
        */
       lineNames: String[][];
       type: "track-list";
+    }
+  | {
+      /**
+       * Line names for the lines of the subgrid, starting from the first line.
+       */
+      lineNames: String[][];
+      type: "subgrid";
     };
 /**
  * Either a track size or `repeat()` function.
@@ -5805,9 +6282,18 @@ export type Scale =
  */
 export type TextTransformCase = "none" | "uppercase" | "lowercase" | "capitalize";
 /**
- * A value for the [white-space](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#white-space-property) property.
+ * A value for the [white-space](https://drafts.csswg.org/css-text-4/#white-space-property) shorthand property.
  */
-export type WhiteSpace = "normal" | "pre" | "nowrap" | "pre-wrap" | "break-spaces" | "pre-line";
+export interface WhiteSpace {
+  /**
+   * How white space is collapsed.
+   */
+  collapse: WhiteSpaceCollapse;
+  /**
+   * Whether lines may wrap.
+   */
+  wrapMode: TextWrapMode;
+}
 /**
  * A value for the [word-break](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#word-break-property) property.
  */
@@ -6287,7 +6773,171 @@ export type BasicShape =
   | {
       type: "polygon";
       value: Polygon;
+    }
+  | {
+      type: "path";
+      value: Path;
+    }
+  | {
+      type: "shape";
+      value: Shape;
+    };
+/**
+ * A [`path()`](https://www.w3.org/TR/css-shapes-1/#funcdef-basic-shape-path) shape.
+ */
+export interface Path {
+  /**
+   * The fill rule used to determine the interior of the path.
+   */
+  fillRule: FillRule;
+  /**
+   * The path data.
+   */
+  path: PathData;
+}
+/**
+ * A [`shape()`](https://drafts.csswg.org/css-shapes-2/#shape-function) shape.
+ */
+export interface Shape {
+  /**
+   * The commands that make up the shape.
+   */
+  commands: ShapeCommand[];
+  /**
+   * The fill rule used to determine the interior of the shape.
+   */
+  fillRule: FillRule;
+  /**
+   * The starting point of the shape.
+   */
+  start: Point;
+}
+/**
+ * A command within a `shape()` shape.
+ *
+ * See [Shape](Shape).
+ */
+export type ShapeCommand =
+  | {
+      /**
+       * The start point of the subpath.
+       */
+      point: Point;
+      /**
+       * Whether the point is relative to the current point (`by`) rather than the reference box (`to`).
+       */
+      relative: boolean;
+      type: "move";
+    }
+  | {
+      /**
+       * The end point of the line.
+       */
+      point: Point;
+      /**
+       * Whether the point is relative to the current point.
+       */
+      relative: boolean;
+      type: "line";
+    }
+  | {
+      /**
+       * Whether the coordinate is relative to the current point.
+       */
+      relative: boolean;
+      type: "hline";
+      /**
+       * The x coordinate of the end of the line.
+       */
+      x: DimensionPercentageFor_LengthValue;
+    }
+  | {
+      /**
+       * Whether the coordinate is relative to the current point.
+       */
+      relative: boolean;
+      type: "vline";
+      /**
+       * The y coordinate of the end of the line.
+       */
+      y: DimensionPercentageFor_LengthValue;
+    }
+  | {
+      /**
+       * The first control point of the curve.
+       */
+      control1: Point;
+      /**
+       * The second control point of the curve, for cubic curves.
+       */
+      control2?: Point | null;
+      /**
+       * The end point of the curve.
+       */
+      point: Point;
+      /**
+       * Whether the end point is relative to the current point.
+       */
+      relative: boolean;
+      type: "curve";
+    }
+  | {
+      /**
+       * The second control point of the curve, for cubic curves.
+       */
+      control?: Point | null;
+      /**
+       * The end point of the curve.
+       */
+      point: Point;
+      /**
+       * Whether the end point is relative to the current point.
+       */
+      relative: boolean;
+      type: "smooth";
+    }
+  | {
+      /**
+       * The end point of the arc.
+       */
+      point: Point;
+      /**
+       * The x radius of the ellipse.
+       */
+      radius_x: DimensionPercentageFor_LengthValue;
+      /**
+       * The y radius of the ellipse.
+       */
+      radius_y: DimensionPercentageFor_LengthValue;
+      /**
+       * Whether the end point is relative to the current point.
+       */
+      relative: boolean;
+      /**
+       * The rotation of the ellipse's x axis.
+       */
+      rotate: Angle;
+      /**
+       * Which of the two possible arcs is drawn.
+       */
+      size: ArcSize;
+      /**
+       * The direction in which the arc is drawn.
+       */
+      sweep: ArcSweep;
+      type: "arc";
+    }
+  | {
+      type: "close";
     };
+/**
+ * The size of an `arc` command within a `shape()`.
+ */
+export type ArcSize = "large" | "small";
+/**
+ * The direction of an `arc` command within a `shape()`.
+ */
+export type ArcSweep = "cw" | "ccw";
 /**
  * A generic value that represents a value for four sides of a box, e.g. border-width, margin, padding, etc.
  *
@@ -7079,10 +7729,18 @@ export type FontFaceProperty =
       type: "unicode-range";
       value: UnicodeRange[];
     }
+  | {
+      type: "font-display";
+      value: FontDisplay;
+    }
   | {
       type: "custom";
       value: CustomProperty;
     };
+/**
+ * A value for the [font-display](https://drafts.csswg.org/css-fonts/#font-display-desc) descriptor in an `@font-face` rule.
+ */
+export type FontDisplay = "auto" | "block" | "swap" | "fallback" | "optional";
 /**
  * A value for the [src](https://drafts.csswg.org/css-fonts/#src-desc) property in an `@font-face` rule.
  */
@@ -8830,6 +9488,10 @@ export interface Font {
  * A value for the [transition](https://www.w3.org/TR/2018/WD-css-transitions-1-20181011/#transition-shorthand-property) property.
  */
 export interface Transition {
+  /**
+   * Whether the transition applies to discretely animated properties.
+   */
+  behavior: TransitionBehavior;
   /**
    * The delay before the transition starts.
    */
@@ -9221,7 +9883,7 @@ export interface Polygon {
   points: Point[];
 }
 /**
- * A point within a `polygon()` shape.
+ * A point within a `polygon()` or `shape()` shape.
  *
  * See [Polygon](Polygon).
  */
@@ -9335,11 +9997,23 @@ export interface Container {
    */
   name: ContainerNameList;
 }
+/**
+ * A value for the [color-scheme](https://drafts.csswg.org/css-color-adjust/#color-scheme-prop) property.
+ */
 export interface ColorScheme {
-  dark: boolean;
-  light: boolean;
+  /**
+   * Forbids the user agent from overriding the color scheme for the element.
+   */
   only: boolean;
+  /**
+   * The color schemes supported by the element, in order of preference. An empty list represents the `normal` keyword.
+   */
+  schemes: ColorSchemeKeyword[];
 }
+/**
+ * A color scheme keyword, as used in the [color-scheme](https://drafts.csswg.org/css-color-adjust/#color-scheme-prop) property.
+ */
+export type ColorSchemeKeyword = "light" | "dark";
 /**
  * A known property with an unparsed value.
  *
@@ -9568,6 +10242,10 @@ export interface PageRule<D = Declaration> {
    * A list of page selectors.
    */
   selectors: PageSelector[];
+  /**
+   * The `size` descriptor, if any. When it is declared more than once, the last declaration wins, since they would override each other.
+   */
+  size?: PageSizeDescriptor | null;
 }
 /**
  * A [page margin rule](https://www.w3.org/TR/css-page-3/#margin-at-rules) rule.
@@ -9621,11 +10299,7 @@ export interface SupportsRule<D = Declaration, M = MediaQuery> {
 /**
  * A [@counter-style](https://drafts.csswg.org/css-counter-styles/#the-counter-style-rule) rule.
  */
-export interface CounterStyleRule<D = Declaration> {
-  /**
-   * Declarations in the `@counter-style` rule.
-   */
-  declarations: DeclarationBlock<D>;
+export interface CounterStyleRule {
   /**
    * The location of the rule in the source file.
    */
@@ -9634,7 +10308,201 @@ export interface CounterStyleRule<D = Declaration> {
    * The name of the counter style to declare.
    */
   name: String;
+  /**
+   * Declarations in the `@counter-style` rule.
+   */
+  properties: CounterStyleProperty[];
+}
+/**
+ * A property within an `@counter-style` rule.
+ *
+ * See [CounterStyleRule](CounterStyleRule).
+ */
+export type CounterStyleProperty =
+  | {
+      type: "system";
+      value: CounterSystem;
+    }
+  | {
+      type: "symbols";
+      value: CounterSymbol[];
+    }
+  | {
+      type: "additive-symbols";
+      value: AdditiveSymbol[];
+    }
+  | {
+      type: "negative";
+      value: Negative;
+    }
+  | {
+      type: "prefix";
+      value: CounterSymbol;
+    }
+  | {
+      type: "suffix";
+      value: CounterSymbol;
+    }
+  | {
+      type: "range";
+      value: CounterRange;
+    }
+  | {
+      type: "pad";
+      value: Pad;
+    }
+  | {
+      type: "fallback";
+      value: CounterStyle;
+    }
+  | {
+      type: "speak-as";
+      value: SpeakAs;
+    }
+  | {
+      type: "custom";
+      value: CustomProperty;
+    };
+/**
+ * A value for the [system](https://drafts.csswg.org/css-counter-styles/#counter-style-system) property in an `@counter-style` rule.
+ */
+export type CounterSystem =
+  | {
+      type: "cyclic";
+    }
+  | {
+      type: "numeric";
+    }
+  | {
+      type: "alphabetic";
+    }
+  | {
+      type: "symbolic";
+    }
+  | {
+      type: "additive";
+    }
+  | {
+      type: "fixed";
+      value: number;
+    }
+  | {
+      type: "extends";
+      value: CounterStyle;
+    };
+/**
+ * A [`<symbol>`](https://drafts.csswg.org/css-counter-styles/#typedef-symbol) within an `@counter-style` rule.
+ */
+export type CounterSymbol =
+  | {
+      type: "string";
+      value: String;
+    }
+  | {
+      type: "ident";
+      value: String;
+    }
+  | {
+      type: "image";
+      value: Image;
+    };
+/**
+ * A tuple within the [additive-symbols](https://drafts.csswg.org/css-counter-styles/#counter-style-symbols) property in an `@counter-style` rule.
+ */
+export interface AdditiveSymbol {
+  /**
+   * The symbol.
+   */
+  symbol: CounterSymbol;
+  /**
+   * The weight of the symbol.
+   */
+  weight: number;
+}
+/**
+ * A value for the [negative](https://drafts.csswg.org/css-counter-styles/#counter-style-negative) property in an `@counter-style` rule.
+ */
+export interface Negative {
+  /**
+   * The symbol to prepend to negative values.
+   */
+  prefix: CounterSymbol;
+  /**
+   * The symbol to append to negative values.
+   */
+  suffix?: CounterSymbol | null;
+}
+/**
+ * A value for the [range](https://drafts.csswg.org/css-counter-styles/#counter-style-range) property in an `@counter-style` rule.
+ */
+export type CounterRange =
+  | {
+      type: "auto";
+    }
+  | {
+      type: "ranges";
+      value: CounterRangeBounds[];
+    };
+/**
+ * A single range within the `range` property in an `@counter-style` rule.
+ */
+export interface CounterRangeBounds {
+  /**
+   * The upper bound of the range.
+   */
+  end: CounterRangeBound;
+  /**
+   * The lower bound of the range.
+   */
+  start: CounterRangeBound;
+}
+/**
+ * A bound of a range within the `range` property in an `@counter-style` rule.
+ */
+export type CounterRangeBound =
+  | {
+      type: "infinite";
+    }
+  | {
+      type: "integer";
+      value: number;
+    };
+/**
+ * A value for the [pad](https://drafts.csswg.org/css-counter-styles/#counter-style-pad) property in an `@counter-style` rule.
+ */
+export interface Pad {
+  /**
+   * The minimum length of the marker representation.
+   */
+  length: number;
+  /**
+   * The symbol used to pad the marker representation.
+   */
+  symbol: CounterSymbol;
 }
+/**
+ * A value for the [speak-as](https://drafts.csswg.org/css-counter-styles/#counter-style-speak-as) property in an `@counter-style` rule.
+ */
+export type SpeakAs =
+  | {
+      type: "auto";
+    }
+  | {
+      type: "bullets";
+    }
+  | {
+      type: "numbers";
+    }
+  | {
+      type: "words";
+    }
+  | {
+      type: "spell-out";
+    }
+  | {
+      type: "counter-style";
+      value: CounterStyle;
+    };
 /**
  * A [@namespace](https://drafts.csswg.org/css-namespaces/#declaration) rule.
  */
@@ -12,8 +12,16 @@ export interface TransformOptions<C extends CustomAtRules> {
   code: Uint8Array,
   /** Whether to enable minification. */
   minify?: boolean,
+  /** The indentation to use when not minifying: a number of spaces, or `"tab"`. Defaults to 2 spaces. */
+  indent?: number | 'tab',
+  /** The line ending to use when not minifying. Defaults to `"lf"`. */
+  lineEnding?: 'lf' | 'crlf',
+  /** Whether to write each top-level rule on its own line when minifying. */
+  newlineBetweenRules?: boolean,
   /** Whether to output a source map. */
   sourceMap?: boolean,
+  /** An optional `sourceRoot` to include in the output source map. */
+  sourceRoot?: string,
   /** An input source map to extend. */
   inputSourceMap?: string,
   /**
@@ -410,6 +418,13 @@ export interface ErrorLocation extends Location {
  */
 export declare function transform<C extends CustomAtRules>(options: TransformOptions<C>): TransformResult;
 
+/**
+ * Like `transform`, but accepts the options as a JSON string and returns the result as a JSON string.
+ * The `code` option must be a string, and the `code` and `map` fields of the result are strings.
+ * Since functions cannot be serialized, the `visitor` option is not supported.
+ */
+export declare function transformJson(options: string): string;
+
 export interface TransformAttributeOptions {
   /** The filename in which the style attribute appeared. Used for error messages and dependencies. */
   filename?: string,
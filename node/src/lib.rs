@@ -2,7 +2,7 @@
 #[global_allocator]
 static GLOBAL: jemallocator::Jemalloc = jemallocator::Jemalloc;
 
-use napi::{CallContext, JsObject, JsUnknown};
+use napi::{CallContext, JsObject, JsString, JsUnknown};
 use napi_derive::{js_function, module_exports};
 
 #[js_function(1)]
@@ -10,6 +10,11 @@ fn transform(ctx: CallContext) -> napi::Result<JsUnknown> {
   lightningcss_napi::transform(ctx)
 }
 
+#[js_function(1)]
+fn transform_json(ctx: CallContext) -> napi::Result<JsString> {
+  lightningcss_napi::transform_json(ctx)
+}
+
 #[js_function(1)]
 fn transform_style_attribute(ctx: CallContext) -> napi::Result<JsUnknown> {
   lightningcss_napi::transform_style_attribute(ctx)
@@ -29,6 +34,7 @@ pub fn bundle_async(ctx: CallContext) -> napi::Result<JsObject> {
 #[cfg_attr(not(target_arch = "wasm32"), module_exports)]
 fn init(mut exports: JsObject) -> napi::Result<()> {
   exports.create_named_method("transform", transform)?;
+  exports.create_named_method("transformJson", transform_json)?;
   exports.create_named_method("transformStyleAttribute", transform_style_attribute)?;
   exports.create_named_method("bundle", bundle)?;
   #[cfg(not(target_arch = "wasm32"))]
@@ -2,18 +2,18 @@ import { test } from 'uvu';
 import * as assert from 'uvu/assert';
 import {webcrypto as crypto} from 'node:crypto';
 
-let transform, Features;
+let transform, transformJson, Features;
 if (process.env.TEST_WASM === 'node') {
-  ({transform, Features} = await import('../../wasm/wasm-node.mjs'));
+  ({transform, transformJson, Features} = await import('../../wasm/wasm-node.mjs'));
 } else if (process.env.TEST_WASM === 'browser') {
   // Define crypto globally for old node.
   // @ts-ignore
   globalThis.crypto ??= crypto;
   let wasm = await import('../../wasm/index.mjs');
   await wasm.default();
-  ({transform, Features} = wasm);
+  ({transform, transformJson, Features} = wasm);
 } else {
-  ({transform, Features} = await import('../index.mjs'));
+  ({transform, transformJson, Features} = await import('../index.mjs'));
 }
 
 test('can enable non-standard syntax', () => {
@@ -68,4 +68,43 @@ test('can disable prefixing', () => {
   assert.equal(res.code.toString(), '.foo{user-select:none}');
 });
 
+test('can configure printer output', () => {
+  let res = transform({
+    filename: 'test.css',
+    code: Buffer.from('.foo { color: red } .bar { color: green }'),
+    indent: 'tab',
+    lineEnding: 'crlf'
+  });
+
+  assert.equal(res.code.toString(), '.foo {\r\n\tcolor: red;\r\n}\r\n\r\n.bar {\r\n\tcolor: green;\r\n}\r\n');
+
+  res = transform({
+    filename: 'test.css',
+    code: Buffer.from('.foo { color: red } @media print { .bar { color: green } .baz { color: blue } }'),
+    minify: true,
+    newlineBetweenRules: true
+  });
+
+  assert.equal(res.code.toString(), '.foo{color:red}\n@media print{.bar{color:green}.baz{color:#00f}}');
+});
+
+test('can transform with JSON options', () => {
+  let res = JSON.parse(transformJson(JSON.stringify({
+    filename: 'test.css',
+    code: '.foo { color: red }',
+    minify: true,
+    cssModules: true
+  })));
+
+  assert.equal(res.code, '.EgL3uq_foo{color:red}');
+  assert.equal(res.map, null);
+  assert.equal(res.exports, {
+    foo: {
+      name: 'EgL3uq_foo',
+      composes: [],
+      isReferenced: false
+    }
+  });
+});
+
 test.run();
@@ -71,6 +71,20 @@ prefixes['any-pseudo'] = {
     })
 }
 
+// No browser supports the standard line-clamp property yet, only -webkit-line-clamp.
+prefixes['line-clamp'] = {
+  browsers: Object.entries(mdn.css.properties['line-clamp'].__compat.support)
+    .flatMap(([key, value]) => {
+      key = MDN_BROWSER_MAPPING[key] || key;
+      let prefixed = [].concat(value).find(v => v.prefix === '-webkit-')?.version_added;
+      if (prefixed && latestBrowserVersions[key]) {
+        return [`${key} ${prefixed}`, `${key} ${latestBrowserVersions[key]}`];
+      }
+
+      return [];
+    })
+};
+
 // Safari 4-13 supports background-clip: text with a prefix.
 prefixes['background-clip'].browsers.push('safari 13');
 prefixes['background-clip'].browsers.push('ios_saf 4', 'ios_saf 13');
@@ -195,7 +209,8 @@ let cssFeatures = [
   'css-has',
   'font-family-system-ui',
   'extended-system-fonts',
-  'calc'
+  'calc',
+  'css-featurequeries'
 ];
 
 let cssFeatureMappings = {
@@ -206,7 +221,8 @@ let cssFeatureMappings = {
   'css-matches-pseudo': 'IsSelector',
   'css-sel2': 'Selectors2',
   'css-sel3': 'Selectors3',
-  'calc': 'CalcFunction'
+  'calc': 'CalcFunction',
+  'css-featurequeries': 'SupportsRule'
 };
 
 let cssFeatureOverrides = {
@@ -294,6 +310,14 @@ let mdnFeatures = {
   spaceSeparatedColorNotation: mdn.css.types.color.rgb.space_separated_parameters.__compat.support,
   textDecorationThicknessPercent: mdn.css.properties['text-decoration-thickness'].percentage.__compat.support,
   textDecorationThicknessShorthand: mdn.css.properties['text-decoration'].includes_thickness.__compat.support,
+  displayFlex: mdn.css.properties.display.flex.__compat.support,
+  displayGrid: mdn.css.properties.display.grid.__compat.support,
+  contentVisibility: mdn.css.properties['content-visibility'].__compat.support,
+  textWrapShorthand: mdn.css.properties['text-wrap-mode'].__compat.support,
+  textWrapStyle: mdn.css.properties['text-wrap-style'].__compat.support,
+  whiteSpaceShorthand: mdn.css.properties['white-space-collapse'].__compat.support,
+  individualTransforms: mdn.css.properties.translate.__compat.support,
+  containerQueries: mdn.css['at-rules'].container.__compat.support,
   cue: mdn.css.selectors.cue.__compat.support,
   cueFunction: mdn.css.selectors.cue.selector_argument.__compat.support,
   anyPseudo: Object.fromEntries(
@@ -314,6 +338,20 @@ let mdnFeatures = {
   ),
   partPseudo: mdn.css.selectors.part.__compat.support,
   imageSet: mdn.css.types.image['image-set'].__compat.support,
+  // Browsers that support the resolution media feature before dppx only support dpi and dpcm.
+  dppxResolutionUnit: {
+    chrome: { version_added: '29' },
+    chrome_android: { version_added: '29' },
+    edge: { version_added: '12' },
+    firefox: { version_added: '16' },
+    firefox_android: { version_added: '16' },
+    opera: { version_added: '16' },
+    safari: { version_added: '16' },
+    safari_ios: { version_added: '16' },
+    samsunginternet_android: { version_added: '2.0' },
+    webview_android: { version_added: '29' },
+    ie: { version_added: false },
+  },
   xResolutionUnit: mdn.css.types.resolution.x.__compat.support,
   nthChildOf: mdn.css.selectors['nth-child'].of_syntax.__compat.support,
   minFunction: mdn.css.types.min.__compat.support,
@@ -335,7 +373,9 @@ let mdnFeatures = {
   lightDark: mdn.css.types.color['light-dark'].__compat.support,
   accentSystemColor: mdn.css.types.color['system-color'].accentcolor_accentcolortext.__compat.support,
   animationTimelineShorthand: mdn.css.properties.animation['animation-timeline_included'].__compat.support,
+  transitionBehavior: mdn.css.properties['transition-behavior'].__compat.support,
   viewTransition: mdn.css.selectors['view-transition'].__compat.support,
+  viewTransitionAtRule: mdn.css['at-rules']['view-transition'].__compat.support,
   detailsContent: mdn.css.selectors['details-content'].__compat.support,
   targetText: mdn.css.selectors['target-text'].__compat.support,
   picker: mdn.css.selectors.picker.__compat.support,
@@ -463,6 +503,18 @@ addValue(compat, {
   ios_saf: parseVersion('10.3')
 }, 'LangSelectorList');
 
+// Unquoted keywords in the `format()` function of `@font-face` `src` shipped alongside `tech()`.
+addValue(compat, {
+  chrome: parseVersion('108'),
+  edge: parseVersion('108'),
+  firefox: parseVersion('107'),
+  opera: parseVersion('94'),
+  safari: parseVersion('17'),
+  ios_saf: parseVersion('17'),
+  samsung: parseVersion('21'),
+  android: parseVersion('108')
+}, 'FontFormatKeywords');
+
 let prefixMapping = {
   webkit: 'WebKit',
   moz: 'Moz',
@@ -640,6 +692,10 @@ pub enum Feature {
 }
 
 impl Feature {
+  pub const ALL: &'static [Feature] = &[
+    ${[...compat.keys()].flat().map(enumify).sort().map(f => `Feature::${f}`).join(',\n    ')}
+  ];
+
   pub fn is_compatible(&self, browsers: Browsers) -> bool {
     match self {
       ${[...compat].map(([features, supportedBrowsers]) =>
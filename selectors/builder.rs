@@ -17,11 +17,12 @@
 //! is non-trivial. This module encapsulates those details and presents an
 //! easy-to-use API for the parser.
 
-use crate::parser::{Combinator, Component, SelectorImpl};
+use crate::parser::{Combinator, Component, Selector, SelectorImpl};
 use crate::sink::Push;
 // use servo_arc::{Arc, HeaderWithLength, ThinArc};
 use smallvec::{self, SmallVec};
 use std::cmp;
+use std::fmt;
 use std::iter;
 use std::ops::{Add, AddAssign};
 use std::ptr;
@@ -229,12 +230,36 @@ impl SpecificityAndFlags {
 
 const MAX_10BIT: u32 = (1u32 << 10) - 1;
 
-#[derive(Clone, Copy, Default, Eq, Ord, PartialEq, PartialOrd)]
-struct Specificity {
-  id_selectors: u32,
-  class_like_selectors: u32,
-  element_selectors: u32,
+/// The specificity of a selector, split into its id, class-like, and element components.
+///
+/// Specificities are ordered by comparing each component in turn, and are formatted
+/// using the conventional `(a,b,c)` notation.
+#[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Specificity {
+  /// The number of id selectors.
+  pub id_selectors: u32,
+  /// The number of class selectors, attribute selectors, and pseudo-classes.
+  pub class_like_selectors: u32,
+  /// The number of type selectors and pseudo-elements.
+  pub element_selectors: u32,
+}
+
+impl fmt::Display for Specificity {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(
+      f,
+      "({},{},{})",
+      self.id_selectors, self.class_like_selectors, self.element_selectors
+    )
+  }
 }
+
+impl fmt::Debug for Specificity {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    fmt::Display::fmt(self, f)
+  }
+}
+
 impl Add for Specificity {
   type Output = Specificity;
 
@@ -336,26 +361,20 @@ where
         //     specificity of a regular pseudo-class with that of its
         //     selector argument S.
         specificity.class_like_selectors += 1;
-        let mut max = 0;
-        for selector in nth_of_data.selectors() {
-          max = std::cmp::max(selector.specificity(), max);
-        }
-        *specificity += Specificity::from(max);
+        *specificity += max_selector_list_specificity(nth_of_data.selectors());
       }
-      Component::Negation(ref list) | Component::Is(ref list) | Component::Any(_, ref list) => {
+      Component::Negation(ref list)
+      | Component::Is(ref list)
+      | Component::Has(ref list)
+      | Component::Any(_, ref list) => {
         // https://drafts.csswg.org/selectors/#specificity-rules:
         //
-        //     The specificity of an :is() pseudo-class is replaced by the
-        //     specificity of the most specific complex selector in its
-        //     selector list argument.
-        let mut max = 0;
-        for selector in &**list {
-          max = std::cmp::max(selector.specificity(), max);
-        }
-        *specificity += Specificity::from(max);
+        //     The specificity of an :is(), :not(), or :has() pseudo-class
+        //     is replaced by the specificity of the most specific complex
+        //     selector in its selector list argument.
+        *specificity += max_selector_list_specificity(list);
       }
       Component::Where(..)
-      | Component::Has(..)
       | Component::ExplicitUniversalType
       | Component::ExplicitAnyNamespace
       | Component::ExplicitNoNamespace
@@ -369,6 +388,14 @@ where
     }
   }
 
+  fn max_selector_list_specificity<'i, Impl>(list: &[Selector<'i, Impl>]) -> Specificity
+  where
+    Impl: SelectorImpl<'i>,
+  {
+    let max = list.iter().map(|selector| selector.specificity()).max().unwrap_or(0);
+    Specificity::from(max)
+  }
+
   let mut specificity = Default::default();
   for simple_selector in iter {
     simple_selector_specificity(&simple_selector, &mut specificity);
@@ -26,6 +26,7 @@ pub mod visitor;
 #[cfg(all(feature = "serde"))]
 mod serialization;
 
+pub use crate::builder::Specificity;
 pub use crate::nth_index_cache::NthIndexCache;
 pub use crate::parser::{Parser, SelectorImpl, SelectorList};
 pub use crate::tree::{Element, OpaqueElement};
@@ -6,7 +6,7 @@ use crate::attr::{AttrSelectorOperator, AttrSelectorWithOptionalNamespace};
 use crate::attr::{NamespaceConstraint, ParsedAttrSelectorOperation};
 use crate::attr::{ParsedCaseSensitivity, SELECTOR_WHITESPACE};
 use crate::bloom::BLOOM_HASH_MASK;
-use crate::builder::{SelectorBuilder, SelectorFlags, SpecificityAndFlags};
+use crate::builder::{SelectorBuilder, SelectorFlags, Specificity, SpecificityAndFlags};
 use crate::context::QuirksMode;
 use crate::sink::Push;
 pub use crate::visitor::SelectorVisitor;
@@ -17,6 +17,7 @@ use cssparser::{Parser as CssParser, ToCss, Token};
 use precomputed_hash::PrecomputedHash;
 use smallvec::{smallvec, SmallVec};
 use std::borrow::Borrow;
+use std::collections::BTreeMap;
 use std::fmt::{self, Debug};
 use std::iter::Rev;
 use std::slice;
@@ -552,6 +553,40 @@ impl<'i, Impl: SelectorImpl<'i>> SelectorList<'i, Impl> {
   pub fn from_vec(v: Vec<Selector<'i, Impl>>) -> Self {
     SelectorList(SmallVec::from_vec(v))
   }
+
+  /// Returns whether any selector in the list uses the given combinator, including
+  /// selectors nested in pseudo-classes such as `:is()` and `:has()`.
+  pub fn contains_combinator(&self, combinator: Combinator) -> bool {
+    self.0.iter().any(|selector| selector.contains_combinator(combinator))
+  }
+
+  /// Returns whether any selector in the list uses the deprecated `/deep/` combinator.
+  pub fn uses_deep_combinator(&self) -> bool {
+    self.contains_combinator(Combinator::Deep)
+  }
+
+  /// Returns the maximum number of combinators in any selector in the list, e.g. 2 for
+  /// `.a > .b .c`. Pseudo-elements are not counted, and neither are selectors nested
+  /// in pseudo-classes such as `:is()`.
+  pub fn combinator_depth(&self) -> usize {
+    self
+      .0
+      .iter()
+      .map(|selector| {
+        selector
+          .iter_raw_match_order()
+          .filter(|component| match component {
+            Component::Combinator(combinator) => !matches!(
+              combinator,
+              Combinator::PseudoElement | Combinator::SlotAssignment | Combinator::Part
+            ),
+            _ => false,
+          })
+          .count()
+      })
+      .max()
+      .unwrap_or(0)
+  }
 }
 
 impl<'i, Impl: SelectorImpl<'i>> From<Selector<'i, Impl>> for SelectorList<'i, Impl> {
@@ -696,6 +731,18 @@ pub fn namespace_empty_string<'i, Impl: SelectorImpl<'i>>() -> Impl::NamespaceUr
   Impl::NamespaceUrl::default()
 }
 
+/// Partitions the given selectors into buckets by specificity, in order from least
+/// to most specific. Selectors within each bucket keep their original order.
+pub fn group_by_specificity<'a, 'i, Impl: SelectorImpl<'i>>(
+  selectors: &'a [Selector<'i, Impl>],
+) -> BTreeMap<Specificity, Vec<&'a Selector<'i, Impl>>> {
+  let mut groups: BTreeMap<Specificity, Vec<_>> = BTreeMap::new();
+  for selector in selectors {
+    groups.entry(selector.specificity_components()).or_default().push(selector);
+  }
+  groups
+}
+
 /// A Selector stores a sequence of simple selectors and combinators. The
 /// iterator classes allow callers to iterate at either the raw sequence level or
 /// at the level of sequences of simple selectors separated by combinators. Most
@@ -733,6 +780,13 @@ impl<'i, Impl: SelectorImpl<'i>> Selector<'i, Impl> {
     self.0.specificity()
   }
 
+  /// Returns the specificity of this selector, split into its
+  /// (id, class, element) components.
+  #[inline]
+  pub fn specificity_components(&self) -> Specificity {
+    Specificity::from(self.specificity())
+  }
+
   #[inline]
   pub fn has_pseudo_element(&self) -> bool {
     self.0.has_pseudo_element()
@@ -815,6 +869,25 @@ impl<'i, Impl: SelectorImpl<'i>> Selector<'i, Impl> {
     })
   }
 
+  /// Returns whether the selector uses the given combinator, including selectors
+  /// nested in pseudo-classes such as `:is()` and `:has()`.
+  pub fn contains_combinator(&self, combinator: Combinator) -> bool {
+    self.iter_raw_match_order().any(|component| match component {
+      Component::Combinator(c) => *c == combinator,
+      Component::Negation(list)
+      | Component::Is(list)
+      | Component::Where(list)
+      | Component::Has(list)
+      | Component::Any(_, list) => list.iter().any(|selector| selector.contains_combinator(combinator)),
+      Component::NthOf(nth_of_data) => nth_of_data
+        .selectors()
+        .iter()
+        .any(|selector| selector.contains_combinator(combinator)),
+      Component::Slotted(selector) | Component::Host(Some(selector)) => selector.contains_combinator(combinator),
+      _ => false,
+    })
+  }
+
   #[inline]
   pub fn has_combinator(&self) -> bool {
     self
@@ -2931,7 +3004,7 @@ where
     parser,
     input,
     &mut child_state,
-    ParseErrorRecovery::IgnoreInvalidSelector,
+    ParseErrorRecovery::DiscardList,
     NestingRequirement::None,
   )?;
   Ok(Component::NthOf(NthOfSelectorData::new(
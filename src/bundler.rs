@@ -91,6 +91,10 @@ struct BundleStyleSheet<'i, 'o, T> {
 
 /// A trait to provide the contents of files to a Bundler.
 ///
+/// This is the hook used to resolve `@import` rules: each import specifier is passed
+/// to [resolve](SourceProvider::resolve) along with the path of the importing file,
+/// and the resulting path is then [read](SourceProvider::read) and parsed.
+///
 /// See [FileProvider](FileProvider) for an implementation that uses the
 /// file system.
 pub trait SourceProvider: Send + Sync {
@@ -617,18 +621,25 @@ where
   }
 
   fn order(&mut self) {
-    process(self.stylesheets.get_mut().unwrap(), 0, &mut HashSet::new());
+    process(
+      self.stylesheets.get_mut().unwrap(),
+      0,
+      &mut HashSet::new(),
+      &mut HashSet::new(),
+    );
 
     fn process<'i, T>(
       stylesheets: &mut Vec<BundleStyleSheet<'i, '_, T>>,
       source_index: u32,
       visited: &mut HashSet<u32>,
+      ancestors: &mut HashSet<u32>,
     ) {
       if visited.contains(&source_index) {
         return;
       }
 
       visited.insert(source_index);
+      ancestors.insert(source_index);
 
       let mut dep_index = 0;
       for i in 0..stylesheets[source_index as usize].css_modules_deps.len() {
@@ -639,7 +650,7 @@ where
         if !visited.contains(&dep_source_index) {
           resolved.parent_dep_index = dep_index;
           resolved.parent_source_index = source_index;
-          process(stylesheets, dep_source_index, visited);
+          process(stylesheets, dep_source_index, visited, ancestors);
         }
 
         dep_index += 1;
@@ -647,15 +658,22 @@ where
 
       for i in 0..stylesheets[source_index as usize].dependencies.len() {
         let dep_source_index = stylesheets[source_index as usize].dependencies[i];
-        let resolved = &mut stylesheets[dep_source_index as usize];
 
-        // In browsers, every instance of an @import is evaluated, so we preserve the last.
-        resolved.parent_dep_index = dep_index;
-        resolved.parent_source_index = source_index;
+        // Browsers ignore @import rules that would create a cycle. Leaving the parent
+        // untouched means the import is dropped rather than inlined during concatenation.
+        if !ancestors.contains(&dep_source_index) {
+          // In browsers, every instance of an @import is evaluated, so we preserve the last.
+          let resolved = &mut stylesheets[dep_source_index as usize];
+          resolved.parent_dep_index = dep_index;
+          resolved.parent_source_index = source_index;
+
+          process(stylesheets, dep_source_index, visited, ancestors);
+        }
 
-        process(stylesheets, dep_source_index, visited);
         dep_index += 1;
       }
+
+      ancestors.remove(&source_index);
     }
   }
 
@@ -706,6 +724,13 @@ where
             let layer = std::mem::replace(rule, CssRule::Ignored);
             dest.push(layer);
           }
+          CssRule::Unknown(unknown) if unknown.name.eq_ignore_ascii_case("charset") => {
+            // A @charset rule is only valid at the start of the bundle, so only the entry's is kept.
+            let charset = std::mem::replace(rule, CssRule::Ignored);
+            if source_index == 0 {
+              dest.push(charset);
+            }
+          }
           CssRule::Ignored => {}
           _ => break,
         }
@@ -1040,6 +1065,37 @@ mod tests {
     "#}
     );
 
+    let res = bundle(
+      TestProvider {
+        map: fs! {
+          "/a.css": r#"
+          @charset "ISO-8859-1";
+          @import "b.css";
+          .a { color: red }
+        "#,
+          "/b.css": r#"
+          @charset "Shift_JIS";
+          .b { color: green }
+        "#
+        },
+      },
+      "/a.css",
+    );
+    assert_eq!(
+      res,
+      indoc! { r#"
+      @charset "ISO-8859-1";
+
+      .b {
+        color: green;
+      }
+
+      .a {
+        color: red;
+      }
+    "#}
+    );
+
     let res = bundle(
       TestProvider {
         map: fs! {
@@ -1100,6 +1156,39 @@ mod tests {
     "#}
     );
 
+    let res = bundle(
+      TestProvider {
+        map: fs! {
+          "/a.css": r#"
+          @import "b.css" layer(foo) supports(display: grid) print;
+          .a { color: red }
+        "#,
+          "/b.css": r#"
+          .b { color: green }
+        "#
+        },
+      },
+      "/a.css",
+    );
+    assert_eq!(
+      res,
+      indoc! { r#"
+      @supports (display: grid) {
+        @media print {
+          @layer foo {
+            .b {
+              color: green;
+            }
+          }
+        }
+      }
+
+      .a {
+        color: red;
+      }
+    "#}
+    );
+
     let res = bundle(
       TestProvider {
         map: fs! {
@@ -1777,6 +1866,85 @@ mod tests {
     // }, "/a.css");
   }
 
+  #[test]
+  fn test_circular_imports() {
+    let res = bundle(
+      TestProvider {
+        map: fs! {
+          "/a.css": r#"
+          @import "b.css";
+          .a { color: red }
+        "#,
+          "/b.css": r#"
+          @import "c.css" print;
+          .b { color: green }
+        "#,
+          "/c.css": r#"
+          @import "a.css";
+          @import "c.css";
+          .c { color: yellow }
+        "#
+        },
+      },
+      "/a.css",
+    );
+    assert_eq!(
+      res,
+      indoc! { r#"
+      @media print {
+        .c {
+          color: #ff0;
+        }
+      }
+
+      .b {
+        color: green;
+      }
+
+      .a {
+        color: red;
+      }
+    "#}
+    );
+
+    let res = bundle(
+      TestProvider {
+        map: fs! {
+          "/a.css": r#"
+          @import "b.css";
+          @import "c.css";
+          .a { color: red }
+        "#,
+          "/b.css": r#"
+          @import "c.css";
+          .b { color: green }
+        "#,
+          "/c.css": r#"
+          @import "b.css";
+          .c { color: yellow }
+        "#
+        },
+      },
+      "/a.css",
+    );
+    assert_eq!(
+      res,
+      indoc! { r#"
+      .b {
+        color: green;
+      }
+
+      .c {
+        color: #ff0;
+      }
+
+      .a {
+        color: red;
+      }
+    "#}
+    );
+  }
+
   #[test]
   fn test_css_module() {
     macro_rules! map {
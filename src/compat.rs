@@ -36,7 +36,9 @@ pub enum Feature {
   ClampFunction,
   ColorFunction,
   ConicGradient,
+  ContainerQueries,
   ContainerQueryLengthUnits,
+  ContentVisibility,
   Cue,
   CueFunction,
   CustomMediaQueries,
@@ -47,10 +49,13 @@ pub enum Feature {
   DevanagariListStyleType,
   Dialog,
   DirSelector,
+  DisplayFlex,
+  DisplayGrid,
   DiscListStyleType,
   DisclosureClosedListStyleType,
   DisclosureOpenListStyleType,
   DoublePositionGradients,
+  DppxResolutionUnit,
   EmUnit,
   EthiopicAbegedeAmEtListStyleType,
   EthiopicAbegedeGezListStyleType,
@@ -76,6 +81,7 @@ pub enum Feature {
   FocusVisible,
   FocusWithin,
   FontFamilySystemUi,
+  FontFormatKeywords,
   FontSizeRem,
   FontSizeXXXLarge,
   FontStretchPercentage,
@@ -100,6 +106,7 @@ pub enum Feature {
   ImageSet,
   InOutOfRange,
   IndeterminatePseudo,
+  IndividualTransforms,
   IsSelector,
   JapaneseFormalListStyleType,
   JapaneseInformalListStyleType,
@@ -194,12 +201,15 @@ pub enum Feature {
   StatePseudoClass,
   StretchSize,
   StringListStyleType,
+  SupportsRule,
   SymbolsListStyleType,
   TamilListStyleType,
   TargetText,
   TeluguListStyleType,
   TextDecorationThicknessPercent,
   TextDecorationThicknessShorthand,
+  TextWrapShorthand,
+  TextWrapStyle,
   ThaiListStyleType,
   TibetanListStyleType,
   TigreListStyleType,
@@ -209,6 +219,7 @@ pub enum Feature {
   TigrinyaEtListStyleType,
   TradChineseFormalListStyleType,
   TradChineseInformalListStyleType,
+  TransitionBehavior,
   UpperAlphaListStyleType,
   UpperArmenianListStyleType,
   UpperHexadecimalListStyleType,
@@ -219,6 +230,7 @@ pub enum Feature {
   VhUnit,
   ViUnit,
   ViewTransition,
+  ViewTransitionAtRule,
   ViewportPercentageUnitsDynamic,
   ViewportPercentageUnitsLarge,
   ViewportPercentageUnitsSmall,
@@ -226,10 +238,249 @@ pub enum Feature {
   VminUnit,
   VwUnit,
   WebkitFillAvailableSize,
+  WhiteSpaceShorthand,
   XResolutionUnit,
 }
 
 impl Feature {
+  pub const ALL: &'static [Feature] = &[
+    Feature::AbsFunction,
+    Feature::AccentSystemColor,
+    Feature::AfarListStyleType,
+    Feature::AmharicAbegedeListStyleType,
+    Feature::AmharicListStyleType,
+    Feature::AnchorSizeSize,
+    Feature::AnimationTimelineShorthand,
+    Feature::AnyLink,
+    Feature::AnyPseudo,
+    Feature::ArabicIndicListStyleType,
+    Feature::ArmenianListStyleType,
+    Feature::AsterisksListStyleType,
+    Feature::AutoSize,
+    Feature::Autofill,
+    Feature::BengaliListStyleType,
+    Feature::BinaryListStyleType,
+    Feature::BorderImageRepeatRound,
+    Feature::BorderImageRepeatSpace,
+    Feature::CalcFunction,
+    Feature::CambodianListStyleType,
+    Feature::CapUnit,
+    Feature::CaseInsensitive,
+    Feature::ChUnit,
+    Feature::Checkmark,
+    Feature::CircleListStyleType,
+    Feature::CjkDecimalListStyleType,
+    Feature::CjkEarthlyBranchListStyleType,
+    Feature::CjkHeavenlyStemListStyleType,
+    Feature::ClampFunction,
+    Feature::ColorFunction,
+    Feature::ConicGradient,
+    Feature::ContainerQueries,
+    Feature::ContainerQueryLengthUnits,
+    Feature::ContentVisibility,
+    Feature::Cue,
+    Feature::CueFunction,
+    Feature::CustomMediaQueries,
+    Feature::DecimalLeadingZeroListStyleType,
+    Feature::DecimalListStyleType,
+    Feature::DefaultPseudo,
+    Feature::DetailsContent,
+    Feature::DevanagariListStyleType,
+    Feature::Dialog,
+    Feature::DirSelector,
+    Feature::DisplayFlex,
+    Feature::DisplayGrid,
+    Feature::DiscListStyleType,
+    Feature::DisclosureClosedListStyleType,
+    Feature::DisclosureOpenListStyleType,
+    Feature::DoublePositionGradients,
+    Feature::DppxResolutionUnit,
+    Feature::EmUnit,
+    Feature::EthiopicAbegedeAmEtListStyleType,
+    Feature::EthiopicAbegedeGezListStyleType,
+    Feature::EthiopicAbegedeListStyleType,
+    Feature::EthiopicAbegedeTiErListStyleType,
+    Feature::EthiopicAbegedeTiEtListStyleType,
+    Feature::EthiopicHalehameAaErListStyleType,
+    Feature::EthiopicHalehameAaEtListStyleType,
+    Feature::EthiopicHalehameAmEtListStyleType,
+    Feature::EthiopicHalehameGezListStyleType,
+    Feature::EthiopicHalehameOmEtListStyleType,
+    Feature::EthiopicHalehameSidEtListStyleType,
+    Feature::EthiopicHalehameSoEtListStyleType,
+    Feature::EthiopicHalehameTigListStyleType,
+    Feature::EthiopicListStyleType,
+    Feature::EthiopicNumericListStyleType,
+    Feature::ExUnit,
+    Feature::ExtendedSystemFonts,
+    Feature::FirstLetter,
+    Feature::FirstLine,
+    Feature::FitContentFunctionSize,
+    Feature::FitContentSize,
+    Feature::FocusVisible,
+    Feature::FocusWithin,
+    Feature::FontFamilySystemUi,
+    Feature::FontFormatKeywords,
+    Feature::FontSizeRem,
+    Feature::FontSizeXXXLarge,
+    Feature::FontStretchPercentage,
+    Feature::FontStyleObliqueAngle,
+    Feature::FontWeightNumber,
+    Feature::FootnotesListStyleType,
+    Feature::FormValidation,
+    Feature::Fullscreen,
+    Feature::Gencontent,
+    Feature::GeorgianListStyleType,
+    Feature::GradientInterpolationHints,
+    Feature::GrammarError,
+    Feature::GujaratiListStyleType,
+    Feature::GurmukhiListStyleType,
+    Feature::HasSelector,
+    Feature::HebrewListStyleType,
+    Feature::HexAlphaColors,
+    Feature::HiraganaIrohaListStyleType,
+    Feature::HiraganaListStyleType,
+    Feature::HypotFunction,
+    Feature::IcUnit,
+    Feature::ImageSet,
+    Feature::InOutOfRange,
+    Feature::IndeterminatePseudo,
+    Feature::IndividualTransforms,
+    Feature::IsSelector,
+    Feature::JapaneseFormalListStyleType,
+    Feature::JapaneseInformalListStyleType,
+    Feature::KannadaListStyleType,
+    Feature::KatakanaIrohaListStyleType,
+    Feature::KatakanaListStyleType,
+    Feature::KhmerListStyleType,
+    Feature::KoreanHangulFormalListStyleType,
+    Feature::KoreanHanjaFormalListStyleType,
+    Feature::KoreanHanjaInformalListStyleType,
+    Feature::LabColors,
+    Feature::LangSelectorList,
+    Feature::LaoListStyleType,
+    Feature::LhUnit,
+    Feature::LightDark,
+    Feature::LinearGradient,
+    Feature::LogicalBorderRadius,
+    Feature::LogicalBorderShorthand,
+    Feature::LogicalBorders,
+    Feature::LogicalInset,
+    Feature::LogicalMargin,
+    Feature::LogicalMarginShorthand,
+    Feature::LogicalPadding,
+    Feature::LogicalPaddingShorthand,
+    Feature::LogicalSize,
+    Feature::LogicalTextAlign,
+    Feature::LowerAlphaListStyleType,
+    Feature::LowerArmenianListStyleType,
+    Feature::LowerGreekListStyleType,
+    Feature::LowerHexadecimalListStyleType,
+    Feature::LowerLatinListStyleType,
+    Feature::LowerNorwegianListStyleType,
+    Feature::LowerRomanListStyleType,
+    Feature::MalayalamListStyleType,
+    Feature::MarkerPseudo,
+    Feature::MaxContentSize,
+    Feature::MaxFunction,
+    Feature::MediaIntervalSyntax,
+    Feature::MediaRangeSyntax,
+    Feature::MinContentSize,
+    Feature::MinFunction,
+    Feature::ModFunction,
+    Feature::MongolianListStyleType,
+    Feature::MyanmarListStyleType,
+    Feature::Namespaces,
+    Feature::Nesting,
+    Feature::NoneListStyleType,
+    Feature::NotSelectorList,
+    Feature::NthChildOf,
+    Feature::OctalListStyleType,
+    Feature::OklabColors,
+    Feature::OptionalPseudo,
+    Feature::OriyaListStyleType,
+    Feature::OromoListStyleType,
+    Feature::OverflowShorthand,
+    Feature::P3Colors,
+    Feature::PartPseudo,
+    Feature::PersianListStyleType,
+    Feature::Picker,
+    Feature::PickerIcon,
+    Feature::PlaceContent,
+    Feature::PlaceItems,
+    Feature::PlaceSelf,
+    Feature::Placeholder,
+    Feature::PlaceholderShown,
+    Feature::QUnit,
+    Feature::RadialGradient,
+    Feature::RcapUnit,
+    Feature::RchUnit,
+    Feature::ReadOnlyWrite,
+    Feature::RemFunction,
+    Feature::RemUnit,
+    Feature::RepeatingConicGradient,
+    Feature::RepeatingLinearGradient,
+    Feature::RepeatingRadialGradient,
+    Feature::RexUnit,
+    Feature::RicUnit,
+    Feature::RlhUnit,
+    Feature::RoundFunction,
+    Feature::Selection,
+    Feature::Selectors2,
+    Feature::Selectors3,
+    Feature::Shadowdomv1,
+    Feature::SidamaListStyleType,
+    Feature::SignFunction,
+    Feature::SimpChineseFormalListStyleType,
+    Feature::SimpChineseInformalListStyleType,
+    Feature::SomaliListStyleType,
+    Feature::SpaceSeparatedColorNotation,
+    Feature::SpellingError,
+    Feature::SquareListStyleType,
+    Feature::StatePseudoClass,
+    Feature::StretchSize,
+    Feature::StringListStyleType,
+    Feature::SupportsRule,
+    Feature::SymbolsListStyleType,
+    Feature::TamilListStyleType,
+    Feature::TargetText,
+    Feature::TeluguListStyleType,
+    Feature::TextDecorationThicknessPercent,
+    Feature::TextDecorationThicknessShorthand,
+    Feature::TextWrapShorthand,
+    Feature::TextWrapStyle,
+    Feature::ThaiListStyleType,
+    Feature::TibetanListStyleType,
+    Feature::TigreListStyleType,
+    Feature::TigrinyaErAbegedeListStyleType,
+    Feature::TigrinyaErListStyleType,
+    Feature::TigrinyaEtAbegedeListStyleType,
+    Feature::TigrinyaEtListStyleType,
+    Feature::TradChineseFormalListStyleType,
+    Feature::TradChineseInformalListStyleType,
+    Feature::TransitionBehavior,
+    Feature::UpperAlphaListStyleType,
+    Feature::UpperArmenianListStyleType,
+    Feature::UpperHexadecimalListStyleType,
+    Feature::UpperLatinListStyleType,
+    Feature::UpperNorwegianListStyleType,
+    Feature::UpperRomanListStyleType,
+    Feature::VbUnit,
+    Feature::VhUnit,
+    Feature::ViUnit,
+    Feature::ViewTransition,
+    Feature::ViewTransitionAtRule,
+    Feature::ViewportPercentageUnitsDynamic,
+    Feature::ViewportPercentageUnitsLarge,
+    Feature::ViewportPercentageUnitsSmall,
+    Feature::VmaxUnit,
+    Feature::VminUnit,
+    Feature::VwUnit,
+    Feature::WebkitFillAvailableSize,
+    Feature::WhiteSpaceShorthand,
+    Feature::XResolutionUnit,
+  ];
+
   pub fn is_compatible(&self, browsers: Browsers) -> bool {
     match self {
       Feature::Selectors2 => {
@@ -1643,6 +1894,51 @@ impl Feature {
           return false;
         }
       }
+      Feature::SupportsRule => {
+        if let Some(version) = browsers.edge {
+          if version < 786432 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 1441792 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.chrome {
+          if version < 1835008 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 589824 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 786688 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 589824 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 9371648 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 262144 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
       Feature::CustomMediaQueries | Feature::FitContentFunctionSize => return false,
       Feature::DoublePositionGradients => {
         if let Some(version) = browsers.chrome {
@@ -2544,6 +2840,186 @@ impl Feature {
           return false;
         }
       }
+      Feature::TextWrapShorthand => {
+        if let Some(version) = browsers.chrome {
+          if version < 8519680 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 8519680 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 8126464 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 7602176 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1179648 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1179648 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1835008 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 8519680 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::TextWrapStyle => {
+        if let Some(version) = browsers.chrome {
+          if version < 8519680 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 8519680 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 8126464 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 7536640 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1115392 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1115392 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1835008 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 8519680 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::IndividualTransforms => {
+        if let Some(version) = browsers.chrome {
+          if version < 6815744 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 6815744 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 4718592 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 5898240 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 917760 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 918784 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1310720 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 6815744 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::WhiteSpaceShorthand => {
+        if let Some(version) = browsers.chrome {
+          if version < 7471104 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 7471104 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 8126464 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 6553600 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1115136 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1115136 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1507328 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 7471104 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
       Feature::Cue => {
         if let Some(version) = browsers.chrome {
           if version < 1703936 {
@@ -2764,6 +3240,51 @@ impl Feature {
           return false;
         }
       }
+      Feature::DppxResolutionUnit => {
+        if let Some(version) = browsers.chrome {
+          if version < 1900544 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 786432 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 1048576 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 1048576 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1048576 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1048576 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 131072 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 1900544 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
       Feature::XResolutionUnit => {
         if let Some(version) = browsers.chrome {
           if version < 4456448 {
@@ -3500,19 +4021,64 @@ impl Feature {
           return false;
         }
       }
-      Feature::ViewTransition => {
+      Feature::TransitionBehavior => {
         if let Some(version) = browsers.chrome {
-          if version < 7143424 {
+          if version < 7667712 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 7143424 {
+          if version < 7667712 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 9437184 {
+          if version < 8454144 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 6750208 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1115136 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1115136 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1572864 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 7667712 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::ViewTransition => {
+        if let Some(version) = browsers.chrome {
+          if version < 7143424 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 7143424 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 9437184 {
             return false;
           }
         }
@@ -3545,6 +4111,46 @@ impl Feature {
           return false;
         }
       }
+      Feature::ViewTransitionAtRule => {
+        if let Some(version) = browsers.chrome {
+          if version < 8257536 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 8257536 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 7340032 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1180160 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1180160 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1835008 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 8257536 {
+            return false;
+          }
+        }
+        if browsers.firefox.is_some() || browsers.ie.is_some() {
+          return false;
+        }
+      }
       Feature::DetailsContent => {
         if let Some(version) = browsers.chrome {
           if version < 8585216 {
@@ -3925,6 +4531,51 @@ impl Feature {
           }
         }
       }
+      Feature::ContainerQueries => {
+        if let Some(version) = browsers.chrome {
+          if version < 6881280 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 6881280 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 7208960 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 5963776 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1048576 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1048576 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1310720 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 6881280 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
       Feature::ContainerQueryLengthUnits => {
         if let Some(version) = browsers.chrome {
           if version < 6881280 {
@@ -3970,6 +4621,143 @@ impl Feature {
           return false;
         }
       }
+      Feature::ContentVisibility => {
+        if let Some(version) = browsers.chrome {
+          if version < 5570560 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5570560 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 8192000 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 4653056 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1179648 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1179648 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 917504 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 5570560 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::DisplayFlex => {
+        if let Some(version) = browsers.chrome {
+          if version < 1900544 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 786432 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 1310720 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 1048576 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 589824 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 589824 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 131072 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 263168 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ie {
+          if version < 720896 {
+            return false;
+          }
+        }
+      }
+      Feature::DisplayGrid => {
+        if let Some(version) = browsers.chrome {
+          if version < 3735552 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 1048576 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 3407872 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 2883584 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 655616 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 656128 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 393216 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 3735552 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
       Feature::EmUnit => {
         if let Some(version) = browsers.chrome {
           if version < 1179648 {
@@ -5704,6 +6492,51 @@ impl Feature {
           return false;
         }
       }
+      Feature::FontFormatKeywords => {
+        if let Some(version) = browsers.chrome {
+          if version < 7077888 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 7077888 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 7012352 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 6160384 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1114112 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1114112 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1376256 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 7077888 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
       Feature::P3Colors | Feature::LangSelectorList => {
         if let Some(version) = browsers.safari {
           if version < 655616 {
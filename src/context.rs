@@ -24,6 +24,16 @@ pub(crate) struct SupportsEntry<'i> {
   pub important_declarations: Vec<Property<'i>>,
 }
 
+/// Fallback declarations collected by a [PropertyHandlerContext](PropertyHandlerContext)
+/// while minifying a style rule, used to generate additional rules.
+#[derive(Debug, Default)]
+pub(crate) struct PropertyHandlerFallbacks<'i> {
+  supports: Vec<SupportsEntry<'i>>,
+  ltr: Vec<Property<'i>>,
+  rtl: Vec<Property<'i>>,
+  dark: Vec<Property<'i>>,
+}
+
 #[derive(Debug, PartialEq)]
 pub(crate) enum DeclarationContext {
   None,
@@ -225,6 +235,25 @@ impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
     dest
   }
 
+  /// Moves the collected fallback declarations out of the context, e.g. to transfer them between threads.
+  #[cfg(feature = "rayon")]
+  pub fn take_fallbacks(&mut self) -> PropertyHandlerFallbacks<'i> {
+    PropertyHandlerFallbacks {
+      supports: std::mem::take(&mut self.supports),
+      ltr: std::mem::take(&mut self.ltr),
+      rtl: std::mem::take(&mut self.rtl),
+      dark: std::mem::take(&mut self.dark),
+    }
+  }
+
+  /// Replaces the collected fallback declarations with ones previously returned by `take_fallbacks`.
+  pub fn restore_fallbacks(&mut self, fallbacks: PropertyHandlerFallbacks<'i>) {
+    self.supports = fallbacks.supports;
+    self.ltr = fallbacks.ltr;
+    self.rtl = fallbacks.rtl;
+    self.dark = fallbacks.dark;
+  }
+
   pub fn reset(&mut self) {
     self.supports.clear();
     self.ltr.clear();
@@ -12,6 +12,7 @@ use crate::error::PrinterErrorKind;
 use crate::properties::css_modules::{Composes, Specifier};
 use crate::selector::SelectorList;
 use data_encoding::{Encoding, Specification};
+use indexmap::IndexMap;
 use lazy_static::lazy_static;
 use pathdiff::diff_paths;
 #[cfg(any(feature = "serde", feature = "nodejs"))]
@@ -19,7 +20,6 @@ use serde::Serialize;
 use smallvec::{smallvec, SmallVec};
 use std::borrow::Cow;
 use std::collections::hash_map::DefaultHasher;
-use std::collections::HashMap;
 use std::fmt::Write;
 use std::hash::{Hash, Hasher};
 use std::path::Path;
@@ -252,10 +252,13 @@ pub struct CssModuleExport {
 }
 
 /// A map of exported names to values.
-pub type CssModuleExports = HashMap<String, CssModuleExport>;
+///
+/// Exports are stored in the order they are first encountered in the source,
+/// so that iteration and serialization are deterministic.
+pub type CssModuleExports = IndexMap<String, CssModuleExport>;
 
-/// A map of placeholders to references.
-pub type CssModuleReferences = HashMap<String, CssModuleReference>;
+/// A map of placeholders to references, in the order they are first encountered in the source.
+pub type CssModuleReferences = IndexMap<String, CssModuleReference>;
 
 lazy_static! {
   static ref ENCODER: Encoding = {
@@ -273,7 +276,7 @@ pub(crate) struct CssModule<'a, 'b, 'c> {
   pub hashes: Vec<String>,
   pub content_hashes: &'a Option<Vec<String>>,
   pub exports_by_source_index: Vec<CssModuleExports>,
-  pub references: &'a mut HashMap<String, CssModuleReference>,
+  pub references: &'a mut CssModuleReferences,
 }
 
 impl<'a, 'b, 'c> CssModule<'a, 'b, 'c> {
@@ -281,7 +284,7 @@ impl<'a, 'b, 'c> CssModule<'a, 'b, 'c> {
     config: &'a Config<'b>,
     sources: &'c Vec<String>,
     project_root: Option<&'c str>,
-    references: &'a mut HashMap<String, CssModuleReference>,
+    references: &'a mut CssModuleReferences,
     content_hashes: &'a Option<Vec<String>>,
   ) -> Self {
     let project_root = project_root.map(|p| Path::new(p));
@@ -304,7 +307,7 @@ impl<'a, 'b, 'c> CssModule<'a, 'b, 'c> {
       .collect();
     Self {
       config,
-      exports_by_source_index: sources.iter().map(|_| HashMap::new()).collect(),
+      exports_by_source_index: sources.iter().map(|_| IndexMap::new()).collect(),
       sources,
       hashes,
       content_hashes,
@@ -362,10 +365,10 @@ impl<'a, 'b, 'c> CssModule<'a, 'b, 'c> {
 
   pub fn reference(&mut self, name: &str, source_index: u32) {
     match self.exports_by_source_index[source_index as usize].entry(name.into()) {
-      std::collections::hash_map::Entry::Occupied(mut entry) => {
+      indexmap::map::Entry::Occupied(mut entry) => {
         entry.get_mut().is_referenced = true;
       }
-      std::collections::hash_map::Entry::Vacant(entry) => {
+      indexmap::map::Entry::Vacant(entry) => {
         entry.insert(CssModuleExport {
           name: self
             .config
@@ -421,10 +424,10 @@ impl<'a, 'b, 'c> CssModule<'a, 'b, 'c> {
       None => {
         // Local export. Mark as used.
         match self.exports_by_source_index[source_index as usize].entry(name.into()) {
-          std::collections::hash_map::Entry::Occupied(mut entry) => {
+          indexmap::map::Entry::Occupied(mut entry) => {
             entry.get_mut().is_referenced = true;
           }
-          std::collections::hash_map::Entry::Vacant(entry) => {
+          indexmap::map::Entry::Vacant(entry) => {
             entry.insert(CssModuleExport {
               name: self
                 .config
@@ -1,14 +1,15 @@
 //! CSS declarations.
 
 use std::borrow::Cow;
+use std::collections::HashMap;
 use std::ops::Range;
 
 use crate::context::{DeclarationContext, PropertyHandlerContext};
 use crate::error::{ParserError, PrinterError, PrinterErrorKind};
 use crate::parser::ParserOptions;
-use crate::printer::Printer;
+use crate::printer::{Printer, PrinterOptions};
 use crate::properties::box_shadow::BoxShadowHandler;
-use crate::properties::custom::{CustomProperty, CustomPropertyName};
+use crate::properties::custom::{CustomProperty, CustomPropertyName, Token, TokenList, TokenOrValue};
 use crate::properties::masking::MaskHandler;
 use crate::properties::text::{Direction, UnicodeBidi};
 use crate::properties::{
@@ -16,7 +17,7 @@ use crate::properties::{
   animation::AnimationHandler,
   background::BackgroundHandler,
   border::BorderHandler,
-  contain::ContainerHandler,
+  contain::{ContainerHandler, ContainmentHandler},
   display::DisplayHandler,
   flex::FlexHandler,
   font::FontHandler,
@@ -24,18 +25,19 @@ use crate::properties::{
   list::ListStyleHandler,
   margin_padding::*,
   outline::OutlineHandler,
-  overflow::OverflowHandler,
+  overflow::{LineClampHandler, OverflowHandler},
+  overscroll::OverscrollBehaviorHandler,
   position::PositionHandler,
   prefix_handler::{FallbackHandler, PrefixHandler},
   size::SizeHandler,
-  text::TextDecorationHandler,
+  text::{TextDecorationHandler, TextWrapHandler},
   transform::TransformHandler,
   transition::TransitionHandler,
   ui::ColorSchemeHandler,
 };
-use crate::properties::{Property, PropertyId};
+use crate::properties::{CSSWideKeyword, Property, PropertyId};
 use crate::selector::SelectorList;
-use crate::traits::{PropertyHandler, ToCss};
+use crate::traits::{Parse, PropertyHandler, ToCss};
 use crate::values::ident::DashedIdent;
 use crate::values::string::CowArcStr;
 #[cfg(feature = "visitor")]
@@ -158,12 +160,12 @@ impl<'i> DeclarationBlock<'i> {
   {
     dest.whitespace()?;
     dest.write_char('{')?;
-    dest.indent();
+    dest.increase_indent();
     dest.newline()?;
 
     self.to_css_declarations(dest, false, &parcel_selectors::SelectorList(SmallVec::new()), 0)?;
 
-    dest.dedent();
+    dest.decrease_indent();
     dest.newline()?;
     dest.write_char('}')
   }
@@ -316,6 +318,34 @@ impl<'i> DeclarationBlock<'i> {
       .chain(self.important_declarations.iter().map(|property| (property, true)))
   }
 
+  /// Adds the namespace prefixes referenced by `attr()` functions in the declarations to `prefixes`.
+  pub(crate) fn collect_namespace_prefixes(&self, prefixes: &mut std::collections::HashSet<CowArcStr<'i>>) {
+    for (property, _) in self.iter() {
+      match property {
+        Property::Unparsed(unparsed) => unparsed.value.collect_attr_namespace_prefixes(prefixes),
+        Property::Custom(custom) => custom.value.collect_attr_namespace_prefixes(prefixes),
+        _ => {}
+      }
+    }
+  }
+
+  /// Serializes the declarations as a map from property names to values, e.g. for CSS-in-JS.
+  ///
+  /// Important declarations take precedence over normal declarations of the same property,
+  /// and otherwise the last declaration wins. The `!important` flag is not included in the value.
+  pub fn to_object(&self, options: PrinterOptions) -> Result<HashMap<String, String>, PrinterError> {
+    let mut s = String::new();
+    let mut printer = Printer::new(&mut s, options);
+    let mut object = HashMap::new();
+    for (property, _) in self.iter() {
+      property.property_id().to_css(&mut printer)?;
+      let name = printer.take_output();
+      property.value_to_css(&mut printer)?;
+      object.insert(name, printer.take_output());
+    }
+    Ok(object)
+  }
+
   /// Returns a mutable iterator over all properties in the declaration.
   pub fn iter_mut(&mut self) -> impl std::iter::DoubleEndedIterator<Item = &mut Property<'i>> {
     self.declarations.iter_mut().chain(self.important_declarations.iter_mut())
@@ -454,6 +484,68 @@ impl<'i> DeclarationBlock<'i> {
     remove(&mut self.declarations, property_id);
     remove(&mut self.important_declarations, property_id);
   }
+
+  /// Merges the declarations from another declaration block into this one.
+  ///
+  /// Properties are compared by the longhands they set, so shorthands and their longhands overlap, e.g.
+  /// `background` overlaps `background-color`. Normal and `!important` declarations are merged separately.
+  /// See [MergeMode](MergeMode) for how conflicts are resolved.
+  pub fn merge_from(&mut self, other: &DeclarationBlock<'i>, mode: MergeMode) {
+    fn merge<'i>(declarations: &mut DeclarationList<'i>, other: &DeclarationList<'i>, mode: MergeMode) {
+      match mode {
+        MergeMode::Replace => {
+          for property in other {
+            // Remove declarations that are entirely overridden. Others are overridden by appending
+            // the new property after them, e.g. `background-color` after `background`.
+            let longhands = leaf_longhands(&property.property_id());
+            declarations.retain(|decl| {
+              !leaf_longhands(&decl.property_id())
+                .iter()
+                .all(|longhand| longhands.contains(longhand))
+            });
+            declarations.push(property.clone());
+          }
+        }
+        MergeMode::Fallback => {
+          let existing: Vec<_> = declarations
+            .iter()
+            .flat_map(|decl| leaf_longhands(&decl.property_id()))
+            .collect();
+          // Properties that are only partially set are prepended so that the existing declarations win.
+          let fallbacks: Vec<_> = other
+            .iter()
+            .filter(|property| {
+              !leaf_longhands(&property.property_id())
+                .iter()
+                .all(|longhand| existing.contains(longhand))
+            })
+            .cloned()
+            .collect();
+          declarations.splice(0..0, fallbacks);
+        }
+      }
+    }
+
+    merge(&mut self.declarations, &other.declarations, mode);
+    merge(&mut self.important_declarations, &other.important_declarations, mode);
+  }
+}
+
+/// Returns the longhand properties that a property sets, expanding nested shorthands.
+fn leaf_longhands<'i>(property_id: &PropertyId<'i>) -> Vec<PropertyId<'i>> {
+  match property_id.longhands() {
+    Some(longhands) => longhands.iter().flat_map(leaf_longhands).collect(),
+    None => vec![property_id.clone()],
+  }
+}
+
+/// Determines how conflicting properties are resolved by [DeclarationBlock::merge_from](DeclarationBlock::merge_from).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeMode {
+  /// Properties from the other declaration block override existing properties.
+  Replace,
+  /// Properties from the other declaration block are only used where they are not already set.
+  Fallback,
 }
 
 struct PropertyDeclarationParser<'a, 'o, 'i> {
@@ -515,11 +607,31 @@ pub(crate) fn parse_declaration<'i, 't>(
   // Stop if we hit a `{` token in a non-custom property to
   // avoid ambiguity between nested rules and declarations.
   // https://github.com/w3c/csswg-drafts/issues/9317
-  let property_id = PropertyId::from(CowArcStr::from(name));
+  let name = CowArcStr::from(name);
+  let property_id = PropertyId::from(name.clone());
   let mut delimiters = Delimiter::Bang;
   if !matches!(property_id, PropertyId::Custom(CustomPropertyName::Custom(..))) {
     delimiters = delimiters | Delimiter::CurlyBracketBlock;
   }
+  let state = input.state();
+  input.skip_whitespace();
+  let location = input.current_source_location();
+  input.reset(&state);
+  if options.is_property_disallowed(&property_id) {
+    let err = location.new_custom_error(ParserError::DisallowedProperty(name));
+    if !options.error_recovery {
+      return Err(err);
+    }
+
+    // Skip the value (including any `!important`) so that the rest of the block is preserved.
+    options.warn(err);
+    input.parse_until_before(delimiters, |input| {
+      while input.next().is_ok() {}
+      Ok::<_, cssparser::ParseError<'i, ParserError<'i>>>(())
+    })?;
+    while input.next().is_ok() {}
+    return Ok(());
+  }
   let property = input.parse_until_before(delimiters, |input| Property::parse(property_id, input, options))?;
   let important = input
     .try_parse(|input| {
@@ -528,6 +640,33 @@ pub(crate) fn parse_declaration<'i, 't>(
     })
     .is_ok();
   input.expect_exhausted()?;
+  match &property {
+    Property::Unparsed(unparsed) if is_invalid_value(&unparsed.property_id, &unparsed.value) => {
+      let err = location.new_custom_error(ParserError::InvalidPropertyValue {
+        name,
+        property_id: unparsed.property_id.clone(),
+      });
+      if options.strict_properties && !options.error_recovery {
+        return Err(err);
+      }
+      options.warn(err);
+      if options.strict_properties {
+        return Ok(());
+      }
+    }
+    Property::Custom(CustomProperty {
+      name: CustomPropertyName::Unknown(ident),
+      ..
+    }) if options.strict_properties && !ident.starts_with('-') => {
+      let err = location.new_custom_error(ParserError::UnknownProperty(name));
+      if !options.error_recovery {
+        return Err(err);
+      }
+      options.warn(err);
+      return Ok(());
+    }
+    _ => {}
+  }
   if important {
     important_declarations.push(property);
   } else {
@@ -536,6 +675,55 @@ pub(crate) fn parse_declaration<'i, 't>(
   Ok(())
 }
 
+/// Returns whether the value of a known property that failed to parse is definitely invalid.
+/// Identifiers, functions, and units that are not known may be syntax that is newer than this
+/// library supports, and variables are resolved at computed-value time, so values containing
+/// these are never considered invalid. Only values made of numbers, known dimensions, strings,
+/// colors, and delimiters are. The exception is properties whose value is a single color, where
+/// the set of keywords is closed, so unknown identifiers other than CSS-wide keywords and vendor
+/// extensions are invalid too.
+fn is_invalid_value(property_id: &PropertyId, value: &TokenList) -> bool {
+  let is_color = matches!(
+    property_id,
+    PropertyId::Color
+      | PropertyId::BackgroundColor
+      | PropertyId::BorderTopColor
+      | PropertyId::BorderBottomColor
+      | PropertyId::BorderLeftColor
+      | PropertyId::BorderRightColor
+      | PropertyId::BorderBlockStartColor
+      | PropertyId::BorderBlockEndColor
+      | PropertyId::BorderInlineStartColor
+      | PropertyId::BorderInlineEndColor
+      | PropertyId::OutlineColor
+      | PropertyId::TextDecorationColor(..)
+      | PropertyId::TextEmphasisColor(..)
+  );
+
+  value.0.iter().all(|token| match token {
+    TokenOrValue::Token(Token::Ident(ident)) => {
+      is_color && !ident.starts_with('-') && CSSWideKeyword::parse_string(ident).is_err()
+    }
+    TokenOrValue::Token(token) => matches!(
+      token,
+      Token::Number { .. }
+        | Token::Percentage { .. }
+        | Token::String(..)
+        | Token::Hash(..)
+        | Token::IDHash(..)
+        | Token::Delim(..)
+        | Token::Comma
+        | Token::WhiteSpace(..)
+    ),
+    TokenOrValue::Color(..)
+    | TokenOrValue::Length(..)
+    | TokenOrValue::Angle(..)
+    | TokenOrValue::Time(..)
+    | TokenOrValue::Resolution(..) => true,
+    _ => false,
+  })
+}
+
 pub(crate) type DeclarationList<'i> = Vec<Property<'i>>;
 
 #[derive(Default)]
@@ -553,6 +741,7 @@ pub(crate) struct DeclarationHandler<'i> {
   scroll_padding: ScrollPaddingHandler<'i>,
   font: FontHandler<'i>,
   text: TextDecorationHandler<'i>,
+  text_wrap: TextWrapHandler,
   list: ListStyleHandler<'i>,
   transition: TransitionHandler<'i>,
   animation: AnimationHandler<'i>,
@@ -560,10 +749,13 @@ pub(crate) struct DeclarationHandler<'i> {
   position: PositionHandler,
   inset: InsetHandler<'i>,
   overflow: OverflowHandler,
+  line_clamp: LineClampHandler,
+  overscroll_behavior: OverscrollBehaviorHandler,
   transform: TransformHandler,
   box_shadow: BoxShadowHandler,
   mask: MaskHandler<'i>,
   container: ContainerHandler<'i>,
+  containment: ContainmentHandler,
   color_scheme: ColorSchemeHandler,
   fallback: FallbackHandler,
   prefix: PrefixHandler,
@@ -592,6 +784,7 @@ impl<'i> DeclarationHandler<'i> {
       || self.scroll_padding.handle_property(property, &mut self.decls, context)
       || self.font.handle_property(property, &mut self.decls, context)
       || self.text.handle_property(property, &mut self.decls, context)
+      || self.text_wrap.handle_property(property, &mut self.decls, context)
       || self.list.handle_property(property, &mut self.decls, context)
       || self.transition.handle_property(property, &mut self.decls, context)
       || self.animation.handle_property(property, &mut self.decls, context)
@@ -599,10 +792,13 @@ impl<'i> DeclarationHandler<'i> {
       || self.position.handle_property(property, &mut self.decls, context)
       || self.inset.handle_property(property, &mut self.decls, context)
       || self.overflow.handle_property(property, &mut self.decls, context)
+      || self.line_clamp.handle_property(property, &mut self.decls, context)
+      || self.overscroll_behavior.handle_property(property, &mut self.decls, context)
       || self.transform.handle_property(property, &mut self.decls, context)
       || self.box_shadow.handle_property(property, &mut self.decls, context)
       || self.mask.handle_property(property, &mut self.decls, context)
       || self.container.handle_property(property, &mut self.decls, context)
+      || self.containment.handle_property(property, &mut self.decls, context)
       || self.color_scheme.handle_property(property, &mut self.decls, context)
       || self.fallback.handle_property(property, &mut self.decls, context)
       || self.prefix.handle_property(property, &mut self.decls, context)
@@ -712,6 +908,7 @@ impl<'i> DeclarationHandler<'i> {
     self.scroll_padding.finalize(&mut self.decls, context);
     self.font.finalize(&mut self.decls, context);
     self.text.finalize(&mut self.decls, context);
+    self.text_wrap.finalize(&mut self.decls, context);
     self.list.finalize(&mut self.decls, context);
     self.transition.finalize(&mut self.decls, context);
     self.animation.finalize(&mut self.decls, context);
@@ -719,10 +916,13 @@ impl<'i> DeclarationHandler<'i> {
     self.position.finalize(&mut self.decls, context);
     self.inset.finalize(&mut self.decls, context);
     self.overflow.finalize(&mut self.decls, context);
+    self.line_clamp.finalize(&mut self.decls, context);
+    self.overscroll_behavior.finalize(&mut self.decls, context);
     self.transform.finalize(&mut self.decls, context);
     self.box_shadow.finalize(&mut self.decls, context);
     self.mask.finalize(&mut self.decls, context);
     self.container.finalize(&mut self.decls, context);
+    self.containment.finalize(&mut self.decls, context);
     self.color_scheme.finalize(&mut self.decls, context);
     self.fallback.finalize(&mut self.decls, context);
     self.prefix.finalize(&mut self.decls, context);
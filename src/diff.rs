@@ -0,0 +1,435 @@
+//! Style sheet diffing.
+//!
+//! Compares two style sheets and reports the rules and declarations that were added, removed,
+//! or changed, e.g. to show what a transform did. See [StyleSheet::diff](crate::stylesheet::StyleSheet::diff).
+
+use crate::declaration::DeclarationBlock;
+use crate::error::PrinterError;
+use crate::printer::PrinterOptions;
+use crate::rules::keyframes::Keyframe;
+use crate::rules::page::{PageMarginRule, PageSizeDescriptor};
+use crate::rules::{CssRule, CssRuleList, Location};
+use crate::traits::ToCss;
+use std::collections::HashMap;
+use std::fmt;
+
+/// A change between two style sheets.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+  /// The path to the changed rule, from the outermost rule to the innermost one,
+  /// e.g. `["@media print", ".foo"]`. Each rule is identified by its prelude.
+  pub rule: Vec<String>,
+  /// The location of the rule in the new style sheet, or in the old style sheet if it was removed.
+  pub loc: Location,
+  /// The kind of change.
+  pub kind: ChangeKind,
+}
+
+/// A kind of change between two style sheets.
+///
+/// See [Change](Change).
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChangeKind {
+  /// The rule was added.
+  RuleAdded,
+  /// The rule was removed.
+  RuleRemoved,
+  /// A declaration was added to the rule.
+  DeclarationAdded {
+    /// The property name.
+    property: String,
+    /// The value of the declaration.
+    value: String,
+  },
+  /// A declaration was removed from the rule.
+  DeclarationRemoved {
+    /// The property name.
+    property: String,
+    /// The value of the removed declaration.
+    value: String,
+  },
+  /// The value of a declaration changed.
+  DeclarationChanged {
+    /// The property name.
+    property: String,
+    /// The previous value.
+    old_value: String,
+    /// The new value.
+    new_value: String,
+  },
+}
+
+impl fmt::Display for Change {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match &self.kind {
+      ChangeKind::RuleAdded => write!(f, "added rule")?,
+      ChangeKind::RuleRemoved => write!(f, "removed rule")?,
+      ChangeKind::DeclarationAdded { property, value } => write!(f, "added `{}: {}`", property, value)?,
+      ChangeKind::DeclarationRemoved { property, value } => write!(f, "removed `{}: {}`", property, value)?,
+      ChangeKind::DeclarationChanged {
+        property,
+        old_value,
+        new_value,
+      } => write!(f, "changed `{}` from `{}` to `{}`", property, old_value, new_value)?,
+    }
+
+    for rule in self.rule.iter().rev() {
+      write!(f, " in `{}`", rule)?;
+    }
+    Ok(())
+  }
+}
+
+/// The contents of a rule that can be compared between style sheets.
+enum Contents<'a, 'i, T> {
+  Rules(&'a CssRuleList<'i, T>),
+  Style(&'a DeclarationBlock<'i>, &'a CssRuleList<'i, T>),
+  Declarations(&'a DeclarationBlock<'i>),
+  Keyframes(&'a Vec<Keyframe<'i>>),
+  Page(
+    &'a Option<PageSizeDescriptor<'i>>,
+    &'a DeclarationBlock<'i>,
+    &'a Vec<PageMarginRule<'i>>,
+  ),
+  /// Rules that are only compared as a whole, identified by their serialization.
+  Opaque,
+}
+
+/// A rule, identified by its prelude and the number of preceding siblings with the same prelude.
+struct Entry<'a, 'i, T> {
+  key: String,
+  index: usize,
+  loc: Location,
+  contents: Contents<'a, 'i, T>,
+}
+
+pub(crate) fn diff<T: ToCss>(old: &CssRuleList<T>, new: &CssRuleList<T>) -> Result<Vec<Change>, PrinterError> {
+  let mut changes = Vec::new();
+  diff_entries(rule_entries(old)?, rule_entries(new)?, &mut Vec::new(), &mut changes)?;
+  Ok(changes)
+}
+
+fn to_string<V: ToCss>(value: &V) -> Result<String, PrinterError> {
+  value.to_css_string(PrinterOptions::default())
+}
+
+fn join<V: ToCss>(values: &[V], separator: &str) -> Result<String, PrinterError> {
+  Ok(values.iter().map(to_string).collect::<Result<Vec<_>, _>>()?.join(separator))
+}
+
+fn with_indices<'a, 'i, T>(entries: &mut [Entry<'a, 'i, T>]) {
+  let mut counts: HashMap<String, usize> = HashMap::new();
+  for entry in entries {
+    let count = counts.entry(entry.key.clone()).or_default();
+    entry.index = *count;
+    *count += 1;
+  }
+}
+
+fn rule_entries<'a, 'i, T: ToCss>(rules: &'a CssRuleList<'i, T>) -> Result<Vec<Entry<'a, 'i, T>>, PrinterError> {
+  let mut entries = Vec::new();
+  for rule in &rules.0 {
+    let (key, loc) = match rule {
+      CssRule::Ignored => continue,
+      CssRule::Style(style) => (to_string(&style.selectors)?, style.loc),
+      CssRule::Nesting(nesting) => (format!("@nest {}", to_string(&nesting.style.selectors)?), nesting.loc),
+      CssRule::NestedDeclarations(nested) => ("&".into(), nested.loc),
+      CssRule::Media(media) => (format!("@media {}", to_string(&media.query)?), media.loc),
+      CssRule::Supports(supports) => (format!("@supports {}", to_string(&supports.condition)?), supports.loc),
+      CssRule::Container(container) => {
+        let mut key = String::from("@container");
+        if let Some(name) = &container.name {
+          key.push(' ');
+          key.push_str(&to_string(name)?);
+        }
+        if let Some(condition) = &container.condition {
+          key.push(' ');
+          key.push_str(&to_string(condition)?);
+        }
+        (key, container.loc)
+      }
+      CssRule::LayerBlock(layer) => {
+        let key = match &layer.name {
+          Some(name) => format!("@layer {}", to_string(name)?),
+          None => "@layer".into(),
+        };
+        (key, layer.loc)
+      }
+      CssRule::Scope(scope) => {
+        let mut key = String::from("@scope");
+        if let Some(start) = &scope.scope_start {
+          key.push_str(&format!(" ({})", to_string(start)?));
+        }
+        if let Some(end) = &scope.scope_end {
+          key.push_str(&format!(" to ({})", to_string(end)?));
+        }
+        (key, scope.loc)
+      }
+      CssRule::StartingStyle(starting_style) => ("@starting-style".into(), starting_style.loc),
+      CssRule::MozDocument(document) => ("@-moz-document".into(), document.loc),
+      CssRule::Keyframes(keyframes) => (
+        format!(
+          "@{}keyframes {}",
+          to_string(&keyframes.vendor_prefix)?,
+          to_string(&keyframes.name)?
+        ),
+        keyframes.loc,
+      ),
+      CssRule::Page(page) => {
+        let key = if page.selectors.is_empty() {
+          "@page".into()
+        } else {
+          format!("@page {}", join(&page.selectors, ", ")?)
+        };
+        (key, page.loc)
+      }
+      _ => {
+        let key = rule.to_css_string(PrinterOptions {
+          minify: true,
+          ..PrinterOptions::default()
+        })?;
+        (key, rule_loc(rule))
+      }
+    };
+
+    let contents = match rule {
+      CssRule::Style(style) => Contents::Style(&style.declarations, &style.rules),
+      CssRule::Nesting(nesting) => Contents::Style(&nesting.style.declarations, &nesting.style.rules),
+      CssRule::NestedDeclarations(nested) => Contents::Declarations(&nested.declarations),
+      CssRule::Keyframes(keyframes) => Contents::Keyframes(&keyframes.keyframes),
+      CssRule::Page(page) => Contents::Page(&page.size, &page.declarations, &page.rules),
+      _ => match rule.nested_rules() {
+        Some(rules) => Contents::Rules(rules),
+        None => Contents::Opaque,
+      },
+    };
+
+    entries.push(Entry {
+      key,
+      index: 0,
+      loc,
+      contents,
+    });
+  }
+  with_indices(&mut entries);
+  Ok(entries)
+}
+
+fn rule_loc<T>(rule: &CssRule<T>) -> Location {
+  match rule {
+    CssRule::Import(import) => import.loc,
+    CssRule::FontFace(font_face) => font_face.loc,
+    CssRule::FontPaletteValues(font_palette_values) => font_palette_values.loc,
+    CssRule::FontFeatureValues(font_feature_values) => font_feature_values.loc,
+    CssRule::CounterStyle(counter_style) => counter_style.loc,
+    CssRule::Namespace(namespace) => namespace.loc,
+    CssRule::Viewport(viewport) => viewport.loc,
+    CssRule::CustomMedia(custom_media) => custom_media.loc,
+    CssRule::LayerStatement(layer) => layer.loc,
+    CssRule::Property(property) => property.loc,
+    CssRule::ViewTransition(view_transition) => view_transition.loc,
+    CssRule::Unknown(unknown) => unknown.loc,
+    _ => Location {
+      source_index: 0,
+      line: 0,
+      column: 0,
+    },
+  }
+}
+
+fn keyframe_entries<'a, 'i, T>(
+  keyframes: &'a [Keyframe<'i>],
+  loc: Location,
+) -> Result<Vec<Entry<'a, 'i, T>>, PrinterError> {
+  let mut entries = keyframes
+    .iter()
+    .map(|keyframe| {
+      Ok(Entry {
+        key: join(&keyframe.selectors, ", ")?,
+        index: 0,
+        loc,
+        contents: Contents::Declarations(&keyframe.declarations),
+      })
+    })
+    .collect::<Result<Vec<_>, PrinterError>>()?;
+  with_indices(&mut entries);
+  Ok(entries)
+}
+
+fn margin_entries<'a, 'i, T>(
+  margin_rules: &'a [PageMarginRule<'i>],
+) -> Result<Vec<Entry<'a, 'i, T>>, PrinterError> {
+  let mut entries = margin_rules
+    .iter()
+    .map(|rule| {
+      Ok(Entry {
+        key: format!("@{}", to_string(&rule.margin_box)?),
+        index: 0,
+        loc: rule.loc,
+        contents: Contents::Declarations(&rule.declarations),
+      })
+    })
+    .collect::<Result<Vec<_>, PrinterError>>()?;
+  with_indices(&mut entries);
+  Ok(entries)
+}
+
+fn diff_entries<T: ToCss>(
+  old: Vec<Entry<T>>,
+  new: Vec<Entry<T>>,
+  path: &mut Vec<String>,
+  changes: &mut Vec<Change>,
+) -> Result<(), PrinterError> {
+  let old_by_key: HashMap<(&str, usize), &Entry<T>> =
+    old.iter().map(|entry| ((entry.key.as_str(), entry.index), entry)).collect();
+  let new_by_key: HashMap<(&str, usize), &Entry<T>> =
+    new.iter().map(|entry| ((entry.key.as_str(), entry.index), entry)).collect();
+
+  for entry in &old {
+    if !new_by_key.contains_key(&(entry.key.as_str(), entry.index)) {
+      path.push(entry.key.clone());
+      changes.push(Change {
+        rule: path.clone(),
+        loc: entry.loc,
+        kind: ChangeKind::RuleRemoved,
+      });
+      path.pop();
+    }
+  }
+
+  for entry in &new {
+    path.push(entry.key.clone());
+    match old_by_key.get(&(entry.key.as_str(), entry.index)) {
+      Some(old) => diff_contents(&old.contents, &entry.contents, entry.loc, path, changes)?,
+      None => changes.push(Change {
+        rule: path.clone(),
+        loc: entry.loc,
+        kind: ChangeKind::RuleAdded,
+      }),
+    }
+    path.pop();
+  }
+
+  Ok(())
+}
+
+fn diff_contents<T: ToCss>(
+  old: &Contents<T>,
+  new: &Contents<T>,
+  loc: Location,
+  path: &mut Vec<String>,
+  changes: &mut Vec<Change>,
+) -> Result<(), PrinterError> {
+  match (old, new) {
+    (Contents::Rules(old), Contents::Rules(new)) => {
+      diff_entries(rule_entries(old)?, rule_entries(new)?, path, changes)
+    }
+    (Contents::Style(old_declarations, old_rules), Contents::Style(new_declarations, new_rules)) => {
+      diff_declarations(None, old_declarations, None, new_declarations, loc, path, changes)?;
+      diff_entries(rule_entries(old_rules)?, rule_entries(new_rules)?, path, changes)
+    }
+    (Contents::Declarations(old), Contents::Declarations(new)) => {
+      diff_declarations(None, old, None, new, loc, path, changes)
+    }
+    (Contents::Keyframes(old), Contents::Keyframes(new)) => {
+      diff_entries::<T>(keyframe_entries(old, loc)?, keyframe_entries(new, loc)?, path, changes)
+    }
+    (
+      Contents::Page(old_size, old_declarations, old_rules),
+      Contents::Page(new_size, new_declarations, new_rules),
+    ) => {
+      diff_declarations(
+        old_size.as_ref(),
+        old_declarations,
+        new_size.as_ref(),
+        new_declarations,
+        loc,
+        path,
+        changes,
+      )?;
+      diff_entries::<T>(margin_entries(old_rules)?, margin_entries(new_rules)?, path, changes)
+    }
+    _ => Ok(()),
+  }
+}
+
+/// Compares two declaration blocks. The `size` descriptor of `@page` rules is compared as if
+/// it were the first declaration in the block.
+fn diff_declarations(
+  old_size: Option<&PageSizeDescriptor>,
+  old: &DeclarationBlock,
+  new_size: Option<&PageSizeDescriptor>,
+  new: &DeclarationBlock,
+  loc: Location,
+  path: &[String],
+  changes: &mut Vec<Change>,
+) -> Result<(), PrinterError> {
+  // Declarations are identified by their property name and the number of preceding
+  // declarations of the same property, e.g. fallbacks.
+  fn entries(
+    size: Option<&PageSizeDescriptor>,
+    declarations: &DeclarationBlock,
+  ) -> Result<Vec<(String, usize, String)>, PrinterError> {
+    let mut entries = Vec::new();
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    if let Some(size) = size {
+      entries.push(("size".into(), 0, to_string(size)?));
+      counts.insert("size".into(), 1);
+    }
+    for (property, important) in declarations.iter() {
+      let name = to_string(&property.property_id())?;
+      let mut value = property.value_to_css_string(PrinterOptions::default())?;
+      if important {
+        value.push_str(" !important");
+      }
+      let count = counts.entry(name.clone()).or_default();
+      entries.push((name, *count, value));
+      *count += 1;
+    }
+    Ok(entries)
+  }
+
+  let old = entries(old_size, old)?;
+  let new = entries(new_size, new)?;
+  let old_by_key: HashMap<(&str, usize), &str> = old
+    .iter()
+    .map(|(property, index, value)| ((property.as_str(), *index), value.as_str()))
+    .collect();
+  let new_by_key: HashMap<(&str, usize), &str> = new
+    .iter()
+    .map(|(property, index, value)| ((property.as_str(), *index), value.as_str()))
+    .collect();
+  let mut push = |kind| {
+    changes.push(Change {
+      rule: path.to_vec(),
+      loc,
+      kind,
+    })
+  };
+
+  for (property, index, value) in &old {
+    if !new_by_key.contains_key(&(property.as_str(), *index)) {
+      push(ChangeKind::DeclarationRemoved {
+        property: property.clone(),
+        value: value.clone(),
+      });
+    }
+  }
+
+  for (property, index, value) in &new {
+    match old_by_key.get(&(property.as_str(), *index)) {
+      Some(old_value) if old_value != value => push(ChangeKind::DeclarationChanged {
+        property: property.clone(),
+        old_value: old_value.to_string(),
+        new_value: value.clone(),
+      }),
+      Some(_) => {}
+      None => push(ChangeKind::DeclarationAdded {
+        property: property.clone(),
+        value: value.clone(),
+      }),
+    }
+  }
+
+  Ok(())
+}
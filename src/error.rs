@@ -1,6 +1,7 @@
 //! Error types.
 
 use crate::properties::custom::Token;
+use crate::properties::PropertyId;
 use crate::rules::Location;
 use crate::values::string::CowArcStr;
 use cssparser::{BasicParseErrorKind, ParseError, ParseErrorKind};
@@ -35,6 +36,104 @@ impl<T: fmt::Display> fmt::Display for Error<T> {
 
 impl<T: fmt::Display + fmt::Debug> std::error::Error for Error<T> {}
 
+impl<T> Error<T> {
+  /// Returns a report of this error that includes the line of source text on which it
+  /// occurred, with a caret pointing at the error location.
+  ///
+  /// The given source must be the contents of the file the error location refers to.
+  pub fn report(&self, source: &str) -> ErrorReport<'_, T> {
+    ErrorReport {
+      error: self,
+      context: self.loc.as_ref().and_then(|loc| ErrorContext::new(source, loc)),
+    }
+  }
+}
+
+/// An error together with the surrounding source text, returned by [Error::report](Error::report).
+///
+/// When displayed, the error message is followed by the line on which the error occurred and
+/// a caret indicating the column.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ErrorReport<'a, T> {
+  /// The error.
+  pub error: &'a Error<T>,
+  /// The source text surrounding the error, if the location could be found in the source.
+  pub context: Option<ErrorContext>,
+}
+
+impl<'a, T: fmt::Display> fmt::Display for ErrorReport<'a, T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    self.error.fmt(f)?;
+    if let Some(context) = &self.context {
+      write!(f, "\n{}", context)?;
+    }
+    Ok(())
+  }
+}
+
+impl<'a, T: fmt::Display + fmt::Debug> std::error::Error for ErrorReport<'a, T> {}
+
+/// A non-fatal issue reported to [ParserOptions::warnings](crate::stylesheet::ParserOptions::warnings),
+/// e.g. a declaration whose value is invalid for its property. Warnings share their type with
+/// errors, so the same issue is reported identically whether or not it is fatal, e.g. with
+/// [ParserOptions::strict_properties](crate::stylesheet::ParserOptions::strict_properties).
+pub type Warning<'i> = Error<ParserError<'i>>;
+
+/// The line of source text on which an error occurred.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(any(feature = "serde", feature = "nodejs"), derive(serde::Serialize))]
+#[cfg_attr(any(feature = "serde"), derive(serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct ErrorContext {
+  /// The text of the line, without the line ending.
+  pub line_text: String,
+  /// The number of characters on the line before the error location.
+  pub offset: usize,
+}
+
+impl ErrorContext {
+  /// Extracts the line referenced by an error location from the source text.
+  /// Returns `None` if the location is outside of the source.
+  pub fn new(source: &str, loc: &ErrorLocation) -> Option<Self> {
+    // Split lines the same way as the tokenizer, where `\r\n` is a single line break.
+    let mut rest = source;
+    for _ in 0..loc.line {
+      let end = rest.find(['\n', '\r', '\x0C'])?;
+      let len = if rest[end..].starts_with("\r\n") { 2 } else { 1 };
+      rest = &rest[end + len..];
+    }
+
+    let line_text = &rest[..rest.find(['\n', '\r', '\x0C']).unwrap_or(rest.len())];
+
+    // Columns are counted in UTF-16 code units, starting from 1.
+    let mut utf16_column = 1;
+    let mut offset = 0;
+    for c in line_text.chars() {
+      if utf16_column >= loc.column as usize {
+        break;
+      }
+      utf16_column += c.len_utf16();
+      offset += 1;
+    }
+
+    Some(ErrorContext {
+      line_text: line_text.into(),
+      offset,
+    })
+  }
+}
+
+impl fmt::Display for ErrorContext {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    writeln!(f, "{}", self.line_text)?;
+    // Keep tabs so the caret lines up with the text above it.
+    for c in self.line_text.chars().take(self.offset) {
+      f.write_str(if c == '\t' { "\t" } else { " " })?;
+    }
+    f.write_str("^--- here")
+  }
+}
+
 /// A line and column location within a source file.
 #[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(any(feature = "serde", feature = "nodejs"), derive(serde::Serialize))]
@@ -96,10 +195,23 @@ pub enum ParserError<'i> {
   InvalidPageSelector,
   /// An invalid value was encountered.
   InvalidValue,
+  /// The value of a declaration was invalid for its property, and will be ignored by browsers.
+  /// This is an error when [ParserOptions::strict_properties](crate::stylesheet::ParserOptions::strict_properties)
+  /// is enabled, and a warning otherwise, in which case the declaration is preserved as is.
+  InvalidPropertyValue {
+    /// The name of the property, as written.
+    name: CowArcStr<'i>,
+    /// The property.
+    #[cfg_attr(any(feature = "serde", feature = "nodejs"), serde(skip))]
+    property_id: PropertyId<'i>,
+  },
   /// Invalid qualified rule.
   QualifiedRuleInvalid,
   /// A selector was invalid.
   SelectorError(SelectorError<'i>),
+  /// An `@charset` rule specified an encoding other than UTF-8. It is kept if it is the first rule
+  /// of the style sheet, and removed otherwise.
+  UnsupportedCharset(CowArcStr<'i>),
   /// An `@import` rule was encountered after any rule besides `@charset` or `@layer`.
   UnexpectedImportRule,
   /// A `@namespace` rule was encountered after any rules besides `@charset`, `@import`, or `@layer`.
@@ -108,6 +220,17 @@ pub enum ParserError<'i> {
   UnexpectedToken(#[cfg_attr(any(feature = "serde", feature = "nodejs"), serde(skip))] Token<'i>),
   /// Maximum nesting depth was reached.
   MaximumNestingDepth,
+  /// The input was not valid UTF-8.
+  InvalidUtf8,
+  /// An unknown font format was used in the `format()` function of an `@font-face` `src` descriptor.
+  /// This is only emitted as a warning, since the source is preserved as is.
+  UnknownFontFormat(CowArcStr<'i>),
+  /// A property was used that is disallowed by the `disallowed_properties` parser option.
+  DisallowedProperty(CowArcStr<'i>),
+  /// An at-rule was used that is disallowed by the `disallowed_at_rules` parser option.
+  DisallowedAtRule(CowArcStr<'i>),
+  /// An unknown property was used while the `strict_properties` parser option is enabled.
+  UnknownProperty(CowArcStr<'i>),
 }
 
 impl<'i> fmt::Display for ParserError<'i> {
@@ -126,8 +249,14 @@ impl<'i> fmt::Display for ParserError<'i> {
       DeprecatedCssModulesValueRule => write!(f, "The @value rule is deprecated"),
       InvalidPageSelector => write!(f, "Invalid page selector"),
       InvalidValue => write!(f, "Invalid value"),
+      InvalidPropertyValue { name, .. } => write!(f, "Invalid value for the {} property", name),
       QualifiedRuleInvalid => write!(f, "Invalid qualified rule"),
       SelectorError(s) => s.fmt(f),
+      UnsupportedCharset(charset) => write!(
+        f,
+        "Unexpected @charset \"{}\", CSS files should be encoded as UTF-8",
+        charset
+      ),
       UnexpectedImportRule => write!(
         f,
         "@import rules must precede all rules aside from @charset and @layer statements"
@@ -138,6 +267,11 @@ impl<'i> fmt::Display for ParserError<'i> {
       ),
       UnexpectedToken(token) => write!(f, "Unexpected token {:?}", token),
       MaximumNestingDepth => write!(f, "Overflowed the maximum nesting depth"),
+      InvalidUtf8 => write!(f, "Invalid UTF-8"),
+      UnknownFontFormat(format) => write!(f, "Unknown font format \"{}\"", format),
+      DisallowedProperty(name) => write!(f, "The {} property is not allowed", name),
+      DisallowedAtRule(name) => write!(f, "The @{} rule is not allowed", name),
+      UnknownProperty(name) => write!(f, "Unknown property {}", name),
     }
   }
 }
@@ -368,6 +502,16 @@ pub enum MinifyErrorKind {
   },
   /// A CSS module selector did not contain at least one class or id selector.
   ImpureCSSModuleSelector,
+  /// A selector uses a pseudo-class or pseudo-element that is not supported by the targets.
+  UnsupportedSelector {
+    /// The name of the unsupported pseudo-class or pseudo-element, e.g. `:has()`.
+    name: String,
+  },
+  /// An at-rule is not supported by the targets. The rule is kept, since browsers that do not support it ignore it.
+  UnsupportedAtRule {
+    /// The name of the unsupported at-rule, e.g. `@view-transition`.
+    name: String,
+  },
 }
 
 impl fmt::Display for MinifyErrorKind {
@@ -384,6 +528,12 @@ impl fmt::Display for MinifyErrorKind {
         f,
         "A selector in CSS modules should contain at least one class or ID selector"
       ),
+      UnsupportedSelector { name } => {
+        write!(f, "The {} selector is not supported by the configured targets", name)
+      }
+      UnsupportedAtRule { name } => {
+        write!(f, "The {} rule is not supported by the configured targets", name)
+      }
     }
   }
 }
@@ -417,6 +567,8 @@ pub enum PrinterErrorKind {
   InvalidComposesSelector,
   /// The CSS modules pattern must end with `[local]` for use in CSS grid.
   InvalidCssModulesPatternInGrid,
+  /// An error occurred while generating a source map.
+  SourceMapError,
 }
 
 impl From<fmt::Error> for PrinterError {
@@ -437,6 +589,7 @@ impl fmt::Display for PrinterErrorKind {
       InvalidComposesNesting => write!(f, "The `composes` property cannot be used within nested rules"),
       InvalidComposesSelector => write!(f, "The `composes` property cannot be used with a simple class selector"),
       InvalidCssModulesPatternInGrid => write!(f, "The CSS modules `pattern` config must end with `[local]` for use in CSS grid line names."),
+      SourceMapError => write!(f, "Error generating source map"),
     }
   }
 }
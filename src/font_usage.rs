@@ -0,0 +1,97 @@
+//! Font usage.
+//!
+//! Collects the font families referenced by a style sheet and the font faces it declares,
+//! e.g. to subset font files. See [StyleSheet::collect_used_fonts](crate::stylesheet::StyleSheet::collect_used_fonts).
+
+use crate::declaration::DeclarationBlock;
+use crate::properties::font::{FontFamily, FontStretch, FontWeight};
+use crate::properties::Property;
+use crate::rules::font_face::{FontFaceProperty, FontFaceRule, FontStyle, Source, UrlSource};
+use crate::rules::{CssRule, CssRuleList};
+use crate::values::size::Size2D;
+
+/// The fonts used by a style sheet.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FontUsage<'i> {
+  /// The unique font families referenced by `font-family` and `font` declarations, in source order.
+  pub referenced_families: Vec<FontFamily<'i>>,
+  /// The font faces declared by `@font-face` rules, in source order.
+  pub declared_faces: Vec<FontFaceInfo<'i>>,
+}
+
+/// A font face declared by an `@font-face` rule.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FontFaceInfo<'i> {
+  /// The `font-family` descriptor.
+  pub family: Option<FontFamily<'i>>,
+  /// The `font-weight` descriptor. A single value is represented as a range with equal bounds.
+  pub weight: Option<Size2D<FontWeight>>,
+  /// The `font-style` descriptor.
+  pub style: Option<FontStyle>,
+  /// The `font-stretch` descriptor. A single value is represented as a range with equal bounds.
+  pub stretch: Option<Size2D<FontStretch>>,
+  /// The `url()` sources listed in the `src` descriptor. `local()` sources are omitted.
+  pub sources: Vec<UrlSource<'i>>,
+}
+
+impl<'i> FontFaceInfo<'i> {
+  fn from_rule(rule: &FontFaceRule<'i>) -> Self {
+    let mut info = FontFaceInfo::default();
+    for property in &rule.properties {
+      match property {
+        FontFaceProperty::FontFamily(family) => info.family = Some(family.clone()),
+        FontFaceProperty::FontWeight(weight) => info.weight = Some(weight.clone()),
+        FontFaceProperty::FontStyle(style) => info.style = Some(style.clone()),
+        FontFaceProperty::FontStretch(stretch) => info.stretch = Some(stretch.clone()),
+        FontFaceProperty::Source(sources) => {
+          info.sources = sources
+            .iter()
+            .filter_map(|source| match source {
+              Source::Url(url) => Some(url.clone()),
+              Source::Local(_) => None,
+            })
+            .collect()
+        }
+        _ => {}
+      }
+    }
+    info
+  }
+}
+
+pub(crate) fn collect_used_fonts<'i, T>(rules: &CssRuleList<'i, T>, usage: &mut FontUsage<'i>) {
+  rules.for_each_rule(&mut |rule| match rule {
+    CssRule::Style(style) => collect_families(&style.declarations, usage),
+    CssRule::Nesting(nesting) => collect_families(&nesting.style.declarations, usage),
+    CssRule::NestedDeclarations(nested) => collect_families(&nested.declarations, usage),
+    CssRule::Keyframes(keyframes) => {
+      for keyframe in &keyframes.keyframes {
+        collect_families(&keyframe.declarations, usage);
+      }
+    }
+    CssRule::Page(page) => {
+      collect_families(&page.declarations, usage);
+      for margin_rule in &page.rules {
+        collect_families(&margin_rule.declarations, usage);
+      }
+    }
+    CssRule::FontFace(font_face) => usage.declared_faces.push(FontFaceInfo::from_rule(font_face)),
+    _ => {}
+  });
+}
+
+fn collect_families<'i>(declarations: &DeclarationBlock<'i>, usage: &mut FontUsage<'i>) {
+  for (property, _) in declarations.iter() {
+    let families = match property {
+      Property::FontFamily(families) => families,
+      Property::Font(font) => &font.family,
+      _ => continue,
+    };
+
+    for family in families {
+      if !usage.referenced_families.contains(family) {
+        usage.referenced_families.push(family.clone());
+      }
+    }
+  }
+}
@@ -25,7 +25,10 @@ mod context;
 pub mod css_modules;
 pub mod declaration;
 pub mod dependencies;
+pub mod diff;
 pub mod error;
+pub mod font_usage;
+pub mod lint;
 mod logical;
 mod macros;
 pub mod media_query;
@@ -52,9 +55,10 @@ mod tests {
   use crate::css_modules::{CssModuleExport, CssModuleExports, CssModuleReference, CssModuleReferences};
   use crate::dependencies::Dependency;
   use crate::error::{Error, ErrorLocation, MinifyErrorKind, ParserError, PrinterErrorKind, SelectorError};
+  use crate::lint::LintWarning;
   use crate::parser::ParserFlags;
   use crate::properties::custom::Token;
-  use crate::properties::Property;
+  use crate::properties::{Property, PropertyId};
   use crate::rules::CssRule;
   use crate::rules::Location;
   use crate::stylesheet::*;
@@ -63,9 +67,9 @@ mod tests {
   use crate::values::color::CssColor;
   use crate::vendor_prefix::VendorPrefix;
   use cssparser::SourceLocation;
+  use indexmap::IndexMap;
   use indoc::indoc;
   use pretty_assertions::assert_eq;
-  use std::collections::HashMap;
   use std::sync::{Arc, RwLock};
 
   fn test(source: &str, expected: &str) {
@@ -281,7 +285,7 @@ mod tests {
     { $($key:expr => $name:literal $(referenced: $referenced: literal)? $($value:literal $(global: $global: literal)? $(from $from:literal)?)*),* } => {
       {
         #[allow(unused_mut)]
-        let mut m = HashMap::new();
+        let mut m = IndexMap::new();
         $(
           #[allow(unused_mut)]
           let mut v = Vec::new();
@@ -2065,10 +2069,34 @@ mod tests {
         ..Browsers::default()
       },
     );
+
+    minify_test(
+      ".foo { border-top: 1px solid red; border-right: 1px solid red; border-bottom: 1px solid red; border-left: 2px dashed blue }",
+      ".foo{border:1px solid red;border-left:2px dashed #00f}",
+    );
+    minify_test(
+      ".foo { border-top: 1px solid red; border-right: 1px solid red; border-bottom: 1px solid red; border-left: 1px solid blue }",
+      ".foo{border:1px solid red;border-left-color:#00f}",
+    );
+    minify_test(
+      ".foo { border-block-start: 1px solid red; border-block-end: 1px solid red }",
+      ".foo{border-block:1px solid red}",
+    );
+    minify_test(
+      ".foo { border-inline-start: 1px solid red; border-inline-end: 1px solid red; border-left: 2px solid blue }",
+      ".foo{border-inline:1px solid red;border-left:2px solid #00f}",
+    );
+    minify_test(
+      ".foo { border-block-start: 1px solid red; border-top: 2px solid blue; border-block-end: 1px solid red }",
+      ".foo{border-block-start:1px solid red;border-top:2px solid #00f;border-block-end:1px solid red}",
+    );
   }
 
   #[test]
   pub fn test_border_image() {
+    minify_test(".foo { border-image: none }", ".foo{border-image:none}");
+    minify_test(".foo { border-image: 10 round }", ".foo{border-image:10 round}");
+    minify_test(".foo { border-image: round }", ".foo{border-image:round}");
     test(
       r#"
       .foo {
@@ -3031,6 +3059,27 @@ mod tests {
         ..Browsers::default()
       },
     );
+
+    minify_test(
+      ".foo { border-radius: 10px 20px 30px 40px / 10px 20px 30px 40px }",
+      ".foo{border-radius:10px 20px 30px 40px}",
+    );
+    minify_test(
+      ".foo { border-radius: 10px 20px 10px 20px / 10px 20px 10px 20px }",
+      ".foo{border-radius:10px 20px}",
+    );
+    minify_test(
+      ".foo { border-radius: 10px 10px 10px 10px / 10px }",
+      ".foo{border-radius:10px}",
+    );
+    minify_test(
+      ".foo { border-radius: 10px 20px 30px 20px / 5px 5px 5px 5px }",
+      ".foo{border-radius:10px 20px 30px/5px}",
+    );
+    minify_test(
+      ".foo { border-radius: 10px 20px 30px 40px / 5px 10px 15px 20px }",
+      ".foo{border-radius:10px 20px 30px 40px/5px 10px 15px 20px}",
+    );
   }
 
   #[test]
@@ -3547,6 +3596,84 @@ mod tests {
     }
   }
 
+  #[test]
+  fn test_length_unit_conversion() {
+    fn length_test(source: &str, expected: &str, browsers: Option<Browsers>) {
+      let mut stylesheet = StyleSheet::parse(source, ParserOptions::default()).unwrap();
+      stylesheet.minify(MinifyOptions::default()).unwrap();
+      let res = stylesheet
+        .to_css(PrinterOptions {
+          minify: true,
+          convert_length_units: true,
+          targets: browsers.into(),
+          ..PrinterOptions::default()
+        })
+        .unwrap();
+      assert_eq!(res.code, expected);
+    }
+
+    for (source, expected) in [
+      ("0px", "0"),
+      ("0in", "0"),
+      ("96px", "1in"),
+      ("192px", "2in"),
+      ("48px", "3pc"),
+      ("12px", "9pt"),
+      ("16px", "1pc"),
+      ("20px", "20px"),
+      ("10px", "10px"),
+      ("120px", "90pt"),
+      ("1in", "1in"),
+      ("2.54cm", "1in"),
+      ("25.4mm", "1in"),
+      ("10mm", "1cm"),
+      ("72pt", "1in"),
+      ("12pt", "1pc"),
+      ("12pc", "2in"),
+      ("1pc", "1pc"),
+      ("1000q", "25cm"),
+      ("-96px", "-1in"),
+      ("1.5px", "1.5px"),
+      ("2000000px", "125000pc"),
+      ("123457px", "123457px"),
+      ("300001px", "300001px"),
+      ("1cm", "1cm"),
+      ("3px", "3px"),
+      ("10rem", "10rem"),
+      ("96em", "96em"),
+      ("96vw", "96vw"),
+      ("96ch", "96ch"),
+    ] {
+      length_test(
+        &format!(".foo {{ width: {} }}", source),
+        &format!(".foo{{width:{}}}", expected),
+        None,
+      );
+    }
+
+    // Other dimensions keep their units.
+    length_test(".foo { transition-delay: 0s }", ".foo{transition-delay:0s}", None);
+    length_test(
+      ".foo { width: calc(100% - 96px) }",
+      ".foo{width:calc(100% - 1in)}",
+      None,
+    );
+
+    // The q unit is only used when supported by the targets.
+    length_test(".foo { width: 0.25mm }", ".foo{width:1q}", None);
+    length_test(
+      ".foo { width: 0.25mm }",
+      ".foo{width:.25mm}",
+      Some(Browsers {
+        safari: Some(12 << 16),
+        ..Browsers::default()
+      }),
+    );
+
+    // Units are preserved unless enabled.
+    minify_test(".foo { width: 96px }", ".foo{width:96px}");
+  }
+
   #[test]
   pub fn test_padding() {
     test(
@@ -4156,6 +4283,26 @@ mod tests {
     minify_test(".foo { aspect-ratio: 2 / 3 }", ".foo{aspect-ratio:2/3}");
     minify_test(".foo { aspect-ratio: auto 2 / 3 }", ".foo{aspect-ratio:auto 2/3}");
     minify_test(".foo { aspect-ratio: 2 / 3 auto }", ".foo{aspect-ratio:auto 2/3}");
+    minify_test(".foo { aspect-ratio: 1 }", ".foo{aspect-ratio:1}");
+    minify_test(".foo { aspect-ratio: 16 / 1 }", ".foo{aspect-ratio:16}");
+    minify_test(".foo { aspect-ratio: 2 / 4 }", ".foo{aspect-ratio:1/2}");
+    minify_test(
+      ".foo { aspect-ratio: auto 1920 / 1080 }",
+      ".foo{aspect-ratio:auto 16/9}",
+    );
+    minify_test(".foo { aspect-ratio: 1.5 / 3 }", ".foo{aspect-ratio:1/2}");
+    minify_test(".foo { aspect-ratio: 0.5 / 0.25 }", ".foo{aspect-ratio:2}");
+    minify_test(".foo { aspect-ratio: 10 / 4 }", ".foo{aspect-ratio:5/2}");
+    minify_test(".foo { aspect-ratio: 2.5 }", ".foo{aspect-ratio:2.5}");
+    minify_test(".foo { aspect-ratio: 0.3 / 1 }", ".foo{aspect-ratio:.3}");
+    minify_test(".foo { aspect-ratio: 1.333 / 1 }", ".foo{aspect-ratio:1.333}");
+    minify_test(".foo { aspect-ratio: 0 / 5 }", ".foo{aspect-ratio:0/5}");
+    minify_test(".foo { aspect-ratio: 4 / 0 }", ".foo{aspect-ratio:4/0}");
+    // Terms of 2^24 or more may have been rounded when parsed, so they are not reduced.
+    minify_test(".foo { aspect-ratio: 16777217 / 2 }", ".foo{aspect-ratio:16777200/2}");
+    minify_test(".foo { aspect-ratio: 16777216 / 2 }", ".foo{aspect-ratio:16777200/2}");
+    minify_test(".foo { aspect-ratio: 16777214 / 8388607 }", ".foo{aspect-ratio:2}");
+    test(".foo { aspect-ratio: 2 / 4 }", ".foo {\n  aspect-ratio: 2 / 4;\n}\n");
 
     minify_test(
       ".foo { width: 200px; width: var(--foo); }",
@@ -4372,12 +4519,47 @@ mod tests {
       ".foo { background-position: 20px center }",
       ".foo{background-position:20px}",
     );
+    minify_test(
+      ".foo { background-position: right 0 bottom 20% }",
+      ".foo{background-position:100% 80%}",
+    );
+    minify_test(
+      ".foo { background-position: right 0 bottom 20px }",
+      ".foo{background-position:right 0 bottom 20px}",
+    );
+    minify_test(
+      ".foo { background-position: right 0 top 20px }",
+      ".foo{background-position:100% 20px}",
+    );
+    minify_test(
+      ".foo { background-position: left 10px bottom 0 }",
+      ".foo{background-position:10px 100%}",
+    );
+    minify_test(
+      ".foo { background-position: right 30% center }",
+      ".foo{background-position:70%}",
+    );
+    minify_test(
+      ".foo { background-position: center bottom 0 }",
+      ".foo{background-position:bottom}",
+    );
 
     minify_test(
       ".foo { background: url('img-sprite.png') no-repeat bottom right }",
       ".foo{background:url(img-sprite.png) 100% 100% no-repeat}",
     );
     minify_test(".foo { background: transparent }", ".foo{background:0 0}");
+    // Empty layers are kept, since other rules may set longhands that rely on the layer count.
+    minify_test(".foo { background: none, none }", ".foo{background:0 0,0 0}");
+    minify_test(".foo { background: none, none, red }", ".foo{background:0 0,0 0,red}");
+    minify_test(
+      ".foo { background: none, none, url(a.png) right bottom no-repeat }",
+      ".foo{background:0 0,0 0,url(a.png) 100% 100% no-repeat}",
+    );
+    minify_test(
+      ".foo { background: url(a.png), url(a.png) }",
+      ".foo{background:url(a.png),url(a.png)}",
+    );
 
     minify_test(".foo { background: url(\"data:image/svg+xml,%3Csvg width='168' height='24' xmlns='http://www.w3.org/2000/svg'%3E%3C/svg%3E\") }", ".foo{background:url(\"data:image/svg+xml,%3Csvg width='168' height='24' xmlns='http://www.w3.org/2000/svg'%3E%3C/svg%3E\")}");
 
@@ -4836,6 +5018,47 @@ mod tests {
         ..Browsers::default()
       },
     );
+
+    minify_test(
+      ".foo { background: url(foo.png) center / cover no-repeat }",
+      ".foo{background:url(foo.png) 50%/cover no-repeat}",
+    );
+    minify_test(
+      ".foo { background: url(foo.png) 0% 0% / auto repeat repeat }",
+      ".foo{background:url(foo.png)}",
+    );
+    minify_test(
+      ".foo { background: url(foo.png) 0% 0% / cover }",
+      ".foo{background:url(foo.png) 0 0/cover}",
+    );
+    minify_test(
+      ".foo { background: url(foo.png); background-size: cover }",
+      ".foo{background:url(foo.png) 0 0/cover}",
+    );
+    minify_test(
+      ".foo { background: url(a.png) left top / 50% repeat-x, red url(b.png) center / contain }",
+      ".foo{background:url(a.png) 0 0/50% repeat-x,red url(b.png) 50%/contain}",
+    );
+    minify_test(
+      ".foo { background: url(a.png) center / cover, url(b.png); background-size: auto }",
+      ".foo{background:url(a.png) 50%,url(b.png)}",
+    );
+    minify_test(
+      ".foo { background: url(a.png), url(b.png); background-size: cover, contain; background-repeat: no-repeat }",
+      ".foo{background:url(a.png) 0 0/cover no-repeat,url(b.png) 0 0/contain no-repeat}",
+    );
+    minify_test(
+      ".foo { background-image: url(a.png), url(b.png); background-size: cover }",
+      ".foo{background-image:url(a.png),url(b.png);background-size:cover}",
+    );
+    minify_test(
+      ".foo { background: url(a.png), url(b.png); background-size: cover; background-position-x: 1px, 2px, 3px }",
+      ".foo{background-color:#0000;background-image:url(a.png),url(b.png);background-position-x:1px,2px,3px;background-position-y:0%,0%;background-repeat:repeat,repeat;background-size:cover;background-attachment:scroll,scroll;background-origin:padding-box,padding-box;background-clip:border-box,border-box}",
+    );
+    minify_test(
+      ".foo { background: url(a.png) center / cover; background-size: var(--size) }",
+      ".foo{background:url(a.png) 50%/cover;background-size:var(--size)}",
+    );
   }
 
   #[test]
@@ -5241,6 +5464,38 @@ mod tests {
       },
     );
 
+    minify_test(".foo { place-items: center center }", ".foo{place-items:center}");
+    minify_test(".foo { place-self: end end }", ".foo{place-self:end}");
+    minify_test(".foo { place-content: start start }", ".foo{place-content:start}");
+    minify_test(".foo { place-items: center start }", ".foo{place-items:center start}");
+    minify_test(".foo { place-items: normal legacy }", ".foo{place-items:normal legacy}");
+    minify_test(".foo { place-self: auto auto }", ".foo{place-self:auto}");
+    minify_test(".foo { place-content: normal normal }", ".foo{place-content:normal}");
+    minify_test(
+      ".foo { align-items: center; justify-items: center; align-self: end; justify-self: end; align-content: start; justify-content: start }",
+      ".foo{place-content:start;place-self:end;place-items:center}",
+    );
+    prefix_test(
+      r#"
+      .foo {
+        place-items: center;
+        place-self: end start;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        align-self: end;
+        justify-self: start;
+        align-items: center;
+        justify-items: center;
+      }
+    "#},
+      Browsers {
+        safari: Some(9 << 16),
+        ..Browsers::default()
+      },
+    );
+
     test(
       r#"
       .foo {
@@ -6097,6 +6352,105 @@ mod tests {
         ..Browsers::default()
       },
     );
+
+    // Modern flexbox is translated to the 2009 and 2012 syntaxes for the targets that need them.
+    prefix_test(
+      r#"
+      .foo {
+        display: flex;
+        flex-direction: row-reverse;
+        flex: 1;
+        order: 2;
+        justify-content: center;
+        align-items: center;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-box-orient: horizontal;
+        -webkit-box-direction: reverse;
+        -webkit-flex-direction: row-reverse;
+        flex-direction: row-reverse;
+        -webkit-box-flex: 1;
+        -webkit-flex: 1;
+        flex: 1;
+        -webkit-box-ordinal-group: 2;
+        -webkit-order: 2;
+        order: 2;
+        -webkit-box-pack: center;
+        -webkit-justify-content: center;
+        justify-content: center;
+        -webkit-box-align: center;
+        -webkit-align-items: center;
+        align-items: center;
+        display: -webkit-box;
+        display: -webkit-flex;
+        display: flex;
+      }
+    "#},
+      Browsers {
+        chrome: Some(20 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        display: flex;
+        flex-direction: row-reverse;
+        flex: 1;
+        order: 2;
+        justify-content: center;
+        align-items: center;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -ms-flex-direction: row-reverse;
+        flex-direction: row-reverse;
+        -ms-flex: 1;
+        flex: 1;
+        -ms-flex-order: 2;
+        order: 2;
+        -ms-flex-pack: center;
+        justify-content: center;
+        -ms-flex-align: center;
+        align-items: center;
+        display: -ms-flexbox;
+        display: flex;
+      }
+    "#},
+      Browsers {
+        ie: Some(10 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        display: flex;
+        flex-direction: row-reverse;
+        flex: 1;
+        order: 2;
+        justify-content: center;
+        align-items: center;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        flex-direction: row-reverse;
+        flex: 1;
+        order: 2;
+        justify-content: center;
+        align-items: center;
+        display: flex;
+      }
+    "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
   }
 
   #[test]
@@ -6133,7 +6487,7 @@ mod tests {
         line-height: 1.2em;
       }
     "#,
-      indoc! {".foo{font:italic small-caps 700 125% 12px/1.2em Helvetica,Times New Roman,sans-serif}"
+      indoc! {".foo{font:italic small-caps 700 expanded 12px/1.2em Helvetica,Times New Roman,sans-serif}"
       },
     );
 
@@ -6559,6 +6913,33 @@ mod tests {
         ..Browsers::default()
       },
     );
+
+    minify_test(
+      ".foo { font-style: normal; font-variant-caps: normal; font-weight: normal; font-stretch: normal; font-size: 12px; line-height: normal; font-family: Arial }",
+      ".foo{font:12px Arial}",
+    );
+    minify_test(
+      ".foo { font-style: italic; font-variant-caps: normal; font-weight: bold; font-stretch: normal; font-size: 12px; line-height: 1.5; font-family: Arial }",
+      ".foo{font:italic 700 12px/1.5 Arial}",
+    );
+    minify_test(
+      ".foo { font-size: 12px; font-family: Arial; line-height: 1.5 }",
+      ".foo{font-family:Arial;font-size:12px;line-height:1.5}",
+    );
+    minify_test(".foo { font: condensed 12px Arial }", ".foo{font:condensed 12px Arial}");
+    minify_test(
+      ".foo { font: 12px Arial; font-stretch: 75% }",
+      ".foo{font:condensed 12px Arial}",
+    );
+    minify_test(".foo { font: 12px Arial; font-stretch: 100% }", ".foo{font:12px Arial}");
+    minify_test(
+      ".foo { font: 12px Arial; font-stretch: 80% }",
+      ".foo{font:12px Arial;font-stretch:80%}",
+    );
+    minify_test(
+      ".foo { font: caption; font-size: 12px }",
+      ".foo{font:caption;font-size:12px}",
+    );
   }
 
   #[test]
@@ -7415,6 +7796,145 @@ mod tests {
         Token::SquareBracketBlock,
       )),
     );
+
+    // The selector list of :nth-child() is not forgiving, unlike :is() and :where().
+    error_test(
+      ":nth-child(1 of .foo, !!) {width: 20px}",
+      ParserError::SelectorError(SelectorError::EmptySelector),
+    );
+    error_test(
+      ":nth-child(1 of) {width: 20px}",
+      ParserError::SelectorError(SelectorError::EmptySelector),
+    );
+    minify_test(":is(:nth-child(1 of !!), .foo) {width: 20px}", ".foo{width:20px}");
+    test(
+      ".foo:has(> .bar, + .baz) {width: 20px}",
+      indoc! {r#"
+      .foo:has(> .bar, + .baz) {
+        width: 20px;
+      }
+    "#},
+    );
+
+    // Serialization is stable: serializing a re-parsed selector produces the same output.
+    use crate::selector::SelectorList;
+    use crate::traits::ParseWithOptions;
+    for source in [
+      ":is(.a, !!)",
+      ":where(.a, :unknown)",
+      ":is(:is(.a, !!) :is(.b))",
+      ":is(:has(> .a))",
+      ":has(~ .a, + .b)",
+      ":not(:is(.a, .b), .c)",
+      ":nth-child(2n + 1 of :is(.a, !!))",
+      ":is(:nth-child(1 of .a), :where())",
+      ":is(::before)",
+      ":is(div).a:is(.b .c)",
+      "::before:is(:hover)",
+      ":host(:is(.a)) ::slotted(.b)",
+      ".a\\:b #\\31 23 [c=\"d e\" i]",
+    ] {
+      for minify in [false, true] {
+        let options = || PrinterOptions {
+          minify,
+          ..PrinterOptions::default()
+        };
+        let selectors = SelectorList::parse_string_with_options(source, ParserOptions::default()).unwrap();
+        let serialized = selectors.to_css_string(options()).unwrap();
+        let reparsed = SelectorList::parse_string_with_options(&serialized, ParserOptions::default()).unwrap();
+        assert_eq!(reparsed.to_css_string(options()).unwrap(), serialized);
+      }
+    }
+
+    let deep_options = || ParserOptions {
+      flags: ParserFlags::DEEP_SELECTOR_COMBINATOR,
+      ..ParserOptions::default()
+    };
+    let parse = |source| SelectorList::parse_string_with_options(source, deep_options()).unwrap();
+    assert!(parse(".foo /deep/ .bar").uses_deep_combinator());
+    assert!(parse(".a, :is(.foo /deep/ .bar)").uses_deep_combinator());
+    assert!(!parse(".foo >>> .bar").uses_deep_combinator());
+    assert!(parse(".foo >>> .bar").contains_combinator(parcel_selectors::parser::Combinator::DeepDescendant));
+    assert!(parse(".a:has(> .b)").contains_combinator(parcel_selectors::parser::Combinator::Child));
+    assert!(!parse(".a .b").contains_combinator(parcel_selectors::parser::Combinator::Child));
+    assert!(
+      parse(":nth-child(1 of .a + .b)").contains_combinator(parcel_selectors::parser::Combinator::NextSibling)
+    );
+    assert_eq!(parse(".a").combinator_depth(), 0);
+    assert_eq!(parse(".a > .b .c").combinator_depth(), 2);
+    assert_eq!(parse(".a .b, .a > .b ~ .c + .d").combinator_depth(), 3);
+    assert_eq!(parse(".a .b::before").combinator_depth(), 1);
+    assert_eq!(parse(".a:is(.b .c .d)").combinator_depth(), 0);
+
+    // :is(), :not(), and :has() take the specificity of their most specific argument.
+    let specificity = |source| parse(source).0[0].specificity();
+    assert_eq!(specificity(".a:has(#b, .c)"), specificity(".a:is(#b, .c)"));
+    assert_eq!(specificity(".a:has(#b, .c)"), specificity(".a:not(#b, .c)"));
+    assert_eq!(specificity(".a:has(> .b span)"), specificity(".a.b span"));
+    assert_eq!(specificity(".a:where(#b)"), specificity(".a"));
+    assert_eq!(specificity(":nth-child(2 of #b)"), specificity(".a#b"));
+
+    prefix_test(
+      ".a:has(.x), .b:has(#y) { color: red }",
+      indoc! {r#"
+      .a:has(.x) {
+        color: red;
+      }
+
+      .b:has(#y) {
+        color: red;
+      }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".a:has(.x), .b:has(.y) { color: red }",
+      indoc! {r#"
+      :is(.a:has(.x), .b:has(.y)) {
+        color: red;
+      }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+    minify_test(
+      ".a:has( >  .b , + .c ~ .d) { color: red }",
+      ".a:has(>.b,+.c~.d){color:red}",
+    );
+
+    let list = parse("div, .a, #b, span.c, .d, div::before, :where(#e)");
+    let groups = crate::selector::group_by_specificity(&list.0);
+    let groups = groups
+      .iter()
+      .map(|(specificity, selectors)| {
+        (
+          specificity.to_string(),
+          selectors
+            .iter()
+            .map(|selector| selector.to_css_string(PrinterOptions::default()).unwrap())
+            .collect::<Vec<_>>(),
+        )
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(
+      groups,
+      vec![
+        ("(0,0,0)".into(), vec![":where(#e)".into()]),
+        ("(0,0,1)".into(), vec!["div".into()]),
+        ("(0,0,2)".into(), vec!["div:before".into()]),
+        ("(0,1,0)".into(), vec![".a".into(), ".d".into()]),
+        ("(0,1,1)".into(), vec!["span.c".into()]),
+        ("(1,0,0)".into(), vec!["#b".into()]),
+      ]
+    );
+    let specificity = parse("#a .b c").0[0].specificity_components();
+    assert_eq!(format!("{:?}", specificity), "(1,1,1)");
+    assert!(specificity > parse(".a .b .c .d").0[0].specificity_components());
   }
 
   #[test]
@@ -7478,22 +7998,44 @@ mod tests {
       "@keyframes \"none\"{0%{background:green}}",
     );
 
-    // named animation ranges cannot be used with to or from
     minify_test(
       r#"
-      @keyframes test {
-        entry to {
-          background: blue
+      @keyframes "123" {
+        from {
+          background: green;
         }
       }
     "#,
-      "@keyframes test{}",
+      "@keyframes \"123\"{0%{background:green}}",
     );
 
-    // CSS-wide keywords without quotes throws an error.
-    error_test(
+    minify_test(
       r#"
-      @keyframes revert {}
+      @keyframes "" {
+        from {
+          background: green;
+        }
+      }
+    "#,
+      "@keyframes \"\"{0%{background:green}}",
+    );
+
+    // named animation ranges cannot be used with to or from
+    minify_test(
+      r#"
+      @keyframes test {
+        entry to {
+          background: blue
+        }
+      }
+    "#,
+      "@keyframes test{}",
+    );
+
+    // CSS-wide keywords without quotes throws an error.
+    error_test(
+      r#"
+      @keyframes revert {}
     "#,
       ParserError::UnexpectedToken(Token::Ident("revert".into())),
     );
@@ -7833,6 +8375,474 @@ mod tests {
     "#,
       "@keyframes test{to{background:#00f}}@-webkit-keyframes test{to{background:red}}",
     );
+
+    let source = r#"
+      @keyframes test {
+        from { opacity: 0 }
+        50% { opacity: .5 }
+        to { opacity: 1 }
+      }
+
+      @keyframes test2 {
+        0% { opacity: 0 }
+        100% { opacity: 1 }
+      }
+    "#;
+    let stylesheet = StyleSheet::parse(source, ParserOptions::default()).unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        minify: true,
+        keyframe_selectors: KeyframeSelectorStyle::Explicit,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(
+      res.code,
+      "@keyframes test{0%{opacity:0}50%{opacity:.5}100%{opacity:1}}@keyframes test2{0%{opacity:0}100%{opacity:1}}"
+    );
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        keyframe_selectors: KeyframeSelectorStyle::Explicit,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(
+      res.code,
+      indoc! {r#"
+      @keyframes test {
+        0% {
+          opacity: 0;
+        }
+
+        50% {
+          opacity: .5;
+        }
+
+        100% {
+          opacity: 1;
+        }
+      }
+
+      @keyframes test2 {
+        0% {
+          opacity: 0;
+        }
+
+        100% {
+          opacity: 1;
+        }
+      }
+      "#}
+    );
+  }
+
+  #[test]
+  fn test_merge_keyframes() {
+    fn merge_keyframes_test(source: &str, expected: &str) {
+      let mut stylesheet = StyleSheet::parse(source, ParserOptions::default()).unwrap();
+      stylesheet
+        .minify(MinifyOptions {
+          merge_keyframes: true,
+          ..MinifyOptions::default()
+        })
+        .unwrap();
+      let res = stylesheet
+        .to_css(PrinterOptions {
+          minify: true,
+          ..PrinterOptions::default()
+        })
+        .unwrap();
+      assert_eq!(res.code, expected);
+    }
+
+    merge_keyframes_test(
+      r#"
+      @keyframes spin {
+        from { opacity: 0 }
+        50% { opacity: 0.5 }
+      }
+      @keyframes spin {
+        0% { opacity: 0.2 }
+        to { opacity: 1 }
+      }
+    "#,
+      "@keyframes spin{50%{opacity:.5}0%{opacity:.2}to{opacity:1}}",
+    );
+    merge_keyframes_test(
+      r#"
+      @keyframes spin {
+        0%, 100% { color: red }
+      }
+      @keyframes spin {
+        to { color: green }
+      }
+    "#,
+      "@keyframes spin{0%{color:red}to{color:green}}",
+    );
+    merge_keyframes_test(
+      r#"
+      @keyframes spin {
+        from { color: red }
+      }
+      @keyframes fade {
+        from { opacity: 0 }
+      }
+      @keyframes spin {
+        to { color: green }
+      }
+    "#,
+      "@keyframes spin{0%{color:red}to{color:green}}@keyframes fade{0%{opacity:0}}",
+    );
+    merge_keyframes_test(
+      r#"
+      @-webkit-keyframes spin {
+        from { color: red }
+      }
+      @keyframes spin {
+        to { color: green }
+      }
+    "#,
+      "@-webkit-keyframes spin{0%{color:red}}@keyframes spin{to{color:green}}",
+    );
+
+    // Without the option, only the last rule is kept.
+    minify_test(
+      r#"
+      @keyframes spin {
+        from { opacity: 0 }
+        50% { opacity: 0.5 }
+      }
+      @keyframes spin {
+        to { opacity: 1 }
+      }
+    "#,
+      "@keyframes spin{to{opacity:1}}",
+    );
+  }
+
+  #[test]
+  fn test_keyframe_collisions() {
+    let source = r#"
+      @keyframes slide-in { from { opacity: 0 } }
+      @keyframes fade { from { opacity: 0 } }
+      @-webkit-keyframes slide-in { from { opacity: 0 } }
+      @media (min-width: 100px) {
+        @keyframes "slide-in" { to { opacity: 1 } }
+      }
+      @keyframes Fade { to { opacity: 1 } }
+      @layer base {
+        @keyframes slide-in { to { opacity: .5 } }
+      }
+    "#;
+    let stylesheet = StyleSheet::parse(source, ParserOptions::default()).unwrap();
+    let conflicts = stylesheet.rules.detect_keyframe_collisions();
+    let conflicts = conflicts
+      .iter()
+      .map(|conflict| {
+        (
+          conflict.first.name.as_str(),
+          conflict.first.loc.line,
+          conflict.second.loc.line,
+        )
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(conflicts, vec![("slide-in", 1, 5), ("slide-in", 1, 9)]);
+
+    let stylesheet = StyleSheet::parse(
+      "@keyframes a { from { opacity: 0 } } @-webkit-keyframes a { from { opacity: 0 } }",
+      ParserOptions::default(),
+    )
+    .unwrap();
+    assert!(stylesheet.rules.detect_keyframe_collisions().is_empty());
+  }
+
+  #[test]
+  fn test_keyframes_custom_properties() {
+    // Token lists inside keyframes, including ones that look like keyframe selectors or
+    // animation keywords, should round-trip unchanged when no color fallbacks are needed.
+    let values = [
+      "from",
+      "to 50%",
+      "from to infinite alternate",
+      "ease-in-out 1s infinite reverse",
+      "@keyframes foo",
+      "{a:b}",
+      "a,b",
+      "var(--a) calc(1px + 2px)",
+      "rgb(from var(--c) r g b)",
+    ];
+
+    let targets = Browsers {
+      chrome: Some(90 << 16),
+      safari: Some(14 << 16),
+      ..Browsers::default()
+    };
+
+    for value in values {
+      minify_test(
+        &format!("@keyframes foo {{ from {{ --custom: {} }} }}", value),
+        &format!("@keyframes foo{{0%{{--custom:{}}}}}", value),
+      );
+      // Blocks are only valid in custom properties.
+      if !value.starts_with('{') {
+        minify_test(
+          &format!("@keyframes foo {{ to {{ animation: var(--x) {} }} }}", value),
+          &format!("@keyframes foo{{to{{animation:var(--x) {}}}}}", value),
+        );
+      }
+      prefix_test(
+        &format!("@keyframes foo {{ from {{ --custom: {} }} }}", value),
+        &format!("@keyframes foo {{\n  from {{\n    --custom: {};\n  }}\n}}\n", value),
+        targets,
+      );
+    }
+
+    // Whitespace and comments are preserved when not minifying, including in color fallbacks.
+    prefix_test(
+      r#"
+      @keyframes foo {
+        from {
+          --custom: from   to /* c */ infinite;
+          --color: lab(50% 20 30)  from;
+        }
+      }
+    "#,
+      indoc! { r#"
+      @keyframes foo {
+        from {
+          --custom: from   to /* c */ infinite;
+          --color: #a16945  from;
+        }
+      }
+
+      @supports (color: lab(0% 0 0)) {
+        @keyframes foo {
+          from {
+            --custom: from   to /* c */ infinite;
+            --color: lab(50% 20 30)  from;
+          }
+        }
+      }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_apply_patch() {
+    fn patch_test(source: &str, byte_range: std::ops::Range<usize>, new_text: &str) {
+      let code = format!(
+        "{}{}{}",
+        &source[..byte_range.start],
+        new_text,
+        &source[byte_range.end..]
+      );
+      let mut stylesheet = StyleSheet::parse(source, ParserOptions::default()).unwrap();
+      let res = stylesheet
+        .apply_patch(&code, StyleSheetPatch::Changed { byte_range, new_text })
+        .map(|_| format!("{:?}", stylesheet));
+      let expected = StyleSheet::parse(&code, ParserOptions::default()).map(|s| format!("{:?}", s));
+      assert_eq!(res, expected);
+    }
+
+    let source = indoc! {r#"
+      @import "foo.css";
+      .foo { color: red }
+      @media print {
+        .bar { color: green }
+      }
+      .baz { color: blue } .qux { color: yellow }
+      @keyframes fade {
+        from { opacity: 0 }
+      }
+    "#};
+    let pos = |s: &str| source.find(s).unwrap();
+
+    // Change a value within a rule.
+    patch_test(source, pos("red")..pos("red") + 3, "purple");
+    // Change a value within a nested rule.
+    patch_test(source, pos("green")..pos("green") + 5, "lime;\n    background: none");
+    // Change a rule that shares a line with a following rule.
+    patch_test(source, pos("blue")..pos("blue") + 4, "navy");
+    patch_test(source, pos(".baz")..pos(".baz") + 4, ".a,\n.b");
+    // Insert and remove whole rules.
+    patch_test(source, pos(".foo")..pos(".foo"), ".new { color: pink }\n");
+    patch_test(source, pos(".foo")..pos("@media"), "");
+    patch_test(source, source.len()..source.len(), ".end { color: white }");
+    // Changes that affect the following rules.
+    patch_test(source, pos("red }")..pos("red }") + 5, "red");
+    patch_test(source, pos("red }")..pos("red }") + 5, "red } /*");
+    patch_test(source, pos(".foo")..pos(".foo"), "@import \"bar.css\";\n");
+    patch_test(source, pos("@import")..pos(".foo"), "");
+    patch_test(source, 0..0, "/*! license */\n");
+
+    // A leading @charset rule with an encoding other than UTF-8 is kept.
+    let source = "@charset \"ISO-8859-1\";\n.foo { color: red }\n.bar { color: green }";
+    let pos = |s: &str| source.find(s).unwrap();
+    patch_test(source, pos("red")..pos("red") + 3, "purple");
+    patch_test(source, pos("green")..pos("green") + 5, "lime");
+    patch_test(source, pos("ISO-8859-1")..pos("ISO-8859-1") + 10, "UTF-8");
+
+    // Patching a minified style sheet re-parses it.
+    let code = ".foo { color: red } .bar { color: red }";
+    let mut stylesheet = StyleSheet::parse(code, ParserOptions::default()).unwrap();
+    stylesheet.minify(MinifyOptions::default()).unwrap();
+    let new_code = ".foo { color: red } .bar { color: blue }";
+    stylesheet
+      .apply_patch(
+        new_code,
+        StyleSheetPatch::Changed {
+          byte_range: 34..37,
+          new_text: "blue",
+        },
+      )
+      .unwrap();
+    let res = stylesheet.to_css(PrinterOptions::default()).unwrap();
+    assert_eq!(res.code, ".foo {\n  color: red;\n}\n\n.bar {\n  color: #00f;\n}\n");
+
+    // Errors are reported at their location in the new source.
+    let mut stylesheet = StyleSheet::parse(code, ParserOptions::default()).unwrap();
+    let error = stylesheet
+      .apply_patch(
+        ".foo { color: red }\n.bar! { color: red }",
+        StyleSheetPatch::Changed {
+          byte_range: 19..24,
+          new_text: "\n.bar!",
+        },
+      )
+      .unwrap_err();
+    assert_eq!(
+      error.loc,
+      Some(ErrorLocation {
+        filename: "".into(),
+        line: 1,
+        column: 5
+      })
+    );
+    assert_eq!(stylesheet.rules.0.len(), 2);
+  }
+
+  #[cfg(feature = "rayon")]
+  #[test]
+  fn test_minify_parallel() {
+    fn parallel_test(source: &str, options: impl Fn() -> MinifyOptions) {
+      let mut stylesheet = StyleSheet::parse(source, ParserOptions::default()).unwrap();
+      stylesheet.minify(options()).unwrap();
+      let expected = stylesheet.to_css(PrinterOptions::default()).unwrap();
+
+      let mut stylesheet = StyleSheet::parse(source, ParserOptions::default()).unwrap();
+      stylesheet.minify_parallel(options()).unwrap();
+      let res = stylesheet.to_css(PrinterOptions::default()).unwrap();
+      assert_eq!(res.code, expected.code);
+    }
+
+    let source = r#"
+      .foo { color: red; background: yellow }
+      .bar { color: red; background: yellow }
+      .foo { padding-inline-start: 10px; color: lab(40% 56.6 39) }
+      .baz { inset-inline: 2px; &:hover { color: blue; margin-inline-end: 4px } }
+      @media (min-width: 100px) {
+        .qux { transition: opacity 200ms }
+        .qux { user-select: none }
+      }
+      .unused { color: green }
+      .foo:is(.a, .b) { color: oklch(60% 0.2 120) }
+      @keyframes fade { from { opacity: 0 } to { opacity: 1 } }
+      .baz::placeholder, .baz::selection { color: red }
+    "#;
+
+    parallel_test(source, MinifyOptions::default);
+    parallel_test(source, || MinifyOptions {
+      targets: Browsers {
+        safari: Some(8 << 16),
+        chrome: Some(60 << 16),
+        firefox: Some(60 << 16),
+        ..Browsers::default()
+      }
+      .into(),
+      unused_symbols: ["unused".into()].into_iter().collect(),
+      ..MinifyOptions::default()
+    });
+
+    // Errors are reported for the first invalid rule in source order.
+    let source = ".foo { color: red } @media print { .a { color: red } } div { color: red } span { color: red }";
+    let options = || ParserOptions {
+      css_modules: Some(crate::css_modules::Config {
+        pure: true,
+        ..Default::default()
+      }),
+      ..ParserOptions::default()
+    };
+    let mut stylesheet = StyleSheet::parse(source, options()).unwrap();
+    let expected = stylesheet.minify(MinifyOptions::default()).unwrap_err();
+    let mut stylesheet = StyleSheet::parse(source, options()).unwrap();
+    let error = stylesheet.minify_parallel(MinifyOptions::default()).unwrap_err();
+    assert_eq!(error, expected);
+  }
+
+  #[test]
+  fn test_transform_keyframes() {
+    use crate::rules::keyframes::{Keyframe, KeyframeSelector};
+
+    let mut stylesheet = StyleSheet::parse(
+      r#"
+      @keyframes a {
+        to { opacity: 1 }
+      }
+
+      @media (width > 100px) {
+        @supports (display: grid) {
+          @keyframes b {
+            0% { opacity: 0.5 }
+            100% { opacity: 1 }
+          }
+        }
+      }
+    "#,
+      ParserOptions::default(),
+    )
+    .unwrap();
+
+    let mut names = Vec::new();
+    stylesheet.transform_keyframes(|keyframes| {
+      names.push(keyframes.name.clone());
+      let has_start = keyframes.keyframes.iter().any(|k| {
+        k.selectors.iter().any(|s| match s {
+          KeyframeSelector::From => true,
+          KeyframeSelector::Percentage(p) => p.0 == 0.0,
+          _ => false,
+        })
+      });
+      if !has_start {
+        keyframes.keyframes.insert(
+          0,
+          Keyframe {
+            selectors: vec![KeyframeSelector::From],
+            declarations: crate::declaration::DeclarationBlock::parse_string(
+              "opacity: 0",
+              ParserOptions::default(),
+            )
+            .unwrap(),
+          },
+        );
+      }
+    });
+    assert_eq!(names.len(), 2);
+
+    stylesheet.minify(MinifyOptions::default()).unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        minify: true,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(
+      res.code,
+      "@keyframes a{0%{opacity:0}to{opacity:1}}@media (width>100px){@supports (display:grid){@keyframes b{0%{opacity:.5}to{opacity:1}}}}"
+    );
   }
 
   #[test]
@@ -8069,13 +9079,55 @@ mod tests {
       ".foo { width: clamp(-100px, 0px, 50% - 50vw); }",
       ".foo{width:clamp(-100px,0px,50% - 50vw)}",
     );
-
+    minify_test(".foo { width: clamp(10px, 1em, 5px) }", ".foo{width:10px}");
+    minify_test(".foo { width: clamp(5px, 1em, 5px) }", ".foo{width:5px}");
+    minify_test(".foo { width: clamp(1in, 1em, 50px) }", ".foo{width:1in}");
     minify_test(
-      ".foo { top: calc(-1 * clamp(1.75rem, 8vw, 4rem)) }",
-      ".foo{top:calc(-1*clamp(1.75rem,8vw,4rem))}",
+      ".foo { width: clamp(10%, 1em, 20px) }",
+      ".foo{width:clamp(10%,1em,20px)}",
     );
     minify_test(
-      ".foo { top: calc(-1 * min(1.75rem, 8vw, 4rem)) }",
+      ".foo { width: clamp(10px, var(--x), 5px) }",
+      ".foo{width:clamp(10px, var(--x), 5px)}",
+    );
+    minify_test(".foo { flex-grow: clamp(3, 1, 2) }", ".foo{flex-grow:3}");
+
+    // Folded values should match evaluating the functions at runtime.
+    fn fold_test(value: &str, expected: f32) {
+      let expected = format!(".foo{{margin-left:{}px}}", expected);
+      let mut stylesheet = StyleSheet::parse(&expected, ParserOptions::default()).unwrap();
+      stylesheet.minify(MinifyOptions::default()).unwrap();
+      let expected = stylesheet
+        .to_css(PrinterOptions {
+          minify: true,
+          ..PrinterOptions::default()
+        })
+        .unwrap();
+      minify_test(&format!(".foo {{ margin-left: {} }}", value), &expected.code);
+    }
+
+    let mut seed = 1u32;
+    let mut next = || {
+      seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+      ((seed >> 16) % 200) as f32 - 100.0
+    };
+    for _ in 0..100 {
+      let (a, b, c) = (next(), next(), next());
+      fold_test(&format!("min({}px, {}px, {}px)", a, b, c), a.min(b).min(c));
+      fold_test(&format!("max({}px, {}px, {}px)", a, b, c), a.max(b).max(c));
+      fold_test(&format!("clamp({}px, {}px, {}px)", a, b, c), a.max(b.min(c)));
+      fold_test(
+        &format!("clamp({}px, {}px + 2px, {}px)", a, b, c),
+        a.max((b + 2.0).min(c)),
+      );
+    }
+
+    minify_test(
+      ".foo { top: calc(-1 * clamp(1.75rem, 8vw, 4rem)) }",
+      ".foo{top:calc(-1*clamp(1.75rem,8vw,4rem))}",
+    );
+    minify_test(
+      ".foo { top: calc(-1 * min(1.75rem, 8vw, 4rem)) }",
       ".foo{top:calc(-1*min(1.75rem,8vw))}",
     );
     minify_test(
@@ -8646,6 +9698,10 @@ mod tests {
       "@media (aspect-ratio: 2) { .foo { color: chartreuse }}",
       "@media (aspect-ratio:2){.foo{color:#7fff00}}",
     );
+    minify_test(
+      "@media (min-aspect-ratio: 1920/1080) { .foo { color: chartreuse }}",
+      "@media (aspect-ratio>=16/9){.foo{color:#7fff00}}",
+    );
     minify_test(
       "@media not screen and (color) { .foo { color: chartreuse }}",
       "@media not screen and (color){.foo{color:#7fff00}}",
@@ -8658,6 +9714,47 @@ mod tests {
       "@media (update: slow) or (hover: none) { .foo { color: chartreuse }}",
       "@media (update:slow) or (hover:none){.foo{color:#7fff00}}",
     );
+    minify_test(
+      "@media (scripting: ENABLED) and (update: fast) { .foo { color: chartreuse }}",
+      "@media (scripting:enabled) and (update:fast){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (overflow-block: paged) and (overflow-inline: scroll) { .foo { color: chartreuse }}",
+      "@media (overflow-block:paged) and (overflow-inline:scroll){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (prefers-contrast: more) or (prefers-reduced-transparency: reduce) or (forced-colors: active) { .foo { color: chartreuse }}",
+      "@media (prefers-contrast:more) or (prefers-reduced-transparency:reduce) or (forced-colors:active){.foo{color:#7fff00}}",
+    );
+    error_test(
+      "@media (scripting: maybe) { .foo { color: chartreuse }}",
+      ParserError::InvalidMediaQuery,
+    );
+    error_test(
+      "@media (update: foo) { .foo { color: chartreuse }}",
+      ParserError::InvalidMediaQuery,
+    );
+    let warnings =
+      error_recovery_test("@media (scripting: maybe), (update: slow), (forced-colors: on) { .foo { color: red }}");
+    assert_eq!(
+      warnings
+        .iter()
+        .map(|w| (w.kind.clone(), w.loc.clone().unwrap().column))
+        .collect::<Vec<_>>(),
+      vec![
+        (ParserError::InvalidMediaQuery, 20),
+        (ParserError::InvalidMediaQuery, 60),
+      ]
+    );
+    // When recovering from errors, queries with unknown keywords are kept.
+    minify_test_with_options(
+      "@media (scripting: maybe) { .foo { color: red }}",
+      "@media (scripting:maybe){.foo{color:red}}",
+      ParserOptions {
+        error_recovery: true,
+        ..ParserOptions::default()
+      },
+    );
     minify_test(
       "@media (width < 600px) and (height < 600px) { .foo { color: chartreuse }}",
       "@media (width<600px) and (height<600px){.foo{color:#7fff00}}",
@@ -9261,7 +10358,7 @@ mod tests {
       }
       "#,
       indoc! { r#"
-        @media (-webkit-min-device-pixel-ratio: 2), (min--moz-device-pixel-ratio: 2), (min-resolution: 2dppx), (min-resolution: 192dpi) {
+        @media (-webkit-min-device-pixel-ratio: 2), (min--moz-device-pixel-ratio: 2), (min-resolution: 2dppx) {
           .foo {
             color: #ff0;
           }
@@ -9273,6 +10370,188 @@ mod tests {
         ..Browsers::default()
       },
     );
+    minify_test(
+      "@media (min-resolution: 192dpi) { .foo { color: red } }",
+      "@media (resolution>=2x){.foo{color:red}}",
+    );
+    minify_test(
+      "@media (resolution: 96dpi) { .foo { color: red } }",
+      "@media (resolution:1x){.foo{color:red}}",
+    );
+    minify_test(
+      "@media (min-resolution: 100dpcm) { .foo { color: red } }",
+      "@media (resolution>=254dpi){.foo{color:red}}",
+    );
+    minify_test(
+      "@media (min-resolution: 150dpi) { .foo { color: red } }",
+      "@media (resolution>=150dpi){.foo{color:red}}",
+    );
+    minify_test(
+      "@media (min-resolution: 1000000dpi) { .foo { color: red } }",
+      "@media (resolution>=1000000dpi){.foo{color:red}}",
+    );
+    minify_test(
+      "@media (min-resolution: 288dpi) { .foo { color: red } }",
+      "@media (resolution>=3x){.foo{color:red}}",
+    );
+    minify_test(
+      "@media (min-resolution: 2x) and (min-resolution: 96dpi) { .foo { color: red } }",
+      "@media (resolution>=2x){.foo{color:red}}",
+    );
+    minify_test(
+      "@media (resolution > 2x) and (resolution >= 192dpi) and (max-resolution: 3x) { .foo { color: red } }",
+      "@media (resolution>2x) and (resolution<=3x){.foo{color:red}}",
+    );
+    minify_test(
+      "@media (min-resolution: 2x) or (min-resolution: 96dpi) { .foo { color: red } }",
+      "@media (resolution>=1x){.foo{color:red}}",
+    );
+    minify_test(
+      "@media (min-resolution: 2x), (min-resolution: 192dpi) { .foo { color: red } }",
+      "@media (resolution>=2x){.foo{color:red}}",
+    );
+    minify_test(
+      "@media (min-resolution: 2dppx), (min-resolution: 3dppx) { .foo { color: red } }",
+      "@media (resolution>=2x){.foo{color:red}}",
+    );
+    minify_test(
+      "@media (max-resolution: 2dppx), (resolution < 3x), print and (min-resolution: 3x) { .foo { color: red } }",
+      "@media (resolution<3x),print and (resolution>=3x){.foo{color:red}}",
+    );
+    minify_test(
+      "@media not all and (min-resolution: 2x), not all and (min-resolution: 3x) { .foo { color: red } }",
+      "@media not all and (resolution>=2x),not all and (resolution>=3x){.foo{color:red}}",
+    );
+    minify_test(
+      "@media (min-width: 100px), (min-width: 200px), (min-height: 50px) { .foo { color: red } }",
+      "@media (width>=100px),(height>=50px){.foo{color:red}}",
+    );
+    minify_test(
+      "@media (min-width: 10em), (min-width: 200px) { .foo { color: red } }",
+      "@media (width>=10em),(width>=200px){.foo{color:red}}",
+    );
+    minify_test(
+      "@media (max-width: 500px) and (max-width: 30rem) and (max-width: 400px) { .foo { color: red } }",
+      "@media (width<=400px) and (width<=30rem){.foo{color:red}}",
+    );
+    minify_test(
+      "@media (max-width: 500px) and (max-width: 400px) { .foo { color: red } }",
+      "@media (width<=400px){.foo{color:red}}",
+    );
+    prefix_test(
+      "@media (min-resolution: 192dpi) { .foo { color: red } }",
+      indoc! {r#"
+      @media (min-resolution: 192dpi) {
+        .foo {
+          color: red;
+        }
+      }
+      "#},
+      Browsers {
+        ie: Some(11 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      "@media (min-resolution: 100dpcm) { .foo { color: red } }",
+      indoc! {r#"
+      @media (min-resolution: 254dpi) {
+        .foo {
+          color: red;
+        }
+      }
+      "#},
+      Browsers {
+        ie: Some(11 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    // Logical simplification of media conditions.
+    minify_test(
+      "@media not (not (color)) { .foo { color: red } }",
+      "@media (color){.foo{color:red}}",
+    );
+    minify_test(
+      "@media (color) and (not (not (hover))) { .foo { color: red } }",
+      "@media (color) and (hover){.foo{color:red}}",
+    );
+    minify_test(
+      "@media (not (color)) and (not (hover)) { .foo { color: red } }",
+      "@media not ((color) or (hover)){.foo{color:red}}",
+    );
+    minify_test(
+      "@media (not (color)) or (not (hover)) { .foo { color: red } }",
+      "@media not ((color) and (hover)){.foo{color:red}}",
+    );
+    minify_test(
+      "@media screen and (not ((not (color)) and (not (hover)))) { .foo { color: red } }",
+      "@media screen and ((color) or (hover)){.foo{color:red}}",
+    );
+    minify_test(
+      "@media (not (color)) and (not (hover)) and (monochrome) { .foo { color: red } }",
+      "@media (not (color)) and (not (hover)) and (monochrome){.foo{color:red}}",
+    );
+    minify_test(
+      "@media (not (color)) and (not (min-width: 100px)) { .foo { color: red } }",
+      "@media (not (color)) and (width<100px){.foo{color:red}}",
+    );
+    minify_test(
+      "@media screen and (min-width: 0) { .foo { color: red } }",
+      "@media screen{.foo{color:red}}",
+    );
+    minify_test(
+      "@media (min-width: 0) and (color) and (min-height: 0) { .foo { color: red } }",
+      "@media (color){.foo{color:red}}",
+    );
+    minify_test("@media (min-width: 0) { .foo { color: red } }", ".foo{color:red}");
+    minify_test(
+      "@media print, (min-width: 0) { .foo { color: red } }",
+      ".foo{color:red}",
+    );
+    minify_test("@media not (min-width: 0) { .foo { color: red } }", "");
+    minify_test(
+      "@media not screen and (min-width: 0) { .foo { color: red } }",
+      "@media not screen{.foo{color:red}}",
+    );
+    minify_test(
+      "@media not print and (not (min-width: 0)) { .foo { color: red } }",
+      ".foo{color:red}",
+    );
+    minify_test(
+      "@media (not (min-width: 0)) or (color), print { .foo { color: red } }",
+      "@media (color),print{.foo{color:red}}",
+    );
+    minify_test(
+      "@media (not (min-width: 0)), print { .foo { color: red } }",
+      "@media print{.foo{color:red}}",
+    );
+    // Conditions that only look trivially true must be preserved.
+    // Negative lengths are invalid in some engines, so the query never matches there.
+    minify_test(
+      "@media (min-height: -10px) { .foo { color: red } }",
+      "@media (height>=-10px){.foo{color:red}}",
+    );
+    minify_test(
+      "@media (width > -1px) or (color) { .foo { color: red } }",
+      "@media (width>-1px) or (color){.foo{color:red}}",
+    );
+    minify_test(
+      "@media (width > 0) { .foo { color: red } }",
+      "@media (width>0){.foo{color:red}}",
+    );
+    minify_test(
+      "@media (max-width: 0) { .foo { color: red } }",
+      "@media (width<=0){.foo{color:red}}",
+    );
+    minify_test(
+      "@media (min-aspect-ratio: 0/1) { .foo { color: red } }",
+      "@media (aspect-ratio>=0){.foo{color:red}}",
+    );
+    minify_test(
+      "@media (min-width: calc(0px + 1px)) { .foo { color: red } }",
+      "@media (width>=1px){.foo{color:red}}",
+    );
     prefix_test(
       r#"
       @media only screen and (min-resolution: 124.8dpi) {
@@ -9374,6 +10653,254 @@ mod tests {
     error_recovery_test("@media unknown(foo) {}");
   }
 
+  #[test]
+  fn test_split_color_schemes() {
+    fn split_test(source: &str, expected_light: &str, expected_dark: &str) {
+      let stylesheet = StyleSheet::parse(source, ParserOptions::default()).unwrap();
+      let (light, dark) = stylesheet.split_color_schemes();
+      let options = || PrinterOptions {
+        minify: true,
+        ..PrinterOptions::default()
+      };
+      assert_eq!(light.to_css(options()).unwrap().code, expected_light);
+      assert_eq!(dark.to_css(options()).unwrap().code, expected_dark);
+    }
+
+    split_test(
+      r#"
+      .foo { color: black; background: white }
+      @media (prefers-color-scheme: dark) {
+        .foo { color: white; background: black }
+      }
+      .bar { color: red }
+    "#,
+      ".foo{color:#000;background:#fff}.bar{color:red}",
+      ".foo{color:#000;background:#fff}.foo{color:#fff;background:#000}.bar{color:red}",
+    );
+    split_test(
+      r#"
+      @media (prefers-color-scheme: light) { .foo { color: black } }
+      @media (prefers-color-scheme: dark) { .foo { color: white } }
+    "#,
+      ".foo{color:#000}",
+      ".foo{color:#fff}",
+    );
+    split_test(
+      r#"
+      @media not (prefers-color-scheme: dark) { .foo { color: black } }
+      @media (prefers-color-scheme) { .bar { color: red } }
+    "#,
+      ".foo{color:#000}.bar{color:red}",
+      ".bar{color:red}",
+    );
+    split_test(
+      r#"
+      @media screen and (prefers-color-scheme: dark) and (min-width: 500px) { .foo { color: white } }
+      @media print, (prefers-color-scheme: dark) { .bar { color: white } }
+      @media (prefers-color-scheme: dark) or (min-width: 500px) { .baz { color: white } }
+      @media (min-width: 500px) { .qux { color: red } }
+    "#,
+      "@media print{.bar{color:#fff}}@media (width>=500px){.baz{color:#fff}}@media (width>=500px){.qux{color:red}}",
+      "@media screen and (width>=500px){.foo{color:#fff}}.bar{color:#fff}.baz{color:#fff}@media (width>=500px){.qux{color:red}}",
+    );
+    split_test(
+      r#"
+      @supports (display: grid) {
+        @media (prefers-color-scheme: dark) { .foo { color: white } }
+      }
+      .foo {
+        color: black;
+        @media (prefers-color-scheme: dark) { color: white }
+      }
+    "#,
+      "@supports (display:grid){}.foo{color:#000}",
+      "@supports (display:grid){.foo{color:#fff}}.foo{color:#000;color:#fff}",
+    );
+    split_test(
+      r#"
+      @media (prefers-color-scheme: dark) {
+        @media (prefers-color-scheme: dark) { .foo { color: white } }
+      }
+      @media (min-width: 500px) {
+        @media (prefers-color-scheme: light) { .bar { color: black } }
+      }
+    "#,
+      "@media (width>=500px){.bar{color:#000}}",
+      ".foo{color:#fff}@media (width>=500px){}",
+    );
+  }
+
+  #[test]
+  fn test_unsupported_selectors() {
+    fn unsupported_test(
+      source: &str,
+      expected: &str,
+      policy: UnsupportedSelectorPolicy,
+      browsers: Browsers,
+      expected_warnings: &[(&str, u32)],
+    ) {
+      use std::sync::{Arc, RwLock};
+      let warnings = Arc::new(RwLock::new(Vec::new()));
+      let mut stylesheet = StyleSheet::parse(source, ParserOptions::default()).unwrap();
+      stylesheet
+        .minify(MinifyOptions {
+          targets: browsers.into(),
+          unsupported_selectors: policy,
+          warnings: Some(warnings.clone()),
+          ..MinifyOptions::default()
+        })
+        .unwrap();
+      let res = stylesheet
+        .to_css(PrinterOptions {
+          minify: true,
+          ..PrinterOptions::default()
+        })
+        .unwrap();
+      assert_eq!(res.code, expected);
+      let warnings = warnings.read().unwrap();
+      let warnings: Vec<_> = warnings
+        .iter()
+        .map(|warning| (warning.kind.to_string(), warning.loc.as_ref().unwrap().line))
+        .collect();
+      let expected_warnings: Vec<_> = expected_warnings
+        .iter()
+        .map(|(name, line)| {
+          (
+            format!("The {} selector is not supported by the configured targets", name),
+            *line,
+          )
+        })
+        .collect();
+      assert_eq!(warnings, expected_warnings);
+    }
+
+    let old = Browsers {
+      safari: Some(14 << 16),
+      ..Browsers::default()
+    };
+    let source = indoc! {r#"
+      .a:has(> img) { color: red }
+      .b { color: green }
+      @media print {
+        .c:focus-visible { color: blue }
+      }
+      @supports selector(:has(a)) {
+        .d:has(a) { color: red }
+      }
+      .e { &:has(a) { color: red } }
+    "#};
+
+    unsupported_test(
+      source,
+      ".a:has(>img){color:red}.b{color:green}@media print{.c:focus-visible{color:#00f}}@supports selector(:has(a)){.d:has(a){color:red}}.e{&:has(a){color:red}}",
+      UnsupportedSelectorPolicy::Keep,
+      old,
+      &[],
+    );
+    unsupported_test(
+      source,
+      ".a:has(>img){color:red}.b{color:green}@media print{.c:focus-visible{color:#00f}}@supports selector(:has(a)){.d:has(a){color:red}}.e{&:has(a){color:red}}",
+      UnsupportedSelectorPolicy::Warn,
+      old,
+      &[(":has()", 0), (":focus-visible", 3), (":has()", 8)],
+    );
+    unsupported_test(
+      source,
+      ".b{color:green}@supports selector(:has(a)){.d:has(a){color:red}}",
+      UnsupportedSelectorPolicy::Remove,
+      old,
+      &[(":has()", 0), (":focus-visible", 3), (":has()", 8)],
+    );
+    unsupported_test(
+      ".a:is(.b, :has(.c)) { color: red } .d:not(:focus-within) { color: red }",
+      "",
+      UnsupportedSelectorPolicy::Remove,
+      Browsers {
+        ie: Some(11 << 16),
+        ..Browsers::default()
+      },
+      &[(":has()", 0), (":focus-within", 0)],
+    );
+    unsupported_test(
+      ".a:has(> img) { color: red }",
+      ".a:has(>img){color:red}",
+      UnsupportedSelectorPolicy::Remove,
+      Browsers {
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
+      &[],
+    );
+  }
+
+  #[test]
+  fn test_media_known_dimensions() {
+    fn known_dimensions_test(source: &str, expected: &str) {
+      let mut stylesheet = StyleSheet::parse(source, ParserOptions::default()).unwrap();
+      stylesheet
+        .minify(MinifyOptions {
+          known_dimensions: Some(KnownDimensions {
+            width: 600.0,
+            height: 800.0,
+          }),
+          ..MinifyOptions::default()
+        })
+        .unwrap();
+      let res = stylesheet
+        .to_css(PrinterOptions {
+          minify: true,
+          ..PrinterOptions::default()
+        })
+        .unwrap();
+      assert_eq!(res.code, expected);
+    }
+
+    known_dimensions_test("@media (max-width: 768px) { .foo { color: red } }", ".foo{color:red}");
+    known_dimensions_test("@media (min-width: 1200px) { .foo { color: red } }", "");
+    known_dimensions_test("@media (width: 600px) { .foo { color: red } }", ".foo{color:red}");
+    known_dimensions_test("@media (height > 800px) { .foo { color: red } }", "");
+    known_dimensions_test(
+      "@media (400px < width <= 600px) { .foo { color: red } }",
+      ".foo{color:red}",
+    );
+    known_dimensions_test(
+      "@media (max-width: calc(512px + 1in)) { .foo { color: red } }",
+      ".foo{color:red}",
+    );
+    known_dimensions_test(
+      "@media (max-width: calc(500px + 2em)) { .foo { color: red } }",
+      "@media (width<=calc(500px + 2em)){.foo{color:red}}",
+    );
+    known_dimensions_test(
+      "@media (orientation: portrait) { .foo { color: red } }",
+      ".foo{color:red}",
+    );
+    known_dimensions_test("@media (orientation: landscape) { .foo { color: red } }", "");
+    known_dimensions_test("@media (aspect-ratio: 3/4) { .foo { color: red } }", ".foo{color:red}");
+    known_dimensions_test(
+      "@media not (min-width: 1200px) { .foo { color: red } }",
+      ".foo{color:red}",
+    );
+    known_dimensions_test(
+      "@media (min-width: 1200px), (max-width: 768px) { .foo { color: red } }",
+      ".foo{color:red}",
+    );
+    known_dimensions_test("@media (min-width: 1200px) and (hover) { .foo { color: red } }", "");
+    known_dimensions_test(
+      "@media (max-width: 768px) and (hover) { .foo { color: red } }",
+      "@media (width<=768px) and (hover){.foo{color:red}}",
+    );
+    known_dimensions_test("@media print and (min-width: 1200px) { .foo { color: red } }", "");
+    known_dimensions_test(
+      "@media print and (max-width: 768px) { .foo { color: red } }",
+      "@media print and (width<=768px){.foo{color:red}}",
+    );
+    known_dimensions_test(
+      ".foo { color: blue; @media (max-width: 768px) { color: red } }",
+      ".foo{color:#00f;color:red}",
+    );
+  }
+
   #[test]
   fn test_merge_layers() {
     test(
@@ -11025,17 +12552,93 @@ mod tests {
       }
     "#},
     );
-  }
 
-  #[test]
-  fn test_merge_supports() {
-    test(
-      r#"
-      @supports (flex: 1) {
-        .foo {
-          color: red;
-        }
-      }
+    // Nested @media rules are combined when they are the only child.
+    minify_test(
+      "@media screen { @media (min-width: 400px) { .a { color: red } } }",
+      "@media screen and (width>=400px){.a{color:red}}",
+    );
+    minify_test(
+      "@media screen { @media (min-width: 400px) { @media (hover) { .a { color: red } } } }",
+      "@media screen and (width>=400px) and (hover){.a{color:red}}",
+    );
+    minify_test(
+      "@media (min-width: 400px) or (hover) { @media (color) { .a { color: red } } }",
+      "@media ((width>=400px) or (hover)) and (color){.a{color:red}}",
+    );
+    minify_test(
+      "@media screen, print { @media (color) { .a { color: red } } }",
+      "@media screen and (color),print and (color){.a{color:red}}",
+    );
+    minify_test(
+      "@media only screen { @media (color) { .a { color: red } } }",
+      "@media only screen and (color){.a{color:red}}",
+    );
+    minify_test("@media print { @media screen { .a { color: red } } }", "");
+    minify_test(
+      "@media not print { @media (color) { .a { color: red } } }",
+      "@media not print{@media (color){.a{color:red}}}",
+    );
+    minify_test(
+      "@media (color) { @media not print { .a { color: red } } }",
+      "@media (color){@media not print{.a{color:red}}}",
+    );
+    minify_test(
+      "@media not print { @media screen { .a { color: red } } }",
+      "@media screen{.a{color:red}}",
+    );
+    minify_test(
+      "@media screen { .a { color: red } @media (color) { .b { color: red } } }",
+      "@media screen{.a{color:red}@media (color){.b{color:red}}}",
+    );
+
+    // Combined rules are merged with identical adjacent rules.
+    minify_test(
+      "@media screen and (color) { .a { color: red } } @media screen { @media (color) { .b { color: red } } }",
+      "@media screen and (color){.a,.b{color:red}}",
+    );
+    minify_test(
+      "@media screen and (color) { .a { color: red } } .b { color: green } @media screen { @media (color) { .c { color: red } } }",
+      "@media screen and (color){.a{color:red}}.b{color:green}@media screen and (color){.c{color:red}}",
+    );
+    minify_test(
+      "@media screen and (color) { .a { color: red } } @media screen and (color) { @media screen and (color) { .b { color: red } } }",
+      "@media screen and (color){.a,.b{color:red}}",
+    );
+
+    // Queries containing unknown tokens when recovering from errors cannot be combined.
+    for (source, expected) in [
+      (
+        "@media screen and (color) { @media screen and   and (min-width:100px) { .a { color: red } } }",
+        "@media screen and (color){@media screen and and (min-width:100px){.a{color:red}}}",
+      ),
+      (
+        "@media screen and   and (min-width:100px) { @media (color) { .a { color: red } } }",
+        "@media screen and and (min-width:100px){@media (color){.a{color:red}}}",
+      ),
+      (
+        "@media (color) { @media screen and   and (min-width:100px) { .a { color: red } } }",
+        "@media (color){@media screen and and (min-width:100px){.a{color:red}}}",
+      ),
+    ] {
+      let options = ParserOptions {
+        error_recovery: true,
+        ..ParserOptions::default()
+      };
+      minify_test_with_options(source, expected, options.clone());
+      minify_test_with_options(expected, expected, options);
+    }
+  }
+
+  #[test]
+  fn test_merge_supports() {
+    test(
+      r#"
+      @supports (flex: 1) {
+        .foo {
+          color: red;
+        }
+      }
       @supports (flex: 1) {
         .foo {
           background: #fff;
@@ -11148,6 +12751,47 @@ mod tests {
       ".foo { transition-property: background, opacity }",
       ".foo{transition-property:background,opacity}",
     );
+    // Overridden items are kept so that longhands in other rules still line up with the list.
+    minify_test(
+      ".foo { transition: opacity 1s, all 2s ease-in }",
+      ".foo{transition:opacity 1s,all 2s ease-in}",
+    );
+    minify_test(
+      ".foo { transition: all 2s, opacity 1s }",
+      ".foo{transition:all 2s,opacity 1s}",
+    );
+    minify_test(
+      ".foo { transition: margin 1s, margin-left 2s }",
+      ".foo{transition:margin 1s,margin-left 2s}",
+    );
+    minify_test(
+      ".foo { transition-property: opacity, all; transition-duration: 1s, 2s; transition-delay: 0s; transition-timing-function: ease }",
+      ".foo{transition:opacity 1s,all 2s}",
+    );
+    minify_test(
+      ".foo { transition-property: opacity, all }",
+      ".foo{transition-property:opacity,all}",
+    );
+    // A zero-duration transition is not the same as none, since other rules may set the duration.
+    minify_test(".foo { transition: all 0s }", ".foo{transition:all}");
+    minify_test(
+      ".foo { transition: opacity 0s, all 0ms }",
+      ".foo{transition:opacity,all}",
+    );
+    minify_test(".foo { transition: all 0s 1s }", ".foo{transition:all 0s 1s}");
+    minify_test(
+      ".foo { transition: opacity 0s, color 1s }",
+      ".foo{transition:opacity,color 1s}",
+    );
+    minify_test(
+      ".foo { transition: all 0s } .foo:hover { transition-duration: .3s }",
+      ".foo{transition:all}.foo:hover{transition-duration:.3s}",
+    );
+    minify_test(
+      ".foo { transition: opacity 1s, all 2s } .foo:hover { transition-duration: .3s, .5s }",
+      ".foo{transition:opacity 1s,all 2s}.foo:hover{transition-duration:.3s,.5s}",
+    );
+    minify_test(".foo { transition: none }", ".foo{transition:none}");
     minify_test(
       ".foo { transition-timing-function: linear }",
       ".foo{transition-timing-function:linear}",
@@ -11865,6 +13509,80 @@ mod tests {
         ..Browsers::default()
       },
     );
+
+    minify_test(
+      ".foo { transition-behavior: allow-discrete }",
+      ".foo{transition-behavior:allow-discrete}",
+    );
+    minify_test(
+      ".foo { transition-behavior: normal, allow-discrete }",
+      ".foo{transition-behavior:normal,allow-discrete}",
+    );
+    minify_test(
+      ".foo { transition: allow-discrete display 1s ease-in 2s, opacity 1s normal }",
+      ".foo{transition:display 1s ease-in 2s allow-discrete,opacity 1s}",
+    );
+    minify_test(
+      ".foo { transition: opacity 1s; transition-behavior: allow-discrete }",
+      ".foo{transition:opacity 1s allow-discrete}",
+    );
+    minify_test(
+      ".foo { transition-behavior: allow-discrete; transition: opacity 1s }",
+      ".foo{transition:opacity 1s}",
+    );
+    minify_test(
+      ".foo { transition-property: opacity, display; transition-duration: 1s; transition-timing-function: ease; transition-delay: 0s; transition-behavior: normal, allow-discrete }",
+      ".foo{transition:opacity 1s,display 1s allow-discrete}",
+    );
+    prefix_test(
+      r#"
+      .foo {
+        transition: opacity 1s, display 1s allow-discrete;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transition: opacity 1s, display 1s;
+        transition-behavior: normal, allow-discrete;
+      }
+    "#
+      },
+      Browsers {
+        chrome: Some(110 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        transition: opacity 1s, display 1s allow-discrete;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transition: opacity 1s, display 1s allow-discrete;
+      }
+    "#
+      },
+      Browsers {
+        chrome: Some(120 << 16),
+        ..Browsers::default()
+      },
+    );
+    minify_test(".foo { overlay: auto }", ".foo{overlay:auto}");
+    minify_test(".foo { overlay: NONE }", ".foo{overlay:none}");
+    minify_test(
+      ".foo { transition-property: overlay }",
+      ".foo{transition-property:overlay}",
+    );
+    minify_test(
+      ".foo { transition: opacity .2s, overlay .2s allow-discrete, display .2s allow-discrete }",
+      ".foo{transition:opacity .2s,overlay .2s allow-discrete,display .2s allow-discrete}",
+    );
+    minify_test(
+      ".foo { transition-property: opacity, overlay; transition-duration: 1s; transition-timing-function: ease; transition-delay: 0s; transition-behavior: normal, allow-discrete }",
+      ".foo{transition:opacity 1s,overlay 1s allow-discrete}",
+    );
   }
 
   #[test]
@@ -11907,11 +13625,40 @@ mod tests {
       ".foo { animation-name: \"default\" }",
       ".foo{animation-name:\"default\"}",
     );
+    minify_test(".foo { animation-name: \"123\" }", ".foo{animation-name:\"123\"}");
+    minify_test(".foo { animation-name: \"\" }", ".foo{animation-name:\"\"}");
+    minify_test(".foo { animation-name: \"-\" }", ".foo{animation-name:\"-\"}");
+    minify_test(".foo { animation-name: \"a b\" }", ".foo{animation-name:\"a b\"}");
+    minify_test(".foo { animation-name: \"--foo\" }", ".foo{animation-name:--foo}");
     minify_test(".foo { animation-duration: 100ms }", ".foo{animation-duration:.1s}");
     minify_test(
       ".foo { animation-duration: 100ms, 2000ms }",
       ".foo{animation-duration:.1s,2s}",
     );
+    minify_test(
+      ".foo { animation-duration: calc(1s + 500ms) }",
+      ".foo{animation-duration:1.5s}",
+    );
+    minify_test(
+      ".foo { animation-duration: calc(50ms + 0.02s), calc(0.7s - 0.6s) }",
+      ".foo{animation-duration:70ms,.1s}",
+    );
+    minify_test(
+      ".foo { animation-delay: calc(calc(100ms + 0.2s) / 2) }",
+      ".foo{animation-delay:.15s}",
+    );
+    minify_test(
+      ".foo { animation: calc(1s + 500ms) spin calc(50ms * 2) }",
+      ".foo{animation:1.5s .1s spin}",
+    );
+    minify_test(
+      ".foo { animation-duration: calc(var(--duration) + 500ms) }",
+      ".foo{animation-duration:calc(var(--duration) + .5s)}",
+    );
+    minify_test(
+      ".foo { animation-duration: calc(1s + 1px) }",
+      ".foo{animation-duration:calc(1s + 1px)}",
+    );
     minify_test(
       ".foo { animation-timing-function: ease }",
       ".foo{animation-timing-function:ease}",
@@ -12312,6 +14059,26 @@ mod tests {
         ..Browsers::default()
       },
     );
+    prefix_test(
+      r#"
+      .foo {
+        animation-name: spin, "spin";
+        animation-duration: 1s, 2s;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-animation-name: spin, spin;
+        animation-name: spin, spin;
+        -webkit-animation-duration: 1s, 2s;
+        animation-duration: 1s, 2s;
+      }
+    "#},
+      Browsers {
+        safari: Some(6 << 16),
+        ..Browsers::default()
+      },
+    );
     prefix_test(
       r#"
       .foo {
@@ -12850,10 +14617,97 @@ mod tests {
     minify_test(".foo { scale: 1 0 1 }", ".foo{scale:1 0}");
     minify_test(".foo { scale: 1 0 0 }", ".foo{scale:1 0 0}");
 
-    // TODO: Re-enable with a better solution
-    //       See: https://github.com/parcel-bundler/lightningcss/issues/288
-    // minify_test(".foo { transform: scale(3); scale: 0.5 }", ".foo{transform:scale(1.5)}");
-    minify_test(".foo { scale: 0.5; transform: scale(3); }", ".foo{transform:scale(3)}");
+    minify_test(".foo { scale: 100% 100% }", ".foo{scale:100%}");
+    minify_test(".foo { scale: 2 3 }", ".foo{scale:2 3}");
+    minify_test(".foo { scale: 2 3 4 }", ".foo{scale:2 3 4}");
+    minify_test(".foo { translate: 0 0 0 }", ".foo{translate:0}");
+
+    // The individual transform properties are applied separately from transform.
+    minify_test(
+      ".foo { transform: scale(3); scale: 0.5 }",
+      ".foo{transform:scale(3);scale:.5}",
+    );
+    minify_test(
+      ".foo { scale: 0.5; transform: scale(3); }",
+      ".foo{transform:scale(3);scale:.5}",
+    );
+    minify_test(
+      ".foo { translate: 10px; transform: rotate(10deg); rotate: 5deg }",
+      ".foo{transform:rotate(10deg);translate:10px;rotate:5deg}",
+    );
+    minify_test(".foo { translate: 10px; translate: 20px }", ".foo{translate:20px}");
+
+    // When the individual properties are not supported, they are combined with transform if possible.
+    prefix_test(
+      r#"
+      .foo {
+        transform: rotate(10deg);
+        translate: 10px 20px;
+        rotate: 0deg;
+        scale: 2 2 1;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transform: translate(10px, 20px)scale(2)rotate(10deg);
+      }
+    "#},
+      Browsers {
+        safari: Some(13 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        scale: 0.5;
+        transform: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transform: scale(.5);
+      }
+    "#},
+      Browsers {
+        safari: Some(13 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        translate: 10px 20px 30px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        translate: 10px 20px 30px;
+      }
+    "#},
+      Browsers {
+        safari: Some(13 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        transform: rotate(10deg);
+        translate: 10px 20px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transform: rotate(10deg);
+        translate: 10px 20px;
+      }
+    "#},
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
+    );
 
     prefix_test(
       r#"
@@ -13192,6 +15046,41 @@ mod tests {
       ".foo { background: repeating-conic-gradient(black 0deg 25%, white 0deg 50%) }",
       ".foo{background:repeating-conic-gradient(#000 0deg 25%,#fff 0deg 50%)}",
     );
+    minify_test(
+      ".foo { background: conic-gradient(at top, red 0, blue) }",
+      ".foo{background:conic-gradient(at top,red 0deg,#00f)}",
+    );
+    minify_test(
+      ".foo { background: repeating-conic-gradient(from 0rad, red 0 10deg, blue 10deg 20deg) }",
+      ".foo{background:repeating-conic-gradient(red 0deg 10deg,#00f 10deg 20deg)}",
+    );
+    minify_test(
+      ".foo { background: conic-gradient(from -90deg at 10px 20px, red 0 25%, 25%, blue 50% 100%) }",
+      ".foo{background:conic-gradient(from -90deg at 10px 20px,red 0deg 25%,25%,#00f 50% 100%)}",
+    );
+    minify_test(
+      ".foo { background: conic-gradient(from 0.25turn at right 10px bottom 20%, red, 10%, blue 0.5turn) }",
+      ".foo{background:conic-gradient(from .25turn at right 10px bottom 20%,red,10%,#00f .5turn)}",
+    );
+    minify_test(
+      ".foo { background: conic-gradient(red, 0, blue) }",
+      ".foo{background:conic-gradient(red,0deg,#00f)}",
+    );
+    prefix_test(
+      ".foo { background: conic-gradient(from 90deg at 25% 75%, lab(50% 40 30) 0 25%, 25%, blue 180deg 100%) }",
+      indoc! { r#"
+        .foo {
+          background: conic-gradient(from 90deg at 25% 75%, #bb5846 0deg 25%, 25%, #00f 180deg 100%);
+          background: conic-gradient(from 90deg at 25% 75%, color(display-p3 .685125 .366092 .297589) 0deg 25%, 25%, #00f 180deg 100%);
+          background: conic-gradient(from 90deg at 25% 75%, lab(50% 40 30) 0deg 25%, 25%, #00f 180deg 100%);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
 
     test(
       r#"
@@ -14127,84 +16016,166 @@ mod tests {
 
     minify_test(
       "@font-face {src: url(\"test.woff\") format(woff);}",
-      "@font-face{src:url(test.woff)format(\"woff\")}",
+      "@font-face{src:url(test.woff)format(woff)}",
     );
     minify_test(
       "@font-face {src: url(\"test.ttc\") format(collection), url(test.ttf) format(truetype);}",
-      "@font-face{src:url(test.ttc)format(\"collection\"),url(test.ttf)format(\"truetype\")}",
+      "@font-face{src:url(test.ttc)format(collection),url(test.ttf)format(truetype)}",
     );
     minify_test(
       "@font-face {src: url(\"test.otf\") format(opentype) tech(features-aat);}",
-      "@font-face{src:url(test.otf)format(\"opentype\")tech(features-aat)}",
+      "@font-face{src:url(test.otf)format(opentype)tech(features-aat)}",
     );
     minify_test(
       "@font-face {src: url(\"test.woff\") format(woff) tech(color-colrv1);}",
-      "@font-face{src:url(test.woff)format(\"woff\")tech(color-colrv1)}",
+      "@font-face{src:url(test.woff)format(woff)tech(color-colrv1)}",
     );
     minify_test(
       "@font-face {src: url(\"test.woff2\") format(woff2) tech(variations);}",
-      "@font-face{src:url(test.woff2)format(\"woff2\")tech(variations)}",
+      "@font-face{src:url(test.woff2)format(woff2)tech(variations)}",
     );
     minify_test(
       "@font-face {src: url(\"test.woff\") format(woff) tech(palettes);}",
-      "@font-face{src:url(test.woff)format(\"woff\")tech(palettes)}",
+      "@font-face{src:url(test.woff)format(woff)tech(palettes)}",
     );
     // multiple tech
     minify_test(
       "@font-face {src: url(\"test.woff\") format(woff) tech(features-opentype, color-sbix);}",
-      "@font-face{src:url(test.woff)format(\"woff\")tech(features-opentype,color-sbix)}",
+      "@font-face{src:url(test.woff)format(woff)tech(features-opentype,color-sbix)}",
     );
     minify_test(
       "@font-face {src: url(\"test.woff\")   format(woff)    tech(incremental, color-svg, features-graphite, features-aat);}",
-      "@font-face{src:url(test.woff)format(\"woff\")tech(incremental,color-svg,features-graphite,features-aat)}",
+      "@font-face{src:url(test.woff)format(woff)tech(incremental,color-svg,features-graphite,features-aat)}",
     );
     // format() function must precede tech() if both are present
     minify_test(
       "@font-face {src: url(\"foo.ttf\") format(opentype) tech(color-colrv1);}",
-      "@font-face{src:url(foo.ttf)format(\"opentype\")tech(color-colrv1)}",
+      "@font-face{src:url(foo.ttf)format(opentype)tech(color-colrv1)}",
     );
-    // only have tech is valid
     minify_test(
-      "@font-face {src: url(\"foo.ttf\") tech(color-SVG);}",
-      "@font-face{src:url(foo.ttf)tech(color-svg)}",
+      "@font-face {src: url(\"test.woff2\") format(\"woff2\") tech(color-COLRv1);}",
+      "@font-face{src:url(test.woff2)format(woff2)tech(color-colrv1)}",
     );
-    // CGQAQ: if tech and format both presence, order is matter, tech before format is invalid
-    // but now just return raw token, we don't have strict mode yet.
-    // ref: https://github.com/parcel-bundler/lightningcss/pull/255#issuecomment-1219049998
     minify_test(
-      "@font-face {src: url(\"foo.ttf\") tech(palettes  color-colrv0  variations) format(opentype);}",
-      "@font-face{src:url(foo.ttf) tech(palettes color-colrv0 variations) format(opentype)}",
+      "@font-face {src: url(\"test.eot\") format(\"embedded-opentype\"), url(test.svg) format(\"svg\");}",
+      "@font-face{src:url(test.eot)format(embedded-opentype),url(test.svg)format(svg)}",
     );
-    // TODO(CGQAQ): make this test pass when we have strict mode
-    // ref: https://github.com/web-platform-tests/wpt/blob/9f8a6ccc41aa725e8f51f4f096f686313bb88d8d/css/css-fonts/parsing/font-face-src-tech.html#L45
-    // error_test(
-    //   "@font-face {src: url(\"foo.ttf\") tech(features-opentype) format(opentype);}",
-    //   ParserError::AtRuleBodyInvalid,
-    // );
-    // error_test(
-    //   "@font-face {src: url(\"foo.ttf\") tech();}",
-    //   ParserError::AtRuleBodyInvalid,
-    // );
-    // error_test(
-    //   "@font-face {src: url(\"foo.ttf\") tech(\"features-opentype\");}",
-    //   ParserError::AtRuleBodyInvalid,
-    // );
-    // error_test(
-    //   "@font-face {src: url(\"foo.ttf\") tech(\"color-colrv0\");}",
-    //   ParserError::AtRuleBodyInvalid,
-    // );
+    // unknown formats are preserved as strings
     minify_test(
-      "@font-face {src: local(\"\") url(\"test.woff\");}",
-      "@font-face{src:local(\"\") url(test.woff)}",
+      "@font-face {src: url(\"test.foo\") format(\"foo\");}",
+      "@font-face{src:url(test.foo)format(\"foo\")}",
     );
-    minify_test("@font-face {font-weight: 200 400}", "@font-face{font-weight:200 400}");
-    minify_test("@font-face {font-weight: 400 400}", "@font-face{font-weight:400}");
     minify_test(
-      "@font-face {font-stretch: 50% 200%}",
-      "@font-face{font-stretch:50% 200%}",
+      "@font-face {src: url(\"test.foo\") format(foo);}",
+      "@font-face{src:url(test.foo)format(\"foo\")}",
     );
-    minify_test("@font-face {font-stretch: 50% 50%}", "@font-face{font-stretch:50%}");
-    minify_test("@font-face {unicode-range: U+26;}", "@font-face{unicode-range:U+26}");
+    test(
+      "@font-face {src: url(\"test.woff2\") format(woff2);}",
+      indoc! {r#"
+      @font-face {
+        src: url("test.woff2") format("woff2");
+      }
+    "#},
+    );
+    // format keywords are only used for targets that support them
+    for (chrome, expected) in [
+      (100, "@font-face{src:url(test.woff2)format(\"woff2\")}"),
+      (108, "@font-face{src:url(test.woff2)format(woff2)}"),
+    ] {
+      let targets = Targets::from(Browsers {
+        chrome: Some(chrome << 16),
+        ..Browsers::default()
+      });
+      let stylesheet = StyleSheet::parse(
+        "@font-face {src: url(test.woff2) format(woff2);}",
+        ParserOptions::default(),
+      )
+      .unwrap();
+      let res = stylesheet
+        .to_css(PrinterOptions {
+          minify: true,
+          targets,
+          ..PrinterOptions::default()
+        })
+        .unwrap();
+      assert_eq!(res.code, expected);
+    }
+
+    let warnings = Arc::new(RwLock::new(Vec::new()));
+    minify_test_with_options(
+      "@font-face {src: url(test.woff2) format(woff2), url(test.foo) format(\"foo\");}",
+      "@font-face{src:url(test.woff2)format(woff2),url(test.foo)format(\"foo\")}",
+      ParserOptions {
+        filename: "test.css".into(),
+        error_recovery: true,
+        warnings: Some(warnings.clone()),
+        ..ParserOptions::default()
+      },
+    );
+    assert_eq!(
+      *warnings.read().unwrap(),
+      vec![Error {
+        kind: ParserError::UnknownFontFormat("foo".into()),
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 0,
+          column: 49
+        })
+      }]
+    );
+    // Unknown formats are only reported when recovering from errors.
+    let warnings = Arc::new(RwLock::new(Vec::new()));
+    minify_test_with_options(
+      "@font-face {src: url(test.foo) format(\"foo\");}",
+      "@font-face{src:url(test.foo)format(\"foo\")}",
+      ParserOptions {
+        warnings: Some(warnings.clone()),
+        ..ParserOptions::default()
+      },
+    );
+    assert!(warnings.read().unwrap().is_empty());
+    // only have tech is valid
+    minify_test(
+      "@font-face {src: url(\"foo.ttf\") tech(color-SVG);}",
+      "@font-face{src:url(foo.ttf)tech(color-svg)}",
+    );
+    // CGQAQ: if tech and format both presence, order is matter, tech before format is invalid
+    // but now just return raw token, we don't have strict mode yet.
+    // ref: https://github.com/parcel-bundler/lightningcss/pull/255#issuecomment-1219049998
+    minify_test(
+      "@font-face {src: url(\"foo.ttf\") tech(palettes  color-colrv0  variations) format(opentype);}",
+      "@font-face{src:url(foo.ttf) tech(palettes color-colrv0 variations) format(opentype)}",
+    );
+    // TODO(CGQAQ): make this test pass when we have strict mode
+    // ref: https://github.com/web-platform-tests/wpt/blob/9f8a6ccc41aa725e8f51f4f096f686313bb88d8d/css/css-fonts/parsing/font-face-src-tech.html#L45
+    // error_test(
+    //   "@font-face {src: url(\"foo.ttf\") tech(features-opentype) format(opentype);}",
+    //   ParserError::AtRuleBodyInvalid,
+    // );
+    // error_test(
+    //   "@font-face {src: url(\"foo.ttf\") tech();}",
+    //   ParserError::AtRuleBodyInvalid,
+    // );
+    // error_test(
+    //   "@font-face {src: url(\"foo.ttf\") tech(\"features-opentype\");}",
+    //   ParserError::AtRuleBodyInvalid,
+    // );
+    // error_test(
+    //   "@font-face {src: url(\"foo.ttf\") tech(\"color-colrv0\");}",
+    //   ParserError::AtRuleBodyInvalid,
+    // );
+    minify_test(
+      "@font-face {src: local(\"\") url(\"test.woff\");}",
+      "@font-face{src:local(\"\") url(test.woff)}",
+    );
+    minify_test("@font-face {font-weight: 200 400}", "@font-face{font-weight:200 400}");
+    minify_test("@font-face {font-weight: 400 400}", "@font-face{font-weight:400}");
+    minify_test(
+      "@font-face {font-stretch: 50% 200%}",
+      "@font-face{font-stretch:50% 200%}",
+    );
+    minify_test("@font-face {font-stretch: 50% 50%}", "@font-face{font-stretch:50%}");
+    minify_test("@font-face {unicode-range: U+26;}", "@font-face{unicode-range:U+26}");
     minify_test("@font-face {unicode-range: u+26;}", "@font-face{unicode-range:U+26}");
     minify_test(
       "@font-face {unicode-range: U+0-7F;}",
@@ -14259,7 +16230,7 @@ mod tests {
         src: url("../fonts/Inter.var.woff2?v=3.19") format("woff2");
         font-display: swap;
       }
-    "#, "@font-face{font-family:Inter;font-style:oblique 0deg 10deg;font-weight:100 900;src:url(../fonts/Inter.var.woff2?v=3.19)format(\"woff2\");font-display:swap}");
+    "#, "@font-face{font-family:Inter;font-style:oblique 0deg 10deg;font-weight:100 900;src:url(../fonts/Inter.var.woff2?v=3.19)format(woff2);font-display:swap}");
     minify_test(r#"
     @font-face {
       font-family: Inter;
@@ -14268,7 +16239,38 @@ mod tests {
       src: url("../fonts/Inter.var.woff2?v=3.19") format("woff2");
       font-display: swap;
     }
-  "#, "@font-face{font-family:Inter;font-style:oblique;font-weight:100 900;src:url(../fonts/Inter.var.woff2?v=3.19)format(\"woff2\");font-display:swap}");
+  "#, "@font-face{font-family:Inter;font-style:oblique;font-weight:100 900;src:url(../fonts/Inter.var.woff2?v=3.19)format(woff2);font-display:swap}");
+    minify_test("@font-face {font-display: block}", "@font-face{font-display:block}");
+    minify_test("@font-face {font-display: Swap}", "@font-face{font-display:swap}");
+    minify_test(
+      "@font-face {font-display: fallback}",
+      "@font-face{font-display:fallback}",
+    );
+    minify_test(
+      "@font-face {font-display: optional}",
+      "@font-face{font-display:optional}",
+    );
+    minify_test("@font-face {font-display: foo}", "@font-face{font-display:foo}");
+    minify_test(
+      "@font-face {font-family: Test; font-display: auto; src: url(test.woff)}",
+      "@font-face{font-family:Test;src:url(test.woff)}",
+    );
+    minify_test(
+      "@media print { @font-face {font-family: Test; font-display: auto} }",
+      "@media print{@font-face{font-family:Test}}",
+    );
+    minify_test(
+      "@font-face {font-family: Test; font-display: swap; font-display: auto}",
+      "@font-face{font-family:Test}",
+    );
+    minify_test(
+      "@font-face {font-family: Test; font-display: auto; font-display: swap}",
+      "@font-face{font-family:Test;font-display:swap}",
+    );
+    minify_test(
+      "@font-face {font-display: block; font-family: Test; font-display: swap}",
+      "@font-face{font-family:Test;font-display:swap}",
+    );
   }
 
   #[test]
@@ -14583,10 +16585,76 @@ mod tests {
       "#,
       ParserError::AtRuleInvalid("bottom-left".into()),
     );
+
+    minify_test("@page {size: auto}", "@page{size:auto}");
+    minify_test("@page {size: 210mm 297mm}", "@page{size:210mm 297mm}");
+    minify_test("@page {size: 8in 8in}", "@page{size:8in}");
+    minify_test("@page {size: 0 0}", "@page{size:0}");
+    minify_test("@page {size: A4}", "@page{size:a4}");
+    minify_test("@page {size: JIS-B5 landscape}", "@page{size:jis-b5 landscape}");
+    minify_test("@page {size: landscape letter}", "@page{size:letter landscape}");
+    minify_test("@page {size: portrait}", "@page{size:portrait}");
+    // size is only a descriptor of @page rules, and is an unknown property elsewhere.
+    minify_test(".foo {size: A4}", ".foo{size:A4}");
+    minify_test("@page {@top-left {size: A4}}", "@page{@top-left{size:A4}}");
+    let stylesheet = StyleSheet::parse(".foo { size: auto }", ParserOptions::default()).unwrap();
+    match &stylesheet.rules.0[0] {
+      CssRule::Style(style) => assert!(matches!(
+        style.declarations.declarations[0].property_id(),
+        PropertyId::Custom(..)
+      )),
+      _ => unreachable!(),
+    }
+    minify_test(
+      "@page :first {size: A4; @top-left {content: none}}",
+      "@page:first{size:a4;@top-left{content:none}}",
+    );
+    minify_test("@page {margin: 1in; size: A4}", "@page{size:a4;margin:1in}");
+    minify_test("@page {size: A4; size: letter}", "@page{size:letter}");
+    minify_test("@page {size: A4 !important}", "@page{size:A4!important}");
+    // The last size declaration wins, whether or not it could be parsed.
+    minify_test("@page {size: var(--x); size: A4}", "@page{size:a4}");
+    minify_test("@page {size: A4; size: var(--x)}", "@page{size:var(--x)}");
+    minify_test("@page {size: A4; margin: 1in; size: foo}", "@page{size:foo;margin:1in}");
+    test(
+      "@page :first { margin: 1in; size: A4; @top-left { content: none } }",
+      indoc! {r#"
+      @page :first {
+        size: a4;
+        margin: 1in;
+
+        @top-left {
+          content: none;
+        }
+      }
+      "#},
+    );
+    let stylesheet = StyleSheet::parse("@page { size: A4 }", ParserOptions::default()).unwrap();
+    match &stylesheet.rules.0[0] {
+      CssRule::Page(page) => {
+        assert_eq!(
+          page.size,
+          Some(crate::rules::page::PageSizeDescriptor::Size(
+            crate::rules::page::PageSize::Keyword {
+              size: Some(crate::rules::page::PageSizeKeyword::A4),
+              orientation: None
+            }
+          ))
+        );
+        assert!(page.declarations.is_empty());
+      }
+      _ => unreachable!(),
+    }
+    // invalid values are preserved as is
+    minify_test("@page {size: -1in}", "@page{size:-1in}");
+    minify_test("@page {size: A4 A5}", "@page{size:A4 A5}");
+    minify_test("@page {size: landscape portrait}", "@page{size:landscape portrait}");
   }
 
   #[test]
   fn test_supports_rule() {
+    use crate::rules::supports::SupportsCondition;
+
     test(
       r#"
       @supports (foo: bar) {
@@ -14882,257 +16950,676 @@ mod tests {
     "#,
       "@supports (color:hsl(0deg, 0%, 0%)){.test{color:#000}}",
     );
-  }
 
-  #[test]
-  fn test_counter_style() {
-    test(
+    prefix_test(
       r#"
-      @counter-style circled-alpha {
-        system: fixed;
-        symbols: Ⓐ Ⓑ Ⓒ;
-        suffix: " ";
+      @supports (display: flex) {
+        .foo {
+          display: flex;
+        }
+      }
+
+      @supports not (display: grid) {
+        .foo {
+          float: left;
+        }
       }
     "#,
       indoc! { r#"
-      @counter-style circled-alpha {
-        system: fixed;
-        symbols: Ⓐ Ⓑ Ⓒ;
-        suffix: " ";
+      .foo {
+        display: flex;
       }
     "#},
+      Browsers {
+        chrome: Some(80 << 16),
+        safari: Some(14 << 16),
+        ..Default::default()
+      },
     );
-  }
-
-  #[test]
-  fn test_namespace() {
-    minify_test(
-      "@namespace url(http://toto.example.org);",
-      "@namespace \"http://toto.example.org\";",
-    );
-    minify_test(
-      "@namespace \"http://toto.example.org\";",
-      "@namespace \"http://toto.example.org\";",
-    );
-    minify_test(
-      "@namespace toto \"http://toto.example.org\";",
-      "@namespace toto \"http://toto.example.org\";",
-    );
-    minify_test(
-      "@namespace toto url(http://toto.example.org);",
-      "@namespace toto \"http://toto.example.org\";",
-    );
-
-    test(
+    prefix_test(
       r#"
-      @namespace "http://example.com/foo";
-
-      x {
-        color: red;
+      @supports (display: grid) or (display: flex) {
+        .foo {
+          color: red;
+        }
       }
     "#,
-      indoc! {r#"
-      @namespace "http://example.com/foo";
-
-      x {
+      indoc! { r#"
+      .foo {
         color: red;
       }
     "#},
+      Browsers {
+        safari: Some(9 << 16),
+        ..Default::default()
+      },
     );
-
-    test(
+    prefix_test(
       r#"
-      @namespace toto "http://toto.example.org";
-
-      toto|x {
-        color: red;
-      }
-
-      [toto|att=val] {
-        color: blue
+      @supports (display: grid) {
+        .foo {
+          display: grid;
+        }
       }
     "#,
-      indoc! {r#"
-      @namespace toto "http://toto.example.org";
-
-      toto|x {
-        color: red;
-      }
-
-      [toto|att="val"] {
-        color: #00f;
+      indoc! { r#"
+      @supports (display: grid) {
+        .foo {
+          display: grid;
+        }
       }
     "#},
+      Browsers {
+        chrome: Some(80 << 16),
+        safari: Some(9 << 16),
+        ..Default::default()
+      },
     );
-
-    test(
+    prefix_test(
       r#"
-      @namespace "http://example.com/foo";
-
-      |x {
-        color: red;
-      }
-
-      [|att=val] {
-        color: blue
+      @supports (color: oklch(50% 0.1 200)) {
+        .foo {
+          color: red;
+        }
       }
     "#,
-      indoc! {r#"
-      @namespace "http://example.com/foo";
-
-      |x {
-        color: red;
-      }
-
-      [att="val"] {
-        color: #00f;
+      indoc! { r#"
+      @supports (color: oklch(50% 0.1 200)) {
+        .foo {
+          color: red;
+        }
       }
     "#},
+      Browsers {
+        safari: Some(13 << 16),
+        ..Default::default()
+      },
     );
-
-    test(
+    prefix_test(
       r#"
-      @namespace "http://example.com/foo";
-
-      *|x {
-        color: red;
+      @supports (color: oklch(50% 0.1 200)) {
+        .foo {
+          color: red;
+        }
       }
-
-      [*|att=val] {
-        color: blue
+    "#,
+      indoc! { r#"
+      .foo {
+        color: red;
+      }
+    "#},
+      Browsers {
+        safari: Some(16 << 16),
+        ..Default::default()
+      },
+    );
+    prefix_test(
+      r#"
+      @supports selector(:has(a)) {
+        .foo {
+          color: red;
+        }
       }
     "#,
-      indoc! {r#"
-      @namespace "http://example.com/foo";
+      indoc! { r#"
+      @supports selector(:has(a)) {
+        .foo {
+          color: red;
+        }
+      }
+    "#},
+      Browsers {
+        chrome: Some(130 << 16),
+        ..Default::default()
+      },
+    );
 
-      *|x {
-        color: red;
+    prefix_test(
+      r#"
+      @supports (container-type: inline-size) {
+        .foo {
+          container-type: inline-size;
+        }
+      }
+    "#,
+      indoc! { r#"
+      .foo {
+        container-type: inline-size;
       }
+    "#},
+      Browsers {
+        firefox: Some(110 << 16),
+        safari: Some(16 << 16),
+        ..Default::default()
+      },
+    );
 
-      [*|att="val"] {
-        color: #00f;
+    prefix_test(
+      r#"
+      @supports (display: flex) {
+        .foo {
+          display: flex;
+        }
+      }
+    "#,
+      indoc! { r#"
+      @supports (display: flex) {
+        .foo {
+          display: flex;
+        }
       }
     "#},
+      Browsers {
+        ie: Some(11 << 16),
+        ..Default::default()
+      },
     );
 
-    error_test(
-      ".foo { color: red } @namespace \"http://example.com/foo\";",
-      ParserError::UnexpectedNamespaceRule,
+    prefix_test(
+      r#"
+      @supports (container-type: scroll-state) {
+        .foo {
+          color: red;
+        }
+      }
+    "#,
+      indoc! { r#"
+      @supports (container-type: scroll-state) {
+        .foo {
+          color: red;
+        }
+      }
+    "#},
+      Browsers {
+        chrome: Some(105 << 16),
+        ..Default::default()
+      },
+    );
+
+    let chrome = Browsers {
+      chrome: Some(130 << 16),
+      ..Default::default()
+    };
+    let evaluate =
+      |condition: &str, browsers: Browsers| SupportsCondition::parse_string(condition).unwrap().evaluate(browsers);
+    assert_eq!(evaluate("(display: flex)", chrome), Some(true));
+    assert_eq!(evaluate("(display: block flex)", chrome), None);
+    assert_eq!(evaluate("not (display: grid)", chrome), Some(false));
+    assert_eq!(evaluate("(display: grid) and (width: 10px)", chrome), None);
+    assert_eq!(
+      evaluate("(display: grid) and (not (display: flex))", chrome),
+      Some(false)
+    );
+    assert_eq!(evaluate("(content-visibility: auto)", chrome), Some(true));
+    assert_eq!(evaluate("(container-type: inline-size)", chrome), Some(true));
+    assert_eq!(evaluate("(container-type: scroll-state)", chrome), None);
+    assert_eq!(
+      evaluate(
+        "(display: flex)",
+        Browsers {
+          ie: Some(11 << 16),
+          ..chrome
+        }
+      ),
+      None
+    );
+    assert_eq!(
+      evaluate(
+        "(container-type: inline-size)",
+        Browsers {
+          safari: Some(15 << 16),
+          ..Default::default()
+        }
+      ),
+      None
     );
+    assert_eq!(evaluate("(container-type: foo)", chrome), None);
+    assert_eq!(evaluate("(display: flex)", Browsers::default()), None);
+    assert_eq!(evaluate("selector(:has(a))", chrome), None);
   }
 
   #[test]
-  fn test_import() {
-    minify_test("@import url(foo.css);", "@import \"foo.css\";");
-    minify_test("@import \"foo.css\";", "@import \"foo.css\";");
-    minify_test("@import url(foo.css) print;", "@import \"foo.css\" print;");
-    minify_test("@import \"foo.css\" print;", "@import \"foo.css\" print;");
+  fn test_counter_style() {
+    test(
+      r#"
+      @counter-style circled-alpha {
+        system: fixed;
+        symbols: Ⓐ Ⓑ Ⓒ;
+        suffix: " ";
+      }
+    "#,
+      indoc! { r#"
+      @counter-style circled-alpha {
+        system: fixed;
+        symbols: Ⓐ Ⓑ Ⓒ;
+        suffix: " ";
+      }
+    "#},
+    );
     minify_test(
-      "@import \"foo.css\" screen and (orientation: landscape);",
-      "@import \"foo.css\" screen and (orientation:landscape);",
+      "@counter-style thumbs { system: cyclic; symbols: \"👍\"; suffix: \" \"; }",
+      "@counter-style thumbs{system:cyclic;symbols:\"👍\";suffix:\" \"}",
+    );
+    error_test(
+      "@counter-style foo { system: cyclic; @bar; symbols: a }",
+      ParserError::AtRuleInvalid("bar".into()),
+    );
+    assert_eq!(
+      error_recovery_test("@counter-style foo { system: cyclic; @bar; symbols: a }")
+        .into_iter()
+        .map(|w| w.kind)
+        .collect::<Vec<_>>(),
+      vec![ParserError::AtRuleInvalid("bar".into())]
+    );
+    minify_test(
+      "@counter-style foo { system: fixed 1; symbols: a b; } @counter-style bar { system: fixed -2; symbols: a b; }",
+      "@counter-style foo{system:fixed;symbols:a b}@counter-style bar{system:fixed -2;symbols:a b}",
+    );
+    minify_test(
+      r#"@counter-style foo {
+        system: additive;
+        additive-symbols: 10 X, V 5, 1 "I";
+        negative: "(" ")";
+        prefix: "";
+        suffix: ". ";
+        range: 1 infinite, infinite -10;
+        pad: "0" 3;
+        fallback: lower-roman;
+        speak-as: spell-out;
+      }"#,
+      "@counter-style foo{system:additive;additive-symbols:10 X,5 V,1 \"I\";negative:\"(\" \")\";range:1 infinite,infinite -10;pad:3 \"0\";fallback:lower-roman;speak-as:spell-out}",
     );
     minify_test(
-      "@import url(foo.css) supports(display: flex);",
-      "@import \"foo.css\" supports(display:flex);",
+      r#"@counter-style foo {
+        system: symbolic;
+        symbols: url(star.png) "*";
+        negative: "-";
+        range: auto;
+        pad: 0 "";
+        fallback: decimal;
+        speak-as: auto;
+      }"#,
+      "@counter-style foo{symbols:url(star.png) \"*\"}",
     );
+    // Descriptors of extended counter styles are inherited, so initial values are significant.
     minify_test(
-      "@import url(foo.css) supports(display: flex) print;",
-      "@import \"foo.css\" supports(display:flex) print;",
+      "@counter-style foo { system: extends decimal; suffix: \". \"; speak-as: bar }",
+      "@counter-style foo{system:extends decimal;suffix:\". \";speak-as:bar}",
     );
+    // Invalid values are preserved as is.
     minify_test(
-      "@import url(foo.css) supports(not (display: flex));",
-      "@import \"foo.css\" supports(not (display:flex));",
+      "@counter-style foo { system: extends symbols(a); range: 5 1; additive-symbols: 1 a, 2 b; pad: -1 a }",
+      "@counter-style foo{system:extends symbols(a);range:5 1;additive-symbols:1 a, 2 b;pad:-1 a}",
     );
     minify_test(
-      "@import url(foo.css) supports((display: flex));",
-      "@import \"foo.css\" supports(display:flex);",
+      "@counter-style foo { fallback: none; speak-as: none; foo: bar }",
+      "@counter-style foo{fallback:none;speak-as:none;foo:bar}",
     );
-    minify_test("@charset \"UTF-8\"; @import url(foo.css);", "@import \"foo.css\";");
-    minify_test("@layer foo; @import url(foo.css);", "@layer foo;@import \"foo.css\";");
-    error_test(
-      ".foo { color: red } @import url(bar.css);",
-      ParserError::UnexpectedImportRule,
+    minify_test(
+      "@counter-style foo { system: cyclic; symbols: a; suffix: \")\"; suffix: \". \" }",
+      "@counter-style foo{system:cyclic;symbols:a}",
     );
-    error_test(
-      "@namespace \"http://example.com/foo\"; @import url(bar.css);",
-      ParserError::UnexpectedImportRule,
+    minify_test(
+      "@counter-style foo { system: cyclic; symbols: a; suffix: \". \"; suffix: \")\" }",
+      "@counter-style foo{system:cyclic;symbols:a;suffix:\")\"}",
+    );
+    minify_test(
+      "@counter-style foo { system: cyclic; symbols: a; suffix: \")\"; suffix: 1px 2px }",
+      "@counter-style foo{system:cyclic;symbols:a;suffix:\")\";suffix:1px 2px}",
+    );
+    minify_test_with_options(
+      "@counter-style foo { system: cyclic; symbols: a; foo: env(--bar) }",
+      "@counter-style foo{system:cyclic;symbols:a;foo:red}",
+      ParserOptions {
+        environment_variables: [("--bar".into(), "red")].into_iter().collect(),
+        ..ParserOptions::default()
+      },
     );
+    // Predefined counter styles cannot be overridden.
     error_test(
-      "@media print { .foo { color: red }} @import url(bar.css);",
-      ParserError::UnexpectedImportRule,
+      "@counter-style decimal { system: cyclic; symbols: a }",
+      ParserError::InvalidValue,
     );
     error_test(
-      "@layer foo; @import url(foo.css); @layer bar; @import url(bar.css)",
-      ParserError::UnexpectedImportRule,
+      "@counter-style none { system: cyclic; symbols: a }",
+      ParserError::InvalidValue,
     );
-    let warnings = error_recovery_test("@import './actual-styles.css';");
-    assert_eq!(warnings, vec![]);
   }
 
   #[test]
-  fn test_prefixes() {
-    prefix_test(
+  fn test_namespace() {
+    minify_test(
+      "@namespace url(http://toto.example.org);",
+      "@namespace \"http://toto.example.org\";",
+    );
+    minify_test(
+      "@namespace \"http://toto.example.org\";",
+      "@namespace \"http://toto.example.org\";",
+    );
+    minify_test(
+      "@namespace toto \"http://toto.example.org\"; toto|x { color: red }",
+      "@namespace toto \"http://toto.example.org\";toto|x{color:red}",
+    );
+    minify_test(
+      "@namespace toto url(http://toto.example.org); toto|x { color: red }",
+      "@namespace toto \"http://toto.example.org\";toto|x{color:red}",
+    );
+
+    test(
       r#"
-      .foo {
-        -webkit-transition: opacity 200ms;
-        -moz-transition: opacity 200ms;
-        transition: opacity 200ms;
+      @namespace "http://example.com/foo";
+
+      x {
+        color: red;
       }
-      "#,
+    "#,
       indoc! {r#"
-      .foo {
-        transition: opacity .2s;
+      @namespace "http://example.com/foo";
+
+      x {
+        color: red;
       }
-      "#},
-      Browsers {
-        chrome: Some(95 << 16),
-        ..Browsers::default()
-      },
+    "#},
     );
 
-    prefix_test(
-      ".foo{transition:opacity 200ms}",
+    test(
+      r#"
+      @namespace toto "http://toto.example.org";
+
+      toto|x {
+        color: red;
+      }
+
+      [toto|att=val] {
+        color: blue
+      }
+    "#,
       indoc! {r#"
-      .foo {
-        -webkit-transition: opacity .2s;
-        -moz-transition: opacity .2s;
-        transition: opacity .2s;
+      @namespace toto "http://toto.example.org";
+
+      toto|x {
+        color: red;
       }
-      "#},
-      Browsers {
-        safari: Some(5 << 16),
-        firefox: Some(14 << 16),
-        ..Browsers::default()
-      },
-    );
-  }
 
-  #[test]
-  fn test_display() {
-    minify_test(".foo { display: block }", ".foo{display:block}");
-    minify_test(".foo { display: block flow }", ".foo{display:block}");
-    minify_test(".foo { display: flow-root }", ".foo{display:flow-root}");
-    minify_test(".foo { display: block flow-root }", ".foo{display:flow-root}");
-    minify_test(".foo { display: inline }", ".foo{display:inline}");
-    minify_test(".foo { display: inline flow }", ".foo{display:inline}");
-    minify_test(".foo { display: inline-block }", ".foo{display:inline-block}");
-    minify_test(".foo { display: inline flow-root }", ".foo{display:inline-block}");
-    minify_test(".foo { display: run-in }", ".foo{display:run-in}");
-    minify_test(".foo { display: run-in flow }", ".foo{display:run-in}");
-    minify_test(".foo { display: list-item }", ".foo{display:list-item}");
-    minify_test(".foo { display: block flow list-item }", ".foo{display:list-item}");
-    minify_test(".foo { display: inline list-item }", ".foo{display:inline list-item}");
-    minify_test(
-      ".foo { display: inline flow list-item }",
-      ".foo{display:inline list-item}",
+      [toto|att="val"] {
+        color: #00f;
+      }
+    "#},
     );
-    minify_test(".foo { display: flex }", ".foo{display:flex}");
-    minify_test(".foo { display: block flex }", ".foo{display:flex}");
-    minify_test(".foo { display: inline-flex }", ".foo{display:inline-flex}");
-    minify_test(".foo { display: inline flex }", ".foo{display:inline-flex}");
-    minify_test(".foo { display: grid }", ".foo{display:grid}");
-    minify_test(".foo { display: block grid }", ".foo{display:grid}");
-    minify_test(".foo { display: inline-grid }", ".foo{display:inline-grid}");
+
+    test(
+      r#"
+      @namespace "http://example.com/foo";
+
+      |x {
+        color: red;
+      }
+
+      [|att=val] {
+        color: blue
+      }
+    "#,
+      indoc! {r#"
+      @namespace "http://example.com/foo";
+
+      |x {
+        color: red;
+      }
+
+      [att="val"] {
+        color: #00f;
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      @namespace "http://example.com/foo";
+
+      *|x {
+        color: red;
+      }
+
+      [*|att=val] {
+        color: blue
+      }
+    "#,
+      indoc! {r#"
+      @namespace "http://example.com/foo";
+
+      *|x {
+        color: red;
+      }
+
+      [*|att="val"] {
+        color: #00f;
+      }
+    "#},
+    );
+
+    error_test(
+      ".foo { color: red } @namespace \"http://example.com/foo\";",
+      ParserError::UnexpectedNamespaceRule,
+    );
+    error_test(
+      "toto|x { color: red }",
+      ParserError::SelectorError(SelectorError::ExpectedNamespace("toto".into())),
+    );
+    error_test(
+      "@namespace toto \"http://toto.example.org\"; .foo:not([tata|att]) { color: red }",
+      ParserError::SelectorError(SelectorError::ExpectedNamespace("tata".into())),
+    );
+    error_test(
+      "@media print { toto|x { color: red } }",
+      ParserError::SelectorError(SelectorError::ExpectedNamespace("toto".into())),
+    );
+    error_test(
+      ".foo { & toto|x { color: red } }",
+      ParserError::SelectorError(SelectorError::ExpectedNamespace("toto".into())),
+    );
+
+    // Rule lists and selectors parsed on their own have no @namespace scope, so any prefix is accepted.
+    {
+      use crate::rules::CssRuleList;
+      use crate::selector::SelectorList;
+      use crate::traits::ParseWithOptions;
+      use cssparser::{Parser, ParserInput};
+
+      let mut input = ParserInput::new("svg|rect { color: red }");
+      let mut parser = Parser::new(&mut input);
+      assert!(CssRuleList::parse(&mut parser, &ParserOptions::default()).is_ok());
+      assert!(SelectorList::parse_string_with_options("svg|rect", ParserOptions::default()).is_ok());
+    }
+
+    minify_test(
+      "@namespace svg url(http://www.w3.org/2000/svg); svg|rect { color: red }",
+      "@namespace svg \"http://www.w3.org/2000/svg\";svg|rect{color:red}",
+    );
+    minify_test(
+      "@namespace svg url(http://www.w3.org/2000/svg); rect { color: red }",
+      "rect{color:red}",
+    );
+    minify_test(
+      "@namespace url(http://www.w3.org/2000/svg); rect { color: red }",
+      "@namespace \"http://www.w3.org/2000/svg\";rect{color:red}",
+    );
+    minify_test(
+      "@namespace a \"a\"; @namespace b \"b\"; @namespace c \"c\"; .foo:not([a|x]) { color: red } @media print { b|x { color: red } }",
+      "@namespace a \"a\";@namespace b \"b\";.foo:not([a|x]){color:red}@media print{b|x{color:red}}",
+    );
+    minify_test(
+      "@namespace a \"a\"; @namespace b \"b\"; .foo { & a|x { color: red } }",
+      "@namespace a \"a\";.foo{& a|x{color:red}}",
+    );
+    minify_test(
+      "@namespace svg url(http://www.w3.org/2000/svg); :-webkit-any(svg|rect) { color: red }",
+      "@namespace svg \"http://www.w3.org/2000/svg\";:-webkit-any(svg|rect){color:red}",
+    );
+    minify_test(
+      "@namespace x url(http://www.w3.org/1999/xlink); @namespace y \"y\"; a::after { content: attr(x|title) }",
+      "@namespace x \"http://www.w3.org/1999/xlink\";a:after{content:attr(x|title)}",
+    );
+    minify_test(
+      "@namespace x \"x\"; @namespace y \"y\"; @namespace z \"z\"; a { --foo: var(--bar, attr(x|title)); & b { width: calc(attr( y|w px) * 2) } }",
+      "@namespace x \"x\";@namespace y \"y\";a{--foo:var(--bar,attr(x|title));& b{width:calc(attr(y|w px) * 2)}}",
+    );
+    minify_test(
+      "@namespace x \"x\"; @keyframes foo { to { content: attr(x|title) } }",
+      "@namespace x \"x\";@keyframes foo{to{content:attr(x|title)}}",
+    );
+    minify_test_with_options(
+      "@namespace svg url(http://www.w3.org/2000/svg); :global(svg|rect) { color: red }",
+      "@namespace svg \"http://www.w3.org/2000/svg\";svg|rect{color:red}",
+      ParserOptions {
+        css_modules: Some(Default::default()),
+        ..ParserOptions::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_import() {
+    minify_test("@import url(foo.css);", "@import \"foo.css\";");
+    minify_test("@import \"foo.css\";", "@import \"foo.css\";");
+    minify_test("@import url(foo.css) print;", "@import \"foo.css\" print;");
+    minify_test("@import \"foo.css\" print;", "@import \"foo.css\" print;");
+    minify_test(
+      "@import \"foo.css\" screen and (orientation: landscape);",
+      "@import \"foo.css\" screen and (orientation:landscape);",
+    );
+    minify_test(
+      "@import url(foo.css) supports(display: flex);",
+      "@import \"foo.css\" supports(display:flex);",
+    );
+    minify_test(
+      "@import url(foo.css) supports(display: flex) print;",
+      "@import \"foo.css\" supports(display:flex) print;",
+    );
+    minify_test(
+      "@import url(foo.css) supports(not (display: flex));",
+      "@import \"foo.css\" supports(not (display:flex));",
+    );
+    minify_test(
+      "@import url(foo.css) supports((display: flex));",
+      "@import \"foo.css\" supports(display:flex);",
+    );
+    minify_test(
+      "@import url(foo.css) supports(selector(a > b));",
+      "@import \"foo.css\" supports(selector(a > b));",
+    );
+    minify_test(
+      "@import url(foo.css) LAYER(foo.bar) SUPPORTS(display: grid) screen and (min-width: 100px);",
+      "@import \"foo.css\" layer(foo.bar) supports(display:grid) screen and (width>=100px);",
+    );
+    minify_test(
+      "@import url(foo.css) layer supports((display: grid) and (gap: 1px)) print;",
+      "@import \"foo.css\" layer supports((display:grid) and (gap:1px)) print;",
+    );
+    // layer() must come before supports(), otherwise it is parsed as a media type.
+    minify_test(
+      "@import url(foo.css) supports(display: grid) layer;",
+      "@import \"foo.css\" supports(display:grid) layer;",
+    );
+    error_test(
+      "@import url(foo.css) supports(display: grid) supports(color: red);",
+      ParserError::UnexpectedToken(Token::Function("supports".into())),
+    );
+    prefix_test(
+      "@import url(foo.css) layer(utilities) supports(backdrop-filter: blur(1px)) (min-resolution: 2dppx);",
+      indoc! { r#"
+        @import "foo.css" layer(utilities) supports((-webkit-backdrop-filter: blur(1px)) or (backdrop-filter: blur(1px))) (-webkit-min-device-pixel-ratio: 2), (min-resolution: 2dppx);
+      "#},
+      Browsers {
+        safari: Some(13 << 16),
+        ..Browsers::default()
+      },
+    );
+    minify_test("@charset \"UTF-8\"; @import url(foo.css);", "@import \"foo.css\";");
+    minify_test("@layer foo; @import url(foo.css);", "@layer foo;@import \"foo.css\";");
+    error_test(
+      ".foo { color: red } @import url(bar.css);",
+      ParserError::UnexpectedImportRule,
+    );
+    error_test(
+      "@namespace \"http://example.com/foo\"; @import url(bar.css);",
+      ParserError::UnexpectedImportRule,
+    );
+    error_test(
+      "@media print { .foo { color: red }} @import url(bar.css);",
+      ParserError::UnexpectedImportRule,
+    );
+    error_test(
+      "@layer foo; @import url(foo.css); @layer bar; @import url(bar.css)",
+      ParserError::UnexpectedImportRule,
+    );
+    let warnings = error_recovery_test("@import './actual-styles.css';");
+    assert_eq!(warnings, vec![]);
+  }
+
+  #[test]
+  fn test_prefixes() {
+    prefix_test(
+      r#"
+      .foo {
+        -webkit-transition: opacity 200ms;
+        -moz-transition: opacity 200ms;
+        transition: opacity 200ms;
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        transition: opacity .2s;
+      }
+      "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo{transition:opacity 200ms}",
+      indoc! {r#"
+      .foo {
+        -webkit-transition: opacity .2s;
+        -moz-transition: opacity .2s;
+        transition: opacity .2s;
+      }
+      "#},
+      Browsers {
+        safari: Some(5 << 16),
+        firefox: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_display() {
+    minify_test(".foo { display: block }", ".foo{display:block}");
+    minify_test(".foo { display: block flow }", ".foo{display:block}");
+    minify_test(".foo { display: flow-root }", ".foo{display:flow-root}");
+    minify_test(".foo { display: block flow-root }", ".foo{display:flow-root}");
+    minify_test(".foo { display: inline }", ".foo{display:inline}");
+    minify_test(".foo { display: inline flow }", ".foo{display:inline}");
+    minify_test(".foo { display: inline-block }", ".foo{display:inline-block}");
+    minify_test(".foo { display: inline flow-root }", ".foo{display:inline-block}");
+    minify_test(".foo { display: run-in }", ".foo{display:run-in}");
+    minify_test(".foo { display: run-in flow }", ".foo{display:run-in}");
+    minify_test(".foo { display: list-item }", ".foo{display:list-item}");
+    minify_test(".foo { display: block flow list-item }", ".foo{display:list-item}");
+    minify_test(".foo { display: inline list-item }", ".foo{display:inline list-item}");
+    minify_test(
+      ".foo { display: inline flow list-item }",
+      ".foo{display:inline list-item}",
+    );
+    minify_test(".foo { display: flex }", ".foo{display:flex}");
+    minify_test(".foo { display: block flex }", ".foo{display:flex}");
+    minify_test(".foo { display: inline-flex }", ".foo{display:inline-flex}");
+    minify_test(".foo { display: inline flex }", ".foo{display:inline-flex}");
+    minify_test(".foo { display: grid }", ".foo{display:grid}");
+    minify_test(".foo { display: block grid }", ".foo{display:grid}");
+    minify_test(".foo { display: inline-grid }", ".foo{display:inline-grid}");
     minify_test(".foo { display: inline grid }", ".foo{display:inline-grid}");
     minify_test(".foo { display: ruby }", ".foo{display:ruby}");
     minify_test(".foo { display: inline ruby }", ".foo{display:ruby}");
@@ -15343,14 +17830,161 @@ mod tests {
     minify_test(".foo { white-space: break-spaces }", ".foo{white-space:break-spaces}");
     minify_test(".foo { white-space: pre-line }", ".foo{white-space:pre-line}");
     minify_test(".foo { white-space: NoWrAp }", ".foo{white-space:nowrap}");
-  }
 
-  #[test]
-  fn test_tab_size() {
-    minify_test(".foo { tab-size: 8 }", ".foo{tab-size:8}");
-    minify_test(".foo { tab-size: 4px }", ".foo{tab-size:4px}");
-    minify_test(".foo { -moz-tab-size: 4px }", ".foo{-moz-tab-size:4px}");
-    minify_test(".foo { -o-tab-size: 4px }", ".foo{-o-tab-size:4px}");
+    // Legacy keywords round trip through the longhands.
+    let legacy = [
+      ("normal", "collapse", "wrap"),
+      ("pre", "preserve", "nowrap"),
+      ("nowrap", "collapse", "nowrap"),
+      ("pre-wrap", "preserve", "wrap"),
+      ("break-spaces", "break-spaces", "wrap"),
+      ("pre-line", "preserve-breaks", "wrap"),
+    ];
+    for (keyword, collapse, mode) in legacy {
+      let expected = format!(".foo{{white-space:{}}}", keyword);
+      minify_test(&format!(".foo {{ white-space: {} {} }}", collapse, mode), &expected);
+      minify_test(&format!(".foo {{ white-space: {} {} }}", mode, collapse), &expected);
+      minify_test(
+        &format!(
+          ".foo {{ white-space-collapse: {}; text-wrap-mode: {} }}",
+          collapse, mode
+        ),
+        &expected,
+      );
+      minify_test(
+        &format!(
+          ".foo {{ text-wrap-mode: {}; white-space-collapse: {} }}",
+          mode, collapse
+        ),
+        &expected,
+      );
+    }
+
+    minify_test(".foo { white-space: collapse }", ".foo{white-space:normal}");
+    minify_test(".foo { white-space: preserve }", ".foo{white-space:pre-wrap}");
+    minify_test(".foo { white-space: discard wrap }", ".foo{white-space:discard}");
+    minify_test(
+      ".foo { white-space: nowrap discard }",
+      ".foo{white-space:discard nowrap}",
+    );
+    minify_test(".foo { white-space: pre pre }", ".foo{white-space:pre pre}");
+    minify_test(
+      ".foo { white-space-collapse: preserve-spaces }",
+      ".foo{white-space-collapse:preserve-spaces}",
+    );
+    minify_test(
+      ".foo { white-space: pre; text-wrap-mode: wrap }",
+      ".foo{white-space:pre-wrap}",
+    );
+    minify_test(
+      ".foo { white-space: pre; white-space-collapse: preserve-breaks }",
+      ".foo{white-space:preserve-breaks nowrap}",
+    );
+    minify_test(
+      ".foo { white-space: pre; text-wrap: balance }",
+      ".foo{white-space:pre-wrap;text-wrap-style:balance}",
+    );
+    minify_test(
+      ".foo { white-space: var(--ws); text-wrap-mode: nowrap }",
+      ".foo{white-space:var(--ws);text-wrap-mode:nowrap}",
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        white-space: preserve-breaks nowrap;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        white-space-collapse: preserve-breaks;
+        text-wrap-mode: nowrap;
+      }
+    "#},
+      Browsers {
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        white-space-collapse: preserve;
+        text-wrap-mode: nowrap;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        white-space: pre;
+      }
+    "#},
+      Browsers {
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        white-space-collapse: preserve-breaks;
+        text-wrap-mode: nowrap;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        white-space: preserve-breaks nowrap;
+      }
+    "#},
+      Browsers {
+        chrome: Some(120 << 16),
+        ..Browsers::default()
+      },
+    );
+    // The text-wrap-style longhand is not supported by older browsers, but the text-wrap shorthand is.
+    prefix_test(
+      r#"
+      .foo {
+        white-space: pre;
+        text-wrap: balance;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        white-space: pre-wrap;
+        text-wrap: balance;
+      }
+    "#},
+      Browsers {
+        chrome: Some(120 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        white-space: pre;
+        text-wrap: balance;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        white-space: pre-wrap;
+        text-wrap-style: balance;
+      }
+    "#},
+      Browsers {
+        chrome: Some(130 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_tab_size() {
+    minify_test(".foo { tab-size: 8 }", ".foo{tab-size:8}");
+    minify_test(".foo { tab-size: 4px }", ".foo{tab-size:4px}");
+    minify_test(".foo { -moz-tab-size: 4px }", ".foo{-moz-tab-size:4px}");
+    minify_test(".foo { -o-tab-size: 4px }", ".foo{-o-tab-size:4px}");
     prefix_test(
       ".foo{ tab-size: 4 }",
       indoc! {r#"
@@ -15414,6 +18048,127 @@ mod tests {
     minify_test(".foo { word-wrap: Anywhere }", ".foo{word-wrap:anywhere}");
   }
 
+  #[test]
+  fn test_text_wrap() {
+    minify_test(".foo { text-wrap-mode: wrap }", ".foo{text-wrap-mode:wrap}");
+    minify_test(".foo { text-wrap-mode: NoWrap }", ".foo{text-wrap-mode:nowrap}");
+    minify_test(".foo { text-wrap-style: auto }", ".foo{text-wrap-style:auto}");
+    minify_test(".foo { text-wrap-style: balance }", ".foo{text-wrap-style:balance}");
+    minify_test(".foo { text-wrap-style: stable }", ".foo{text-wrap-style:stable}");
+    minify_test(".foo { text-wrap-style: pretty }", ".foo{text-wrap-style:pretty}");
+
+    let modes = [("wrap", None), ("nowrap", Some("nowrap"))];
+    let styles = [
+      ("auto", None),
+      ("balance", Some("balance")),
+      ("stable", Some("stable")),
+      ("pretty", Some("pretty")),
+    ];
+    for (mode, mode_out) in modes {
+      for (style, style_out) in styles {
+        let expected = match (mode_out, style_out) {
+          (None, None) => "wrap".to_string(),
+          (Some(v), None) | (None, Some(v)) => v.to_string(),
+          (Some(m), Some(s)) => format!("{} {}", m, s),
+        };
+        let expected = format!(".foo{{text-wrap:{}}}", expected);
+        minify_test(&format!(".foo {{ text-wrap: {} {} }}", mode, style), &expected);
+        minify_test(&format!(".foo {{ text-wrap: {} {} }}", style, mode), &expected);
+        minify_test(
+          &format!(".foo {{ text-wrap-mode: {}; text-wrap-style: {} }}", mode, style),
+          &expected,
+        );
+      }
+    }
+
+    minify_test(".foo { text-wrap: balance }", ".foo{text-wrap:balance}");
+    minify_test(".foo { text-wrap: nowrap }", ".foo{text-wrap:nowrap}");
+    minify_test(
+      ".foo { text-wrap: balance; text-wrap-mode: nowrap }",
+      ".foo{text-wrap:nowrap balance}",
+    );
+    minify_test(
+      ".foo { text-wrap: nowrap balance; text-wrap-style: auto }",
+      ".foo{text-wrap:nowrap}",
+    );
+    minify_test(
+      ".foo { text-wrap: var(--wrap); text-wrap-style: balance }",
+      ".foo{text-wrap:var(--wrap);text-wrap-style:balance}",
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        text-wrap-mode: nowrap;
+        text-wrap-style: balance;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        text-wrap-mode: nowrap;
+        text-wrap-style: balance;
+      }
+    "#},
+      Browsers {
+        chrome: Some(120 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        text-wrap: nowrap balance;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        text-wrap-mode: nowrap;
+        text-wrap-style: balance;
+      }
+    "#},
+      Browsers {
+        safari: Some(17 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        text-wrap-mode: wrap;
+        text-wrap-style: balance;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        text-wrap: balance;
+      }
+    "#},
+      Browsers {
+        chrome: Some(120 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        text-wrap-mode: nowrap;
+        text-wrap-style: balance;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        text-wrap: nowrap balance;
+      }
+    "#},
+      Browsers {
+        chrome: Some(130 << 16),
+        firefox: Some(124 << 16),
+        safari: Some(18 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
   #[test]
   fn test_hyphens() {
     minify_test(".foo { hyphens: manual }", ".foo{hyphens:manual}");
@@ -15841,6 +18596,18 @@ mod tests {
       ".foo { -moz-text-decoration: yellow dotted underline }",
       ".foo{-moz-text-decoration:underline dotted #ff0}",
     );
+    minify_test(
+      ".foo { text-decoration: 2px yellow dotted underline }",
+      ".foo{text-decoration:underline 2px dotted #ff0}",
+    );
+    minify_test(
+      ".foo { text-decoration: underline auto solid currentColor }",
+      ".foo{text-decoration:underline}",
+    );
+    minify_test(
+      ".foo { text-decoration: none auto solid currentColor }",
+      ".foo{text-decoration:none}",
+    );
 
     test(
       r#"
@@ -17035,6 +19802,61 @@ mod tests {
         ..Browsers::default()
       },
     );
+
+    prefix_test(
+      r#"
+      .foo {
+        inset: 1px 2px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        top: 1px;
+        bottom: 1px;
+        left: 2px;
+        right: 2px;
+      }
+    "#},
+      Browsers {
+        safari: Some(13 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        top: 0;
+        left: 0;
+        bottom: 0;
+        right: 0;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        inset: 0;
+      }
+    "#},
+      Browsers {
+        safari: Some(14 << 16 | 1 << 8),
+        ..Browsers::default()
+      },
+    );
+
+    // Logical properties between physical ones must not be merged across.
+    minify_test(
+      ".foo { top: 0; inset-inline-start: 1px; right: 0; bottom: 0; left: 0 }",
+      ".foo{top:0;inset-inline-start:1px;bottom:0;left:0;right:0}",
+    );
+    minify_test(
+      ".foo { inset-block-start: 1px; top: 0; inset-block-end: 2px }",
+      ".foo{inset-block-start:1px;top:0;inset-block-end:2px}",
+    );
+    minify_test(
+      ".foo { top: 1px; inset-block-start: 2px; bottom: 3px }",
+      ".foo{top:1px;inset-block-start:2px;bottom:3px}",
+    );
+    minify_test(".foo { inset-inline-start: 1px; inset: 0 }", ".foo{inset:0}");
   }
 
   #[test]
@@ -17172,101 +19994,263 @@ mod tests {
         ..Browsers::default()
       },
     );
-  }
 
-  #[test]
-  fn test_ui() {
-    minify_test(".foo { resize: both }", ".foo{resize:both}");
-    minify_test(".foo { resize: Horizontal }", ".foo{resize:horizontal}");
-    minify_test(".foo { cursor: ew-resize }", ".foo{cursor:ew-resize}");
-    minify_test(
-      ".foo { cursor: url(\"test.cur\"), ew-resize }",
-      ".foo{cursor:url(test.cur),ew-resize}",
-    );
-    minify_test(
-      ".foo { cursor: url(\"test.cur\"), url(\"foo.cur\"), ew-resize }",
-      ".foo{cursor:url(test.cur),url(foo.cur),ew-resize}",
-    );
-    minify_test(".foo { caret-color: auto }", ".foo{caret-color:auto}");
-    minify_test(".foo { caret-color: yellow }", ".foo{caret-color:#ff0}");
-    minify_test(".foo { caret-shape: block }", ".foo{caret-shape:block}");
-    minify_test(".foo { caret: yellow block }", ".foo{caret:#ff0 block}");
-    minify_test(".foo { caret: block yellow }", ".foo{caret:#ff0 block}");
-    minify_test(".foo { caret: block }", ".foo{caret:block}");
-    minify_test(".foo { caret: yellow }", ".foo{caret:#ff0}");
-    minify_test(".foo { caret: auto auto }", ".foo{caret:auto}");
-    minify_test(".foo { caret: auto }", ".foo{caret:auto}");
-    minify_test(".foo { caret: yellow auto }", ".foo{caret:#ff0}");
-    minify_test(".foo { caret: auto block }", ".foo{caret:block}");
-    minify_test(".foo { user-select: none }", ".foo{user-select:none}");
-    minify_test(".foo { -webkit-user-select: none }", ".foo{-webkit-user-select:none}");
-    minify_test(".foo { accent-color: auto }", ".foo{accent-color:auto}");
-    minify_test(".foo { accent-color: yellow }", ".foo{accent-color:#ff0}");
-    minify_test(".foo { appearance: None }", ".foo{appearance:none}");
+    minify_test(".foo { line-clamp: 3 }", ".foo{line-clamp:3}");
+    minify_test(".foo { line-clamp: none }", ".foo{line-clamp:none}");
+    minify_test(".foo { -webkit-line-clamp: 3 }", ".foo{-webkit-line-clamp:3}");
     minify_test(
-      ".foo { -webkit-appearance: textfield }",
-      ".foo{-webkit-appearance:textfield}",
+      ".foo { -webkit-line-clamp: 3; line-clamp: 3 }",
+      ".foo{-webkit-line-clamp:3;line-clamp:3}",
     );
-
+    minify_test(".foo { line-clamp: 2; line-clamp: 3 }", ".foo{line-clamp:3}");
     prefix_test(
       r#"
       .foo {
-        user-select: none;
+        line-clamp: 3;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-user-select: none;
-        -moz-user-select: none;
-        -ms-user-select: none;
-        user-select: none;
+        display: -webkit-box;
+        -webkit-box-orient: vertical;
+        -webkit-line-clamp: 3;
+        line-clamp: 3;
       }
     "#},
       Browsers {
-        safari: Some(8 << 16),
-        opera: Some(5 << 16),
-        firefox: Some(10 << 16),
-        ie: Some(10 << 16),
+        chrome: Some(100 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
       .foo {
-        -webkit-user-select: none;
-        -moz-user-select: none;
-        -ms-user-select: none;
-        user-select: none;
+        display: inline-block;
+        line-clamp: 3;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-user-select: none;
-        user-select: none;
+        display: inline-block;
+        -webkit-box-orient: vertical;
+        -webkit-line-clamp: 3;
+        line-clamp: 3;
       }
     "#},
       Browsers {
-        safari: Some(8 << 16),
-        opera: Some(80 << 16),
-        firefox: Some(80 << 16),
-        edge: Some(80 << 16),
+        chrome: Some(100 << 16),
         ..Browsers::default()
       },
     );
-
     prefix_test(
       r#"
       .foo {
-        -webkit-user-select: none;
-        -moz-user-select: none;
-        -ms-user-select: none;
-        user-select: none;
+        line-clamp: none;
       }
     "#,
       indoc! {r#"
       .foo {
-        user-select: none;
+        -webkit-line-clamp: none;
+        line-clamp: none;
+      }
+    "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        display: -webkit-box;
+        -webkit-box-orient: vertical;
+        -webkit-line-clamp: 3;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-box-orient: vertical;
+        display: -webkit-box;
+        -webkit-line-clamp: 3;
+      }
+    "#},
+      Browsers {
+        firefox: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_overscroll_behavior() {
+    minify_test(
+      ".foo { overscroll-behavior: contain }",
+      ".foo{overscroll-behavior:contain}",
+    );
+    minify_test(
+      ".foo { overscroll-behavior: contain contain }",
+      ".foo{overscroll-behavior:contain}",
+    );
+    minify_test(
+      ".foo { overscroll-behavior: contain auto }",
+      ".foo{overscroll-behavior:contain auto}",
+    );
+    minify_test(
+      ".foo { overscroll-behavior: auto auto }",
+      ".foo{overscroll-behavior:auto}",
+    );
+    minify_test(
+      ".foo { overscroll-behavior: none contain }",
+      ".foo{overscroll-behavior:none contain}",
+    );
+    minify_test(
+      ".foo { overscroll-behavior-x: contain; overscroll-behavior-y: contain }",
+      ".foo{overscroll-behavior:contain}",
+    );
+    minify_test(
+      ".foo { overscroll-behavior-x: none; overscroll-behavior-y: auto }",
+      ".foo{overscroll-behavior:none auto}",
+    );
+    minify_test(
+      ".foo { overscroll-behavior-x: none }",
+      ".foo{overscroll-behavior-x:none}",
+    );
+    minify_test(
+      ".foo { overscroll-behavior: auto; overscroll-behavior-y: none }",
+      ".foo{overscroll-behavior:auto none}",
+    );
+    minify_test(
+      ".foo { overscroll-behavior-inline: contain; overscroll-behavior-block: none }",
+      ".foo{overscroll-behavior-inline:contain;overscroll-behavior-block:none}",
+    );
+    minify_test(
+      ".foo { overscroll-behavior-inline: contain; overscroll-behavior-x: auto }",
+      ".foo{overscroll-behavior-inline:contain;overscroll-behavior-x:auto}",
+    );
+    minify_test(
+      ".foo { overscroll-behavior-x: auto; overscroll-behavior-inline: contain; overscroll-behavior-y: none }",
+      ".foo{overscroll-behavior-x:auto;overscroll-behavior-inline:contain;overscroll-behavior-y:none}",
+    );
+    minify_test(
+      ".foo { overscroll-behavior: var(--foo) }",
+      ".foo{overscroll-behavior:var(--foo)}",
+    );
+    test(
+      r#"
+      .foo {
+        overscroll-behavior-x: contain;
+        overscroll-behavior-y: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        overscroll-behavior: contain none;
+      }
+    "#},
+    );
+  }
+
+  #[test]
+  fn test_ui() {
+    minify_test(".foo { resize: both }", ".foo{resize:both}");
+    minify_test(".foo { resize: Horizontal }", ".foo{resize:horizontal}");
+    minify_test(".foo { cursor: ew-resize }", ".foo{cursor:ew-resize}");
+    minify_test(
+      ".foo { cursor: url(\"test.cur\"), ew-resize }",
+      ".foo{cursor:url(test.cur),ew-resize}",
+    );
+    minify_test(
+      ".foo { cursor: url(\"test.cur\"), url(\"foo.cur\"), ew-resize }",
+      ".foo{cursor:url(test.cur),url(foo.cur),ew-resize}",
+    );
+    minify_test(".foo { caret-color: auto }", ".foo{caret-color:auto}");
+    minify_test(".foo { caret-color: yellow }", ".foo{caret-color:#ff0}");
+    minify_test(".foo { caret-color: AccentColor }", ".foo{caret-color:accentcolor}");
+    minify_test(".foo { accent-color: auto }", ".foo{accent-color:auto}");
+    minify_test(".foo { accent-color: yellow }", ".foo{accent-color:#ff0}");
+    minify_test(".foo { accent-color: AUTO }", ".foo{accent-color:auto}");
+    minify_test(
+      ".foo { accent-color: red; accent-color: blue }",
+      ".foo{accent-color:#00f}",
+    );
+    minify_test(".foo { caret-shape: block }", ".foo{caret-shape:block}");
+    minify_test(".foo { caret: yellow block }", ".foo{caret:#ff0 block}");
+    minify_test(".foo { caret: block yellow }", ".foo{caret:#ff0 block}");
+    minify_test(".foo { caret: block }", ".foo{caret:block}");
+    minify_test(".foo { caret: yellow }", ".foo{caret:#ff0}");
+    minify_test(".foo { caret: auto auto }", ".foo{caret:auto}");
+    minify_test(".foo { caret: auto }", ".foo{caret:auto}");
+    minify_test(".foo { caret: yellow auto }", ".foo{caret:#ff0}");
+    minify_test(".foo { caret: auto block }", ".foo{caret:block}");
+    minify_test(".foo { user-select: none }", ".foo{user-select:none}");
+    minify_test(".foo { -webkit-user-select: none }", ".foo{-webkit-user-select:none}");
+    minify_test(".foo { accent-color: auto }", ".foo{accent-color:auto}");
+    minify_test(".foo { accent-color: yellow }", ".foo{accent-color:#ff0}");
+    minify_test(".foo { appearance: None }", ".foo{appearance:none}");
+    minify_test(
+      ".foo { -webkit-appearance: textfield }",
+      ".foo{-webkit-appearance:textfield}",
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        user-select: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-user-select: none;
+        -moz-user-select: none;
+        -ms-user-select: none;
+        user-select: none;
+      }
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        opera: Some(5 << 16),
+        firefox: Some(10 << 16),
+        ie: Some(10 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        -webkit-user-select: none;
+        -moz-user-select: none;
+        -ms-user-select: none;
+        user-select: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-user-select: none;
+        user-select: none;
+      }
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        opera: Some(80 << 16),
+        firefox: Some(80 << 16),
+        edge: Some(80 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        -webkit-user-select: none;
+        -moz-user-select: none;
+        -ms-user-select: none;
+        user-select: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        user-select: none;
       }
     "#},
       Browsers {
@@ -17362,6 +20346,36 @@ mod tests {
       },
     );
 
+    prefix_test(
+      ".foo { accent-color: lch(50.998% 135.363 338) }",
+      indoc! { r#"
+        .foo {
+          accent-color: #ee00be;
+          accent-color: color(display-p3 .972962 -.362078 .804206);
+          accent-color: lch(50.998% 135.363 338);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { accent-color: auto }",
+      indoc! { r#"
+        .foo {
+          accent-color: auto;
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
     prefix_test(
       ".foo { caret: lch(50.998% 135.363 338) block }",
       indoc! { r#"
@@ -17612,17 +20626,17 @@ mod tests {
     // test image-set(<string>)
     minify_test(
       ".foo { background: image-set(\"foo.png\" 2x, url(bar.png) 1x) }",
-      ".foo{background:image-set(\"foo.png\" 2x,\"bar.png\" 1x)}",
+      ".foo{background:image-set(\"foo.png\" 2x,\"bar.png\")}",
     );
 
     // test image-set(type(<string>))
     minify_test(
       ".foo { background: image-set('foo.webp' type('webp'), url(foo.jpg)) }",
-      ".foo{background:image-set(\"foo.webp\" 1x type(\"webp\"),\"foo.jpg\" 1x)}",
+      ".foo{background:image-set(\"foo.webp\" type(\"webp\"),\"foo.jpg\")}",
     );
     minify_test(
       ".foo { background: image-set('foo.avif' 2x type('image/avif'), url(foo.png)) }",
-      ".foo{background:image-set(\"foo.avif\" 2x type(\"image/avif\"),\"foo.png\" 1x)}",
+      ".foo{background:image-set(\"foo.avif\" 2x type(\"image/avif\"),\"foo.png\")}",
     );
     minify_test(
       ".foo { background: image-set(url('example.png') 3x type('image/png')) }",
@@ -17631,7 +20645,21 @@ mod tests {
 
     minify_test(
       ".foo { background: image-set(url(example.png) type('image/png') 1x) }",
-      ".foo{background:image-set(\"example.png\" 1x type(\"image/png\"))}",
+      ".foo{background:image-set(\"example.png\" type(\"image/png\"))}",
+    );
+
+    // test mixed resolution units
+    minify_test(
+      ".foo { background: image-set(url(a.png) 96dpi, url(b.png) 192dpi, url(c.png) 3dppx, url(d.png) 1x) }",
+      ".foo{background:image-set(\"a.png\",\"b.png\" 192dpi,\"c.png\" 3x,\"d.png\")}",
+    );
+    test(
+      ".foo { background: image-set(url(a.png) 96dpi, url(b.png) 192dpi, url(c.png) 3dppx) }",
+      indoc! {r#"
+      .foo {
+        background: image-set("a.png" 96dpi, "b.png" 192dpi, "c.png" 3x);
+      }
+    "#},
     );
 
     minify_test(
@@ -17676,6 +20704,7 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
+        background: url("bar.png");
         background: -webkit-image-set(url("foo.png") 2x, url("bar.png") 1x);
         background: image-set("foo.png" 2x, "bar.png" 1x);
       }
@@ -17696,6 +20725,7 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
+        background: url("bar.png");
         background: -webkit-image-set(url("foo.png") 2x, url("bar.png") 1x);
         background: image-set("foo.png" 2x, "bar.png" 1x);
       }
@@ -17723,6 +20753,44 @@ mod tests {
       },
     );
 
+    prefix_test(
+      r#"
+      .foo {
+        background-image: image-set("foo.avif" type("image/avif"), "foo@2x.png" 2x, "foo.png" 1x), linear-gradient(red, blue);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        background-image: url("foo.png"), linear-gradient(red, #00f);
+        background-image: -webkit-image-set(url("foo.avif") 1x type("image/avif"), url("foo@2x.png") 2x, url("foo.png") 1x), -webkit-linear-gradient(top, red, #00f);
+        background-image: image-set("foo.avif" 1x type("image/avif"), "foo@2x.png" 2x, "foo.png" 1x), linear-gradient(red, #00f);
+      }
+    "#},
+      Browsers {
+        ie: Some(11 << 16),
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        background: image-set("foo@3x.png" 3x, "foo@2x.png" 192dpi);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        background: url("foo@2x.png");
+        background: image-set("foo@3x.png" 3x, "foo@2x.png" 192dpi);
+      }
+    "#},
+      Browsers {
+        firefox: Some(80 << 16),
+        ..Browsers::default()
+      },
+    );
+
     for property in &[
       "background",
       "background-image",
@@ -18738,31 +21806,108 @@ mod tests {
   }
 
   #[test]
-  fn test_relative_color() {
-    fn test(input: &str, output: &str) {
-      let output = CssColor::parse_string(output)
-        .unwrap()
-        .to_css_string(PrinterOptions {
-          minify: true,
-          ..PrinterOptions::default()
-        })
-        .unwrap();
-      minify_test(
-        &format!(".foo {{ color: {} }}", input),
-        &format!(".foo{{color:{}}}", output),
-      );
-    }
+  fn test_oklab_fallbacks() {
+    // Static oklab() and oklch() colors are converted to the nearest in-gamut rgb() color,
+    // with P3 and LAB fallbacks. Custom properties use @supports rules for the fallbacks.
+    // Colors containing variables or currentColor cannot be converted and are left as is.
+    prefix_test(
+      r#"
+      .foo {
+        color: oklch(70% 0.1 200);
+        background-color: oklab(50% 0.3 0.1);
+        border-color: oklch(70% 0.1 var(--h));
+        outline-color: oklch(from currentColor l c h);
+        --x: oklch(70% 0.1 200);
+        --y: oklch(70% 0.1 var(--h)) currentColor;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        color: #40b1b7;
+        color: color(display-p3 .381906 .685023 .710512);
+        color: lab(66.1711% -31.3595 -12.905);
+        border-color: oklch(70% .1 var(--h));
+        outline-color: oklch(from currentColor l c h);
+        --x: #40b1b7;
+        --y: oklch(70% .1 var(--h)) currentColor;
+        background-color: #c0002e;
+        background-color: color(display-p3 .800423 -.275907 .066036);
+        background-color: lab(38.4382% 101.965 64.1667);
+      }
 
-    test("lab(from indianred calc(l * .8) a b)", "lab(43.1402% 45.7516 23.1557)");
-    test("lch(from indianred calc(l + 10) c h)", "lch(63.9252% 51.2776 26.8448)");
-    test("lch(from indianred l calc(c - 50) h)", "lch(53.9252% 1.27763 26.8448)");
-    test(
-      "lch(from indianred l c calc(h + 180deg))",
-      "lch(53.9252% 51.2776 206.845)",
-    );
-    test("lch(from orchid l 30 h)", "lch(62.7526% 30 326.969)");
-    test("lch(from orchid l 30 h)", "lch(62.7526% 30 326.969)");
-    test("lch(from peru calc(l * 0.8) c h)", "lch(49.8022% 54.0117 63.6804)");
+      @supports (color: color(display-p3 0 0 0)) {
+        .foo {
+          --x: color(display-p3 .381906 .685023 .710512);
+        }
+      }
+
+      @supports (color: lab(0% 0 0)) {
+        .foo {
+          --x: lab(66.1711% -31.3595 -12.905);
+        }
+      }
+    "#},
+      Browsers {
+        safari: Some(14 << 16),
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        color: oklch(70% 0.1 200);
+        --x: oklab(50% 0.3 0.1);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        color: oklch(70% .1 200);
+        --x: oklab(50% .3 .1);
+      }
+    "#},
+      Browsers {
+        safari: Some(16 << 16),
+        chrome: Some(111 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_relative_color() {
+    fn test(input: &str, output: &str) {
+      let output = CssColor::parse_string(output)
+        .unwrap()
+        .to_css_string(PrinterOptions {
+          minify: true,
+          ..PrinterOptions::default()
+        })
+        .unwrap();
+      minify_test(
+        &format!(".foo {{ color: {} }}", input),
+        &format!(".foo{{color:{}}}", output),
+      );
+    }
+
+    test("lab(from indianred calc(l * .8) a b)", "lab(43.1402% 45.7516 23.1557)");
+    test("lch(from indianred calc(l + 10) c h)", "lch(63.9252% 51.2776 26.8448)");
+    test("lch(from indianred l calc(c - 50) h)", "lch(53.9252% 1.27763 26.8448)");
+    test(
+      "lch(from indianred l c calc(h + 180deg))",
+      "lch(53.9252% 51.2776 206.845)",
+    );
+    test("lch(from indianred l c calc(h + 180))", "lch(53.9252% 51.2776 206.845)");
+    test("oklch(from red l c calc(h / 2))", "oklch(62.7955% .257683 14.6169)");
+    test("hsl(from rgb(10 20 30) calc(h + 180) s l)", "hsl(30deg 50% 7.84314%)");
+    test("hwb(from red calc(h + 30) w b)", "hwb(30deg 0% 0%)");
+    minify_test(
+      ".foo { color: hsl(from red calc(h + 30px) s l) }",
+      ".foo{color:hsl(from red calc(h + 30px) s l)}",
+    );
+    test("lch(from orchid l 30 h)", "lch(62.7526% 30 326.969)");
+    test("lch(from orchid l 30 h)", "lch(62.7526% 30 326.969)");
+    test("lch(from peru calc(l * 0.8) c h)", "lch(49.8022% 54.0117 63.6804)");
     test("rgb(from indianred 255 g b)", "rgb(255, 92, 92)");
     test("rgb(from indianred r g b / .5)", "rgba(205, 92, 92, .5)");
     test(
@@ -22049,6 +25194,11 @@ mod tests {
 
   #[test]
   fn test_grid() {
+    // The legacy -ms- grid syntax is not supported, but is passed through as is.
+    minify_test(
+      ".foo { display: -ms-grid; -ms-grid-columns: 1fr 1fr; -ms-grid-row: 2 }",
+      ".foo{display:-ms-grid;-ms-grid-columns:1fr 1fr;-ms-grid-row:2}",
+    );
     minify_test(
       ".foo { grid-template-columns: [first nav-start]  150px [main-start] 1fr [last]; }",
       ".foo{grid-template-columns:[first nav-start]150px[main-start]1fr[last]}",
@@ -22134,6 +25284,47 @@ mod tests {
       ".foo { grid-template-rows: [linename1 linename2] 100px repeat(auto-fit, [linename1] 300px) [linename3]; }",
       ".foo{grid-template-rows:[linename1 linename2]100px repeat(auto-fit,[linename1]300px)[linename3]}",
     );
+    minify_test(
+      ".foo { grid-template-columns: repeat(1, 1fr); }",
+      ".foo{grid-template-columns:1fr}",
+    );
+    minify_test(
+      ".foo { grid-template-columns: [a] repeat(1, [b] 1fr [c] 2fr [d]) [e] 100px; }",
+      ".foo{grid-template-columns:[a b]1fr[c]2fr[d e]100px}",
+    );
+    minify_test(
+      ".foo { grid-template-columns: [] 1fr [] repeat(2, [] 1fr); }",
+      ".foo{grid-template-columns:1fr repeat(2,1fr)}",
+    );
+    minify_test(
+      ".foo { grid-template-rows: subgrid; }",
+      ".foo{grid-template-rows:subgrid}",
+    );
+    minify_test(
+      ".foo { grid-template-columns: subgrid [a] [] [b c] [] []; }",
+      ".foo{grid-template-columns:subgrid[a][][b c]}",
+    );
+    minify_test(
+      ".foo { grid-template: subgrid [a] / subgrid; }",
+      ".foo{grid-template:subgrid[a]/subgrid}",
+    );
+    minify_test(".foo { grid: subgrid / auto-flow; }", ".foo{grid:subgrid/auto-flow}");
+    minify_test(
+      ".foo { grid-template-rows: repeat(1, 10px); grid-template-columns: 1fr; grid-template-areas: \"a\"; }",
+      ".foo{grid-template:\"a\"10px/1fr}",
+    );
+    test(
+      r#"
+      .foo {
+        grid-template-rows: subgrid [a] [b];
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        grid-template-rows: subgrid [a] [b];
+      }
+    "#},
+    );
 
     minify_test(".foo { grid-auto-rows: auto; }", ".foo{grid-auto-rows:auto}");
     minify_test(".foo { grid-auto-rows: 1fr; }", ".foo{grid-auto-rows:1fr}");
@@ -22417,6 +25608,91 @@ mod tests {
     "#},
     );
 
+    test(
+      r#"
+      .foo {
+        grid: [header-top] "a a a" [header-bottom]
+              [main-top] "b b b" 1fr [main-bottom]
+              / auto 1fr auto;
+      }
+    "#,
+      indoc! { r#"
+      .foo {
+        grid: [header-top] "a a a" [header-bottom]
+              [main-top] "b b b" 1fr [main-bottom]
+              / auto 1fr auto;
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      .foo {
+        grid: "header header header" 40px
+              "sidebar main ." 1fr
+              "footer footer footer" auto / 200px 1fr 1fr;
+      }
+    "#,
+      indoc! { r#"
+      .foo {
+        grid: "header header header" 40px
+              "sidebar main ." 1fr
+              "footer footer footer"
+              / 200px 1fr 1fr;
+      }
+    "#},
+    );
+
+    test(
+      r#"
+      .foo {
+        grid-template-rows: 40px 1fr;
+        grid-template-columns: 200px 1fr;
+        grid-template-areas: "header header" "sidebar main";
+        grid-auto-flow: row;
+        grid-auto-rows: auto;
+        grid-auto-columns: auto;
+      }
+    "#,
+      indoc! { r#"
+      .foo {
+        grid: "header header" 40px
+              "sidebar main" 1fr
+              / 200px 1fr;
+      }
+    "#},
+    );
+
+    // Cells are only aligned when opted in.
+    test_with_printer_options(
+      r#"
+      .foo {
+        grid-template-areas: "head head" "nav main" "foot .";
+      }
+      .bar {
+        grid: "header header header" 40px "sidebar main ." 1fr "footer footer footer" auto / 200px 1fr 1fr;
+      }
+    "#,
+      indoc! { r#"
+      .foo {
+        grid-template-areas: "head head"
+                             "nav  main"
+                             "foot .";
+      }
+
+      .bar {
+        grid: "header  header header" 40px
+              "sidebar main   ." 1fr
+              "footer  footer footer"
+              / 200px 1fr 1fr;
+      }
+    "#},
+      PrinterOptions {
+        align_grid_template_areas: true,
+        ..PrinterOptions::default()
+      },
+    );
+
     minify_test(
       r#"
       .foo {
@@ -23034,6 +26310,17 @@ mod tests {
         }
       "#},
     );
+
+    minify_test(
+      ".foo{grid-row-start:1;grid-column-start:2}",
+      ".foo{grid-row-start:1;grid-column-start:2}",
+    );
+    minify_test(
+      ".foo{grid-row-start:1;grid-column-start:2;grid-row-end:3}",
+      ".foo{grid-row:1/3;grid-column-start:2}",
+    );
+    minify_test(".foo{grid-area:foo;grid-column-end:2}", ".foo{grid-area:foo/foo/foo/2}");
+    minify_test(".foo{grid-area:1/2/3/4;grid-row:5}", ".foo{grid-area:5/2/auto/4}");
   }
 
   #[test]
@@ -23921,7 +27208,58 @@ mod tests {
         color: #ff0;
       }
     "#},
-    )
+    );
+
+    minify_test(
+      "@charset \"ISO-8859-1\"; @import url(foo.css); .foo { color: red }",
+      "@charset \"ISO-8859-1\";@import \"foo.css\";.foo{color:red}",
+    );
+    minify_test(".foo { color: red } @charset \"ISO-8859-1\";", ".foo{color:red}");
+
+    let warnings = Arc::new(RwLock::new(Vec::new()));
+    test_with_options(
+      r#"@charset "ISO-8859-1";
+      .foo {
+        color: red;
+      }
+
+      @charset "utf-8";
+      @charset "Shift_JIS";
+    "#,
+      indoc! { r#"
+      @charset "ISO-8859-1";
+
+      .foo {
+        color: red;
+      }
+    "#},
+      ParserOptions {
+        filename: "test.css".into(),
+        warnings: Some(warnings.clone()),
+        ..ParserOptions::default()
+      },
+    );
+    assert_eq!(
+      *warnings.read().unwrap(),
+      vec![
+        Error {
+          kind: ParserError::UnsupportedCharset("ISO-8859-1".into()),
+          loc: Some(ErrorLocation {
+            filename: "test.css".into(),
+            line: 0,
+            column: 10
+          })
+        },
+        Error {
+          kind: ParserError::UnsupportedCharset("Shift_JIS".into()),
+          loc: Some(ErrorLocation {
+            filename: "test.css".into(),
+            line: 6,
+            column: 16
+          })
+        },
+      ]
+    );
   }
 
   #[test]
@@ -25526,7 +28864,7 @@ mod tests {
         "circles" => "EgL3uq_circles" referenced: true,
         "fade" => "EgL3uq_fade"
       },
-      HashMap::new(),
+      IndexMap::new(),
       Default::default(),
       false,
     );
@@ -25534,89 +28872,192 @@ mod tests {
     css_modules_test(
       r#"
       .foo {
-        color: red;
+        -webkit-animation-name: spin, spin;
+        animation-name: spin, "spin";
+        animation-duration: 1s, 2s;
       }
 
-      #id {
-        animation: 2s test;
+      .bar {
+        animation: spin 1s, "fade" 2s;
       }
 
-      @keyframes test {
-        from { color: red }
-        to { color: yellow }
+      @-webkit-keyframes spin {
+        from { opacity: 0 }
+      }
+
+      @keyframes spin {
+        from { opacity: 0 }
+      }
+
+      @keyframes fade {
+        from { opacity: 0 }
       }
     "#,
       indoc! {r#"
       .EgL3uq_foo {
-        color: red;
+        -webkit-animation-name: EgL3uq_spin, EgL3uq_spin;
+        animation-name: EgL3uq_spin, EgL3uq_spin;
+        animation-duration: 1s, 2s;
       }
 
-      #EgL3uq_id {
-        animation: 2s test;
+      .EgL3uq_bar {
+        animation: 1s EgL3uq_spin, 2s EgL3uq_fade;
       }
 
-      @keyframes test {
+      @-webkit-keyframes EgL3uq_spin {
         from {
-          color: red;
+          opacity: 0;
         }
+      }
 
-        to {
-          color: #ff0;
+      @keyframes EgL3uq_spin {
+        from {
+          opacity: 0;
+        }
+      }
+
+      @keyframes EgL3uq_fade {
+        from {
+          opacity: 0;
         }
       }
     "#},
       map! {
         "foo" => "EgL3uq_foo",
-        "id" => "EgL3uq_id"
-      },
-      HashMap::new(),
-      crate::css_modules::Config {
-        animation: false,
-        // custom_idents: false,
-        ..Default::default()
+        "bar" => "EgL3uq_bar",
+        "spin" => "EgL3uq_spin" referenced: true,
+        "fade" => "EgL3uq_fade" referenced: true
       },
+      IndexMap::new(),
+      Default::default(),
       false,
     );
 
     css_modules_test(
       r#"
-      @counter-style circles {
-        symbols: Ⓐ Ⓑ Ⓒ;
-      }
-
-      ul {
-        list-style: circles;
+      .foo {
+        animation-name: "my-anim", "none";
       }
 
-      ol {
-        list-style-type: none;
+      .bar {
+        animation: "my-anim" 1s;
       }
 
-      li {
-        list-style-type: disc;
+      @keyframes "my-anim" {
+        from { opacity: 0 }
       }
     "#,
       indoc! {r#"
-      @counter-style circles {
-        symbols: Ⓐ Ⓑ Ⓒ;
+      .EgL3uq_foo {
+        animation-name: EgL3uq_my-anim, "none";
       }
 
-      ul {
-        list-style: circles;
+      .EgL3uq_bar {
+        animation: 1s EgL3uq_my-anim;
       }
 
-      ol {
-        list-style-type: none;
+      @keyframes EgL3uq_my-anim {
+        from {
+          opacity: 0;
+        }
       }
-
-      li {
+    "#},
+      map! {
+        "foo" => "EgL3uq_foo",
+        "bar" => "EgL3uq_bar",
+        "my-anim" => "EgL3uq_my-anim" referenced: true
+      },
+      IndexMap::new(),
+      Default::default(),
+      false,
+    );
+
+    css_modules_test(
+      r#"
+      .foo {
+        color: red;
+      }
+
+      #id {
+        animation: 2s test;
+      }
+
+      @keyframes test {
+        from { color: red }
+        to { color: yellow }
+      }
+    "#,
+      indoc! {r#"
+      .EgL3uq_foo {
+        color: red;
+      }
+
+      #EgL3uq_id {
+        animation: 2s test;
+      }
+
+      @keyframes test {
+        from {
+          color: red;
+        }
+
+        to {
+          color: #ff0;
+        }
+      }
+    "#},
+      map! {
+        "foo" => "EgL3uq_foo",
+        "id" => "EgL3uq_id"
+      },
+      IndexMap::new(),
+      crate::css_modules::Config {
+        animation: false,
+        // custom_idents: false,
+        ..Default::default()
+      },
+      false,
+    );
+
+    css_modules_test(
+      r#"
+      @counter-style circles {
+        symbols: Ⓐ Ⓑ Ⓒ;
+      }
+
+      ul {
+        list-style: circles;
+      }
+
+      ol {
+        list-style-type: none;
+      }
+
+      li {
+        list-style-type: disc;
+      }
+    "#,
+      indoc! {r#"
+      @counter-style circles {
+        symbols: Ⓐ Ⓑ Ⓒ;
+      }
+
+      ul {
+        list-style: circles;
+      }
+
+      ol {
+        list-style-type: none;
+      }
+
+      li {
         list-style-type: disc;
       }
     "#},
       map! {
         "circles" => "EgL3uq_circles" referenced: true
       },
-      HashMap::new(),
+      IndexMap::new(),
       crate::css_modules::Config {
         custom_idents: false,
         ..Default::default()
@@ -25663,7 +29104,7 @@ mod tests {
         "a" => "EgL3uq_a",
         "b" => "EgL3uq_b"
       },
-      HashMap::new(),
+      IndexMap::new(),
       Default::default(),
       false,
     );
@@ -25701,7 +29142,7 @@ mod tests {
         "grid" => "EgL3uq_grid",
         "bar" => "EgL3uq_bar"
       },
-      HashMap::new(),
+      IndexMap::new(),
       Default::default(),
       false,
     );
@@ -25738,7 +29179,7 @@ mod tests {
         "grid" => "EgL3uq_grid",
         "bar" => "EgL3uq_bar"
       },
-      HashMap::new(),
+      IndexMap::new(),
       crate::css_modules::Config {
         grid: false,
         ..Default::default()
@@ -25758,7 +29199,7 @@ mod tests {
       }
     "#},
       map! {},
-      HashMap::new(),
+      IndexMap::new(),
       Default::default(),
       false,
     );
@@ -25793,7 +29234,7 @@ mod tests {
       map! {
         "bar" => "EgL3uq_bar"
       },
-      HashMap::new(),
+      IndexMap::new(),
       Default::default(),
       false,
     );
@@ -25826,7 +29267,7 @@ mod tests {
         "test" => "EgL3uq_test" "EgL3uq_foo",
         "foo" => "EgL3uq_foo"
       },
-      HashMap::new(),
+      IndexMap::new(),
       Default::default(),
       false,
     );
@@ -25856,7 +29297,7 @@ mod tests {
         "b" => "EgL3uq_b" "EgL3uq_foo",
         "foo" => "EgL3uq_foo"
       },
-      HashMap::new(),
+      IndexMap::new(),
       Default::default(),
       false,
     );
@@ -25894,7 +29335,7 @@ mod tests {
         "foo" => "EgL3uq_foo",
         "bar" => "EgL3uq_bar"
       },
-      HashMap::new(),
+      IndexMap::new(),
       Default::default(),
       false,
     );
@@ -25914,7 +29355,7 @@ mod tests {
       map! {
         "test" => "EgL3uq_test" "foo" global: true
       },
-      HashMap::new(),
+      IndexMap::new(),
       Default::default(),
       false,
     );
@@ -25934,7 +29375,7 @@ mod tests {
       map! {
         "test" => "EgL3uq_test" "foo" global: true "bar" global: true
       },
-      HashMap::new(),
+      IndexMap::new(),
       Default::default(),
       false,
     );
@@ -25954,7 +29395,7 @@ mod tests {
       map! {
         "test" => "EgL3uq_test" "foo" from "foo.css"
       },
-      HashMap::new(),
+      IndexMap::new(),
       Default::default(),
       false,
     );
@@ -25974,7 +29415,7 @@ mod tests {
       map! {
         "test" => "EgL3uq_test" "foo" from "foo.css" "bar" from "foo.css"
       },
-      HashMap::new(),
+      IndexMap::new(),
       Default::default(),
       false,
     );
@@ -26005,7 +29446,7 @@ mod tests {
         "test" => "EgL3uq_test" "EgL3uq_foo" "foo" from "foo.css" "bar" from "bar.css",
         "foo" => "EgL3uq_foo"
       },
-      HashMap::new(),
+      IndexMap::new(),
       Default::default(),
       false,
     );
@@ -26024,7 +29465,7 @@ mod tests {
       map! {
         "foo" => "test-EgL3uq-foo"
       },
-      HashMap::new(),
+      IndexMap::new(),
       crate::css_modules::Config {
         pattern: crate::css_modules::Pattern::parse("test-[hash]-[local]").unwrap(),
         ..Default::default()
@@ -26089,7 +29530,7 @@ mod tests {
       map! {
         "foo" => "EgL3uq_foo"
       },
-      HashMap::new(),
+      IndexMap::new(),
       Default::default(),
       false,
     );
@@ -26150,7 +29591,7 @@ mod tests {
         "bar" => "EgL3uq_bar",
         "--Cooler" => "--EgL3uq_Cooler" referenced: true
       },
-      HashMap::from([(
+      IndexMap::from([(
         "--ma1CsG".into(),
         CssModuleReference::Dependency {
           name: "--color".into(),
@@ -26179,7 +29620,7 @@ mod tests {
         "test" => "EgL3uq_test",
         "rotate" => "EgL3uq_rotate" referenced: true
       },
-      HashMap::new(),
+      IndexMap::new(),
       Default::default(),
       false,
     );
@@ -26197,7 +29638,7 @@ mod tests {
       map! {
         "test" => "EgL3uq_test"
       },
-      HashMap::new(),
+      IndexMap::new(),
       Default::default(),
       false,
     );
@@ -26215,7 +29656,7 @@ mod tests {
       map! {
         "test" => "EgL3uq_test"
       },
-      HashMap::new(),
+      IndexMap::new(),
       Default::default(),
       false,
     );
@@ -26233,7 +29674,7 @@ mod tests {
       map! {
         "test" => "EgL3uq_test"
       },
-      HashMap::new(),
+      IndexMap::new(),
       crate::css_modules::Config {
         animation: false,
         ..Default::default()
@@ -26255,7 +29696,7 @@ mod tests {
         "test" => "EgL3uq_test",
         "rotate" => "EgL3uq_rotate" referenced: true
       },
-      HashMap::new(),
+      IndexMap::new(),
       crate::css_modules::Config { ..Default::default() },
       false,
     );
@@ -26275,7 +29716,7 @@ mod tests {
       map! {
         "test" => "_5h2kwG-test" "foo" from "foo.css" "bar" from "foo.css"
       },
-      HashMap::new(),
+      IndexMap::new(),
       crate::css_modules::Config {
         pattern: crate::css_modules::Pattern::parse("[content-hash]-[local]").unwrap(),
         ..Default::default()
@@ -26302,7 +29743,7 @@ mod tests {
         "main" => "EgL3uq_main",
         "box2" => "EgL3uq_box2"
       },
-      HashMap::new(),
+      IndexMap::new(),
       crate::css_modules::Config { ..Default::default() },
       false,
     );
@@ -26325,7 +29766,7 @@ mod tests {
       map! {
         "box2" => "EgL3uq_box2"
       },
-      HashMap::new(),
+      IndexMap::new(),
       crate::css_modules::Config {
         container: false,
         ..Default::default()
@@ -26340,7 +29781,7 @@ mod tests {
         "foo" => "EgL3uq_foo",
         "bar" => "EgL3uq_bar"
       },
-      HashMap::new(),
+      IndexMap::new(),
       Default::default(),
       true,
     );
@@ -26350,7 +29791,7 @@ mod tests {
       map! {
         "foo" => "EgL3uq_foo"
       },
-      HashMap::new(),
+      IndexMap::new(),
       Default::default(),
       true,
     );
@@ -26360,7 +29801,7 @@ mod tests {
       map! {
         "foo" => "EgL3uq_foo"
       },
-      HashMap::new(),
+      IndexMap::new(),
       Default::default(),
       true,
     );
@@ -26374,7 +29815,7 @@ mod tests {
         "baz" => "EgL3uq_baz",
         "qux" => "EgL3uq_qux"
       },
-      HashMap::new(),
+      IndexMap::new(),
       Default::default(),
       true,
     );
@@ -26385,7 +29826,7 @@ mod tests {
       map! {
         "foo" => "EgL3uq_foo"
       },
-      HashMap::new(),
+      IndexMap::new(),
       Default::default(),
       true,
     );
@@ -26396,7 +29837,7 @@ mod tests {
         "foo" => "EgL3uq_foo",
         "bar" => "EgL3uq_bar"
       },
-      HashMap::new(),
+      IndexMap::new(),
       Default::default(),
       true,
     );
@@ -26409,7 +29850,7 @@ mod tests {
         "bar" => "EgL3uq_bar",
         "baz" => "EgL3uq_baz"
       },
-      HashMap::new(),
+      IndexMap::new(),
       Default::default(),
       true,
     );
@@ -26421,7 +29862,7 @@ mod tests {
         "foo" => "EgL3uq_foo",
         "bar" => "EgL3uq_bar"
       },
-      HashMap::new(),
+      IndexMap::new(),
       Default::default(),
       true,
     );
@@ -26438,7 +29879,7 @@ mod tests {
         map! {
           "foo" => "EgL3uq_foo"
         },
-        HashMap::new(),
+        IndexMap::new(),
         Default::default(),
         true,
       );
@@ -26448,7 +29889,7 @@ mod tests {
         map! {
           "bar" => "EgL3uq_bar"
         },
-        HashMap::new(),
+        IndexMap::new(),
         Default::default(),
         true,
       );
@@ -26460,7 +29901,7 @@ mod tests {
           "bar" => "EgL3uq_bar",
           "baz" => "EgL3uq_baz"
         },
-        HashMap::new(),
+        IndexMap::new(),
         Default::default(),
         true,
       );
@@ -26471,7 +29912,7 @@ mod tests {
         map! {
           "foo" => "EgL3uq_foo"
         },
-        HashMap::new(),
+        IndexMap::new(),
         Default::default(),
         true,
       );
@@ -26481,7 +29922,7 @@ mod tests {
         map! {
           "foo" => "EgL3uq_foo"
         },
-        HashMap::new(),
+        IndexMap::new(),
         Default::default(),
         true,
       );
@@ -26577,6 +30018,35 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_css_modules_exports_order() {
+    let stylesheet = StyleSheet::parse(
+      r#"
+      .zebra { animation: spin 1s, fade 2s; }
+      .apple { color: var(--yellow); }
+      @keyframes fade {}
+      #middle { animation-name: alpha; }
+      @keyframes spin {}
+      @keyframes alpha {}
+      "#,
+      ParserOptions {
+        filename: "test.css".into(),
+        css_modules: Some(crate::css_modules::Config {
+          dashed_idents: true,
+          ..Default::default()
+        }),
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+
+    let exports = stylesheet.to_css(PrinterOptions::default()).unwrap().exports.unwrap();
+    assert_eq!(
+      exports.keys().collect::<Vec<_>>(),
+      ["zebra", "spin", "fade", "apple", "--yellow", "middle", "alpha"]
+    );
+  }
+
   #[test]
   fn test_pseudo_replacement() {
     let source = r#"
@@ -27023,6 +30493,42 @@ mod tests {
       ".foo { clip-path: circle(50px at 0 100px) border-box; }",
       ".foo{clip-path:circle(50px at 0 100px)}",
     );
+    minify_test(
+      ".foo { clip-path: inset(10px 10px 10px 10px) content-box; }",
+      ".foo{clip-path:inset(10px) content-box}",
+    );
+    minify_test(
+      ".foo { clip-path: shape(from 0 0, line to 100% 0, vline by 50%, close); }",
+      ".foo{clip-path:shape(from 0 0,line to 100% 0,vline by 50%,close)}",
+    );
+    minify_test(
+      ".foo { clip-path: shape(nonzero from 10px 10px, move to 20px 20px, hline to 50%, line by 5% 10px) padding-box; }",
+      ".foo{clip-path:shape(from 10px 10px,move to 20px 20px,hline to 50%,line by 5% 10px) padding-box}",
+    );
+    minify_test(
+      ".foo { clip-path: margin-box shape(evenodd from 0 0, curve to 10px 10px with 0 5px / 5px 10px, curve by 10px 0 with 5px -5px); }",
+      ".foo{clip-path:shape(evenodd from 0 0,curve to 10px 10px with 0 5px/5px 10px,curve by 10px 0 with 5px -5px) margin-box}",
+    );
+    minify_test(
+      ".foo { clip-path: shape(from 0 0, smooth to 10px 10px with 5px 5px, smooth by 10px 10px); }",
+      ".foo{clip-path:shape(from 0 0,smooth to 10px 10px with 5px 5px,smooth by 10px 10px)}",
+    );
+    minify_test(
+      ".foo { clip-path: shape(from 0 0, arc to 10px 10px of 5px 5px ccw small rotate 0deg); }",
+      ".foo{clip-path:shape(from 0 0,arc to 10px 10px of 5px)}",
+    );
+    minify_test(
+      ".foo { clip-path: shape(from 0 0, arc by 10px 10px rotate 45deg large of 5px 10% cw); }",
+      ".foo{clip-path:shape(from 0 0,arc by 10px 10px of 5px 10% cw large rotate 45deg)}",
+    );
+    minify_test(
+      ".foo { clip-path: shape(from 0 0, arc to 10px 10px); }",
+      ".foo{clip-path:shape(from 0 0, arc to 10px 10px)}",
+    );
+    minify_test(
+      ".foo { clip-path: shape(from 0 0); }",
+      ".foo{clip-path:shape(from 0 0)}",
+    );
 
     prefix_test(
       ".foo { clip-path: circle(50px); }",
@@ -27359,6 +30865,39 @@ mod tests {
       },
     );
 
+    minify_test(
+      r#"
+        .foo {
+          mask-image: url(a.svg);
+          mask-position: center;
+          mask-size: cover;
+          mask-repeat: no-repeat;
+          mask-clip: content-box;
+          mask-origin: padding-box;
+          mask-composite: subtract;
+          mask-mode: luminance;
+          mask-border: url(b.png) 30;
+        }
+      "#,
+      ".foo{mask:url(a.svg) 50%/cover no-repeat padding-box content-box subtract luminance;mask-border:url(b.png) 30}",
+    );
+
+    minify_test(
+      r#"
+        .foo {
+          mask-image: url(a.svg), url(b.svg);
+          mask-position: 0 0, center;
+          mask-size: auto;
+          mask-repeat: repeat, no-repeat;
+          mask-clip: border-box, border-box;
+          mask-origin: border-box, border-box;
+          mask-composite: add, add;
+          mask-mode: match-source, match-source;
+        }
+      "#,
+      ".foo{mask-image:url(a.svg),url(b.svg);mask-position:0 0,50%;mask-size:auto;mask-repeat:repeat,no-repeat;mask-clip:border-box,border-box;mask-origin:border-box,border-box;mask-composite:add,add;mask-mode:match-source,match-source}",
+    );
+
     prefix_test(
       r#"
         .foo {
@@ -27423,6 +30962,108 @@ mod tests {
       },
     );
 
+    prefix_test(
+      r#"
+        .foo {
+          -webkit-mask-composite: source-out;
+          mask-composite: subtract;
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          -webkit-mask-composite: source-out;
+          mask-composite: subtract;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+        .foo {
+          -webkit-mask-composite: source-out;
+          mask-composite: subtract;
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          mask-composite: subtract;
+        }
+    "#},
+      Browsers {
+        chrome: Some(120 << 16),
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+        .foo {
+          mask-composite: subtract;
+          -webkit-mask-composite: xor;
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          mask-composite: subtract;
+          -webkit-mask-composite: xor;
+        }
+    "#},
+      Browsers {
+        chrome: Some(120 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+        .foo {
+          -webkit-mask-source-type: alpha;
+          -webkit-mask-composite: xor;
+          mask: url(foo.svg);
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          mask: url("foo.svg");
+        }
+    "#},
+      Browsers {
+        chrome: Some(120 << 16),
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+        .foo {
+          mask-border-source: url(foo.png);
+          -webkit-mask-box-image-source: url(bar.png);
+          mask: linear-gradient(lab(40% 56.6 39), red);
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          -webkit-mask-box-image-source: url("foo.png");
+          mask-border-source: url("foo.png");
+          -webkit-mask: linear-gradient(#b32323, red);
+          mask: linear-gradient(#b32323, red);
+          -webkit-mask: linear-gradient(lab(40% 56.6 39), red);
+          mask: linear-gradient(lab(40% 56.6 39), red);
+          -webkit-mask-box-image-source: url("bar.png");
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
     prefix_test(
       r#"
         .foo {
@@ -27706,39 +31347,211 @@ mod tests {
   }
 
   #[test]
-  fn test_filter() {
+  fn test_path() {
+    use crate::values::path::PathData;
+
+    minify_test(".foo { d: none }", ".foo{d:none}");
     minify_test(
-      ".foo { filter: url('filters.svg#filter-id'); }",
-      ".foo{filter:url(filters.svg#filter-id)}",
+      ".foo { d: path('M 10 10 L 20 20 L 30 20 Z') }",
+      ".foo{d:path(\"M10 10 20 20H30Z\")}",
     );
-    minify_test(".foo { filter: blur(5px); }", ".foo{filter:blur(5px)}");
-    minify_test(".foo { filter: blur(0px); }", ".foo{filter:blur()}");
-    minify_test(".foo { filter: brightness(10%); }", ".foo{filter:brightness(10%)}");
-    minify_test(".foo { filter: brightness(100%); }", ".foo{filter:brightness()}");
     minify_test(
-      ".foo { filter: drop-shadow(16px 16px 20px yellow); }",
-      ".foo{filter:drop-shadow(16px 16px 20px #ff0)}",
+      ".foo { d: path('m10,10 l10,10 h10 v-5 z') }",
+      ".foo{d:path(\"M10 10 20 20H30V15Z\")}",
     );
     minify_test(
-      ".foo { filter: contrast(175%) brightness(3%); }",
-      ".foo{filter:contrast(175%)brightness(3%)}",
+      ".foo { d: path('M 100 100 C 100.5 100.5 101 101 102 102 S 103 103 104 104') }",
+      ".foo{d:path(\"M100 100c.5.5 1 1 2 2s1 1 2 2\")}",
     );
-    minify_test(".foo { filter: hue-rotate(0) }", ".foo{filter:hue-rotate()}");
-
-    prefix_test(
-      ".foo { filter: blur(5px) }",
-      indoc! { r#"
-        .foo {
-          -webkit-filter: blur(5px);
-          filter: blur(5px);
-        }
-      "#},
-      Browsers {
-        chrome: Some(20 << 16),
-        ..Browsers::default()
-      },
+    minify_test(
+      ".foo { d: path('M 0.5 0.5 L 0.25 0.75 L 1 1') }",
+      ".foo{d:path(\"M.5.5.25.75 1 1\")}",
     );
-
+    minify_test(
+      ".foo { d: path('M 0 0 A 10 10 0 0 1 20 20') }",
+      ".foo{d:path(\"M0 0A10 10 0 0 1 20 20\")}",
+    );
+    minify_test(
+      ".foo { d: path('M 100 100 Q 110 100 110 110 T 120 120 Z M 0 0') }",
+      ".foo{d:path(\"M100 100q10 0 10 10t10 10ZM0 0\")}",
+    );
+    minify_test(".foo { d: path('M1e2 1E-1 2-3') }", ".foo{d:path(\"M100 .1 2-3\")}");
+    minify_test(".foo { d: path('') }", ".foo{d:path(\"\")}");
+    // Coordinates are not rounded, and relative offsets resolve to exactly the same points.
+    minify_test(
+      ".foo { d: path('M 1234.5625 10 L 1300.25 10.5') }",
+      ".foo{d:path(\"M1234.5625 10l65.6875.5\")}",
+    );
+    minify_test(
+      ".foo { d: path('M 0.1 0.2 L 0.3 0.4 L 1.234567 7.654321') }",
+      ".foo{d:path(\"M.1.2.3.4 1.234567 7.654321\")}",
+    );
+    minify_test(
+      ".foo { d: path('M 1234.5678 10 L 1300.25 10.5') }",
+      ".foo{d:path(\"M1234.5678 10 1300.25 10.5\")}",
+    );
+    // Relative commands are only made absolute if the result is exact.
+    minify_test(
+      ".foo { d: path('m.1 .1 l.2 .2 l.4 .4 l.7 .7 l.3 .3') }",
+      ".foo{d:path(\"M.1.1l.2.2.4.4.7.7.3.3\")}",
+    );
+    minify_test(
+      ".foo { d: path('m10 10 l-10 -10 l.5 .25 h.25 v.25') }",
+      ".foo{d:path(\"M10 10 0 0 .5.25H.75V.5\")}",
+    );
+    test(
+      ".foo { d: path('M 1234.5625 10 L 1.234567 7.654321') }",
+      indoc! {r#"
+      .foo {
+        d: path("M 1234.5625 10 L 1.234567 7.654321");
+      }
+    "#},
+    );
+    test(
+      ".foo { d: path('m10,10 l10,10 h10 v-5 z') }",
+      indoc! {r#"
+      .foo {
+        d: path("m 10 10 l 10 10 h 10 v -5 Z");
+      }
+    "#},
+    );
+
+    // Invalid paths are preserved as-is.
+    minify_test(".foo { d: path('L 10 10') }", ".foo{d:path(\"L 10 10\")}");
+    minify_test(".foo { d: path('M 10') }", ".foo{d:path(\"M 10\")}");
+    minify_test(
+      ".foo { d: path('M 0 0 A 1 1 0 2 0 5 5') }",
+      ".foo{d:path(\"M 0 0 A 1 1 0 2 0 5 5\")}",
+    );
+
+    minify_test(
+      ".foo { clip-path: path('M 0 0 L 10 0 L 10 10 Z') }",
+      ".foo{clip-path:path(\"M0 0H10V10Z\")}",
+    );
+    minify_test(
+      ".foo { clip-path: path(evenodd, 'M 0 0 L 10 0 L 10 10 Z') }",
+      ".foo{clip-path:path(evenodd,\"M0 0H10V10Z\")}",
+    );
+    minify_test(
+      ".foo { clip-path: path(nonzero, 'M 0 0 L 10 0 L 10 10 Z') border-box }",
+      ".foo{clip-path:path(\"M0 0H10V10Z\")}",
+    );
+    minify_test(".foo { offset-path: none }", ".foo{offset-path:none}");
+    minify_test(".foo { offset-path: url(#path) }", ".foo{offset-path:url(#path)}");
+    minify_test(
+      ".foo { offset-path: path('M 0 0 L 100 100') }",
+      ".foo{offset-path:path(\"M0 0 100 100\")}",
+    );
+    minify_test(
+      ".foo { offset-path: circle(50px at 0 0) }",
+      ".foo{offset-path:circle(50px at 0 0)}",
+    );
+    minify_test(
+      ".foo { offset-path: content-box circle(50px at center) }",
+      ".foo{offset-path:circle(50px) content-box}",
+    );
+    minify_test(
+      ".foo { offset-path: inset(10px 10px) border-box }",
+      ".foo{offset-path:inset(10px)}",
+    );
+    minify_test(".foo { offset-path: view-box }", ".foo{offset-path:view-box}");
+
+    // Minified paths must resolve to the same absolute coordinates.
+    let paths = [
+      "M 10 10 L 20 20 L 30 20 Z",
+      "m 5.5 5.5 l 0.1 0.2 l 0.3 -0.7 h -2 v 0.25 z m 3 3 l 1 1",
+      "M 100 100 C 100.5 100.5 101 101 102 102 S 103 103 104 104",
+      "M 10 80 Q 52.5 10 95 80 T 180 80",
+      "M 6 10 A 6 4 10 1 0 14 10 a 6 4 10 0 1 -8 0",
+      "M 1000.25 -1000.75 l -999.5 1999 L 0.001 0.002 Z l 5 5",
+      "m .1 .1 l .2 .2 l .4 .4 l .7 .7 l .3 .3 h .1 v .1 z m 1.1 2.2 c .1 .2 .3 .4 .5 .6",
+    ];
+    for source in paths {
+      let path = PathData::parse_string(&format!("'{}'", source)).unwrap();
+      let minified = path
+        .to_css_string(PrinterOptions {
+          minify: true,
+          ..PrinterOptions::default()
+        })
+        .unwrap();
+      let reparsed = PathData::parse_string(&minified).unwrap();
+      assert_eq!(reparsed.to_absolute(), path.to_absolute(), "{}", minified);
+    }
+  }
+
+  #[test]
+  fn test_shape_outside() {
+    minify_test(".foo { shape-outside: none }", ".foo{shape-outside:none}");
+    minify_test(".foo { shape-outside: margin-box }", ".foo{shape-outside:margin-box}");
+    minify_test(".foo { shape-outside: content-box }", ".foo{shape-outside:content-box}");
+    minify_test(
+      ".foo { shape-outside: circle(50% at center) }",
+      ".foo{shape-outside:circle(50%)}",
+    );
+    minify_test(
+      ".foo { shape-outside: circle(50%) margin-box }",
+      ".foo{shape-outside:circle(50%)}",
+    );
+    minify_test(
+      ".foo { shape-outside: padding-box ellipse(10px 20px at left top) }",
+      ".foo{shape-outside:ellipse(10px 20px at 0 0) padding-box}",
+    );
+    minify_test(
+      ".foo { shape-outside: inset(5px 5px 5px 5px round 10px) border-box }",
+      ".foo{shape-outside:inset(5px round 10px) border-box}",
+    );
+    minify_test(
+      ".foo { shape-outside: polygon(0 0, 100% 0, 50% 100%) }",
+      ".foo{shape-outside:polygon(0 0,100% 0,50% 100%)}",
+    );
+    minify_test(
+      ".foo { shape-outside: shape(from 0 0, line to 100% 100%, close) content-box }",
+      ".foo{shape-outside:shape(from 0 0,line to 100% 100%,close) content-box}",
+    );
+    minify_test(
+      ".foo { shape-outside: url(foo.png) }",
+      ".foo{shape-outside:url(foo.png)}",
+    );
+    minify_test(
+      ".foo { shape-outside: linear-gradient(red, transparent) }",
+      ".foo{shape-outside:linear-gradient(red,#0000)}",
+    );
+  }
+
+  #[test]
+  fn test_filter() {
+    minify_test(
+      ".foo { filter: url('filters.svg#filter-id'); }",
+      ".foo{filter:url(filters.svg#filter-id)}",
+    );
+    minify_test(".foo { filter: blur(5px); }", ".foo{filter:blur(5px)}");
+    minify_test(".foo { filter: blur(0px); }", ".foo{filter:blur()}");
+    minify_test(".foo { filter: brightness(10%); }", ".foo{filter:brightness(10%)}");
+    minify_test(".foo { filter: brightness(100%); }", ".foo{filter:brightness()}");
+    minify_test(
+      ".foo { filter: drop-shadow(16px 16px 20px yellow); }",
+      ".foo{filter:drop-shadow(16px 16px 20px #ff0)}",
+    );
+    minify_test(
+      ".foo { filter: contrast(175%) brightness(3%); }",
+      ".foo{filter:contrast(175%)brightness(3%)}",
+    );
+    minify_test(".foo { filter: hue-rotate(0) }", ".foo{filter:hue-rotate()}");
+
+    prefix_test(
+      ".foo { filter: blur(5px) }",
+      indoc! { r#"
+        .foo {
+          -webkit-filter: blur(5px);
+          filter: blur(5px);
+        }
+      "#},
+      Browsers {
+        chrome: Some(20 << 16),
+        ..Browsers::default()
+      },
+    );
+
     prefix_test(
       ".foo { filter: blur(5px) }",
       indoc! { r#"
@@ -28008,6 +31821,16 @@ mod tests {
 
   #[test]
   fn test_custom_media() {
+    custom_media_test(
+      r#"
+      @import "foo.css" supports(display: grid) (--narrow);
+      @custom-media --narrow (max-width: 30em);
+      "#,
+      indoc! {r#"
+      @import "foo.css" supports(display: grid) (width <= 30em);
+      "#},
+    );
+
     custom_media_test(
       r#"
       @custom-media --modern (color), (hover);
@@ -28366,7 +32189,7 @@ mod tests {
       }
       "#,
       indoc! {r#"
-      @media screen and ((prefers-color-scheme: dark) or ((width < 300px))) {
+      @media screen and ((prefers-color-scheme: dark) or (width < 300px)) {
         .foo {
           order: 6;
         }
@@ -28663,13 +32486,13 @@ mod tests {
 
     dep_test(
       ".foo { background: image-set('./img12x.png', './img21x.png' 2x)}",
-      ".foo{background:image-set(\"hXFI8W\" 1x,\"5TkpBa\" 2x)}",
+      ".foo{background:image-set(\"hXFI8W\",\"5TkpBa\" 2x)}",
       vec![("./img12x.png", "hXFI8W"), ("./img21x.png", "5TkpBa")],
     );
 
     dep_test(
       ".foo { background: image-set(url(./img12x.png), url('./img21x.png') 2x)}",
-      ".foo{background:image-set(\"hXFI8W\" 1x,\"5TkpBa\" 2x)}",
+      ".foo{background:image-set(\"hXFI8W\",\"5TkpBa\" 2x)}",
       vec![("./img12x.png", "hXFI8W"), ("./img21x.png", "5TkpBa")],
     );
 
@@ -28703,6 +32526,41 @@ mod tests {
       vec![("foo.png", "Vwkwkq")],
     );
 
+    dep_test(
+      "@keyframes fade { from { background-image: url(a.png) } to { background-image: url(\"b.png\") } }",
+      "@keyframes fade{0%{background-image:url(\"F5r5lG\")}to{background-image:url(\"pjQwwG\")}}",
+      vec![("a.png", "F5r5lG"), ("b.png", "pjQwwG")],
+    );
+
+    dep_test(
+      "@font-face { font-family: Foo; src: url(foo.woff2) format(woff2) }",
+      "@font-face{font-family:Foo;src:url(\"dlUb5G\")format(woff2)}",
+      vec![("foo.woff2", "dlUb5G")],
+    );
+
+    let stylesheet = StyleSheet::parse(
+      "@keyframes fade {\n  to { background-image: url(a.png) }\n}",
+      ParserOptions {
+        filename: "test.css".into(),
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        analyze_dependencies: Some(Default::default()),
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    match &res.dependencies.unwrap()[0] {
+      Dependency::Url(dep) => {
+        assert_eq!(dep.loc.file_path, "test.css");
+        assert_eq!(dep.loc.start, crate::dependencies::Location { line: 2, column: 30 });
+        assert_eq!(dep.loc.end, crate::dependencies::Location { line: 2, column: 34 });
+      }
+      _ => unreachable!(),
+    }
+
     dep_error_test(
       ".foo { --test: url(\"foo.png\") }",
       PrinterErrorKind::AmbiguousUrlInCustomProperty { url: "foo.png".into() },
@@ -28739,6 +32597,165 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_to_css_with_writer() {
+    let source = ".foo { color: red }\n@media print { .bar { color: yellow } }";
+    let stylesheet = StyleSheet::parse(
+      source,
+      ParserOptions {
+        filename: "test.css".into(),
+        css_modules: Some(Default::default()),
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+    let expected = stylesheet.to_css(PrinterOptions::default()).unwrap();
+
+    // Output is appended to the existing contents of the writer.
+    let mut dest = String::from("/* header */\n");
+    let res = stylesheet.to_css_with_writer(&mut dest, PrinterOptions::default()).unwrap();
+    assert_eq!(dest, format!("/* header */\n{}", expected.code));
+    assert_eq!(res.code, "");
+    assert_eq!(res.exports, expected.exports);
+    assert_eq!(res.references, expected.references);
+  }
+
+  #[test]
+  fn test_printer_indent() {
+    let source = r#"
+      @media (width > 100px) {
+        .foo {
+          color: red;
+          grid-template-areas: "a b" "c d";
+        }
+      }
+    "#;
+
+    test_with_printer_options(
+      source,
+      "@media (width > 100px) {\n\t.foo {\n\t\tcolor: red;\n\t\tgrid-template-areas: \"a b\"\n\t\t                     \"c d\";\n\t}\n}\n",
+      PrinterOptions {
+        indent: Indent::Tab,
+        ..PrinterOptions::default()
+      },
+    );
+
+    test_with_printer_options(
+      source,
+      "@media (width > 100px) {\r\n    .foo {\r\n        color: red;\r\n        grid-template-areas: \"a b\"\r\n                             \"c d\";\r\n    }\r\n}\r\n",
+      PrinterOptions {
+        indent: Indent::Spaces(4),
+        line_ending: LineEnding::Crlf,
+        ..PrinterOptions::default()
+      },
+    );
+
+    test_with_printer_options(
+      source,
+      "@media (width>100px){.foo{color:red;grid-template-areas:\"a b\"\"c d\"}}",
+      PrinterOptions {
+        minify: true,
+        indent: Indent::Tab,
+        line_ending: LineEnding::Crlf,
+        ..PrinterOptions::default()
+      },
+    );
+
+    // increase_indent() uses the configured indentation style, while indent() and indent_by()
+    // count characters written as spaces, e.g. to align content with the previous line.
+    let mut dest = String::new();
+    let mut printer = crate::printer::Printer::new(
+      &mut dest,
+      PrinterOptions {
+        indent: Indent::Tab,
+        ..PrinterOptions::default()
+      },
+    );
+    printer.increase_indent();
+    printer.increase_indent();
+    printer.indent();
+    printer.indent_by(3);
+    printer.newline().unwrap();
+    printer.write_char('a').unwrap();
+    printer.dedent_by(3);
+    printer.decrease_indent();
+    printer.newline().unwrap();
+    printer.write_char('b').unwrap();
+    assert!(printer.is_nested());
+    printer.dedent();
+    printer.newline().unwrap();
+    printer.write_char('c').unwrap();
+    assert!(!printer.is_nested());
+    printer.decrease_indent();
+    printer.indent_by(2);
+    printer.indent_by(2);
+    assert!(printer.is_nested());
+    printer.dedent();
+    printer.dedent();
+    printer.newline().unwrap();
+    printer.write_char('d').unwrap();
+    assert_eq!(dest, "\n\t\t     a\n\t  b\n\tc\nd");
+
+    let source = r#"
+      @import "foo.css";
+      .foo { color: red; & .bar { color: green } }
+      @media (width > 100px) {
+        .foo { color: red }
+        .bar { color: green }
+      }
+      @keyframes spin {
+        from { transform: rotate(0deg) }
+        to { transform: rotate(360deg) }
+      }
+      .baz::placeholder { color: red }
+    "#;
+
+    let mut stylesheet = StyleSheet::parse(source, ParserOptions::default()).unwrap();
+    let targets = Browsers {
+      safari: Some(8 << 16),
+      ..Browsers::default()
+    }
+    .into();
+    stylesheet
+      .minify(MinifyOptions {
+        targets,
+        ..MinifyOptions::default()
+      })
+      .unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        minify: true,
+        newline_between_rules: true,
+        line_ending: LineEnding::Crlf,
+        targets,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(
+      res.code,
+      [
+        "@import \"foo.css\";",
+        ".foo{color:red}",
+        ".foo .bar{color:green}",
+        "@media not (max-width:100px){.foo{color:red}.bar{color:green}}",
+        "@-webkit-keyframes spin{0%{-webkit-transform:rotate(0);transform:rotate(0)}to{-webkit-transform:rotate(360deg);transform:rotate(360deg)}}",
+        "@keyframes spin{0%{-webkit-transform:rotate(0);transform:rotate(0)}to{-webkit-transform:rotate(360deg);transform:rotate(360deg)}}",
+        ".baz::-webkit-input-placeholder{color:red}",
+        ".baz::placeholder{color:red}",
+      ]
+      .join("\r\n")
+    );
+
+    let stylesheet = StyleSheet::parse("/*! a\nb */\n.foo { color: red }", ParserOptions::default()).unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        line_ending: LineEnding::Crlf,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(res.code, "/*! a\r\nb */\r\n.foo {\r\n  color: red;\r\n}\r\n");
+  }
+
   #[test]
   fn test_api() {
     let stylesheet = StyleSheet::parse(".foo:hover { color: red }", ParserOptions::default()).unwrap();
@@ -28771,6 +32788,35 @@ mod tests {
       "color: #f0f !important"
     );
 
+    let property = Property::parse_value_string(
+      PropertyId::Animation(VendorPrefix::None),
+      "spin 1s",
+      ParserOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(
+      property.value_to_css_string(PrinterOptions::default()).unwrap(),
+      "1s spin"
+    );
+    assert_eq!(
+      Property::parse_value_string(
+        "color".into(),
+        "red !important",
+        ParserOptions {
+          filename: "test.css".into(),
+          ..ParserOptions::default()
+        }
+      ),
+      Err(Error {
+        kind: ParserError::UnexpectedToken(Token::Delim('!')),
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 0,
+          column: 5
+        })
+      })
+    );
+
     let code = indoc! { r#"
       .foo {
         color: green;
@@ -28858,6 +32904,7 @@ mod tests {
   fn test_substitute_vars() {
     use crate::properties::custom::TokenList;
     use crate::traits::ParseWithOptions;
+    use std::collections::HashMap;
 
     fn test(property: Property, vars: HashMap<&str, &str>, expected: &str) {
       if let Property::Unparsed(unparsed) = property {
@@ -29360,24 +33407,140 @@ mod tests {
         }
     "#},
     );
-  }
 
-  #[test]
-  fn test_quoting_unquoting_urls() {
-    // Quotes remain double quotes when not minifying
-    test(
-      r#".foo {
-      background-image: url("0123abcd");
-    }"#,
-      r#".foo {
-  background-image: url("0123abcd");
-}
-"#,
-    );
+    prefix_test(
+      r#"
+      @property --color {
+        syntax: '<color>';
+        inherits: false;
+        initial-value: lab(40% 56.6 39);
+      }
+    "#,
+      indoc! {r#"
+        @property --color {
+          syntax: "<color>";
+          inherits: false;
+          initial-value: #b32323;
+        }
 
-    // Quotes removed when minifying
-    minify_test(
-      r#".foo {
+        @supports (color: color(display-p3 0 0 0)) {
+          @property --color {
+            syntax: "<color>";
+            inherits: false;
+            initial-value: color(display-p3 .643308 .192455 .167712);
+          }
+        }
+
+        @supports (color: lab(0% 0 0)) {
+          @property --color {
+            syntax: "<color>";
+            inherits: false;
+            initial-value: lab(40% 56.6 39);
+          }
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      @property --color {
+        syntax: '<color>';
+        inherits: false;
+        initial-value: lab(40% 56.6 39);
+      }
+    "#,
+      indoc! {r#"
+        @property --color {
+          syntax: "<color>";
+          inherits: false;
+          initial-value: lab(40% 56.6 39);
+        }
+    "#},
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      @property --colors {
+        syntax: '<color>#';
+        inherits: false;
+        initial-value: color(display-p3 0 1 0), red;
+      }
+    "#,
+      indoc! {r#"
+        @property --colors {
+          syntax: "<color>#";
+          inherits: false;
+          initial-value: #00f942, red;
+        }
+
+        @supports (color: color(display-p3 0 0 0)) {
+          @property --colors {
+            syntax: "<color>#";
+            inherits: false;
+            initial-value: color(display-p3 0 1 0), red;
+          }
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      @property --color {
+        syntax: '<color>';
+        inherits: false;
+        initial-value: lab(40% 56.6 39);
+      }
+
+      @property --color {
+        syntax: '<color>';
+        inherits: false;
+        initial-value: red;
+      }
+    "#,
+      indoc! {r#"
+        @property --color {
+          syntax: "<color>";
+          inherits: false;
+          initial-value: red;
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_quoting_unquoting_urls() {
+    // Quotes remain double quotes when not minifying
+    test(
+      r#".foo {
+      background-image: url("0123abcd");
+    }"#,
+      r#".foo {
+  background-image: url("0123abcd");
+}
+"#,
+    );
+
+    // Quotes removed when minifying
+    minify_test(
+      r#".foo {
       background-image: url("0123abcd");
     }"#,
       r#".foo{background-image:url(0123abcd)}"#,
@@ -29403,6 +33566,48 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_url_rewriter() {
+    let rewriter = |url: &str, source_index: u32| format!("../src{}/{}", source_index, url);
+    let mut stylesheet = StyleSheet::parse(
+      r#"
+      .foo {
+        background: url(img.png);
+        --custom: url("icon one.svg");
+      }
+    "#,
+      ParserOptions {
+        source_index: 2,
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+    stylesheet.minify(MinifyOptions::default()).unwrap();
+
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        url_rewriter: Some(&rewriter),
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(
+      res.code,
+      ".foo {\n  --custom: url(\"../src2/icon one.svg\");\n  background: url(\"../src2/img.png\");\n}\n"
+    );
+
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        minify: true,
+        url_rewriter: Some(&rewriter),
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(
+      res.code,
+      ".foo{--custom:url(\"../src2/icon one.svg\");background:url(../src2/img.png)}"
+    );
+  }
+
   #[test]
   fn test_zindex() {
     minify_test(".foo { z-index: 2 }", ".foo{z-index:2}");
@@ -29413,84 +33618,602 @@ mod tests {
   }
 
   #[test]
-  #[cfg(feature = "sourcemap")]
-  fn test_input_source_map() {
-    let source = r#".imported {
-      content: "yay, file support!";
-    }
+  #[cfg(feature = "sourcemap")]
+  fn test_input_source_map() {
+    let source = r#".imported {
+      content: "yay, file support!";
+    }
+
+    .selector {
+      margin: 1em;
+      background-color: #f60;
+    }
+
+    .selector .nested {
+      margin: 0.5em;
+    }
+
+    /*# sourceMappingURL=data:application/json;base64,ewoJInZlcnNpb24iOiAzLAoJInNvdXJjZVJvb3QiOiAicm9vdCIsCgkiZmlsZSI6ICJzdGRvdXQiLAoJInNvdXJjZXMiOiBbCgkJInN0ZGluIiwKCQkic2Fzcy9fdmFyaWFibGVzLnNjc3MiLAoJCSJzYXNzL19kZW1vLnNjc3MiCgldLAoJInNvdXJjZXNDb250ZW50IjogWwoJCSJAaW1wb3J0IFwiX3ZhcmlhYmxlc1wiO1xuQGltcG9ydCBcIl9kZW1vXCI7XG5cbi5zZWxlY3RvciB7XG4gIG1hcmdpbjogJHNpemU7XG4gIGJhY2tncm91bmQtY29sb3I6ICRicmFuZENvbG9yO1xuXG4gIC5uZXN0ZWQge1xuICAgIG1hcmdpbjogJHNpemUgLyAyO1xuICB9XG59IiwKCQkiJGJyYW5kQ29sb3I6ICNmNjA7XG4kc2l6ZTogMWVtOyIsCgkJIi5pbXBvcnRlZCB7XG4gIGNvbnRlbnQ6IFwieWF5LCBmaWxlIHN1cHBvcnQhXCI7XG59IgoJXSwKCSJtYXBwaW5ncyI6ICJBRUFBLFNBQVMsQ0FBQztFQUNSLE9BQU8sRUFBRSxvQkFBcUI7Q0FDL0I7O0FGQ0QsU0FBUyxDQUFDO0VBQ1IsTUFBTSxFQ0hELEdBQUc7RURJUixnQkFBZ0IsRUNMTCxJQUFJO0NEVWhCOztBQVBELFNBQVMsQ0FJUCxPQUFPLENBQUM7RUFDTixNQUFNLEVDUEgsS0FBRztDRFFQIiwKCSJuYW1lcyI6IFtdCn0= */"#;
+
+    let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+    stylesheet.minify(MinifyOptions::default()).unwrap();
+    let mut sm = parcel_sourcemap::SourceMap::new("/");
+    stylesheet
+      .to_css(PrinterOptions {
+        source_map: Some(&mut sm),
+        minify: true,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    let map = sm.to_json(None).unwrap();
+    assert_eq!(
+      map,
+      r#"{"version":3,"sourceRoot":null,"mappings":"AAAA,uCCGA,2CAAA","sources":["sass/_demo.scss","stdin"],"sourcesContent":[".imported {\n  content: \"yay, file support!\";\n}","@import \"_variables\";\n@import \"_demo\";\n\n.selector {\n  margin: $size;\n  background-color: $brandColor;\n\n  .nested {\n    margin: $size / 2;\n  }\n}"],"names":[]}"#
+    );
+  }
+
+  #[test]
+  #[cfg(feature = "sourcemap")]
+  fn test_source_maps_with_license_comments() {
+    let source = r#"/*! a single line comment */
+    /*!
+      a comment
+      containing
+      multiple
+      lines
+    */
+    .a {
+      display: flex;
+    }
+
+    .b {
+      display: hidden;
+    }
+    "#;
+
+    let mut sm = parcel_sourcemap::SourceMap::new("/");
+    let source_index = sm.add_source("input.css");
+    sm.set_source_content(source_index as usize, source).unwrap();
+
+    let mut stylesheet = StyleSheet::parse(
+      &source,
+      ParserOptions {
+        source_index,
+        ..Default::default()
+      },
+    )
+    .unwrap();
+    stylesheet.minify(MinifyOptions::default()).unwrap();
+    stylesheet
+      .to_css(PrinterOptions {
+        source_map: Some(&mut sm),
+        minify: true,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    let map = sm.to_json(None).unwrap();
+    assert_eq!(
+      map,
+      r#"{"version":3,"sourceRoot":null,"mappings":";;;;;;;AAOI,gBAIA","sources":["input.css"],"sourcesContent":["/*! a single line comment */\n    /*!\n      a comment\n      containing\n      multiple\n      lines\n    */\n    .a {\n      display: flex;\n    }\n\n    .b {\n      display: hidden;\n    }\n    "],"names":[]}"#
+    );
+  }
+
+  #[test]
+  #[cfg(feature = "sourcemap")]
+  fn test_source_map_embed_sources() {
+    let source = ".a {\n  color: red;\n}\n";
+    let mut stylesheet = StyleSheet::parse(
+      source,
+      ParserOptions {
+        filename: "input.css".into(),
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+    stylesheet.minify(MinifyOptions::default()).unwrap();
+
+    let mut sm = parcel_sourcemap::SourceMap::new("/");
+    let (_, map) = stylesheet
+      .to_css_with_source_map(PrinterOptions {
+        source_map: Some(&mut sm),
+        source_root: Some("https://example.com/src/".into()),
+        embed_sources: true,
+        minify: true,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(
+      map,
+      r#"{"version":3,"sourceRoot":"https://example.com/src/","mappings":"AAAA","sources":["input.css"],"sourcesContent":[".a {\n  color: red;\n}\n"],"names":[]}"#
+    );
+  }
+
+  #[test]
+  #[cfg(feature = "sourcemap")]
+  fn test_to_css_with_source_map() {
+    let stylesheet = StyleSheet::parse(
+      ".a {\n  color: red;\n}\n",
+      ParserOptions {
+        filename: "input.css".into(),
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+
+    let (css, map) = stylesheet
+      .to_css_with_source_map(PrinterOptions {
+        minify: true,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(css, ".a{color:red}");
+    assert_eq!(
+      map,
+      r#"{"version":3,"sourceRoot":null,"mappings":"AAAA","sources":["input.css"],"sourcesContent":[],"names":[]}"#
+    );
+
+    let (css, map) = stylesheet
+      .to_css_with_source_map(PrinterOptions {
+        minify: true,
+        append_source_map_comment: true,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(
+      css,
+      format!(
+        ".a{{color:red}}\n/*# sourceMappingURL=data:application/json;base64,{} */\n",
+        data_encoding::BASE64.encode(map.as_bytes())
+      )
+    );
+
+    let (css, _) = stylesheet
+      .to_css_with_source_map(PrinterOptions {
+        append_source_map_comment: true,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert!(css.starts_with(".a {\n  color: red;\n}\n/*# sourceMappingURL=data:application/json;base64,"));
+  }
+
+  #[test]
+  fn test_error_report() {
+    let source = ".foo {\r\n  color: red;\r\n}\n\n\t.bar > > .baz { color: red }\n";
+    let err = StyleSheet::parse(
+      source,
+      ParserOptions {
+        filename: "test.css".into(),
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap_err();
+    let report = err.report(source);
+    assert_eq!(
+      report.context,
+      Some(crate::error::ErrorContext {
+        line_text: "\t.bar > > .baz { color: red }".into(),
+        offset: 8,
+      })
+    );
+    assert_eq!(
+      report.to_string(),
+      format!("{}\n\t.bar > > .baz {{ color: red }}\n\t       ^--- here", err)
+    );
+
+    // Columns are counted in UTF-16 code units.
+    let source = ".😀 { color: red }\n.a { color: red; }}";
+    let err = StyleSheet::parse(source, ParserOptions::default()).unwrap_err();
+    assert_eq!(err.report(source).context.unwrap().line_text, ".a { color: red; }}");
+    let loc = ErrorLocation {
+      filename: String::new(),
+      line: 0,
+      column: 5,
+    };
+    assert_eq!(crate::error::ErrorContext::new(source, &loc).unwrap().offset, 3);
+    assert_eq!(
+      crate::error::ErrorContext::new(source, &ErrorLocation { line: 5, ..loc }),
+      None
+    );
+  }
+
+  #[test]
+  fn test_lint_vendor_prefixes() {
+    let stylesheet = StyleSheet::parse(
+      r#"
+      .foo {
+        -webkit-transform: none;
+        transform: none;
+        -webkit-box-orient: vertical;
+        -webkit-mask-image: url(foo.png);
+      }
+
+      @media print {
+        .bar {
+          -moz-user-select: none;
+          -webkit-user-select: none;
+        }
+      }
+    "#,
+      ParserOptions {
+        filename: "test.css".into(),
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+
+    let targets = Targets::from(Browsers {
+      chrome: Some(90 << 16),
+      firefox: Some(80 << 16),
+      safari: Some(14 << 16),
+      ..Browsers::default()
+    });
+    let warnings = stylesheet.lint_vendor_prefixes(targets);
+    assert_eq!(
+      warnings,
+      vec![
+        LintWarning {
+          property: "-webkit-transform".into(),
+          suggestion: "transform".into(),
+          loc: ErrorLocation {
+            filename: "test.css".into(),
+            line: 1,
+            column: 7,
+          },
+        },
+        LintWarning {
+          property: "-moz-user-select".into(),
+          suggestion: "user-select".into(),
+          loc: ErrorLocation {
+            filename: "test.css".into(),
+            line: 9,
+            column: 9,
+          },
+        },
+      ]
+    );
+    assert_eq!(
+      warnings[0].to_string(),
+      "-webkit-transform is not needed for the configured targets, use transform instead at test.css:1:7"
+    );
+
+    assert_eq!(stylesheet.lint_vendor_prefixes(Targets::default()), vec![]);
+  }
+
+  #[test]
+  fn test_collect_used_fonts() {
+    let stylesheet = StyleSheet::parse(
+      r#"
+      @font-face {
+        font-family: Inter;
+        font-weight: 100 900;
+        font-style: oblique 0deg 10deg;
+        src: local(Inter), url(inter.woff2) format("woff2"), url(inter.woff);
+      }
+
+      .foo {
+        font-family: Inter, "Helvetica Neue", sans-serif;
+      }
+
+      @media print {
+        @font-face {
+          font-family: "Print Serif";
+          font-weight: bold;
+          font-stretch: 75% 100%;
+          src: url(print.woff2);
+        }
+
+        .bar {
+          font: italic 12px/1.5 "Print Serif", serif;
+        }
+      }
+
+      .baz {
+        & .qux {
+          font-family: sans-serif;
+        }
+      }
+    "#,
+      ParserOptions::default(),
+    )
+    .unwrap();
+
+    let usage = stylesheet.collect_used_fonts();
+    let families = usage
+      .referenced_families
+      .iter()
+      .map(|family| family.to_css_string(PrinterOptions::default()).unwrap())
+      .collect::<Vec<_>>();
+    assert_eq!(
+      families,
+      vec!["Inter", "Helvetica Neue", "sans-serif", "Print Serif", "serif"]
+    );
+
+    assert_eq!(usage.declared_faces.len(), 2);
+    let inter = &usage.declared_faces[0];
+    assert_eq!(
+      inter.family.as_ref().unwrap().to_css_string(PrinterOptions::default()).unwrap(),
+      "Inter"
+    );
+    assert_eq!(
+      inter.weight.as_ref().unwrap().to_css_string(PrinterOptions::default()).unwrap(),
+      "100 900"
+    );
+    assert_eq!(
+      inter.style.as_ref().unwrap().to_css_string(PrinterOptions::default()).unwrap(),
+      "oblique 0deg 10deg"
+    );
+    assert_eq!(inter.stretch, None);
+    let urls = inter.sources.iter().map(|source| source.url.url.as_ref()).collect::<Vec<_>>();
+    assert_eq!(urls, vec!["inter.woff2", "inter.woff"]);
+
+    let print = &usage.declared_faces[1];
+    assert_eq!(
+      print.family.as_ref().unwrap().to_css_string(PrinterOptions::default()).unwrap(),
+      "Print Serif"
+    );
+    assert_eq!(
+      print.weight.as_ref().unwrap().to_css_string(PrinterOptions::default()).unwrap(),
+      "bold"
+    );
+    assert_eq!(print.style, None);
+    assert_eq!(
+      print
+        .stretch
+        .as_ref()
+        .unwrap()
+        .to_css_string(PrinterOptions::default())
+        .unwrap(),
+      "75% 100%"
+    );
+    assert_eq!(print.sources.len(), 1);
+    assert_eq!(print.sources[0].url.url.as_ref(), "print.woff2");
+  }
+
+  #[test]
+  fn test_extract_layer_order() {
+    let stylesheet = StyleSheet::parse(
+      r#"
+      @import "reset.css" layer(reset);
+      @import "anon.css" layer;
+      @layer base, components;
+
+      @layer components {
+        @layer buttons {
+          .btn { color: red }
+        }
+
+        @layer cards;
+      }
+
+      @media print {
+        @layer print {
+          .foo { color: black }
+        }
+      }
+
+      @layer {
+        .bar { color: green }
+      }
+
+      @layer base.typography, reset;
+
+      .baz {
+        @layer nested {
+          color: blue;
+        }
+      }
+    "#,
+      ParserOptions::default(),
+    )
+    .unwrap();
+
+    let order = stylesheet
+      .extract_layer_order()
+      .iter()
+      .map(|name| name.0.iter().map(|part| part.as_ref()).collect::<Vec<_>>().join("."))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      order,
+      vec![
+        "reset",
+        "<anonymous-1>",
+        "base.typography",
+        "base",
+        "components.buttons",
+        "components.cards",
+        "components",
+        "print",
+        "<anonymous-2>",
+        "nested",
+      ]
+    );
+
+    let stylesheet = StyleSheet::parse(".foo { color: red }", ParserOptions::default()).unwrap();
+    assert_eq!(stylesheet.extract_layer_order(), vec![]);
+  }
+
+  #[test]
+  fn test_minify_stats() {
+    let source = r#"
+      .foo {
+        -webkit-transition: opacity 200ms;
+        transition: opacity 200ms;
+        color: red;
+        color: green;
+      }
 
-    .selector {
-      margin: 1em;
-      background-color: #f60;
-    }
+      .bar {
+        animation-name: spin;
+        animation-duration: 1s;
+        animation-timing-function: ease;
+        animation-iteration-count: 1;
+        animation-direction: normal;
+        animation-play-state: running;
+        animation-delay: 0s;
+        animation-fill-mode: none;
+        animation-timeline: auto;
+        user-select: none;
+      }
 
-    .selector .nested {
-      margin: 0.5em;
-    }
+      .baz {
+        color: green;
+      }
 
-    /*# sourceMappingURL=data:application/json;base64,ewoJInZlcnNpb24iOiAzLAoJInNvdXJjZVJvb3QiOiAicm9vdCIsCgkiZmlsZSI6ICJzdGRvdXQiLAoJInNvdXJjZXMiOiBbCgkJInN0ZGluIiwKCQkic2Fzcy9fdmFyaWFibGVzLnNjc3MiLAoJCSJzYXNzL19kZW1vLnNjc3MiCgldLAoJInNvdXJjZXNDb250ZW50IjogWwoJCSJAaW1wb3J0IFwiX3ZhcmlhYmxlc1wiO1xuQGltcG9ydCBcIl9kZW1vXCI7XG5cbi5zZWxlY3RvciB7XG4gIG1hcmdpbjogJHNpemU7XG4gIGJhY2tncm91bmQtY29sb3I6ICRicmFuZENvbG9yO1xuXG4gIC5uZXN0ZWQge1xuICAgIG1hcmdpbjogJHNpemUgLyAyO1xuICB9XG59IiwKCQkiJGJyYW5kQ29sb3I6ICNmNjA7XG4kc2l6ZTogMWVtOyIsCgkJIi5pbXBvcnRlZCB7XG4gIGNvbnRlbnQ6IFwieWF5LCBmaWxlIHN1cHBvcnQhXCI7XG59IgoJXSwKCSJtYXBwaW5ncyI6ICJBRUFBLFNBQVMsQ0FBQztFQUNSLE9BQU8sRUFBRSxvQkFBcUI7Q0FDL0I7O0FGQ0QsU0FBUyxDQUFDO0VBQ1IsTUFBTSxFQ0hELEdBQUc7RURJUixnQkFBZ0IsRUNMTCxJQUFJO0NEVWhCOztBQVBELFNBQVMsQ0FJUCxPQUFPLENBQUM7RUFDTixNQUFNLEVDUEgsS0FBRztDRFFQIiwKCSJuYW1lcyI6IFtdCn0= */"#;
+      .empty {}
+    "#;
 
-    let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
-    stylesheet.minify(MinifyOptions::default()).unwrap();
-    let mut sm = parcel_sourcemap::SourceMap::new("/");
+    let stats = Arc::new(RwLock::new(MinifyStats::default()));
+    let mut stylesheet = StyleSheet::parse(source, ParserOptions::default()).unwrap();
     stylesheet
+      .minify(MinifyOptions {
+        targets: Browsers {
+          safari: Some(13 << 16),
+          ..Browsers::default()
+        }
+        .into(),
+        stats: Some(stats.clone()),
+        ..MinifyOptions::default()
+      })
+      .unwrap();
+
+    let stats = stats.read().unwrap().clone();
+    let bytes_after = stylesheet
       .to_css(PrinterOptions {
-        source_map: Some(&mut sm),
         minify: true,
+        targets: Browsers {
+          safari: Some(13 << 16),
+          ..Browsers::default()
+        }
+        .into(),
         ..PrinterOptions::default()
       })
-      .unwrap();
-    let map = sm.to_json(None).unwrap();
+      .unwrap()
+      .code
+      .len();
     assert_eq!(
-      map,
-      r#"{"version":3,"sourceRoot":null,"mappings":"AAAA,uCCGA,2CAAA","sources":["sass/_demo.scss","stdin"],"sourcesContent":[".imported {\n  content: \"yay, file support!\";\n}","@import \"_variables\";\n@import \"_demo\";\n\n.selector {\n  margin: $size;\n  background-color: $brandColor;\n\n  .nested {\n    margin: $size / 2;\n  }\n}"],"names":[]}"#
-    );
+      stats,
+      MinifyStats {
+        rules_removed: 1,
+        declarations_removed: 9,
+        shorthands_formed: 1,
+        prefixes_added: 1,
+        prefixes_removed: 1,
+        bytes_before: stats.bytes_before,
+        bytes_after,
+      }
+    );
+    assert!(stats.bytes_before > stats.bytes_after);
   }
 
   #[test]
-  #[cfg(feature = "sourcemap")]
-  fn test_source_maps_with_license_comments() {
-    let source = r#"/*! a single line comment */
-    /*!
-      a comment
-      containing
-      multiple
-      lines
-    */
-    .a {
-      display: flex;
+  fn test_diff() {
+    fn diff_test(old: &str, new: &str, expected: Vec<&str>) {
+      let old = StyleSheet::parse(old, ParserOptions::default()).unwrap();
+      let new = StyleSheet::parse(new, ParserOptions::default()).unwrap();
+      let changes = old.diff(&new).unwrap();
+      assert_eq!(changes.iter().map(|c| c.to_string()).collect::<Vec<_>>(), expected);
     }
 
-    .b {
-      display: hidden;
-    }
-    "#;
+    diff_test(
+      ".spin { animation-duration: 1s; color: red }",
+      ".spin { animation-duration: 2s; color: red }",
+      vec!["changed `animation-duration` from `1s` to `2s` in `.spin`"],
+    );
+    diff_test(
+      ".foo { color: red; width: 10px } .bar { color: red }",
+      ".foo { color: red; height: 10px } .baz { color: red }",
+      vec![
+        "removed rule in `.bar`",
+        "removed `width: 10px` in `.foo`",
+        "added `height: 10px` in `.foo`",
+        "added rule in `.baz`",
+      ],
+    );
+    diff_test(
+      ".foo { color: red } .foo { color: green !important }",
+      ".foo { color: red } .foo { color: green }",
+      vec!["changed `color` from `green !important` to `green` in `.foo`"],
+    );
+    diff_test(
+      ".foo { display: -webkit-box; display: flex }",
+      ".foo { display: flex }",
+      vec![
+        "removed `display: flex` in `.foo`",
+        "changed `display` from `-webkit-box` to `flex` in `.foo`",
+      ],
+    );
+    diff_test(
+      "@media (min-width: 500px) { .foo { color: red; .bar { color: red } } }",
+      "@media (min-width: 500px) { .foo { color: red; .bar { color: blue } } }",
+      vec!["changed `color` from `red` to `#00f` in `& .bar` in `.foo` in `@media (width >= 500px)`"],
+    );
+    diff_test(
+      "@keyframes spin { from { rotate: 0deg } to { rotate: 360deg } }",
+      "@keyframes spin { from { rotate: 0deg } 50% { opacity: 1 } to { rotate: 180deg } }",
+      vec![
+        "added rule in `50%` in `@keyframes spin`",
+        "changed `rotate` from `360deg` to `180deg` in `to` in `@keyframes spin`",
+      ],
+    );
+    diff_test(
+      "@import 'a.css'; @font-face { font-family: Foo; src: url(foo.woff) }",
+      "@font-face { font-family: Foo; src: url(foo.woff2) }",
+      vec![
+        "removed rule in `@import \"a.css\";`",
+        "removed rule in `@font-face{font-family:Foo;src:url(foo.woff)}`",
+        "added rule in `@font-face{font-family:Foo;src:url(foo.woff2)}`",
+      ],
+    );
+    diff_test(
+      "@page { size: A4; margin: 1in }",
+      "@page { size: letter; margin: 1in }",
+      vec!["changed `size` from `a4` to `letter` in `@page`"],
+    );
+    diff_test(".foo { color: red }", ".foo { color: red }", vec![]);
+
+    let old = StyleSheet::parse(".foo { color: red }", ParserOptions::default()).unwrap();
+    let new = StyleSheet::parse("\n\n.foo { color: blue }", ParserOptions::default()).unwrap();
+    let changes = old.diff(&new).unwrap();
+    assert_eq!(changes[0].rule, vec![".foo".to_string()]);
+    assert_eq!(changes[0].loc.line, 2);
+    assert_eq!(
+      changes[0].kind,
+      crate::diff::ChangeKind::DeclarationChanged {
+        property: "color".into(),
+        old_value: "red".into(),
+        new_value: "#00f".into()
+      }
+    );
+  }
 
-    let mut sm = parcel_sourcemap::SourceMap::new("/");
-    let source_index = sm.add_source("input.css");
-    sm.set_source_content(source_index as usize, source).unwrap();
+  #[test]
+  fn test_from_bytes() {
+    let stylesheet = StyleSheet::from_bytes(b"\xEF\xBB\xBF.foo { color: red }", ParserOptions::default()).unwrap();
+    let res = stylesheet.to_css(PrinterOptions::default()).unwrap();
+    assert_eq!(res.code, ".foo {\n  color: red;\n}\n");
 
-    let mut stylesheet = StyleSheet::parse(
-      &source,
+    let stylesheet = StyleSheet::from_bytes(".😀 { color: red }".as_bytes(), ParserOptions::default()).unwrap();
+    let res = stylesheet.to_css(PrinterOptions::default()).unwrap();
+    assert_eq!(res.code, ".😀 {\n  color: red;\n}\n");
+
+    let err = StyleSheet::from_bytes(
+      b".foo {\r\n  content: \"\xF0\x9F\x98\x80\xFF\";\n}",
       ParserOptions {
-        source_index,
-        ..Default::default()
+        filename: "test.css".into(),
+        ..ParserOptions::default()
       },
     )
-    .unwrap();
-    stylesheet.minify(MinifyOptions::default()).unwrap();
-    stylesheet
-      .to_css(PrinterOptions {
-        source_map: Some(&mut sm),
-        minify: true,
-        ..PrinterOptions::default()
-      })
-      .unwrap();
-    let map = sm.to_json(None).unwrap();
+    .unwrap_err();
+    assert_eq!(err.kind, ParserError::InvalidUtf8);
     assert_eq!(
-      map,
-      r#"{"version":3,"sourceRoot":null,"mappings":";;;;;;;AAOI,gBAIA","sources":["input.css"],"sourcesContent":["/*! a single line comment */\n    /*!\n      a comment\n      containing\n      multiple\n      lines\n    */\n    .a {\n      display: flex;\n    }\n\n    .b {\n      display: hidden;\n    }\n    "],"names":[]}"#
+      err.loc,
+      Some(ErrorLocation {
+        filename: "test.css".into(),
+        line: 1,
+        column: 15,
+      })
     );
+    assert_eq!(err.to_string(), "Invalid UTF-8 at test.css:1:15");
   }
 
   #[test]
@@ -29545,68 +34268,332 @@ mod tests {
         }
       }
 
-      input:placeholder {
+      input:placeholder {
+        color: red;
+      }
+
+      input::hover {
+        color: red;
+      }
+    "#},
+      ParserOptions {
+        filename: "test.css".into(),
+        error_recovery: true,
+        warnings: warnings.clone(),
+        ..ParserOptions::default()
+      },
+    );
+    let w = warnings.unwrap();
+    let warnings = w.read().unwrap();
+    assert_eq!(
+      *warnings,
+      vec![
+        Error {
+          kind: ParserError::SelectorError(SelectorError::EmptySelector),
+          loc: Some(ErrorLocation {
+            filename: "test.css".into(),
+            line: 1,
+            column: 7
+          })
+        },
+        Error {
+          kind: ParserError::UnexpectedToken(Token::Semicolon),
+          loc: Some(ErrorLocation {
+            filename: "test.css".into(),
+            line: 10,
+            column: 17
+          })
+        },
+        Error {
+          kind: ParserError::SelectorError(SelectorError::EmptySelector),
+          loc: Some(ErrorLocation {
+            filename: "test.css".into(),
+            line: 15,
+            column: 9
+          })
+        },
+        Error {
+          kind: ParserError::SelectorError(SelectorError::UnsupportedPseudoClass("placeholder".into())),
+          loc: Some(ErrorLocation {
+            filename: "test.css".into(),
+            line: 24,
+            column: 13,
+          }),
+        },
+        Error {
+          kind: ParserError::SelectorError(SelectorError::UnsupportedPseudoElement("hover".into())),
+          loc: Some(ErrorLocation {
+            filename: "test.css".into(),
+            line: 28,
+            column: 13,
+          }),
+        },
+      ]
+    )
+  }
+
+  #[test]
+  fn test_invalid_property_value_warnings() {
+    use std::sync::{Arc, RwLock};
+    let warnings = Arc::new(RwLock::new(Vec::new()));
+    test_with_options(
+      r#"
+      .foo {
+        color: 1px;
+        width: inherit;
+        height: var(--height);
+        margin: env(safe-area-inset-top);
+        unknown: 1px;
+        -webkit-box-shadow: 1px 2px 3px 4px 5px;
+        align-self: anchor-center;
+        grid-template-rows: masonry;
+        width: calc-size(auto, size);
+        height: 10foo;
+        border-top-color: foo bar;
+        outline-color: inherit;
+        border-left-color: -webkit-link;
+      }
+    "#,
+      indoc! { r#"
+      .foo {
+        color: 1px;
+        width: inherit;
+        height: var(--height);
+        margin: env(safe-area-inset-top);
+        unknown: 1px;
+        -webkit-box-shadow: 1px 2px 3px 4px 5px;
+        align-self: anchor-center;
+        grid-template-rows: masonry;
+        width: calc-size(auto, size);
+        height: 10foo;
+        border-top-color: foo bar;
+        outline-color: inherit;
+        border-left-color: -webkit-link;
+      }
+    "#},
+      ParserOptions {
+        filename: "test.css".into(),
+        warnings: Some(warnings.clone()),
+        ..ParserOptions::default()
+      },
+    );
+    let warnings = warnings.read().unwrap();
+    assert_eq!(
+      *warnings,
+      vec![
+        Error {
+          kind: ParserError::InvalidPropertyValue {
+            name: "color".into(),
+            property_id: PropertyId::Color,
+          },
+          loc: Some(ErrorLocation {
+            filename: "test.css".into(),
+            line: 2,
+            column: 16
+          }),
+        },
+        Error {
+          kind: ParserError::InvalidPropertyValue {
+            name: "-webkit-box-shadow".into(),
+            property_id: PropertyId::BoxShadow(VendorPrefix::WebKit),
+          },
+          loc: Some(ErrorLocation {
+            filename: "test.css".into(),
+            line: 7,
+            column: 29
+          }),
+        },
+        Error {
+          kind: ParserError::InvalidPropertyValue {
+            name: "border-top-color".into(),
+            property_id: PropertyId::BorderTopColor,
+          },
+          loc: Some(ErrorLocation {
+            filename: "test.css".into(),
+            line: 12,
+            column: 27
+          }),
+        },
+      ]
+    );
+    assert_eq!(
+      warnings[0].to_string(),
+      "Invalid value for the color property at test.css:2:16"
+    );
+
+    // Colors have a closed set of keywords, so unknown identifiers are invalid.
+    let warnings = Arc::new(RwLock::new(Vec::new()));
+    minify_test_with_options(
+      ".foo { color: foo }",
+      ".foo{color:foo}",
+      ParserOptions {
+        warnings: Some(warnings.clone()),
+        ..ParserOptions::default()
+      },
+    );
+    assert_eq!(
+      warnings.read().unwrap().iter().map(|w| w.kind.clone()).collect::<Vec<_>>(),
+      vec![ParserError::InvalidPropertyValue {
+        name: "color".into(),
+        property_id: PropertyId::Color,
+      }]
+    );
+  }
+
+  #[test]
+  fn test_disallowed() {
+    use std::sync::{Arc, RwLock};
+    let warnings = Arc::new(RwLock::new(Vec::new()));
+    let options = ParserOptions {
+      error_recovery: true,
+      warnings: Some(warnings.clone()),
+      disallowed_properties: ["animation".into(), "transition-duration".into()].into_iter().collect(),
+      disallowed_at_rules: ["import".into(), "keyframes".into()].into_iter().collect(),
+      ..ParserOptions::default()
+    };
+    test_with_options(
+      r#"
+      @import "foo.css";
+      .foo {
+        color: red;
+        animation: spin 1s;
+        -webkit-animation-name: spin;
+        transition-duration: 1s;
+        transition-delay: 1s;
+      }
+      @KEYFRAMES spin {
+        to { color: red }
+      }
+      @media print {
+        .foo { animation-duration: 1s }
+      }
+    "#,
+      indoc! { r#"
+      .foo {
+        color: red;
+        transition-delay: 1s;
+      }
+    "#},
+      options.clone(),
+    );
+    let warnings = warnings.read().unwrap();
+    assert_eq!(
+      warnings.iter().map(|w| w.kind.clone()).collect::<Vec<_>>(),
+      vec![
+        ParserError::DisallowedAtRule("import".into()),
+        ParserError::DisallowedProperty("animation".into()),
+        ParserError::DisallowedProperty("-webkit-animation-name".into()),
+        ParserError::DisallowedProperty("transition-duration".into()),
+        ParserError::DisallowedAtRule("KEYFRAMES".into()),
+        ParserError::DisallowedProperty("animation-duration".into()),
+      ]
+    );
+    assert_eq!(
+      warnings[1].to_string(),
+      "The animation property is not allowed at :4:20"
+    );
+
+    match StyleSheet::parse(
+      ".foo { animation: spin 1s }",
+      ParserOptions {
+        disallowed_properties: ["animation".into()].into_iter().collect(),
+        ..ParserOptions::default()
+      },
+    ) {
+      Err(e) => assert_eq!(e.kind, ParserError::DisallowedProperty("animation".into())),
+      Ok(_) => unreachable!(),
+    }
+  }
+
+  #[test]
+  fn test_strict_properties() {
+    use std::sync::{Arc, RwLock};
+    let warnings = Arc::new(RwLock::new(Vec::new()));
+    let options = ParserOptions {
+      error_recovery: true,
+      warnings: Some(warnings.clone()),
+      strict_properties: true,
+      ..ParserOptions::default()
+    };
+    test_with_options(
+      r#"
+      .foo {
         color: red;
+        colr: blue;
+        width: 1deg;
+        -webkit-foo: bar;
+        --foo: bar;
+        height: var(--foo);
+        align-self: anchor-center;
+        grid-template-rows: masonry;
+        width: calc-size(auto, size);
+        color: foo;
       }
-
-      input::hover {
+    "#,
+      indoc! { r#"
+      .foo {
         color: red;
+        -webkit-foo: bar;
+        --foo: bar;
+        height: var(--foo);
+        align-self: anchor-center;
+        grid-template-rows: masonry;
+        width: calc-size(auto, size);
       }
     "#},
-      ParserOptions {
-        filename: "test.css".into(),
-        error_recovery: true,
-        warnings: warnings.clone(),
-        ..ParserOptions::default()
-      },
+      options.clone(),
     );
-    let w = warnings.unwrap();
-    let warnings = w.read().unwrap();
+    let warnings = warnings.read().unwrap();
     assert_eq!(
-      *warnings,
+      warnings.iter().map(|w| w.kind.clone()).collect::<Vec<_>>(),
       vec![
-        Error {
-          kind: ParserError::SelectorError(SelectorError::EmptySelector),
-          loc: Some(ErrorLocation {
-            filename: "test.css".into(),
-            line: 1,
-            column: 7
-          })
-        },
-        Error {
-          kind: ParserError::UnexpectedToken(Token::Semicolon),
-          loc: Some(ErrorLocation {
-            filename: "test.css".into(),
-            line: 10,
-            column: 17
-          })
-        },
-        Error {
-          kind: ParserError::SelectorError(SelectorError::EmptySelector),
-          loc: Some(ErrorLocation {
-            filename: "test.css".into(),
-            line: 15,
-            column: 9
-          })
-        },
-        Error {
-          kind: ParserError::SelectorError(SelectorError::UnsupportedPseudoClass("placeholder".into())),
-          loc: Some(ErrorLocation {
-            filename: "test.css".into(),
-            line: 24,
-            column: 13,
-          }),
+        ParserError::UnknownProperty("colr".into()),
+        ParserError::InvalidPropertyValue {
+          name: "width".into(),
+          property_id: PropertyId::Width,
         },
-        Error {
-          kind: ParserError::SelectorError(SelectorError::UnsupportedPseudoElement("hover".into())),
-          loc: Some(ErrorLocation {
-            filename: "test.css".into(),
-            line: 28,
-            column: 13,
-          }),
+        ParserError::InvalidPropertyValue {
+          name: "color".into(),
+          property_id: PropertyId::Color,
         },
       ]
+    );
+    assert_eq!(warnings[0].to_string(), "Unknown property colr at :3:15");
+
+    match StyleSheet::parse(
+      ".foo { colr: blue }",
+      ParserOptions {
+        strict_properties: true,
+        ..ParserOptions::default()
+      },
+    ) {
+      Err(e) => assert_eq!(e.kind, ParserError::UnknownProperty("colr".into())),
+      Ok(_) => unreachable!(),
+    }
+    assert!(StyleSheet::parse(
+      ".foo { align-self: anchor-center }",
+      ParserOptions {
+        strict_properties: true,
+        ..ParserOptions::default()
+      },
     )
+    .is_ok());
+    match StyleSheet::parse(
+      ".foo { width: 1deg }",
+      ParserOptions {
+        strict_properties: true,
+        ..ParserOptions::default()
+      },
+    ) {
+      Err(e) => assert_eq!(
+        e.kind,
+        ParserError::InvalidPropertyValue {
+          name: "width".into(),
+          property_id: PropertyId::Width,
+        }
+      ),
+      Ok(_) => unreachable!(),
+    }
   }
 
   #[test]
@@ -30244,6 +35231,72 @@ mod tests {
       "@media (width<=env(unknown)){body{padding:env(unknown)}}",
     );
 
+    let options = ParserOptions {
+      environment_variables: [("safe-area-inset-top", "20px"), ("--branding-padding", "1px 2px")]
+        .into_iter()
+        .map(|(name, value)| (name.into(), value))
+        .collect(),
+      ..ParserOptions::default()
+    };
+    test_with_options(
+      r#"
+      @media (max-width: env(safe-area-inset-top)) {
+        .foo {
+          padding-top: env(safe-area-inset-top, 0px);
+          padding-bottom: env(safe-area-inset-bottom, 0px);
+          margin: env(--branding-padding);
+          margin-left: env(--branding-padding 2, 5px);
+          --inset: calc(env(safe-area-inset-top) + 1px);
+        }
+      }
+    "#,
+      indoc! {r#"
+      @media (width <= env(safe-area-inset-top)) {
+        .foo {
+          padding-top: 20px;
+          padding-bottom: env(safe-area-inset-bottom, 0px);
+          margin: 1px 2px;
+          margin-left: env(--branding-padding 2, 5px);
+          --inset: calc(20px + 1px);
+        }
+      }
+      "#},
+      options,
+    );
+
+    // Values that are entirely a resolved env() reference are fully parsed, so they can be minified.
+    let options = ParserOptions {
+      environment_variables: [("safe-area-inset-top", "20px"), ("--brand-color", "#ff0000")]
+        .into_iter()
+        .map(|(name, value)| (name.into(), value))
+        .collect(),
+      ..ParserOptions::default()
+    };
+    minify_test_with_options(
+      ".foo { padding-top: env(safe-area-inset-top); padding-right: 0; padding-bottom: 0; padding-left: 0; color: env(--brand-color, blue) }",
+      ".foo{color:red;padding:20px 0 0}",
+      options.clone(),
+    );
+    minify_test_with_options(
+      ".foo { width: calc(env(safe-area-inset-top) + 10px) }",
+      ".foo{width:calc(20px + 10px)}",
+      options,
+    );
+
+    // Environment variables referenced within the value of another variable are not substituted again.
+    let options = ParserOptions {
+      environment_variables: [("a", "env(a)"), ("b", "calc(env(b) + 1px)")]
+        .into_iter()
+        .map(|(name, value)| (name.into(), value))
+        .collect(),
+      ..ParserOptions::default()
+    };
+    minify_test_with_options(
+      ".x { width: env(a); height: calc(env(b) + 1px); --x: env(a) }",
+      ".x{width:env(a);height:calc(calc(env(b) + 1px) + 1px);--x:env(a)}",
+      options,
+    );
+
     prefix_test(
       r#"
       .foo {
@@ -30310,7 +35363,7 @@ mod tests {
         "--brand-color" => "--EgL3uq_brand-color" referenced: true,
         "--branding-small" => "--EgL3uq_branding-small" referenced: true
       },
-      HashMap::new(),
+      IndexMap::new(),
       crate::css_modules::Config {
         dashed_idents: true,
         ..Default::default()
@@ -30386,24 +35439,136 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_contain() {
+    minify_test(".foo { contain: none }", ".foo{contain:none}");
+    minify_test(".foo { contain: strict }", ".foo{contain:strict}");
+    minify_test(".foo { contain: content }", ".foo{contain:content}");
+    minify_test(".foo { contain: size }", ".foo{contain:size}");
+    minify_test(".foo { contain: paint layout }", ".foo{contain:layout paint}");
+    minify_test(".foo { contain: style inline-size }", ".foo{contain:inline-size style}");
+    minify_test(".foo { contain: layout style paint }", ".foo{contain:content}");
+    minify_test(".foo { contain: paint style layout }", ".foo{contain:content}");
+    minify_test(".foo { contain: size layout style paint }", ".foo{contain:strict}");
+    minify_test(
+      ".foo { contain: inline-size layout style paint }",
+      ".foo{contain:inline-size layout style paint}",
+    );
+    minify_test(".foo { contain: size inline-size }", ".foo{contain:size inline-size}");
+    minify_test(".foo { contain: layout layout }", ".foo{contain:layout layout}");
+    minify_test(".foo { contain: none layout }", ".foo{contain:none layout}");
+
+    minify_test(
+      ".foo { content-visibility: visible }",
+      ".foo{content-visibility:visible}",
+    );
+    minify_test(".foo { content-visibility: auto }", ".foo{content-visibility:auto}");
+    minify_test(".foo { content-visibility: hidden }", ".foo{content-visibility:hidden}");
+
+    minify_test(
+      ".foo { contain: strict; content-visibility: hidden }",
+      ".foo{contain:strict;content-visibility:hidden}",
+    );
+    minify_test(
+      ".foo { contain: layout paint; content-visibility: hidden }",
+      ".foo{contain:layout paint;content-visibility:hidden}",
+    );
+    minify_test(
+      ".foo { content-visibility: auto; contain: content }",
+      ".foo{contain:content;content-visibility:auto}",
+    );
+    minify_test(
+      ".foo { contain: size; content-visibility: auto }",
+      ".foo{contain:size;content-visibility:auto}",
+    );
+    minify_test(
+      ".foo { contain: none; content-visibility: hidden }",
+      ".foo{contain:none;content-visibility:hidden}",
+    );
+    minify_test(
+      ".foo { contain: strict; content-visibility: visible }",
+      ".foo{contain:strict;content-visibility:visible}",
+    );
+    minify_test(
+      ".foo { contain: strict; content-visibility: var(--visibility) }",
+      ".foo{contain:strict;content-visibility:var(--visibility)}",
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        contain: strict;
+        content-visibility: hidden;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        contain: strict;
+        content-visibility: hidden;
+      }
+    "#},
+      Browsers {
+        safari: Some(17 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        contain: strict;
+        content-visibility: hidden;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        contain: strict;
+        content-visibility: hidden;
+      }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
   #[test]
   fn test_color_scheme() {
     minify_test(".foo { color-scheme: normal; }", ".foo{color-scheme:normal}");
     minify_test(".foo { color-scheme: light; }", ".foo{color-scheme:light}");
     minify_test(".foo { color-scheme: dark; }", ".foo{color-scheme:dark}");
     minify_test(".foo { color-scheme: light dark; }", ".foo{color-scheme:light dark}");
-    minify_test(".foo { color-scheme: dark light; }", ".foo{color-scheme:light dark}");
+    minify_test(".foo { color-scheme: dark light; }", ".foo{color-scheme:dark light}");
     minify_test(".foo { color-scheme: only light; }", ".foo{color-scheme:light only}");
     minify_test(".foo { color-scheme: only dark; }", ".foo{color-scheme:dark only}");
     minify_test(
       ".foo { color-scheme: dark light only; }",
-      ".foo{color-scheme:light dark only}",
+      ".foo{color-scheme:dark light only}",
+    );
+    minify_test(
+      ".foo { color-scheme: only dark light; }",
+      ".foo{color-scheme:dark light only}",
+    );
+    minify_test(
+      ".foo { color-scheme: dark light dark; }",
+      ".foo{color-scheme:dark light}",
     );
+    minify_test(".foo { color-scheme: LIGHT Dark; }", ".foo{color-scheme:light dark}");
     minify_test(".foo { color-scheme: foo bar light; }", ".foo{color-scheme:light}");
     minify_test(
       ".foo { color-scheme: only foo dark bar; }",
       ".foo{color-scheme:dark only}",
     );
+    minify_test(".foo { color-scheme: only; }", ".foo{color-scheme:only}");
+    minify_test(".foo { color-scheme: only foo; }", ".foo{color-scheme:only foo}");
+    minify_test(
+      ".foo { color-scheme: light only dark; }",
+      ".foo{color-scheme:light only dark}",
+    );
+    minify_test(
+      ".foo { color-scheme: normal light; }",
+      ".foo{color-scheme:normal light}",
+    );
     prefix_test(
       ".foo { color-scheme: dark; }",
       indoc! { r#"
@@ -30711,6 +35876,123 @@ mod tests {
       "@layer { @view-transition { navigation: auto; types: foo bar; } }",
       "@layer{@view-transition{navigation:auto;types:foo bar}}",
     );
+    minify_test(
+      "@view-transition { navigation: none; types: slide forwards; }",
+      "@view-transition{navigation:none;types:slide forwards}",
+    );
+    test(
+      "@view-transition { navigation: auto; types: slide; }",
+      indoc! {r#"
+      @view-transition {
+        navigation: auto;
+        types: slide;
+      }
+    "#},
+    );
+    minify_test(
+      ".foo { view-transition-name: hero; view-transition-class: card wide }",
+      ".foo{view-transition-name:hero;view-transition-class:card wide}",
+    );
+
+    minify_test(
+      "@view-transition { navigation: auto; foo: bar }",
+      "@view-transition{navigation:auto}",
+    );
+    let warnings = Arc::new(RwLock::new(Vec::new()));
+    minify_test_with_options(
+      "@view-transition { navigation: auto; foo: bar; types: slide; }",
+      "@view-transition{navigation:auto;types:slide}",
+      ParserOptions {
+        filename: "test.css".into(),
+        warnings: Some(warnings.clone()),
+        ..ParserOptions::default()
+      },
+    );
+    assert_eq!(
+      *warnings.read().unwrap(),
+      vec![Error {
+        kind: ParserError::InvalidDeclaration,
+        loc: Some(ErrorLocation {
+          filename: "test.css".into(),
+          line: 0,
+          column: 42
+        })
+      }]
+    );
+
+    // The rule is kept with a warning for targets that do not support it.
+    for (browsers, expected) in [
+      (
+        Browsers {
+          chrome: Some(120 << 16),
+          ..Browsers::default()
+        },
+        1,
+      ),
+      (
+        Browsers {
+          firefox: Some(130 << 16),
+          ..Browsers::default()
+        },
+        1,
+      ),
+      (
+        Browsers {
+          chrome: Some(126 << 16),
+          safari: Some((18 << 16) | (2 << 8)),
+          ..Browsers::default()
+        },
+        0,
+      ),
+    ] {
+      let mut stylesheet = StyleSheet::parse(
+        ".foo { color: red }\n@media (prefers-reduced-motion: no-preference) { @view-transition { navigation: auto } }",
+        ParserOptions {
+          filename: "test.css".into(),
+          ..ParserOptions::default()
+        },
+      )
+      .unwrap();
+      let warnings = Arc::new(RwLock::new(Vec::new()));
+      stylesheet
+        .minify(MinifyOptions {
+          targets: browsers.into(),
+          warnings: Some(warnings.clone()),
+          ..MinifyOptions::default()
+        })
+        .unwrap();
+      let res = stylesheet
+        .to_css(PrinterOptions {
+          minify: true,
+          ..PrinterOptions::default()
+        })
+        .unwrap();
+      assert_eq!(
+        res.code,
+        ".foo{color:red}@media (prefers-reduced-motion:no-preference){@view-transition{navigation:auto}}"
+      );
+      let warnings = warnings.read().unwrap();
+      assert_eq!(warnings.len(), expected);
+      if expected > 0 {
+        assert_eq!(
+          warnings[0],
+          Error {
+            kind: MinifyErrorKind::UnsupportedAtRule {
+              name: "@view-transition".into()
+            },
+            loc: Some(ErrorLocation {
+              filename: "test.css".into(),
+              line: 1,
+              column: 50
+            })
+          }
+        );
+        assert_eq!(
+          warnings[0].kind.to_string(),
+          "The @view-transition rule is not supported by the configured targets"
+        );
+      }
+    }
   }
 
   #[test]
@@ -30870,4 +36152,47 @@ mod tests {
       },
     );
   }
+
+  #[test]
+  fn test_minimum_browser() {
+    use crate::targets::{BrowserName, BrowserVersion};
+
+    assert_eq!(Browsers::default().minimum_browser(), None);
+    assert_eq!(
+      Browsers {
+        chrome: Some(120 << 16),
+        ..Browsers::default()
+      }
+      .minimum_browser(),
+      Some((BrowserName::Chrome, BrowserVersion::from(120 << 16)))
+    );
+    assert_eq!(
+      Browsers {
+        chrome: Some(120 << 16),
+        firefox: Some(120 << 16),
+        safari: Some(13 << 16),
+        ..Browsers::default()
+      }
+      .minimum_browser(),
+      Some((BrowserName::Safari, BrowserVersion::from(13 << 16)))
+    );
+    assert_eq!(
+      Browsers {
+        chrome: Some(80 << 16),
+        ie: Some(11 << 16),
+        safari: Some(13 << 16),
+        ..Browsers::default()
+      }
+      .minimum_browser(),
+      Some((BrowserName::Ie, BrowserVersion::from(11 << 16)))
+    );
+    assert_eq!(BrowserName::IosSaf.to_string(), "ios_saf");
+    assert_eq!(BrowserVersion::from((15 << 16) | (4 << 8)).to_string(), "15.4");
+    assert_eq!(BrowserVersion::from((13 << 16) | (1 << 8) | 2).to_string(), "13.1.2");
+    assert_eq!(BrowserVersion::from(120 << 16).to_string(), "120");
+    assert_eq!(
+      u32::from(BrowserVersion::from((15 << 16) | (4 << 8))),
+      (15 << 16) | (4 << 8)
+    );
+  }
 }
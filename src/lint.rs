@@ -0,0 +1,95 @@
+//! Linting.
+//!
+//! Lints analyze a parsed style sheet and report code that could be improved,
+//! without modifying it. See [StyleSheet::lint_vendor_prefixes](crate::stylesheet::StyleSheet::lint_vendor_prefixes).
+
+use crate::declaration::DeclarationBlock;
+use crate::error::ErrorLocation;
+use crate::properties::PropertyId;
+use crate::rules::{CssRule, CssRuleList, Location};
+use crate::targets::Targets;
+use crate::traits::ToCss;
+use crate::vendor_prefix::VendorPrefix;
+use std::fmt;
+
+/// A warning reported by a lint.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LintWarning {
+  /// The property name as written, including its vendor prefix, e.g. `-webkit-transform`.
+  pub property: String,
+  /// The suggested replacement, e.g. `transform`.
+  pub suggestion: String,
+  /// The location of the rule containing the declaration.
+  pub loc: ErrorLocation,
+}
+
+impl fmt::Display for LintWarning {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "{} is not needed for the configured targets, use {} instead at {}",
+      self.property, self.suggestion, self.loc
+    )
+  }
+}
+
+pub(crate) fn lint_vendor_prefixes<T>(
+  rules: &CssRuleList<T>,
+  targets: Targets,
+  sources: &[String],
+  warnings: &mut Vec<LintWarning>,
+) {
+  let mut lint = |declarations: &DeclarationBlock, loc: &Location| {
+    for property in declarations.iter().map(|(property, _)| property) {
+      let property_id = property.property_id();
+      let prefix = property_id.prefix();
+      if prefix.is_empty() || prefix == VendorPrefix::None {
+        continue;
+      }
+
+      // Only report properties that have an unprefixed equivalent, e.g. not `-webkit-box-orient`.
+      let mut unprefixed = property_id.with_prefix(VendorPrefix::None);
+      if PropertyId::from(unprefixed.name()) != unprefixed {
+        continue;
+      }
+
+      unprefixed.set_prefixes_for_targets(targets);
+      let needed = unprefixed.prefix();
+      for p in prefix - VendorPrefix::None {
+        if !needed.contains(p) {
+          warnings.push(LintWarning {
+            property: property_id.with_prefix(p).to_css_string(Default::default()).unwrap(),
+            suggestion: property_id.name().into(),
+            loc: ErrorLocation {
+              filename: sources.get(loc.source_index as usize).cloned().unwrap_or_default(),
+              line: loc.line,
+              column: loc.column,
+            },
+          });
+        }
+      }
+    }
+  };
+
+  visit_declarations(rules, &mut lint);
+}
+
+fn visit_declarations<T, F: FnMut(&DeclarationBlock, &Location)>(rules: &CssRuleList<T>, f: &mut F) {
+  rules.for_each_rule(&mut |rule| match rule {
+    CssRule::Style(style) => f(&style.declarations, &style.loc),
+    CssRule::Nesting(nesting) => f(&nesting.style.declarations, &nesting.style.loc),
+    CssRule::NestedDeclarations(nested) => f(&nested.declarations, &nested.loc),
+    CssRule::Keyframes(keyframes) => {
+      for keyframe in &keyframes.keyframes {
+        f(&keyframe.declarations, &keyframes.loc);
+      }
+    }
+    CssRule::Page(page) => {
+      f(&page.declarations, &page.loc);
+      for margin_rule in &page.rules {
+        f(&margin_rule.declarations, &margin_rule.loc);
+      }
+    }
+    _ => {}
+  });
+}
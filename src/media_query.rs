@@ -10,11 +10,11 @@ use crate::rules::custom_media::CustomMediaRule;
 use crate::rules::Location;
 use crate::stylesheet::ParserOptions;
 use crate::targets::{should_compile, Targets};
-use crate::traits::{Parse, ParseWithOptions, ToCss};
+use crate::traits::{Parse, ParseWithOptions, ToCss, Zero};
 use crate::values::ident::{DashedIdent, Ident};
 use crate::values::number::{CSSInteger, CSSNumber};
 use crate::values::string::CowArcStr;
-use crate::values::{length::Length, ratio::Ratio, resolution::Resolution};
+use crate::values::{calc::Calc, length::Length, ratio::Ratio, resolution::Resolution};
 use crate::vendor_prefix::VendorPrefix;
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
@@ -28,6 +28,17 @@ use std::collections::{HashMap, HashSet};
 #[cfg(feature = "serde")]
 use crate::serialization::ValueWrapper;
 
+/// The known dimensions of the viewport, in pixels, used to statically evaluate media queries.
+///
+/// This is useful when CSS is generated for a fixed size embedded context, such as an email or widget.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct KnownDimensions {
+  /// The width of the viewport in pixels.
+  pub width: CSSNumber,
+  /// The height of the viewport in pixels.
+  pub height: CSSNumber,
+}
+
 /// A [media query list](https://drafts.csswg.org/mediaqueries/#mq-list).
 #[derive(Clone, Debug, PartialEq, Default)]
 #[cfg_attr(feature = "visitor", derive(Visit), visit(visit_media_list, MEDIA_QUERIES))]
@@ -92,6 +103,43 @@ impl<'i> MediaList<'i> {
     Ok(())
   }
 
+  /// Minifies the conditions of each query, converting `resolution` feature values to their
+  /// shortest units and simplifying logical operators, and removes any queries that became
+  /// duplicates, never match, or are contained in the range of another query as a result.
+  pub(crate) fn minify(&mut self, targets: &Targets) {
+    for query in self.media_queries.iter_mut() {
+      query.minify(targets);
+    }
+
+    // If any query always matches, so does the whole list.
+    if let Some(query) = self.media_queries.iter().find(|query| query.always_matches()) {
+      self.media_queries = vec![query.clone()];
+      return;
+    }
+
+    // A query list matches if any of its queries match, so a query whose range bound implies the
+    // bound of another query with the same media type is redundant, e.g. `(min-resolution: 3x)`
+    // in `(min-resolution: 2x), (min-resolution: 3x)`.
+    remove_implied_bounds(&mut self.media_queries, Operator::Or, |query| {
+      if query.qualifier == Some(Qualifier::Not) {
+        return None;
+      }
+      let (id, op, value) = query.condition.as_ref()?.range_bound()?;
+      Some(((query.qualifier, query.media_type.clone(), id), op, value))
+    });
+
+    let mut i = 0;
+    while i < self.media_queries.len() {
+      if self.media_queries[..i].contains(&self.media_queries[i])
+        || (self.media_queries[i].never_matches() && self.media_queries.len() > 1)
+      {
+        self.media_queries.remove(i);
+      } else {
+        i += 1;
+      }
+    }
+  }
+
   pub(crate) fn transform_resolution(&mut self, targets: Targets) {
     let mut i = 0;
     while i < self.media_queries.len() {
@@ -125,26 +173,80 @@ impl<'i> MediaList<'i> {
     !self.media_queries.is_empty() && self.media_queries.iter().all(|mq| mq.never_matches())
   }
 
+  /// Evaluates the media query list against a viewport with known dimensions.
+  ///
+  /// Returns `Some(true)` if the list always matches, `Some(false)` if it never matches,
+  /// and `None` if the result depends on other features of the environment.
+  pub fn evaluate(&self, dimensions: &KnownDimensions) -> Option<bool> {
+    if self.media_queries.is_empty() {
+      return Some(true);
+    }
+
+    evaluate_operation(
+      Operator::Or,
+      self.media_queries.iter().map(|query| query.evaluate(dimensions)),
+    )
+  }
+
+  /// Resolves `prefers-color-scheme` features for the given color scheme, i.e. `light` or `dark`.
+  ///
+  /// Returns the remaining media query list if the result depends on other features.
+  pub(crate) fn resolve_color_scheme(&self, scheme: &str) -> Resolved<MediaList<'i>> {
+    if self.media_queries.is_empty() {
+      return Resolved::Known(true);
+    }
+
+    let mut media_queries = Vec::new();
+    for query in &self.media_queries {
+      match query.resolve_color_scheme(scheme) {
+        Resolved::Known(true) => return Resolved::Known(true),
+        Resolved::Known(false) => {}
+        Resolved::Residual(query) => media_queries.push(query),
+      }
+    }
+
+    if media_queries.is_empty() {
+      Resolved::Known(false)
+    } else {
+      Resolved::Residual(MediaList { media_queries })
+    }
+  }
+
   /// Attempts to combine the given media query list into this one. The resulting media query
   /// list matches if both the original media query lists would have matched.
   ///
   /// Returns an error if the boolean logic is not possible.
   pub fn and(&mut self, b: &MediaList<'i>) -> Result<(), ()> {
+    if b.media_queries.is_empty() {
+      return Ok(());
+    }
+
     if self.media_queries.is_empty() {
       self.media_queries.extend(b.media_queries.iter().cloned());
       return Ok(());
     }
 
-    for b in &b.media_queries {
-      if self.media_queries.contains(&b) {
-        continue;
+    // Each query in this list must be combined with each query in the other list.
+    let mut media_queries = Vec::new();
+    for a in &self.media_queries {
+      for b in &b.media_queries {
+        let mut query = a.clone();
+        query.and(b)?;
+        if !query.never_matches() && !media_queries.contains(&query) {
+          media_queries.push(query);
+        }
       }
+    }
 
-      for a in &mut self.media_queries {
-        a.and(&b)?;
-      }
+    if media_queries.is_empty() {
+      media_queries.push(MediaQuery {
+        qualifier: Some(Qualifier::Not),
+        media_type: MediaType::All,
+        condition: None,
+      });
     }
 
+    self.media_queries = media_queries;
     Ok(())
   }
 
@@ -350,6 +452,29 @@ impl<'i> MediaQuery<'i> {
     }
   }
 
+  fn minify(&mut self, targets: &Targets) {
+    let result = match &mut self.condition {
+      Some(condition) => condition.minify(targets),
+      None => return,
+    };
+
+    match result {
+      // A condition that always matches can be removed, e.g. `screen and (min-width: 0)` => `screen`.
+      Some(true) => self.condition = None,
+      // A condition that never matches makes the whole query either never match, or always match when negated.
+      Some(false) => {
+        self.qualifier = if self.qualifier == Some(Qualifier::Not) {
+          None
+        } else {
+          Some(Qualifier::Not)
+        };
+        self.media_type = MediaType::All;
+        self.condition = None;
+      }
+      None => {}
+    }
+  }
+
   /// Returns whether the media query is guaranteed to always match.
   pub fn always_matches(&self) -> bool {
     self.qualifier == None && self.media_type == MediaType::All && self.condition == None
@@ -360,11 +485,76 @@ impl<'i> MediaQuery<'i> {
     self.qualifier == Some(Qualifier::Not) && self.media_type == MediaType::All && self.condition == None
   }
 
+  /// Evaluates the media query against a viewport with known dimensions.
+  /// Media types other than `all` are unknown.
+  pub fn evaluate(&self, dimensions: &KnownDimensions) -> Option<bool> {
+    let media_type = if self.media_type == MediaType::All {
+      Some(true)
+    } else {
+      None
+    };
+    let condition = match &self.condition {
+      Some(condition) => condition.evaluate(dimensions),
+      None => Some(true),
+    };
+
+    let result = evaluate_operation(Operator::And, [media_type, condition].into_iter());
+    if self.qualifier == Some(Qualifier::Not) {
+      result.map(|result| !result)
+    } else {
+      result
+    }
+  }
+
+  fn resolve_color_scheme(&self, scheme: &str) -> Resolved<MediaQuery<'i>> {
+    let condition = match &self.condition {
+      Some(condition) => condition.resolve_color_scheme(scheme),
+      None => return Resolved::Residual(self.clone()),
+    };
+
+    let negated = self.qualifier == Some(Qualifier::Not);
+    match condition {
+      Resolved::Known(false) => Resolved::Known(negated),
+      Resolved::Known(true) if self.media_type == MediaType::All => Resolved::Known(!negated),
+      Resolved::Known(true) => Resolved::Residual(MediaQuery {
+        qualifier: self.qualifier,
+        media_type: self.media_type.clone(),
+        condition: None,
+      }),
+      Resolved::Residual(condition) => Resolved::Residual(MediaQuery {
+        qualifier: self.qualifier,
+        media_type: self.media_type.clone(),
+        condition: Some(condition),
+      }),
+    }
+  }
+
   /// Attempts to combine the given media query into this one. The resulting media query
   /// matches if both of the original media queries would have matched.
   ///
   /// Returns an error if the boolean logic is not possible.
   pub fn and<'a>(&mut self, b: &MediaQuery<'i>) -> Result<(), ()> {
+    if self == b {
+      return Ok(());
+    }
+
+    // `not` negates the whole query, including the condition, e.g. `not screen and (color)`
+    // matches if either the media type or the condition doesn't match. This cannot be
+    // combined with another query.
+    let a_negated = self.qualifier == Some(Qualifier::Not);
+    let b_negated = b.qualifier == Some(Qualifier::Not);
+    if (a_negated && self.condition.is_some()) || (b_negated && b.condition.is_some()) {
+      return Err(());
+    }
+
+    // Unknown tokens are printed as is, so they cannot be combined with another condition.
+    if [&self.condition, &b.condition]
+      .iter()
+      .any(|c| c.as_ref().is_some_and(|c| c.contains_unknown()))
+    {
+      return Err(());
+    }
+
     let at = (&self.qualifier, &self.media_type);
     let bt = (&b.qualifier, &b.media_type);
     let (qualifier, media_type) = match (at, bt) {
@@ -381,6 +571,12 @@ impl<'i> MediaQuery<'i> {
           return Err(())
         }
       },
+      // `not screen and screen` => not all
+      ((&Some(Qualifier::Not), a), (_, b)) |
+      ((_, b), (&Some(Qualifier::Not), a)) if a == b => (Some(Qualifier::Not), MediaType::All),
+      // `not screen and (color)` => ERROR
+      ((&Some(Qualifier::Not), _), (_, MediaType::All)) if b.condition.is_some() => return Err(()),
+      ((_, MediaType::All), (&Some(Qualifier::Not), _)) if self.condition.is_some() => return Err(()),
       // `all and print` => print
       // `print and all` => print
       // `all and not print` => not print
@@ -392,9 +588,19 @@ impl<'i> MediaQuery<'i> {
       ((q, t), (&Some(Qualifier::Not), _)) => (q.clone(), t.clone()),
       // `print and screen` => not all
       ((_, a), (_, b)) if a != b => (Some(Qualifier::Not), MediaType::All),
-      ((_, a), _) => (None, a.clone())
+      // `only screen and screen` => only screen
+      ((a, t), (b, _)) => ((*a).or(*b), t.clone())
     };
 
+    if qualifier == Some(Qualifier::Not) && media_type == MediaType::All {
+      *self = MediaQuery {
+        qualifier,
+        media_type,
+        condition: None,
+      };
+      return Ok(());
+    }
+
     self.qualifier = qualifier;
     self.media_type = media_type;
 
@@ -512,6 +718,15 @@ enum_property! {
   }
 }
 
+impl Operator {
+  fn negate(&self) -> Operator {
+    match self {
+      Operator::And => Operator::Or,
+      Operator::Or => Operator::And,
+    }
+  }
+}
+
 /// Represents a media condition.
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "visitor", derive(Visit))]
@@ -590,7 +805,11 @@ impl<'i> QueryCondition<'i> for MediaCondition<'i> {
 
   fn needs_parens(&self, parent_operator: Option<Operator>, targets: &Targets) -> bool {
     match self {
-      MediaCondition::Not(_) => true,
+      // Negated range features are printed as the opposite range, which has its own parentheses.
+      MediaCondition::Not(c) => match c.negate() {
+        Some(negated) => negated.needs_parens(parent_operator, targets),
+        None => true,
+      },
       MediaCondition::Operation { operator, .. } => Some(*operator) != parent_operator,
       MediaCondition::Feature(f) => f.needs_parens(parent_operator, targets),
       MediaCondition::Unknown(_) => false,
@@ -681,6 +900,149 @@ impl<'i> MediaCondition<'i> {
       _ => {}
     }
   }
+
+  /// Minifies the condition, converting `resolution` feature values to their shortest units and
+  /// simplifying logical operators. Returns the value of the condition if it is constant, in which
+  /// case the caller should replace it.
+  fn minify(&mut self, targets: &Targets) -> Option<bool> {
+    match self {
+      MediaCondition::Feature(feature) => {
+        feature.minify_resolution(targets);
+        if feature.always_matches() {
+          return Some(true);
+        }
+      }
+      MediaCondition::Not(not) => {
+        if let Some(result) = not.minify(targets) {
+          return Some(!result);
+        }
+
+        match &mut **not {
+          // not (not (color)) => (color)
+          MediaCondition::Not(inner) => *self = (**inner).clone(),
+          // not ((not (color)) and (not (hover))) => (color) or (hover)
+          MediaCondition::Operation { operator, conditions } if is_negation_list(conditions) => {
+            *self = MediaCondition::Operation {
+              operator: operator.negate(),
+              conditions: conditions.drain(..).map(into_negated_condition).collect(),
+            };
+          }
+          _ => {}
+        }
+      }
+      MediaCondition::Operation { operator, conditions } => {
+        // Remove conditions that do not affect the result, e.g. `(min-width: 0)` in an `and` operation.
+        // A condition that determines the result makes the whole operation constant.
+        let identity = *operator == Operator::And;
+        let mut i = 0;
+        while i < conditions.len() {
+          match conditions[i].minify(targets) {
+            Some(result) if result == identity => {
+              conditions.remove(i);
+            }
+            Some(result) => return Some(result),
+            None => i += 1,
+          }
+        }
+
+        if conditions.is_empty() {
+          return Some(identity);
+        }
+
+        // Remove range bounds that are implied by another bound in the same operation.
+        // With `and`, the weaker bound is redundant, e.g. `(min-resolution: 2x) and (min-resolution: 1x)`.
+        // With `or`, the stronger bound is redundant, e.g. `(min-resolution: 2x) or (min-resolution: 1x)`.
+        remove_implied_bounds(conditions, *operator, MediaCondition::range_bound);
+
+        if conditions.len() == 1 {
+          *self = conditions.pop().unwrap();
+        } else if is_negation_list(conditions) {
+          // (not (color)) and (not (hover)) => not ((color) or (hover))
+          *self = MediaCondition::Not(Box::new(MediaCondition::Operation {
+            operator: operator.negate(),
+            conditions: conditions.drain(..).map(into_negated_condition).collect(),
+          }));
+        }
+      }
+      MediaCondition::Unknown(_) => {}
+    }
+
+    None
+  }
+
+  /// Returns the feature, comparison operator, and value of a range feature that can be compared
+  /// with other bounds of the same feature. Resolutions are converted to dppx, and lengths to px.
+  fn range_bound(&self) -> Option<(MediaFeatureId, MediaFeatureComparison, CSSNumber)> {
+    match self {
+      MediaCondition::Feature(MediaFeature::Range {
+        name: MediaFeatureName::Standard(id),
+        operator,
+        value,
+      }) if *operator != MediaFeatureComparison::Equal => {
+        let value = match (id, value) {
+          (MediaFeatureId::Resolution, MediaFeatureValue::Resolution(value)) => value.to_dppx(),
+          (
+            MediaFeatureId::Width
+            | MediaFeatureId::Height
+            | MediaFeatureId::DeviceWidth
+            | MediaFeatureId::DeviceHeight,
+            MediaFeatureValue::Length(length),
+          ) => length_to_px(length)?,
+          _ => return None,
+        };
+        Some((*id, *operator, value))
+      }
+      _ => None,
+    }
+  }
+}
+
+/// Removes items whose range bound is implied by the bound of another item with the same key.
+/// With `and`, the weaker bound is removed, and with `or`, the stronger bound is removed.
+/// The remaining bound takes the position of the first of the two items.
+fn remove_implied_bounds<T, K: PartialEq>(
+  items: &mut Vec<T>,
+  operator: Operator,
+  bound: impl Fn(&T) -> Option<(K, MediaFeatureComparison, CSSNumber)>,
+) {
+  let mut i = 0;
+  while i < items.len() {
+    let existing = bound(&items[i]).and_then(|(key, op, value)| {
+      (0..i).find_map(|j| {
+        let (other_key, other_op, other_value) = bound(&items[j])?;
+        if other_key != key || !bound_has_same_direction(op, other_op) {
+          return None;
+        }
+        Some((j, bound_implies(op, value, other_op, other_value)))
+      })
+    });
+
+    match existing {
+      Some((j, implies_existing)) => {
+        let remove_existing = implies_existing == (operator == Operator::And);
+        let item = items.remove(i);
+        if remove_existing {
+          items[j] = item;
+        }
+      }
+      None => i += 1,
+    }
+  }
+}
+
+/// Returns whether every condition is a negation that would be printed with `not`,
+/// so that applying De Morgan's laws results in a single negation.
+fn is_negation_list(conditions: &[MediaCondition]) -> bool {
+  conditions
+    .iter()
+    .all(|condition| matches!(condition, MediaCondition::Not(inner) if inner.negate().is_none()))
+}
+
+fn into_negated_condition(condition: MediaCondition) -> MediaCondition {
+  match condition {
+    MediaCondition::Not(inner) => *inner,
+    _ => unreachable!(),
+  }
 }
 
 impl<'i> ParseWithOptions<'i> for MediaCondition<'i> {
@@ -850,6 +1212,67 @@ where
 }
 
 impl<'i> MediaCondition<'i> {
+  /// Returns whether the condition is or contains unknown tokens, e.g. when parsed with error recovery.
+  fn contains_unknown(&self) -> bool {
+    match self {
+      MediaCondition::Feature(_) => false,
+      MediaCondition::Not(condition) => condition.contains_unknown(),
+      MediaCondition::Operation { conditions, .. } => conditions.iter().any(|c| c.contains_unknown()),
+      MediaCondition::Unknown(_) => true,
+    }
+  }
+
+  /// Evaluates the media condition against a viewport with known dimensions.
+  pub fn evaluate(&self, dimensions: &KnownDimensions) -> Option<bool> {
+    match self {
+      MediaCondition::Feature(feature) => feature.evaluate(dimensions),
+      MediaCondition::Not(condition) => condition.evaluate(dimensions).map(|result| !result),
+      MediaCondition::Operation { operator, conditions } => evaluate_operation(
+        *operator,
+        conditions.iter().map(|condition| condition.evaluate(dimensions)),
+      ),
+      MediaCondition::Unknown(_) => None,
+    }
+  }
+
+  fn resolve_color_scheme(&self, scheme: &str) -> Resolved<MediaCondition<'i>> {
+    match self {
+      MediaCondition::Feature(QueryFeature::Plain {
+        name: MediaFeatureName::Standard(MediaFeatureId::PrefersColorScheme),
+        value: MediaFeatureValue::Ident(ident),
+      }) => Resolved::Known(ident.0.eq_ignore_ascii_case(scheme)),
+      // The boolean form matches any color scheme.
+      MediaCondition::Feature(QueryFeature::Boolean {
+        name: MediaFeatureName::Standard(MediaFeatureId::PrefersColorScheme),
+      }) => Resolved::Known(true),
+      MediaCondition::Not(condition) => match condition.resolve_color_scheme(scheme) {
+        Resolved::Known(result) => Resolved::Known(!result),
+        Resolved::Residual(condition) => Resolved::Residual(MediaCondition::Not(Box::new(condition))),
+      },
+      MediaCondition::Operation { operator, conditions } => {
+        let short_circuit = *operator == Operator::Or;
+        let mut remaining = Vec::new();
+        for condition in conditions {
+          match condition.resolve_color_scheme(scheme) {
+            Resolved::Known(result) if result == short_circuit => return Resolved::Known(short_circuit),
+            Resolved::Known(_) => {}
+            Resolved::Residual(condition) => remaining.push(condition),
+          }
+        }
+
+        match remaining.len() {
+          0 => Resolved::Known(!short_circuit),
+          1 => Resolved::Residual(remaining.pop().unwrap()),
+          _ => Resolved::Residual(MediaCondition::Operation {
+            operator: *operator,
+            conditions: remaining,
+          }),
+        }
+      }
+      _ => Resolved::Residual(self.clone()),
+    }
+  }
+
   fn negate(&self) -> Option<MediaCondition<'i>> {
     match self {
       MediaCondition::Not(not) => Some((**not).clone()),
@@ -1010,6 +1433,174 @@ pub enum QueryFeature<'i, FeatureId> {
 /// A [media feature](https://drafts.csswg.org/mediaqueries/#typedef-media-feature)
 pub type MediaFeature<'i> = QueryFeature<'i, MediaFeatureId>;
 
+impl<'i> MediaFeature<'i> {
+  fn minify_resolution(&mut self, targets: &Targets) {
+    match self {
+      MediaFeature::Plain {
+        name: MediaFeatureName::Standard(MediaFeatureId::Resolution),
+        value,
+      }
+      | MediaFeature::Range {
+        name: MediaFeatureName::Standard(MediaFeatureId::Resolution),
+        value,
+        ..
+      } => value.minify_resolution(targets),
+      MediaFeature::Interval {
+        name: MediaFeatureName::Standard(MediaFeatureId::Resolution),
+        start,
+        end,
+        ..
+      } => {
+        start.minify_resolution(targets);
+        end.minify_resolution(targets);
+      }
+      _ => {}
+    }
+  }
+
+  /// Returns whether the media feature is trivially true, e.g. `(min-width: 0)`.
+  fn always_matches(&self) -> bool {
+    match self {
+      MediaFeature::Range {
+        name:
+          MediaFeatureName::Standard(
+            MediaFeatureId::Width
+            | MediaFeatureId::Height
+            | MediaFeatureId::DeviceWidth
+            | MediaFeatureId::DeviceHeight,
+          ),
+        operator,
+        value: MediaFeatureValue::Length(Length::Value(length)),
+      } => *operator == MediaFeatureComparison::GreaterThanEqual && length.is_zero(),
+      _ => false,
+    }
+  }
+
+  /// Evaluates the media feature against a viewport with known dimensions.
+  /// Only the `width`, `height`, `aspect-ratio`, and `orientation` features can be evaluated.
+  pub fn evaluate(&self, dimensions: &KnownDimensions) -> Option<bool> {
+    match self {
+      QueryFeature::Plain {
+        name: MediaFeatureName::Standard(MediaFeatureId::Orientation),
+        value: MediaFeatureValue::Ident(ident),
+      } => {
+        let portrait = dimensions.height >= dimensions.width;
+        match_ignore_ascii_case! { &ident.0,
+          "portrait" => Some(portrait),
+          "landscape" => Some(!portrait),
+          _ => None
+        }
+      }
+      QueryFeature::Plain {
+        name: MediaFeatureName::Standard(id),
+        value,
+      } => Some(compare_feature_value(
+        id.dimension(dimensions)?,
+        MediaFeatureComparison::Equal,
+        value.dimension()?,
+      )),
+      QueryFeature::Boolean {
+        name: MediaFeatureName::Standard(id),
+      } => Some(id.dimension(dimensions)? != 0.0),
+      QueryFeature::Range {
+        name: MediaFeatureName::Standard(id),
+        operator,
+        value,
+      } => Some(compare_feature_value(
+        id.dimension(dimensions)?,
+        *operator,
+        value.dimension()?,
+      )),
+      QueryFeature::Interval {
+        name: MediaFeatureName::Standard(id),
+        start,
+        start_operator,
+        end,
+        end_operator,
+      } => {
+        let value = id.dimension(dimensions)?;
+        Some(
+          compare_feature_value(start.dimension()?, *start_operator, value)
+            && compare_feature_value(value, *end_operator, end.dimension()?),
+        )
+      }
+      _ => None,
+    }
+  }
+}
+
+/// Returns whether two range comparisons bound a value from the same side.
+fn bound_has_same_direction(a: MediaFeatureComparison, b: MediaFeatureComparison) -> bool {
+  use MediaFeatureComparison::*;
+  matches!(
+    (a, b),
+    (GreaterThan | GreaterThanEqual, GreaterThan | GreaterThanEqual)
+      | (LessThan | LessThanEqual, LessThan | LessThanEqual)
+  )
+}
+
+/// Returns whether the bound `feature <a_op> a` implies the bound `feature <b_op> b`,
+/// where both bounds have the same direction.
+fn bound_implies(a_op: MediaFeatureComparison, a: CSSNumber, b_op: MediaFeatureComparison, b: CSSNumber) -> bool {
+  use MediaFeatureComparison::*;
+  match a_op {
+    GreaterThan | GreaterThanEqual => a > b || (a == b && (a_op == GreaterThan || b_op == GreaterThanEqual)),
+    LessThan | LessThanEqual => a < b || (a == b && (a_op == LessThan || b_op == LessThanEqual)),
+    Equal => false,
+  }
+}
+
+/// Converts a length to pixels, folding `calc()` expressions of absolute lengths.
+/// Relative lengths such as `em` depend on user preferences, so they cannot be converted.
+fn length_to_px(length: &Length) -> Option<CSSNumber> {
+  fn calc_to_px(calc: &Calc<Length>) -> Option<CSSNumber> {
+    match calc {
+      Calc::Value(length) => length_to_px(length),
+      Calc::Sum(a, b) => Some(calc_to_px(a)? + calc_to_px(b)?),
+      Calc::Product(number, calc) => Some(number * calc_to_px(calc)?),
+      Calc::Number(_) | Calc::Function(_) => None,
+    }
+  }
+
+  match length {
+    Length::Value(value) => value.to_px(),
+    Length::Calc(calc) => calc_to_px(calc),
+  }
+}
+
+fn compare_feature_value(a: CSSNumber, operator: MediaFeatureComparison, b: CSSNumber) -> bool {
+  match operator {
+    MediaFeatureComparison::Equal => a == b,
+    MediaFeatureComparison::GreaterThan => a > b,
+    MediaFeatureComparison::GreaterThanEqual => a >= b,
+    MediaFeatureComparison::LessThan => a < b,
+    MediaFeatureComparison::LessThanEqual => a <= b,
+  }
+}
+
+/// The result of statically resolving part of a media query.
+pub(crate) enum Resolved<T> {
+  /// The query always or never matches.
+  Known(bool),
+  /// The query depends on other features, and was rewritten to only include those.
+  Residual(T),
+}
+
+/// Combines the results of evaluating several conditions with an operator.
+/// Unknown results only matter if the known results don't determine the outcome.
+fn evaluate_operation<I: Iterator<Item = Option<bool>>>(operator: Operator, results: I) -> Option<bool> {
+  let short_circuit = operator == Operator::Or;
+  let mut result = Some(!short_circuit);
+  for r in results {
+    match r {
+      Some(r) if r == short_circuit => return Some(short_circuit),
+      Some(_) => {}
+      None => result = None,
+    }
+  }
+  result
+}
+
 impl<'i, FeatureId> ParseWithOptions<'i> for QueryFeature<'i, FeatureId>
 where
   FeatureId: for<'x> Parse<'x> + std::fmt::Debug + PartialEq + ValueType + Clone,
@@ -1051,7 +1642,9 @@ where
       return Err(input.new_custom_error(ParserError::InvalidMediaQuery));
     }
 
-    let value = MediaFeatureValue::parse(input, name.value_type())?;
+    input.skip_whitespace();
+    let location = input.current_source_location();
+    let mut value = MediaFeatureValue::parse(input, name.value_type())?;
     if !value.check_type(name.value_type()) {
       if options.error_recovery {
         options.warn(ParseError {
@@ -1063,6 +1656,14 @@ where
       }
     }
 
+    if !value.check_keyword(name.keywords()) {
+      if options.error_recovery {
+        options.warn(location.new_custom_error(ParserError::InvalidMediaQuery));
+      } else {
+        return Err(location.new_custom_error(ParserError::InvalidMediaQuery));
+      }
+    }
+
     if let Some(operator) = operator.or(legacy_op) {
       if !name.value_type().allows_ranges() {
         return Err(input.new_custom_error(ParserError::InvalidMediaQuery));
@@ -1287,6 +1888,12 @@ mod private {
   pub trait ValueType {
     /// Returns the value type for this feature id.
     fn value_type(&self) -> MediaFeatureType;
+
+    /// Returns the keywords accepted by this feature id, if it is an
+    /// identifier with a known set of values. An empty list means any identifier is accepted.
+    fn keywords(&self) -> &'static [&'static str] {
+      &[]
+    }
   }
 }
 
@@ -1299,6 +1906,13 @@ impl<'i, FeatureId: ValueType> ValueType for MediaFeatureName<'i, FeatureId> {
       _ => MediaFeatureType::Unknown,
     }
   }
+
+  fn keywords(&self) -> &'static [&'static str] {
+    match self {
+      Self::Standard(standard) => standard.keywords(),
+      _ => &[],
+    }
+  }
 }
 
 impl<'i, FeatureId: FeatureToCss> ToCss for MediaFeatureName<'i, FeatureId> {
@@ -1376,7 +1990,7 @@ macro_rules! define_query_features {
     $vis:vis enum $name:ident {
       $(
         $(#[$meta: meta])*
-        $str: literal: $id: ident = $ty: ident,
+        $str: literal: $id: ident = $ty: ident $([$($kw: literal),+])?,
       )+
     }
   ) => {
@@ -1398,6 +2012,14 @@ macro_rules! define_query_features {
           )+
         }
       }
+
+      fn keywords(&self) -> &'static [&'static str] {
+        match self {
+          $(
+            Self::$id => &[$($($kw),+)?],
+          )+
+        }
+      }
     }
   }
 }
@@ -1414,11 +2036,11 @@ define_query_features! {
     /// The [aspect-ratio](https://w3c.github.io/csswg-drafts/mediaqueries-5/#aspect-ratio) media feature.
     "aspect-ratio": AspectRatio = Ratio,
     /// The [orientation](https://w3c.github.io/csswg-drafts/mediaqueries-5/#orientation) media feature.
-    "orientation": Orientation = Ident,
+    "orientation": Orientation = Ident["portrait", "landscape"],
     /// The [overflow-block](https://w3c.github.io/csswg-drafts/mediaqueries-5/#overflow-block) media feature.
-    "overflow-block": OverflowBlock = Ident,
+    "overflow-block": OverflowBlock = Ident["none", "scroll", "paged"],
     /// The [overflow-inline](https://w3c.github.io/csswg-drafts/mediaqueries-5/#overflow-inline) media feature.
-    "overflow-inline": OverflowInline = Ident,
+    "overflow-inline": OverflowInline = Ident["none", "scroll"],
     /// The [horizontal-viewport-segments](https://w3c.github.io/csswg-drafts/mediaqueries-5/#horizontal-viewport-segments) media feature.
     "horizontal-viewport-segments": HorizontalViewportSegments = Integer,
     /// The [vertical-viewport-segments](https://w3c.github.io/csswg-drafts/mediaqueries-5/#vertical-viewport-segments) media feature.
@@ -1432,7 +2054,7 @@ define_query_features! {
     /// The [grid](https://w3c.github.io/csswg-drafts/mediaqueries-5/#grid) media feature.
     "grid": Grid = Boolean,
     /// The [update](https://w3c.github.io/csswg-drafts/mediaqueries-5/#update) media feature.
-    "update": Update = Ident,
+    "update": Update = Ident["none", "slow", "fast"],
     /// The [environment-blending](https://w3c.github.io/csswg-drafts/mediaqueries-5/#environment-blending) media feature.
     "environment-blending": EnvironmentBlending = Ident,
     /// The [color](https://w3c.github.io/csswg-drafts/mediaqueries-5/#color) media feature.
@@ -1462,19 +2084,19 @@ define_query_features! {
     /// The [video-dynamic-range](https://w3c.github.io/csswg-drafts/mediaqueries-5/#video-dynamic-range) media feature.
     "video-dynamic-range": VideoDynamicRange = Ident,
     /// The [scripting](https://w3c.github.io/csswg-drafts/mediaqueries-5/#scripting) media feature.
-    "scripting": Scripting = Ident,
+    "scripting": Scripting = Ident["none", "initial-only", "enabled"],
     /// The [prefers-reduced-motion](https://w3c.github.io/csswg-drafts/mediaqueries-5/#prefers-reduced-motion) media feature.
-    "prefers-reduced-motion": PrefersReducedMotion = Ident,
+    "prefers-reduced-motion": PrefersReducedMotion = Ident["no-preference", "reduce"],
     /// The [prefers-reduced-transparency](https://w3c.github.io/csswg-drafts/mediaqueries-5/#prefers-reduced-transparency) media feature.
-    "prefers-reduced-transparency": PrefersReducedTransparency = Ident,
+    "prefers-reduced-transparency": PrefersReducedTransparency = Ident["no-preference", "reduce"],
     /// The [prefers-contrast](https://w3c.github.io/csswg-drafts/mediaqueries-5/#prefers-contrast) media feature.
-    "prefers-contrast": PrefersContrast = Ident,
+    "prefers-contrast": PrefersContrast = Ident["no-preference", "less", "more", "custom"],
     /// The [forced-colors](https://w3c.github.io/csswg-drafts/mediaqueries-5/#forced-colors) media feature.
-    "forced-colors": ForcedColors = Ident,
+    "forced-colors": ForcedColors = Ident["none", "active"],
     /// The [prefers-color-scheme](https://w3c.github.io/csswg-drafts/mediaqueries-5/#prefers-color-scheme) media feature.
-    "prefers-color-scheme": PrefersColorScheme = Ident,
+    "prefers-color-scheme": PrefersColorScheme = Ident["light", "dark"],
     /// The [prefers-reduced-data](https://w3c.github.io/csswg-drafts/mediaqueries-5/#prefers-reduced-data) media feature.
-    "prefers-reduced-data": PrefersReducedData = Ident,
+    "prefers-reduced-data": PrefersReducedData = Ident["no-preference", "reduce"],
     /// The [device-width](https://w3c.github.io/csswg-drafts/mediaqueries-5/#device-width) media feature.
     "device-width": DeviceWidth = Length,
     /// The [device-height](https://w3c.github.io/csswg-drafts/mediaqueries-5/#device-height) media feature.
@@ -1493,6 +2115,18 @@ define_query_features! {
   }
 }
 
+impl MediaFeatureId {
+  /// Returns the value of the feature for a viewport with known dimensions, if it only depends on them.
+  fn dimension(&self, dimensions: &KnownDimensions) -> Option<CSSNumber> {
+    match self {
+      MediaFeatureId::Width => Some(dimensions.width),
+      MediaFeatureId::Height => Some(dimensions.height),
+      MediaFeatureId::AspectRatio => Some(dimensions.width / dimensions.height),
+      _ => None,
+    }
+  }
+}
+
 pub(crate) trait FeatureToCss: ToCss {
   fn to_css_with_prefix<W>(&self, prefix: &str, dest: &mut Printer<W>) -> Result<(), PrinterError>
   where
@@ -1605,6 +2239,21 @@ pub enum MediaFeatureValue<'i> {
 }
 
 impl<'i> MediaFeatureValue<'i> {
+  fn minify_resolution(&mut self, targets: &Targets) {
+    if let MediaFeatureValue::Resolution(resolution) = self {
+      *resolution = resolution.to_shortest_unit(targets);
+    }
+  }
+
+  /// Returns the value as a number of pixels for lengths, or as a quotient for ratios.
+  fn dimension(&self) -> Option<CSSNumber> {
+    match self {
+      MediaFeatureValue::Length(length) => length_to_px(length),
+      MediaFeatureValue::Ratio(Ratio(a, b)) => Some(a / b),
+      _ => None,
+    }
+  }
+
   fn value_type(&self) -> MediaFeatureType {
     use MediaFeatureValue::*;
     match self {
@@ -1625,6 +2274,22 @@ impl<'i> MediaFeatureValue<'i> {
       (a, b) => a == b,
     }
   }
+
+  /// Checks that an identifier value is one of the given keywords, and normalizes its case.
+  fn check_keyword(&mut self, keywords: &[&'static str]) -> bool {
+    match self {
+      MediaFeatureValue::Ident(ident) if !keywords.is_empty() => {
+        match keywords.iter().find(|kw| ident.0.eq_ignore_ascii_case(kw)) {
+          Some(kw) => {
+            *ident = Ident((*kw).into());
+            true
+          }
+          None => false,
+        }
+      }
+      _ => true,
+    }
+  }
 }
 
 impl<'i> MediaFeatureValue<'i> {
@@ -1941,6 +2606,52 @@ mod tests {
     assert_eq!(and("all", "only screen"), "only screen");
     assert_eq!(and("only screen", "all"), "only screen");
     assert_eq!(and("print", "print"), "print");
+    assert_eq!(and("not screen", "screen"), "not all");
+    assert_eq!(and("screen", "not screen"), "not all");
+    assert_eq!(and("not screen", "not screen"), "not screen");
+    assert_eq!(and("only screen", "screen"), "only screen");
+    assert_eq!(and("screen", "only screen"), "only screen");
+    assert_eq!(
+      and("only screen and (min-width: 250px)", "(color)"),
+      "only screen and (width >= 250px) and (color)"
+    );
+  }
+
+  #[test]
+  fn test_and_error() {
+    fn and_error(a: &str, b: &str) {
+      let mut a = parse(a);
+      let b = parse(b);
+      assert_eq!(a.and(&b), Err(()));
+    }
+
+    and_error("not print", "not screen");
+    and_error("not print and (color)", "(min-width: 250px)");
+    and_error("(min-width: 250px)", "not print and (color)");
+    and_error("not print", "(color)");
+    and_error("(color)", "not print");
+  }
+
+  #[test]
+  fn test_list_and() {
+    fn and(a: &str, b: &str) -> String {
+      fn parse(s: &str) -> MediaList<'_> {
+        let mut input = ParserInput::new(s);
+        let mut parser = Parser::new(&mut input);
+        MediaList::parse(&mut parser, &ParserOptions::default()).unwrap()
+      }
+
+      let mut a = parse(a);
+      let b = parse(b);
+      a.and(&b).unwrap();
+      a.to_css_string(PrinterOptions::default()).unwrap()
+    }
+
+    assert_eq!(and("screen, print", "(color)"), "screen and (color), print and (color)");
+    assert_eq!(and("(color)", "screen, print"), "screen and (color), print and (color)");
+    assert_eq!(and("screen, print", "print"), "print");
+    assert_eq!(and("screen", "print"), "not all");
+    assert_eq!(and("screen", ""), "screen");
   }
 
   #[test]
@@ -1,8 +1,9 @@
 use crate::declaration::{parse_declaration, DeclarationBlock, DeclarationList};
-use crate::error::{Error, ParserError, PrinterError};
+use crate::error::{Error, ParserError, PrinterError, Warning};
 use crate::media_query::*;
 use crate::printer::Printer;
 use crate::properties::custom::TokenList;
+use crate::properties::PropertyId;
 use crate::rules::container::{ContainerCondition, ContainerName, ContainerRule};
 use crate::rules::font_feature_values::FontFeatureValuesRule;
 use crate::rules::font_palette_values::FontPaletteValuesRule;
@@ -42,6 +43,7 @@ use crate::visitor::{Visit, VisitTypes, Visitor};
 use bitflags::bitflags;
 use cssparser::*;
 use parcel_selectors::parser::{NestingRequirement, ParseErrorRecovery};
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, RwLock};
 
 bitflags! {
@@ -70,12 +72,41 @@ pub struct ParserOptions<'o, 'i> {
   /// Whether to ignore invalid rules and declarations rather than erroring.
   pub error_recovery: bool,
   /// A list that will be appended to when a warning occurs.
-  pub warnings: Option<Arc<RwLock<Vec<Error<ParserError<'i>>>>>>,
+  pub warnings: Option<Arc<RwLock<Vec<Warning<'i>>>>>,
   /// Feature flags to enable.
   pub flags: ParserFlags,
+  /// A set of lowercase property names that are not allowed, e.g. `animation`. Vendor prefixed
+  /// versions of these properties, and the longhands of disallowed shorthands, are also disallowed.
+  /// Declarations of these properties are treated as invalid.
+  pub disallowed_properties: HashSet<String>,
+  /// A set of lowercase at-rule names that are not allowed, without the `@`, e.g. `import`.
+  /// Rules with these names are treated as invalid.
+  pub disallowed_at_rules: HashSet<String>,
+  /// Whether to treat declarations of unknown properties, and declarations whose values are invalid
+  /// for their property, as invalid rather than preserving them as is. Unknown vendor prefixed properties
+  /// are still preserved. Only values that are reported as `InvalidPropertyValue` warnings are treated as invalid,
+  /// so values that contain identifiers or functions that could not be parsed are preserved, since they
+  /// may use newer syntax.
+  pub strict_properties: bool,
+  /// Known values of environment variables, keyed by name, e.g. `safe-area-inset-top`.
+  /// References to these via `env()` in property values are replaced by the given CSS value. Variables
+  /// that are not in the map, or are referenced with indices, are left as is. When the whole value of a
+  /// declaration is a single `env()` reference, the property is parsed from the variable's value, so it
+  /// can be minified like any other declaration. Otherwise, the value is kept as a list of tokens.
+  /// `env()` references within the values themselves are not substituted.
+  pub environment_variables: HashMap<String, &'i str>,
 }
 
 impl<'o, 'i> ParserOptions<'o, 'i> {
+  /// Returns a copy of these options without any environment variables. Used to parse the value
+  /// of an environment variable, so that `env()` references within it are not substituted again.
+  pub(crate) fn without_environment_variables(&self) -> Self {
+    ParserOptions {
+      environment_variables: HashMap::new(),
+      ..self.clone()
+    }
+  }
+
   #[inline]
   pub(crate) fn warn(&self, warning: ParseError<'i, ParserError<'i>>) {
     if let Some(warnings) = &self.warnings {
@@ -84,6 +115,32 @@ impl<'o, 'i> ParserOptions<'o, 'i> {
       }
     }
   }
+
+  pub(crate) fn is_property_disallowed(&self, property_id: &PropertyId) -> bool {
+    fn is_longhand_of(shorthand: &PropertyId, name: &str) -> bool {
+      match shorthand.longhands() {
+        Some(longhands) => longhands
+          .iter()
+          .any(|longhand| longhand.name() == name || is_longhand_of(longhand, name)),
+        None => false,
+      }
+    }
+
+    if self.disallowed_properties.is_empty() {
+      return false;
+    }
+
+    let name = property_id.name().to_ascii_lowercase();
+    self.disallowed_properties.contains(&name)
+      || self
+        .disallowed_properties
+        .iter()
+        .any(|disallowed| is_longhand_of(&PropertyId::from(disallowed.as_str()), &name))
+  }
+
+  pub(crate) fn is_at_rule_disallowed(&self, name: &str) -> bool {
+    !self.disallowed_at_rules.is_empty() && self.disallowed_at_rules.contains(&name.to_ascii_lowercase())
+  }
 }
 
 #[derive(Clone, Default)]
@@ -140,6 +197,7 @@ pub struct TopLevelRuleParser<'a, 'o, 'i, T: crate::traits::AtRuleParser<'i>> {
   state: State,
   at_rule_parser: &'a mut T,
   rules: &'a mut CssRuleList<'i, T::AtRule>,
+  namespaces: HashMap<CowArcStr<'i>, CowArcStr<'i>>,
 }
 
 impl<'a, 'o, 'b, 'i, T: crate::traits::AtRuleParser<'i>> TopLevelRuleParser<'a, 'o, 'i, T> {
@@ -153,9 +211,36 @@ impl<'a, 'o, 'b, 'i, T: crate::traits::AtRuleParser<'i>> TopLevelRuleParser<'a,
       state: State::Start,
       at_rule_parser,
       rules,
+      namespaces: HashMap::new(),
     }
   }
 
+  /// Restores the parser state that follows the given rules, so that parsing can resume
+  /// part way through a style sheet.
+  pub(crate) fn resume_after(mut self, rules: &[CssRule<'i, T::AtRule>]) -> Self {
+    self.state = State::Start;
+    for rule in rules {
+      match rule {
+        CssRule::Import(..) => self.state = State::Imports,
+        CssRule::Namespace(namespace) => {
+          self.state = State::Namespaces;
+          if let Some(prefix) = &namespace.prefix {
+            self.namespaces.insert(prefix.0.clone(), namespace.url.0.clone());
+          }
+        }
+        CssRule::LayerStatement(..) if self.state <= State::Layers => self.state = State::Layers,
+        CssRule::Unknown(..) | CssRule::Ignored => {}
+        _ => self.state = State::Body,
+      }
+    }
+    self
+  }
+
+  /// Returns the number of rules parsed so far.
+  pub(crate) fn rule_count(&self) -> usize {
+    self.rules.0.len()
+  }
+
   pub fn nested<'x: 'b>(&'x mut self) -> NestedRuleParser<'x, 'o, 'i, T> {
     NestedRuleParser {
       options: &self.options,
@@ -165,6 +250,7 @@ impl<'a, 'o, 'b, 'i, T: crate::traits::AtRuleParser<'i>> TopLevelRuleParser<'a,
       rules: &mut self.rules,
       is_in_style_rule: false,
       allow_declarations: false,
+      namespaces: Some(&self.namespaces),
     }
   }
 }
@@ -273,6 +359,10 @@ impl<'a, 'o, 'i, T: crate::traits::AtRuleParser<'i>> AtRuleParser<'i> for TopLev
     name: CowRcStr<'i>,
     input: &mut Parser<'i, 't>,
   ) -> Result<Self::Prelude, ParseError<'i, Self::Error>> {
+    if self.options.is_at_rule_disallowed(&name) {
+      return Err(input.new_custom_error(ParserError::DisallowedAtRule(name.into())));
+    }
+
     match_ignore_ascii_case! { &*name,
       "import" => {
         if self.state > State::Imports {
@@ -314,7 +404,7 @@ impl<'a, 'o, 'i, T: crate::traits::AtRuleParser<'i>> AtRuleParser<'i> for TopLev
         // @charset is removed by rust-cssparser if it’s the first rule in the stylesheet.
         // Anything left is technically invalid, however, users often concatenate CSS files
         // together, so we are more lenient and simply ignore @charset rules in the middle of a file.
-        input.expect_string()?;
+        parse_charset(input, self.options)?;
         return Ok(AtRulePrelude::Charset)
       },
       "custom-media" if self.options.flags.contains(ParserFlags::CUSTOM_MEDIA) => {
@@ -367,11 +457,16 @@ impl<'a, 'o, 'i, T: crate::traits::AtRuleParser<'i>> AtRuleParser<'i> for TopLev
       AtRulePrelude::Namespace(prefix, url) => {
         self.state = State::Namespaces;
 
-        self.rules.0.push(CssRule::Namespace(NamespaceRule {
+        let rule = NamespaceRule {
           prefix: prefix.map(|x| x.into()),
           url: url.into(),
           loc,
-        }));
+        };
+        if let Some(prefix) = &rule.prefix {
+          self.namespaces.insert(prefix.0.clone(), rule.url.0.clone());
+        }
+
+        self.rules.0.push(CssRule::Namespace(rule));
         Ok(())
       }
       AtRulePrelude::CustomMedia(name, query) => {
@@ -442,6 +537,9 @@ pub struct NestedRuleParser<'a, 'o, 'i, T: crate::traits::AtRuleParser<'i>> {
   rules: &'a mut CssRuleList<'i, T::AtRule>,
   is_in_style_rule: bool,
   allow_declarations: bool,
+  /// The namespace prefixes declared by `@namespace` rules, mapped to their URLs. When `None`,
+  /// any prefix is accepted, e.g. when parsing a rule list outside of a style sheet.
+  namespaces: Option<&'a HashMap<CowArcStr<'i>, CowArcStr<'i>>>,
 }
 
 impl<'a, 'o, 'b, 'i, T: crate::traits::AtRuleParser<'i>> NestedRuleParser<'a, 'o, 'i, T> {
@@ -459,6 +557,7 @@ impl<'a, 'o, 'b, 'i, T: crate::traits::AtRuleParser<'i>> NestedRuleParser<'a, 'o
       rules: &mut rules,
       is_in_style_rule: self.is_in_style_rule || is_style_rule,
       allow_declarations: self.allow_declarations || self.is_in_style_rule || is_style_rule,
+      namespaces: self.namespaces,
     };
 
     let parse_declarations = nested_parser.parse_declarations();
@@ -549,6 +648,10 @@ impl<'a, 'o, 'b, 'i, T: crate::traits::AtRuleParser<'i>> AtRuleParser<'i> for Ne
     name: CowRcStr<'i>,
     input: &mut Parser<'i, 't>,
   ) -> Result<Self::Prelude, ParseError<'i, Self::Error>> {
+    if self.options.is_at_rule_disallowed(&name) {
+      return Err(input.new_custom_error(ParserError::DisallowedAtRule(name.into())));
+    }
+
     let result = match_ignore_ascii_case! { &*name,
       "media" => {
         let media = MediaList::parse(input, &self.options)?;
@@ -582,7 +685,7 @@ impl<'a, 'o, 'b, 'i, T: crate::traits::AtRuleParser<'i>> AtRuleParser<'i> for Ne
         AtRulePrelude::FontPaletteValues(name)
       },
       "counter-style" => {
-        let name = CustomIdent::parse(input)?;
+        let name = CounterStyleRule::parse_name(input)?;
         AtRulePrelude::CounterStyle(name)
       },
       "viewport" | "-ms-viewport" => {
@@ -660,10 +763,7 @@ impl<'a, 'o, 'b, 'i, T: crate::traits::AtRuleParser<'i>> AtRuleParser<'i> for Ne
         AtRulePrelude::StartingStyle
       },
       "scope" => {
-        let selector_parser = SelectorParser {
-          is_nesting_allowed: true,
-          options: &self.options,
-        };
+        let selector_parser = SelectorParser::new(&self.options, self.namespaces);
 
         let scope_start = if input.try_parse(|input| input.expect_parenthesis_block()).is_ok() {
           Some(input.parse_nested_block(|input| {
@@ -691,10 +791,7 @@ impl<'a, 'o, 'b, 'i, T: crate::traits::AtRuleParser<'i>> AtRuleParser<'i> for Ne
       },
       "nest" if self.is_in_style_rule => {
         self.options.warn(input.new_custom_error(ParserError::DeprecatedNestRule));
-        let selector_parser = SelectorParser {
-          is_nesting_allowed: true,
-          options: &self.options,
-        };
+        let selector_parser = SelectorParser::new(&self.options, self.namespaces);
         let selectors = SelectorList::parse(&selector_parser, input, ParseErrorRecovery::DiscardList, NestingRequirement::Contained)?;
         AtRulePrelude::Nest(selectors)
       },
@@ -766,7 +863,7 @@ impl<'a, 'o, 'b, 'i, T: crate::traits::AtRuleParser<'i>> AtRuleParser<'i> for Ne
     let loc = self.loc(start);
     match prelude {
       AtRulePrelude::FontFace => {
-        let mut decl_parser = FontFaceDeclarationParser;
+        let mut decl_parser = FontFaceDeclarationParser { options: self.options };
         let mut parser = RuleBodyParser::new(input, &mut decl_parser);
         let mut properties = vec![];
         while let Some(decl) = parser.next() {
@@ -799,11 +896,8 @@ impl<'a, 'o, 'b, 'i, T: crate::traits::AtRuleParser<'i>> AtRuleParser<'i> for Ne
         Ok(())
       }
       AtRulePrelude::CounterStyle(name) => {
-        self.rules.0.push(CssRule::CounterStyle(CounterStyleRule {
-          name,
-          declarations: DeclarationBlock::parse(input, self.options)?,
-          loc,
-        }));
+        let rule = CounterStyleRule::parse(name, input, loc, self.options)?;
+        self.rules.0.push(CssRule::CounterStyle(rule));
         Ok(())
       }
       AtRulePrelude::Media(query) => {
@@ -897,10 +991,11 @@ impl<'a, 'o, 'b, 'i, T: crate::traits::AtRuleParser<'i>> AtRuleParser<'i> for Ne
         Ok(())
       }
       AtRulePrelude::ViewTransition => {
-        self
-          .rules
-          .0
-          .push(CssRule::ViewTransition(ViewTransitionRule::parse(input, loc)?));
+        self.rules.0.push(CssRule::ViewTransition(ViewTransitionRule::parse(
+          input,
+          loc,
+          self.options,
+        )?));
         Ok(())
       }
       AtRulePrelude::Nest(selectors) => {
@@ -957,10 +1052,7 @@ impl<'a, 'o, 'b, 'i, T: crate::traits::AtRuleParser<'i>> QualifiedRuleParser<'i>
     &mut self,
     input: &mut Parser<'i, 't>,
   ) -> Result<Self::Prelude, ParseError<'i, Self::Error>> {
-    let selector_parser = SelectorParser {
-      is_nesting_allowed: true,
-      options: &self.options,
-    };
+    let selector_parser = SelectorParser::new(&self.options, self.namespaces);
     if self.is_in_style_rule {
       SelectorList::parse_relative(
         &selector_parser,
@@ -1132,6 +1224,7 @@ pub fn parse_rule_list<'a, 'o, 'i, 't, T: crate::traits::AtRuleParser<'i>>(
     rules: &mut CssRuleList(Vec::new()),
     is_in_style_rule: false,
     allow_declarations: false,
+    namespaces: None,
   };
 
   let (_, rules) = parser.parse_nested(input, false)?;
@@ -1152,11 +1245,30 @@ pub fn parse_style_block<'a, 'o, 'i, 't, T: crate::traits::AtRuleParser<'i>>(
     rules: &mut CssRuleList(Vec::new()),
     is_in_style_rule: is_nested,
     allow_declarations: true,
+    namespaces: None,
   };
 
   parser.parse_style_block(input)
 }
 
+/// Parses the encoding of a `@charset` rule, and warns if it is not UTF-8. Returns the encoding
+/// if it is not UTF-8, in which case a leading `@charset` rule is kept by the style sheet parser.
+pub(crate) fn parse_charset<'i, 't>(
+  input: &mut Parser<'i, 't>,
+  options: &ParserOptions<'_, 'i>,
+) -> Result<Option<CowArcStr<'i>>, ParseError<'i, ParserError<'i>>> {
+  input.skip_whitespace();
+  let location = input.current_source_location();
+  let charset = input.expect_string()?.clone();
+  if charset.eq_ignore_ascii_case("utf-8") || charset.eq_ignore_ascii_case("utf8") {
+    return Ok(None);
+  }
+
+  let charset: CowArcStr<'i> = charset.into();
+  options.warn(location.new_custom_error(ParserError::UnsupportedCharset(charset.clone())));
+  Ok(Some(charset))
+}
+
 #[inline]
 pub fn starts_with_ignore_ascii_case(string: &str, prefix: &str) -> bool {
   string.len() >= prefix.len() && string.as_bytes()[0..prefix.len()].eq_ignore_ascii_case(prefix.as_bytes())
@@ -7,7 +7,7 @@ use crate::rules::{Location, StyleContext};
 use crate::selector::SelectorList;
 use crate::targets::{Targets, TargetsWithSupportsScope};
 use crate::vendor_prefix::VendorPrefix;
-use cssparser::{serialize_identifier, serialize_name};
+use cssparser::{serialize_identifier, serialize_name, serialize_string};
 #[cfg(feature = "sourcemap")]
 use parcel_sourcemap::{OriginalLocation, SourceMap};
 
@@ -20,6 +20,21 @@ pub struct PrinterOptions<'a> {
   #[cfg(feature = "sourcemap")]
   #[cfg_attr(docsrs, doc(cfg(feature = "sourcemap")))]
   pub source_map: Option<&'a mut SourceMap>,
+  /// An optional `sourceRoot` to include in the source map when it is serialized by the printer,
+  /// i.e. with `append_source_map_comment` or [to_css_with_source_map](crate::stylesheet::StyleSheet::to_css_with_source_map).
+  #[cfg(feature = "sourcemap")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "sourcemap")))]
+  pub source_root: Option<String>,
+  /// Whether to embed the contents of the original source files in the
+  /// `sourcesContent` field of the generated source map.
+  #[cfg(feature = "sourcemap")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "sourcemap")))]
+  pub embed_sources: bool,
+  /// Whether to append a `/*# sourceMappingURL=... */` comment containing the
+  /// generated source map as an inline data url to the end of the output.
+  #[cfg(feature = "sourcemap")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "sourcemap")))]
+  pub append_source_map_comment: bool,
   /// An optional project root path, used to generate relative paths for sources used in CSS module hashes.
   pub project_root: Option<&'a str>,
   /// Targets to output the CSS for.
@@ -35,6 +50,68 @@ pub struct PrinterOptions<'a> {
   /// A mapping of pseudo classes to replace with class names that can be applied
   /// from JavaScript. Useful for polyfills, for example.
   pub pseudo_classes: Option<PseudoClasses<'a>>,
+  /// The indentation style to use when not minifying.
+  pub indent: Indent,
+  /// The line ending to use when not minifying, or between top-level rules
+  /// when `newline_between_rules` is enabled.
+  pub line_ending: LineEnding,
+  /// Whether to write each top-level rule on its own line when minifying.
+  /// This can make minified output easier to diff, and sometimes compress better.
+  pub newline_between_rules: bool,
+  /// An optional function used to rewrite `url()` values, e.g. when combining
+  /// files from different directories into a single output file. It receives the
+  /// original url and the index of the source file it appeared in, and returns the new url.
+  ///
+  /// This is not called when the `analyze_dependencies` option is enabled, because
+  /// urls are replaced with placeholders in that case.
+  pub url_rewriter: Option<UrlRewriter<'a>>,
+  /// How to print `@keyframes` selectors.
+  pub keyframe_selectors: KeyframeSelectorStyle,
+  /// Whether to convert absolute lengths to the absolute unit with the shortest serialization
+  /// when minifying, e.g. `96px` to `1in`. Relative lengths such as `rem` are never converted.
+  pub convert_length_units: bool,
+  /// Whether to pad the cells of `grid-template-areas` strings when not minifying, so that
+  /// the columns of each row line up, e.g. `"nav  main"` below `"head head"`.
+  pub align_grid_template_areas: bool,
+}
+
+/// A function that rewrites a `url()`, given the original url and the index of
+/// the source file it appeared in. See [PrinterOptions](PrinterOptions).
+pub type UrlRewriter<'a> = &'a dyn Fn(&str, u32) -> String;
+
+/// An indentation style, as used in [PrinterOptions](PrinterOptions).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Indent {
+  /// Indent each level by the given number of spaces.
+  Spaces(u8),
+  /// Indent each level by a tab character.
+  Tab,
+}
+
+impl Default for Indent {
+  fn default() -> Self {
+    Indent::Spaces(2)
+  }
+}
+
+/// A line ending style, as used in [PrinterOptions](PrinterOptions).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum LineEnding {
+  /// A line feed (`\n`).
+  #[default]
+  Lf,
+  /// A carriage return followed by a line feed (`\r\n`).
+  Crlf,
+}
+
+/// How to print `@keyframes` selectors, as used in [PrinterOptions](PrinterOptions).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum KeyframeSelectorStyle {
+  /// Use the shortest form when minifying, e.g. `0%` instead of `from`, and `to` instead of `100%`.
+  #[default]
+  Shortest,
+  /// Always use percentages, e.g. `0%` instead of `from`, and `100%` instead of `to`.
+  Explicit,
 }
 
 /// A mapping of user action pseudo classes to replace with class names.
@@ -72,8 +149,21 @@ pub struct Printer<'a, 'b, 'c, W> {
   #[cfg(feature = "sourcemap")]
   #[cfg_attr(docsrs, doc(cfg(feature = "sourcemap")))]
   pub(crate) source_maps: Vec<Option<SourceMap>>,
+  #[cfg(feature = "sourcemap")]
+  pub(crate) source_root: Option<String>,
+  #[cfg(feature = "sourcemap")]
+  pub(crate) embed_sources: bool,
+  #[cfg(feature = "sourcemap")]
+  append_source_map_comment: bool,
   pub(crate) loc: Location,
   indent: u8,
+  alignment: u8,
+  indent_style: Indent,
+  line_ending: LineEnding,
+  newline_between_rules: bool,
+  pub(crate) keyframe_selectors: KeyframeSelectorStyle,
+  pub(crate) convert_length_units: bool,
+  pub(crate) align_grid_template_areas: bool,
   line: u32,
   col: u32,
   pub(crate) minify: bool,
@@ -86,6 +176,7 @@ pub struct Printer<'a, 'b, 'c, W> {
   pub(crate) dependencies: Option<Vec<Dependency>>,
   pub(crate) remove_imports: bool,
   pub(crate) pseudo_classes: Option<PseudoClasses<'a>>,
+  url_rewriter: Option<UrlRewriter<'a>>,
   context: Option<&'a StyleContext<'a, 'b>>,
 }
 
@@ -99,12 +190,25 @@ impl<'a, 'b, 'c, W: std::fmt::Write + Sized> Printer<'a, 'b, 'c, W> {
       source_map: options.source_map,
       #[cfg(feature = "sourcemap")]
       source_maps: Vec::new(),
+      #[cfg(feature = "sourcemap")]
+      source_root: options.source_root,
+      #[cfg(feature = "sourcemap")]
+      embed_sources: options.embed_sources,
+      #[cfg(feature = "sourcemap")]
+      append_source_map_comment: options.append_source_map_comment,
       loc: Location {
         source_index: 0,
         line: 0,
         column: 1,
       },
       indent: 0,
+      alignment: 0,
+      indent_style: options.indent,
+      line_ending: options.line_ending,
+      newline_between_rules: options.newline_between_rules,
+      keyframe_selectors: options.keyframe_selectors,
+      convert_length_units: options.convert_length_units,
+      align_grid_template_areas: options.align_grid_template_areas,
       line: 0,
       col: 0,
       minify: options.minify,
@@ -119,6 +223,7 @@ impl<'a, 'b, 'c, W: std::fmt::Write + Sized> Printer<'a, 'b, 'c, W> {
       },
       remove_imports: matches!(&options.analyze_dependencies, Some(d) if d.remove_imports),
       pseudo_classes: options.pseudo_classes,
+      url_rewriter: options.url_rewriter,
       context: None,
     }
   }
@@ -148,6 +253,16 @@ impl<'a, 'b, 'c, W: std::fmt::Write + Sized> Printer<'a, 'b, 'c, W> {
 
   /// Writes a raw string which may contain newlines to the underlying destination.
   pub fn write_str_with_newlines(&mut self, s: &str) -> Result<(), PrinterError> {
+    if self.line_ending == LineEnding::Crlf {
+      for (i, line) in s.split('\n').enumerate() {
+        if i > 0 {
+          self.write_char('\n')?;
+        }
+        self.write_str(line.strip_suffix('\r').unwrap_or(line))?;
+      }
+      return Ok(());
+    }
+
     let mut last_line_start: usize = 0;
 
     for (idx, n) in s.char_indices() {
@@ -166,8 +281,13 @@ impl<'a, 'b, 'c, W: std::fmt::Write + Sized> Printer<'a, 'b, 'c, W> {
   }
 
   /// Write a single character to the underlying destination.
+  ///
+  /// Newline characters are written using the configured line ending.
   pub fn write_char(&mut self, c: char) -> Result<(), PrinterError> {
     if c == '\n' {
+      if self.line_ending == LineEnding::Crlf {
+        self.dest.write_char('\r')?;
+      }
       self.line += 1;
       self.col = 0;
     } else {
@@ -207,36 +327,73 @@ impl<'a, 'b, 'c, W: std::fmt::Write + Sized> Printer<'a, 'b, 'c, W> {
     }
 
     self.write_char('\n')?;
-    if self.indent > 0 {
-      self.write_str(&" ".repeat(self.indent as usize))?;
+    let spaces = match self.indent_style {
+      Indent::Spaces(width) => self.indent as usize * width as usize + self.alignment as usize,
+      Indent::Tab => {
+        if self.indent > 0 {
+          self.write_str(&"\t".repeat(self.indent as usize))?;
+        }
+        self.alignment as usize
+      }
+    };
+
+    if spaces > 0 {
+      self.write_str(&" ".repeat(spaces))?;
     }
 
     Ok(())
   }
 
-  /// Increases the current indent level.
+  /// Writes a newline character between two top-level rules if the `minify` and
+  /// `newline_between_rules` options are enabled. Otherwise, nothing is printed.
+  pub fn rule_separator(&mut self) -> Result<(), PrinterError> {
+    if self.minify && self.newline_between_rules && self.indent == 0 && self.alignment == 0 {
+      self.write_char('\n')?;
+    }
+
+    Ok(())
+  }
+
+  /// Increases the current indent by one level, using the configured
+  /// [indentation style](PrinterOptions::indent).
+  pub fn increase_indent(&mut self) {
+    self.indent += 1;
+  }
+
+  /// Decreases the current indent by one level.
+  /// See [increase_indent](Printer::increase_indent).
+  pub fn decrease_indent(&mut self) {
+    self.indent -= 1;
+  }
+
+  /// Increases the current indent by two spaces, regardless of the configured
+  /// [indentation style](PrinterOptions::indent). This is equivalent to `indent_by(2)`.
+  /// Use [increase_indent](Printer::increase_indent) to indent by one level instead.
   pub fn indent(&mut self) {
-    self.indent += 2;
+    self.indent_by(2);
   }
 
-  /// Decreases the current indent level.
+  /// Decreases the current indent by two spaces. This is equivalent to `dedent_by(2)`.
   pub fn dedent(&mut self) {
-    self.indent -= 2;
+    self.dedent_by(2);
   }
 
-  /// Increases the current indent level by the given number of characters.
+  /// Increases the current indent by the given number of characters.
+  /// This is always written as spaces, and is used to align content
+  /// with the previous line.
   pub fn indent_by(&mut self, amt: u8) {
-    self.indent += amt;
+    self.alignment += amt;
   }
 
-  /// Decreases the current indent level by the given number of characters.
+  /// Decreases the current indent by the given number of characters.
   pub fn dedent_by(&mut self, amt: u8) {
-    self.indent -= amt;
+    self.alignment -= amt;
   }
 
-  /// Returns whether the indent level is greater than one.
+  /// Returns whether the indent is greater than one level, counting
+  /// every two characters of alignment as a level.
   pub fn is_nested(&self) -> bool {
-    self.indent > 2
+    self.indent as usize * 2 + self.alignment as usize > 2
   }
 
   /// Adds a mapping to the source map, if any.
@@ -284,6 +441,67 @@ impl<'a, 'b, 'c, W: std::fmt::Write + Sized> Printer<'a, 'b, 'c, W> {
     }
   }
 
+  /// Writes a `sourceMappingURL` comment containing the source map, if any, when the
+  /// `append_source_map_comment` option is enabled.
+  #[cfg(feature = "sourcemap")]
+  pub(crate) fn write_source_map_comment(&mut self) -> Result<(), PrinterError> {
+    if !self.append_source_map_comment {
+      return Ok(());
+    }
+
+    if let Some(map) = &mut self.source_map {
+      let json = map.to_json(self.source_root.as_deref()).map_err(|_| PrinterError {
+        kind: PrinterErrorKind::SourceMapError,
+        loc: None,
+      })?;
+      if self.minify {
+        self.write_char('\n')?;
+      }
+      self.write_str("/*# sourceMappingURL=data:application/json;base64,")?;
+      self.write_str(&data_encoding::BASE64.encode(json.as_bytes()))?;
+      self.write_str(" */")?;
+      self.write_char('\n')?;
+    }
+
+    Ok(())
+  }
+
+  /// Writes a `url()` to the underlying destination, after rewriting it with the
+  /// `url_rewriter` option, if any. When minifying, the shortest of the quoted and
+  /// unquoted forms is chosen.
+  pub fn write_url(&mut self, url: &str) -> Result<(), PrinterError> {
+    let rewritten;
+    let url = if let Some(rewriter) = &self.url_rewriter {
+      rewritten = rewriter(url, self.loc.source_index);
+      rewritten.as_str()
+    } else {
+      url
+    };
+
+    if self.minify {
+      let mut buf = String::new();
+      cssparser::ToCss::to_css(&cssparser::Token::UnquotedUrl(url.into()), &mut buf)?;
+
+      // If the unquoted url is longer than it would be quoted (e.g. `url("...")`)
+      // then serialize as a string and choose the shorter version.
+      if buf.len() > url.len() + 7 {
+        let mut buf2 = String::new();
+        serialize_string(url, &mut buf2)?;
+        if buf2.len() + 5 < buf.len() {
+          self.write_str("url(")?;
+          self.write_str(&buf2)?;
+          return self.write_char(')');
+        }
+      }
+
+      self.write_str(&buf)
+    } else {
+      self.write_str("url(")?;
+      serialize_string(url, self)?;
+      self.write_char(')')
+    }
+  }
+
   /// Writes a CSS identifier to the underlying destination, escaping it
   /// as appropriate. If the `css_modules` option was enabled, then a hash
   /// is added, and the mapping is added to the CSS module.
@@ -413,6 +631,14 @@ impl<'a, 'b, 'c, W: std::fmt::Write + Sized> Printer<'a, 'b, 'c, W> {
   }
 }
 
+impl<'a, 'b, 'c> Printer<'a, 'b, 'c, String> {
+  /// Takes the output written so far, leaving the destination empty.
+  pub(crate) fn take_output(&mut self) -> String {
+    self.col = 0;
+    std::mem::take(self.dest)
+  }
+}
+
 impl<'a, 'b, 'c, W: std::fmt::Write + Sized> std::fmt::Write for Printer<'a, 'b, 'c, W> {
   fn write_str(&mut self, s: &str) -> std::fmt::Result {
     self.col += s.len() as u32;
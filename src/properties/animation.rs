@@ -14,7 +14,7 @@ use crate::values::ident::DashedIdent;
 use crate::values::number::CSSNumber;
 use crate::values::percentage::Percentage;
 use crate::values::size::Size2D;
-use crate::values::string::CSSString;
+use crate::values::string::{is_valid_custom_ident, write_custom_ident, CSSString};
 use crate::values::{easing::EasingFunction, ident::CustomIdent, time::Time};
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
@@ -64,22 +64,13 @@ impl<'i> ToCss for AnimationName<'i> {
         s.to_css_with_options(dest, css_module_animation_enabled)
       }
       AnimationName::String(s) => {
-        if css_module_animation_enabled {
+        // CSS-wide keywords, `none`, and other invalid identifiers keep their quotes, so they are not scoped.
+        if css_module_animation_enabled && is_valid_custom_ident(s, &["none"]) {
           if let Some(css_module) = &mut dest.css_module {
             css_module.reference(&s, dest.loc.source_index)
           }
         }
-
-        // CSS-wide keywords and `none` cannot remove quotes.
-        match_ignore_ascii_case! { &*s,
-          "none" | "initial" | "inherit" | "unset" | "default" | "revert" | "revert-layer" => {
-            serialize_string(&s, dest)?;
-            Ok(())
-          },
-          _ => {
-            dest.write_ident(s.as_ref(), css_module_animation_enabled)
-          }
-        }
+        write_custom_ident(s, &["none"], dest, css_module_animation_enabled)
       }
     }
   }
@@ -970,16 +970,27 @@ impl<'i> BackgroundHandler<'i> {
       &mut origins,
       &mut clips,
     ) {
-      // Only use shorthand syntax if the number of layers matches on all properties.
+      // The number of layers is determined by background-image. Shorter lists
+      // are repeated to match, so only use shorthand syntax if no list is longer.
       let len = images.len();
-      if x_positions.len() == len
-        && y_positions.len() == len
-        && repeats.len() == len
-        && sizes.len() == len
-        && attachments.len() == len
-        && origins.len() == len
-        && clips.0.len() == len
+      let fits = |count: usize| count == len || (count > 0 && count < len);
+      if fits(x_positions.len())
+        && fits(y_positions.len())
+        && fits(repeats.len())
+        && fits(sizes.len())
+        && fits(attachments.len())
+        && fits(origins.len())
+        && fits(clips.0.len())
       {
+        // Expand the shorter lists only once the shorthand is used, so longhands are left as written.
+        repeat_layers(x_positions, len);
+        repeat_layers(y_positions, len);
+        repeat_layers(repeats, len);
+        repeat_layers(sizes, len);
+        repeat_layers(attachments, len);
+        repeat_layers(origins, len);
+        repeat_layers(&mut clips.0, len);
+
         let clip_prefixes = if clips.0.iter().any(|clip| *clip == BackgroundClip::Text) {
           context.targets.prefixes(clips.1, Feature::BackgroundClip)
         } else {
@@ -1143,3 +1154,17 @@ fn is_background_property(property_id: &PropertyId) -> bool {
     _ => false,
   }
 }
+
+/// Repeats the values in a background layer list until it has `len` items,
+/// as if the list had been specified for each background image layer.
+/// Lists that are empty or already at least `len` items long are left untouched.
+fn repeat_layers<T: Clone>(values: &mut SmallVec<[T; 1]>, len: usize) {
+  let count = values.len();
+  if count == 0 || count >= len {
+    return;
+  }
+
+  for i in count..len {
+    values.push(values[i % count].clone());
+  }
+}
@@ -302,14 +302,22 @@ impl<'i> BorderImage<'i> {
   where
     W: std::fmt::Write,
   {
-    if *source != Image::default() {
+    let has_source = *source != Image::default();
+    if has_source {
       source.to_css(dest)?;
     }
     let has_slice = *slice != BorderImageSlice::default();
     let has_width = *width != Rect::all(BorderImageSideWidth::default());
     let has_outset = *outset != Rect::all(LengthOrNumber::Number(0.0));
+    let has_repeat = *repeat != BorderImageRepeat::default();
+    if !has_source && !has_slice && !has_width && !has_outset && !has_repeat {
+      return source.to_css(dest);
+    }
+
     if has_slice || has_width || has_outset {
-      dest.write_str(" ")?;
+      if has_source {
+        dest.write_str(" ")?;
+      }
       slice.to_css(dest)?;
       if has_width || has_outset {
         dest.delim('/', true)?;
@@ -324,8 +332,10 @@ impl<'i> BorderImage<'i> {
       }
     }
 
-    if *repeat != BorderImageRepeat::default() {
-      dest.write_str(" ")?;
+    if has_repeat {
+      if has_source || has_slice || has_width || has_outset {
+        dest.write_str(" ")?;
+      }
       repeat.to_css(dest)?;
     }
 
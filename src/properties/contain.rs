@@ -2,6 +2,7 @@
 
 #![allow(non_upper_case_globals)]
 
+use bitflags::bitflags;
 use cssparser::*;
 use smallvec::SmallVec;
 
@@ -160,3 +161,223 @@ shorthand_handler!(ContainerHandler -> Container<'i> {
   name: ContainerName(ContainerNameList<'i>),
   container_type: ContainerType(ContainerType),
 });
+
+bitflags! {
+  /// A value for the [contain](https://drafts.csswg.org/css-contain-2/#contain-property) property.
+  ///
+  /// Multiple containment types may be specified by combining the flags. The `strict` and
+  /// `content` keywords are represented by their equivalent combinations.
+  #[cfg_attr(feature = "visitor", derive(Visit))]
+  #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(from = "SerializedContain", into = "SerializedContain"))]
+  #[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+  #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy, Default)]
+  pub struct Contain: u8 {
+    /// Size containment.
+    const Size       = 0b00001;
+    /// Inline size containment.
+    const InlineSize = 0b00010;
+    /// Layout containment.
+    const Layout     = 0b00100;
+    /// Style containment.
+    const Style      = 0b01000;
+    /// Paint containment.
+    const Paint      = 0b10000;
+    /// Equivalent to `size layout style paint`.
+    const Strict     = Self::Size.bits() | Self::Layout.bits() | Self::Style.bits() | Self::Paint.bits();
+    /// Equivalent to `layout style paint`.
+    const Content    = Self::Layout.bits() | Self::Style.bits() | Self::Paint.bits();
+  }
+}
+
+impl<'i> Parse<'i> for Contain {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(Contain::empty());
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("strict")).is_ok() {
+      return Ok(Contain::Strict);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("content")).is_ok() {
+      return Ok(Contain::Content);
+    }
+
+    let mut value = Contain::empty();
+    while let Ok(ident) = input.try_parse(|input| input.expect_ident_cloned()) {
+      let location = input.current_source_location();
+      let flag = match_ignore_ascii_case! { &ident,
+        "size" => Contain::Size,
+        "inline-size" => Contain::InlineSize,
+        "layout" => Contain::Layout,
+        "style" => Contain::Style,
+        "paint" => Contain::Paint,
+        _ => return Err(location.new_unexpected_token_error(Token::Ident(ident.clone())))
+      };
+
+      // Each keyword may only appear once, and size and inline-size are mutually exclusive.
+      if value.intersects(flag)
+        || (flag.intersects(Contain::Size | Contain::InlineSize)
+          && value.intersects(Contain::Size | Contain::InlineSize))
+      {
+        return Err(location.new_unexpected_token_error(Token::Ident(ident.clone())));
+      }
+
+      value |= flag;
+    }
+
+    if value.is_empty() {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(value)
+  }
+}
+
+impl ToCss for Contain {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.is_empty() {
+      return dest.write_str("none");
+    }
+
+    if *self == Contain::Strict {
+      return dest.write_str("strict");
+    }
+
+    if *self == Contain::Content {
+      return dest.write_str("content");
+    }
+
+    let mut needs_space = false;
+    macro_rules! val {
+      ($val: ident, $str: expr) => {
+        #[allow(unused_assignments)]
+        if self.contains(Contain::$val) {
+          if needs_space {
+            dest.write_char(' ')?;
+          }
+          dest.write_str($str)?;
+          needs_space = true;
+        }
+      };
+    }
+
+    val!(Size, "size");
+    val!(InlineSize, "inline-size");
+    val!(Layout, "layout");
+    val!(Style, "style");
+    val!(Paint, "paint");
+    Ok(())
+  }
+}
+
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+struct SerializedContain {
+  size: bool,
+  inline_size: bool,
+  layout: bool,
+  style: bool,
+  paint: bool,
+}
+
+impl From<Contain> for SerializedContain {
+  fn from(contain: Contain) -> Self {
+    Self {
+      size: contain.contains(Contain::Size),
+      inline_size: contain.contains(Contain::InlineSize),
+      layout: contain.contains(Contain::Layout),
+      style: contain.contains(Contain::Style),
+      paint: contain.contains(Contain::Paint),
+    }
+  }
+}
+
+impl From<SerializedContain> for Contain {
+  fn from(s: SerializedContain) -> Contain {
+    let mut contain = Contain::empty();
+    contain.set(Contain::Size, s.size);
+    contain.set(Contain::InlineSize, s.inline_size);
+    contain.set(Contain::Layout, s.layout);
+    contain.set(Contain::Style, s.style);
+    contain.set(Contain::Paint, s.paint);
+    contain
+  }
+}
+
+#[cfg(feature = "jsonschema")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jsonschema")))]
+impl<'a> schemars::JsonSchema for Contain {
+  fn is_referenceable() -> bool {
+    true
+  }
+
+  fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+    SerializedContain::json_schema(gen)
+  }
+
+  fn schema_name() -> String {
+    "Contain".into()
+  }
+}
+
+enum_property! {
+  /// A value for the [content-visibility](https://drafts.csswg.org/css-contain-2/#content-visibility) property.
+  pub enum ContentVisibility {
+    /// No effect. The element's contents are laid out and rendered as normal.
+    Visible,
+    /// The element turns on layout, style, and paint containment, and skips its
+    /// contents when they are not relevant to the user.
+    Auto,
+    /// The element skips its contents, and turns on size, layout, style, and paint containment.
+    Hidden,
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct ContainmentHandler {
+  contain: Option<Contain>,
+  content_visibility: Option<ContentVisibility>,
+}
+
+impl<'i> PropertyHandler<'i> for ContainmentHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::Contain(val) => self.contain = Some(*val),
+      Property::ContentVisibility(val) => self.content_visibility = Some(*val),
+      Property::Unparsed(val)
+        if matches!(val.property_id, PropertyId::Contain | PropertyId::ContentVisibility) =>
+      {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, _: &mut PropertyHandlerContext<'i, '_>) {
+    // Both properties are always kept, even if content-visibility implies the same containment.
+    // Another rule may change content-visibility, which would otherwise lose the containment.
+    if let Some(contain) = std::mem::take(&mut self.contain) {
+      dest.push(Property::Contain(contain));
+    }
+
+    if let Some(content_visibility) = std::mem::take(&mut self.content_visibility) {
+      dest.push(Property::ContentVisibility(content_visibility));
+    }
+  }
+}
@@ -390,11 +390,12 @@ impl<'i> TokenList<'i> {
             })?;
             tokens.push(var);
           } else if f == "env" {
-            let env = input.parse_nested_block(|input| {
-              let env = EnvironmentVariable::parse_nested(input, options, depth + 1)?;
-              Ok(TokenOrValue::Env(env))
-            })?;
-            tokens.push(env);
+            let env =
+              input.parse_nested_block(|input| EnvironmentVariable::parse_nested(input, options, depth + 1))?;
+            match env.resolve(options, depth + 1) {
+              Some(value) => tokens.extend(value.0),
+              None => tokens.push(TokenOrValue::Env(env)),
+            }
           } else {
             let arguments = input.parse_nested_block(|input| TokenList::parse(input, options, depth + 1))?;
             tokens.push(TokenOrValue::Function(Function {
@@ -1007,6 +1008,38 @@ fn integer_decode(v: f32) -> (u32, i16, i8) {
 }
 
 impl<'i> TokenList<'i> {
+  /// Adds the namespace prefixes of `attr()` functions in the token list, e.g. `x` in `attr(x|title)`, to `prefixes`.
+  pub(crate) fn collect_attr_namespace_prefixes(&self, prefixes: &mut std::collections::HashSet<CowArcStr<'i>>) {
+    for token in &self.0 {
+      match token {
+        TokenOrValue::Function(f) => {
+          if f.name.0.eq_ignore_ascii_case("attr") {
+            let mut arguments = f.arguments.0.iter().skip_while(|token| token.is_whitespace());
+            if let (
+              Some(TokenOrValue::Token(Token::Ident(prefix))),
+              Some(TokenOrValue::Token(Token::Delim('|'))),
+            ) = (arguments.next(), arguments.next())
+            {
+              prefixes.insert(prefix.clone());
+            }
+          }
+          f.arguments.collect_attr_namespace_prefixes(prefixes);
+        }
+        TokenOrValue::Var(v) => {
+          if let Some(fallback) = &v.fallback {
+            fallback.collect_attr_namespace_prefixes(prefixes);
+          }
+        }
+        TokenOrValue::Env(v) => {
+          if let Some(fallback) = &v.fallback {
+            fallback.collect_attr_namespace_prefixes(prefixes);
+          }
+        }
+        _ => {}
+      }
+    }
+  }
+
   pub(crate) fn get_necessary_fallbacks(&self, targets: Targets) -> ColorFallbackKind {
     let mut fallbacks = ColorFallbackKind::empty();
     for token in &self.0 {
@@ -1379,6 +1412,24 @@ impl<'i> EnvironmentVariable<'i> {
     })
   }
 
+  /// Returns the value of this variable from the `environment_variables` parser option, if any.
+  pub(crate) fn value(&self, options: &ParserOptions<'_, 'i>) -> Option<&'i str> {
+    if !self.indices.is_empty() {
+      return None;
+    }
+
+    options.environment_variables.get(self.name.name()).copied()
+  }
+
+  /// Returns the parsed value of this variable from the `environment_variables` parser option, if any.
+  fn resolve(&self, options: &ParserOptions<'_, 'i>, depth: usize) -> Option<TokenList<'i>> {
+    let value = self.value(options)?;
+    let options = options.without_environment_variables();
+    let mut input = ParserInput::new(value);
+    let mut parser = Parser::new(&mut input);
+    parser.parse_entirely(|parser| TokenList::parse(parser, &options, depth)).ok()
+  }
+
   pub(crate) fn to_css<W>(&self, dest: &mut Printer<W>, is_custom_property: bool) -> Result<(), PrinterError>
   where
     W: std::fmt::Write,
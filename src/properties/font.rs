@@ -261,6 +261,28 @@ impl Into<Percentage> for &FontStretch {
   }
 }
 
+impl FontStretch {
+  /// Returns the keyword equivalent to this value, if any.
+  fn to_keyword(&self) -> Option<FontStretchKeyword> {
+    use FontStretchKeyword::*;
+    match self {
+      FontStretch::Keyword(keyword) => Some(*keyword),
+      FontStretch::Percentage(Percentage(val)) => Some(match *val {
+        0.5 => UltraCondensed,
+        0.625 => ExtraCondensed,
+        0.75 => Condensed,
+        0.875 => SemiCondensed,
+        1.0 => Normal,
+        1.125 => SemiExpanded,
+        1.25 => Expanded,
+        1.5 => ExtraExpanded,
+        2.0 => UltraExpanded,
+        _ => return None,
+      }),
+    }
+  }
+}
+
 impl ToCss for FontStretch {
   fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
   where
@@ -774,9 +796,17 @@ impl<'i> ToCss for Font<'i> {
       dest.write_char(' ')?;
     }
 
-    if self.stretch != FontStretch::default() {
-      self.stretch.to_css(dest)?;
-      dest.write_char(' ')?;
+    // The `font` property only accepts keywords for font-stretch, not percentages.
+    match self.stretch.to_keyword() {
+      Some(FontStretchKeyword::Normal) => {}
+      Some(keyword) => {
+        keyword.to_css(dest)?;
+        dest.write_char(' ')?;
+      }
+      None => {
+        self.stretch.to_css(dest)?;
+        dest.write_char(' ')?;
+      }
     }
 
     self.size.to_css(dest)?;
@@ -940,6 +970,8 @@ impl<'i> FontHandler<'i> {
       && variant_caps.is_some()
     {
       let caps = variant_caps.unwrap();
+      let stretch = stretch.unwrap();
+      let stretch_is_keyword = stretch.to_keyword().is_some();
       push!(
         Font,
         Font {
@@ -947,7 +979,11 @@ impl<'i> FontHandler<'i> {
           size: size.unwrap(),
           style: style.unwrap(),
           weight: weight.unwrap(),
-          stretch: stretch.unwrap(),
+          stretch: if stretch_is_keyword {
+            stretch.clone()
+          } else {
+            FontStretch::default()
+          },
           line_height: line_height.unwrap(),
           variant_caps: if caps.is_css2() {
             caps
@@ -962,6 +998,11 @@ impl<'i> FontHandler<'i> {
       if !caps.is_css2() {
         push!(FontVariantCaps, variant_caps.unwrap());
       }
+
+      // Likewise, percentages that don't match a keyword need a separate font-stretch property.
+      if !stretch_is_keyword {
+        push!(FontStretch, stretch);
+      }
     } else {
       if let Some(val) = family {
         push!(FontFamily, val);
@@ -24,7 +24,7 @@ use crate::serialization::ValueWrapper;
 
 /// A [track sizing](https://drafts.csswg.org/css-grid-2/#track-sizing) value
 /// for the `grid-template-rows` and `grid-template-columns` properties.
-#[derive(Debug, Clone, PartialEq, Parse, ToCss)]
+#[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "visitor", derive(Visit))]
 #[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
 #[cfg_attr(
@@ -39,6 +39,13 @@ pub enum TrackSizing<'i> {
   /// A list of grid tracks.
   #[cfg_attr(feature = "serde", serde(borrow))]
   TrackList(TrackList<'i>),
+  /// The [`subgrid`](https://drafts.csswg.org/css-grid-2/#subgrid-listing) keyword, which adopts
+  /// the tracks of the parent grid.
+  #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+  Subgrid {
+    /// Line names for the lines of the subgrid, starting from the first line.
+    line_names: Vec<CustomIdentList<'i>>,
+  },
 }
 
 /// A [`<track-list>`](https://drafts.csswg.org/css-grid-2/#typedef-track-list) value,
@@ -444,7 +451,37 @@ impl<'i> Parse<'i> for TrackList<'i> {
       return Err(input.new_custom_error(ParserError::InvalidDeclaration));
     }
 
-    Ok(TrackList { line_names, items })
+    // `repeat(1, ...)` is equivalent to its contents, so flatten it into the list,
+    // merging the line names at its edges with the adjacent ones.
+    let mut names_iter = line_names.into_iter();
+    let mut line_names = vec![names_iter.next().unwrap_or_default()];
+    let mut flattened = Vec::with_capacity(items.len());
+    for item in items {
+      match item {
+        TrackListItem::TrackRepeat(TrackRepeat {
+          count: RepeatCount::Number(1),
+          line_names: repeat_names,
+          track_sizes,
+        }) if !track_sizes.is_empty() => {
+          let mut repeat_names = repeat_names.into_iter();
+          line_names.last_mut().unwrap().extend(repeat_names.next().unwrap_or_default());
+          for size in track_sizes {
+            flattened.push(TrackListItem::TrackSize(size));
+            line_names.push(repeat_names.next().unwrap_or_default());
+          }
+        }
+        item => {
+          flattened.push(item);
+          line_names.push(Default::default());
+        }
+      }
+      line_names.last_mut().unwrap().extend(names_iter.next().unwrap_or_default());
+    }
+
+    Ok(TrackList {
+      line_names,
+      items: flattened,
+    })
   }
 }
 
@@ -493,6 +530,51 @@ impl<'i> TrackSizing<'i> {
     match self {
       TrackSizing::None => true,
       TrackSizing::TrackList(list) => list.is_explicit(),
+      TrackSizing::Subgrid { .. } => false,
+    }
+  }
+}
+
+impl<'i> Parse<'i> for TrackSizing<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(TrackSizing::None);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("subgrid")).is_ok() {
+      let mut line_names = Vec::new();
+      while let Ok(names) = input.try_parse(parse_line_names) {
+        line_names.push(names);
+      }
+
+      // Trailing empty line names have no effect.
+      while line_names.last().is_some_and(|names| names.is_empty()) {
+        line_names.pop();
+      }
+
+      return Ok(TrackSizing::Subgrid { line_names });
+    }
+
+    Ok(TrackSizing::TrackList(TrackList::parse(input)?))
+  }
+}
+
+impl<'i> ToCss for TrackSizing<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      TrackSizing::None => dest.write_str("none"),
+      TrackSizing::TrackList(list) => list.to_css(dest),
+      TrackSizing::Subgrid { line_names } => {
+        dest.write_str("subgrid")?;
+        for names in line_names {
+          dest.whitespace()?;
+          serialize_line_names(names, dest)?;
+        }
+        Ok(())
+      }
     }
   }
 }
@@ -632,6 +714,7 @@ impl ToCss for GridTemplateAreas {
     match self {
       GridTemplateAreas::None => dest.write_str("none"),
       GridTemplateAreas::Areas { areas, .. } => {
+        let widths = self.column_widths(dest);
         let mut iter = areas.iter();
         let mut next = iter.next();
         let mut first = true;
@@ -640,7 +723,7 @@ impl ToCss for GridTemplateAreas {
             dest.newline()?;
           }
 
-          self.write_string(dest, &mut iter, &mut next)?;
+          self.write_string(dest, &widths, &mut iter, &mut next)?;
 
           if first {
             first = false;
@@ -662,9 +745,44 @@ impl ToCss for GridTemplateAreas {
 }
 
 impl GridTemplateAreas {
+  /// Returns the width of each column when not minifying and the `align_grid_template_areas`
+  /// printer option is enabled, so that the cells of each row can be padded to line up.
+  /// Empty if the cells should not be aligned.
+  fn column_widths<W>(&self, dest: &Printer<W>) -> Vec<usize> {
+    let (areas, columns) = match self {
+      GridTemplateAreas::Areas { areas, columns } => (areas, *columns as usize),
+      _ => return Vec::new(),
+    };
+
+    // Area names may be renamed by CSS modules, so their final width is unknown.
+    let css_module_grid_enabled = dest.css_module.as_ref().is_some_and(|css_module| css_module.config.grid);
+    if dest.minify || !dest.align_grid_template_areas || css_module_grid_enabled || columns < 2 {
+      return Vec::new();
+    }
+
+    let mut widths = vec![0; columns];
+    for (i, token) in areas.iter().enumerate() {
+      let width = &mut widths[i % columns];
+      *width = (*width).max(Self::cell_width(token));
+    }
+    widths
+  }
+
+  fn cell_width(token: &Option<String>) -> usize {
+    match token {
+      Some(name) => {
+        let mut s = String::new();
+        let _ = serialize_identifier(name, &mut s);
+        s.chars().count()
+      }
+      None => 1,
+    }
+  }
+
   fn write_string<'a, W>(
     &self,
     dest: &mut Printer<W>,
+    widths: &[usize],
     iter: &mut std::slice::Iter<'a, Option<String>>,
     next: &mut Option<&'a Option<String>>,
   ) -> Result<(), PrinterError>
@@ -694,6 +812,13 @@ impl GridTemplateAreas {
           dest.write_char('.')?;
           last_was_null = true;
         }
+
+        // Pad all but the last column so the cells of each row line up.
+        if let Some(width) = widths.get(i as usize).filter(|_| i + 1 < columns) {
+          for _ in Self::cell_width(token)..*width {
+            dest.write_char(' ')?;
+          }
+        }
       }
 
       *next = iter.next();
@@ -833,6 +958,7 @@ impl GridTemplate<'_> {
           _ => unreachable!(),
         };
 
+        let widths = self.areas.column_widths(dest);
         let mut areas_iter = areas.iter();
         let mut line_names_iter = track_list.line_names.iter();
         let mut items_iter = track_list.items.iter();
@@ -875,7 +1001,7 @@ impl GridTemplate<'_> {
             newline!();
           }
 
-          self.areas.write_string(dest, &mut areas_iter, &mut next)?;
+          self.areas.write_string(dest, &widths, &mut areas_iter, &mut next)?;
 
           if let Some(item) = items_iter.next() {
             if *item != TrackListItem::TrackSize(TrackSize::default()) {
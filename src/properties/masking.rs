@@ -565,12 +565,12 @@ property_bitflags! {
     const MaskMode = 1 << 7;
     const Mask(_vp) = Self::MaskImage.bits() | Self::MaskPosition.bits() | Self::MaskSize.bits() | Self::MaskRepeat.bits() | Self::MaskClip.bits() | Self::MaskOrigin.bits() | Self::MaskComposite.bits() | Self::MaskMode.bits();
 
-    const MaskBorderSource = 1 << 7;
-    const MaskBorderMode = 1 << 8;
-    const MaskBorderSlice = 1 << 9;
-    const MaskBorderWidth = 1 << 10;
-    const MaskBorderOutset = 1 << 11;
-    const MaskBorderRepeat = 1 << 12;
+    const MaskBorderSource = 1 << 8;
+    const MaskBorderMode = 1 << 9;
+    const MaskBorderSlice = 1 << 10;
+    const MaskBorderWidth = 1 << 11;
+    const MaskBorderOutset = 1 << 12;
+    const MaskBorderRepeat = 1 << 13;
     const MaskBorder = Self::MaskBorderSource.bits() | Self::MaskBorderMode.bits() | Self::MaskBorderSlice.bits() | Self::MaskBorderWidth.bits() | Self::MaskBorderOutset.bits() | Self::MaskBorderRepeat.bits();
   }
 }
@@ -705,6 +705,13 @@ impl<'i> PropertyHandler<'i> for MaskHandler<'i> {
           .insert(MaskProperty::try_from(&val.property_id).unwrap());
         dest.push(Property::Unparsed(unparsed));
       }
+      Property::WebKitMaskComposite(..) | Property::WebKitMaskSourceType(..) => {
+        // These are emitted as-is, but flush first so that a later unprefixed
+        // mask-composite or mask-mode is not moved before them.
+        self.flush(dest, context);
+        dest.push(property.clone());
+        return true;
+      }
       Property::MaskBorderSource(val) => property!(border_source, val, &VendorPrefix::None),
       Property::WebKitMaskBoxImageSource(val, _) => property!(border_source, val, &VendorPrefix::WebKit),
       Property::MaskBorderMode(val) => self.border_mode = Some(val.clone()),
@@ -860,6 +867,21 @@ impl<'i> MaskHandler<'i> {
           }
         }
 
+        // The unprefixed shorthand resets mask-composite and mask-mode, which
+        // overrides earlier -webkit- declarations in browsers that support it.
+        if prefix.contains(VendorPrefix::None) && context.targets.browsers.is_some() {
+          remove_webkit_properties(
+            !context
+              .targets
+              .prefixes(VendorPrefix::None, Feature::MaskComposite)
+              .contains(VendorPrefix::WebKit),
+            !context
+              .targets
+              .prefixes(VendorPrefix::None, Feature::Mask)
+              .contains(VendorPrefix::WebKit),
+            dest,
+          );
+        }
         self.flush_mask_shorthand(masks, prefix, dest);
         self.flushed_properties.insert(MaskProperty::Mask);
 
@@ -928,6 +950,12 @@ impl<'i> MaskHandler<'i> {
     prop!(origins, MaskOrigin);
 
     if let Some(composites) = composites {
+      // Earlier -webkit-mask-composite declarations are overridden either by
+      // the unprefixed property or by the prefixed fallback added below.
+      if context.targets.browsers.is_some() {
+        remove_webkit_properties(true, false, dest);
+      }
+
       let prefix = context.targets.prefixes(VendorPrefix::None, Feature::MaskComposite);
       if prefix.contains(VendorPrefix::WebKit) {
         dest.push(Property::WebKitMaskComposite(
@@ -940,6 +968,10 @@ impl<'i> MaskHandler<'i> {
     }
 
     if let Some(modes) = modes {
+      if context.targets.browsers.is_some() {
+        remove_webkit_properties(false, true, dest);
+      }
+
       let prefix = context.targets.prefixes(VendorPrefix::None, Feature::Mask);
       if prefix.contains(VendorPrefix::WebKit) {
         dest.push(Property::WebKitMaskSourceType(
@@ -1137,6 +1169,15 @@ impl<'i> MaskHandler<'i> {
   }
 }
 
+/// Removes earlier `-webkit-mask-composite` and/or `-webkit-mask-source-type` declarations.
+fn remove_webkit_properties(composite: bool, mode: bool, dest: &mut DeclarationList) {
+  dest.retain(|property| match property {
+    Property::WebKitMaskComposite(..) => !composite,
+    Property::WebKitMaskSourceType(..) => !mode,
+    _ => true,
+  });
+}
+
 #[inline]
 fn is_mask_property(property_id: &PropertyId) -> bool {
   match property_id {
@@ -110,8 +110,10 @@ pub(crate) mod margin_padding;
 pub mod masking;
 pub mod outline;
 pub mod overflow;
+pub mod overscroll;
 pub mod position;
 pub(crate) mod prefix_handler;
+pub mod shape;
 pub mod size;
 pub mod svg;
 pub mod text;
@@ -120,7 +122,7 @@ pub mod transition;
 pub mod ui;
 
 use crate::declaration::DeclarationBlock;
-use crate::error::{ParserError, PrinterError};
+use crate::error::{Error, ParserError, PrinterError};
 use crate::logical::{LogicalGroup, PropertyCategory};
 use crate::macros::enum_property;
 use crate::parser::starts_with_ignore_ascii_case;
@@ -159,6 +161,8 @@ use margin_padding::*;
 use masking::*;
 use outline::*;
 use overflow::*;
+use overscroll::*;
+use shape::*;
 use size::*;
 use smallvec::{smallvec, SmallVec};
 #[cfg(feature = "into_owned")]
@@ -225,7 +229,12 @@ macro_rules! define_properties {
           (VendorPrefix::None, name_ref)
         };
 
+        // Some legacy properties such as -webkit-mask-composite are only defined with a prefix.
         Self::from_name_and_prefix(name_ref, prefix)
+          .or_else(|_| match prefix {
+            VendorPrefix::None => Err(()),
+            _ => Self::from_name_and_prefix(name.as_ref(), VendorPrefix::None),
+          })
           .unwrap_or_else(|_| PropertyId::Custom(name.into()))
       }
     }
@@ -398,7 +407,8 @@ macro_rules! define_properties {
         }
       }
 
-      /// Returns whether a property is a shorthand.
+      /// Returns whether a property is a shorthand. See [longhands](PropertyId::longhands) to
+      /// get the properties it expands to.
       pub fn is_shorthand(&self) -> bool {
         $(
           macro_rules! shorthand {
@@ -452,7 +462,10 @@ macro_rules! define_properties {
         shorthand_value(self, decls)
       }
 
-      /// Returns a list of longhand property ids for a shorthand.
+      /// Returns a list of longhand property ids for a shorthand, or `None` if this is not a shorthand.
+      ///
+      /// The longhands of a vendor prefixed shorthand have the same prefix, e.g. `-webkit-flex-flow`
+      /// expands to `-webkit-flex-direction` and `-webkit-flex-wrap`.
       pub fn longhands(&self) -> Option<Vec<PropertyId<'static>>> {
         macro_rules! prefix_default {
           ($x: ty, $p: ident) => {
@@ -716,6 +729,24 @@ macro_rules! define_properties {
           _ => {}
         };
 
+        // If the whole value is an env() reference with a known value, parse the property from that value
+        // so that it is fully typed. Otherwise, env() references are substituted in the unparsed tokens below.
+        if !options.environment_variables.is_empty() {
+          input.reset(&state);
+          if let Ok(value) = input.try_parse(|input| {
+            let env = EnvironmentVariable::parse(input, options, 0)?;
+            input.expect_exhausted()?;
+            env.value(options).ok_or_else(|| input.new_custom_error(ParserError::InvalidValue))
+          }) {
+            let options = options.without_environment_variables();
+            let mut input = ParserInput::new(value);
+            let mut parser = Parser::new(&mut input);
+            if let Ok(property) = parser.parse_entirely(|input| Property::parse(property_id.clone(), input, &options)) {
+              return Ok(property);
+            }
+          }
+        }
+
         // If a value was unable to be parsed, treat as an unparsed property.
         // This is different from a custom property, handled below, in that the property name is known
         // and stored as an enum rather than a string. This lets property handlers more easily deal with it.
@@ -746,6 +777,19 @@ macro_rules! define_properties {
         Self::parse(property_id, &mut parser, &options)
       }
 
+      /// Parses a CSS property value from a string, ensuring that the entire input is consumed.
+      ///
+      /// Unlike [parse_string](Property::parse_string), the error includes the filename and location
+      /// from the options. Any warnings are appended to the `warnings` list in the options. This is
+      /// useful for fuzzing the parser of an individual property.
+      pub fn parse_value_string(property_id: PropertyId<'i>, input: &'i str, options: ParserOptions<'_, 'i>) -> Result<Self, Error<ParserError<'i>>> {
+        let mut input = ParserInput::new(input);
+        let mut parser = Parser::new(&mut input);
+        parser
+          .parse_entirely(|input| Self::parse(property_id, input, &options))
+          .map_err(|err| Error::from(err, options.filename.clone()))
+      }
+
       /// Sets the vendor prefixes for this property.
       ///
       /// If the property doesn't support vendor prefixes, this function does nothing.
@@ -1237,6 +1281,15 @@ define_properties! {
   "overflow-x": OverflowX(OverflowKeyword),
   "overflow-y": OverflowY(OverflowKeyword),
   "text-overflow": TextOverflow(TextOverflow, VendorPrefix) / O,
+  // https://drafts.csswg.org/css-overflow-4/#line-clamp
+  "line-clamp": LineClamp(LineClamp, VendorPrefix) / WebKit,
+
+  // https://drafts.csswg.org/css-overscroll-1/
+  "overscroll-behavior": OverscrollBehavior(OverscrollBehavior) shorthand: true,
+  "overscroll-behavior-x": OverscrollBehaviorX(OverscrollBehaviorKeyword) [logical_group: OverscrollBehavior, category: Physical],
+  "overscroll-behavior-y": OverscrollBehaviorY(OverscrollBehaviorKeyword) [logical_group: OverscrollBehavior, category: Physical],
+  "overscroll-behavior-inline": OverscrollBehaviorInline(OverscrollBehaviorKeyword) [logical_group: OverscrollBehavior, category: Logical],
+  "overscroll-behavior-block": OverscrollBehaviorBlock(OverscrollBehaviorKeyword) [logical_group: OverscrollBehavior, category: Logical],
 
   // https://www.w3.org/TR/2020/WD-css-position-3-20200519
   "position": Position(position::Position),
@@ -1452,6 +1505,7 @@ define_properties! {
   "transition-duration": TransitionDuration(SmallVec<[Time; 1]>, VendorPrefix) / WebKit / Moz / Ms,
   "transition-delay": TransitionDelay(SmallVec<[Time; 1]>, VendorPrefix) / WebKit / Moz / Ms,
   "transition-timing-function": TransitionTimingFunction(SmallVec<[EasingFunction; 1]>, VendorPrefix) / WebKit / Moz / Ms,
+  "transition-behavior": TransitionBehavior(SmallVec<[TransitionBehavior; 1]>),
   "transition": Transition(SmallVec<[Transition<'i>; 1]>, VendorPrefix) / WebKit / Moz / Ms shorthand: true,
 
   "animation-name": AnimationName(AnimationNameList<'i>, VendorPrefix) / WebKit / Moz / O,
@@ -1481,9 +1535,16 @@ define_properties! {
   "rotate": Rotate(Rotate),
   "scale": Scale(Scale),
 
+  // https://drafts.fxtf.org/motion-1/
+  "offset-path": OffsetPath(OffsetPath<'i>),
+
   // https://www.w3.org/TR/2021/CRD-css-text-3-20210422
   "text-transform": TextTransform(TextTransform),
-  "white-space": WhiteSpace(WhiteSpace),
+  "white-space-collapse": WhiteSpaceCollapse(WhiteSpaceCollapse),
+  "text-wrap-mode": TextWrapMode(TextWrapMode),
+  "text-wrap-style": TextWrapStyle(TextWrapStyle),
+  "text-wrap": TextWrap(TextWrap) shorthand: true,
+  "white-space": WhiteSpace(WhiteSpace) shorthand: true,
   "tab-size": TabSize(LengthOrNumber, VendorPrefix) / Moz / O,
   "word-break": WordBreak(WordBreak),
   "line-break": LineBreak(LineBreak),
@@ -1562,6 +1623,8 @@ define_properties! {
   "shape-rendering": ShapeRendering(ShapeRendering),
   "text-rendering": TextRendering(TextRendering),
   "image-rendering": ImageRendering(ImageRendering),
+  // https://www.w3.org/TR/SVG2/paths.html#TheDProperty
+  "d": D(SVGPath),
 
   // https://www.w3.org/TR/css-masking-1/
   "clip-path": ClipPath(ClipPath<'i>, VendorPrefix) / WebKit,
@@ -1596,6 +1659,9 @@ define_properties! {
   "mask-box-image-outset": WebKitMaskBoxImageOutset(Rect<LengthOrNumber>, VendorPrefix) / WebKit unprefixed: false,
   "mask-box-image-repeat": WebKitMaskBoxImageRepeat(BorderImageRepeat, VendorPrefix) / WebKit unprefixed: false,
 
+  // https://www.w3.org/TR/css-shapes-1/
+  "shape-outside": ShapeOutside(ShapeOutside<'i>),
+
   // https://drafts.fxtf.org/filter-effects-1/
   "filter": Filter(FilterList<'i>, VendorPrefix) / WebKit,
   "backdrop-filter": BackdropFilter(FilterList<'i>, VendorPrefix) / WebKit,
@@ -1603,10 +1669,15 @@ define_properties! {
   // https://drafts.csswg.org/css2/
   "z-index": ZIndex(position::ZIndex),
 
+  // https://drafts.csswg.org/css-position-4/
+  "overlay": Overlay(position::Overlay),
+
   // https://drafts.csswg.org/css-contain-3/
   "container-type": ContainerType(ContainerType),
   "container-name": ContainerName(ContainerNameList<'i>),
   "container": Container(Container<'i>) shorthand: true,
+  "contain": Contain(Contain),
+  "content-visibility": ContentVisibility(ContentVisibility),
 
   // https://w3c.github.io/csswg-drafts/css-view-transitions-1/
   "view-transition-name": ViewTransitionName(ViewTransitionName<'i>),
@@ -1619,6 +1690,267 @@ define_properties! {
   "print-color-adjust": PrintColorAdjust(PrintColorAdjust, VendorPrefix) / WebKit,
 }
 
+impl<'i> Property<'i> {
+  /// Returns the [initial value](https://drafts.csswg.org/css-cascade/#initial-values) of the given property.
+  ///
+  /// Vendor prefixes are preserved. Returns `None` for unknown and custom properties, for properties
+  /// whose initial value depends on the user agent (e.g. `font-family`), and for properties whose
+  /// initial value cannot be represented by their parsed value type (e.g. `box-shadow: none`).
+  pub fn initial_value(property_id: &PropertyId<'i>) -> Option<Property<'i>> {
+    let value = initial_value_str(property_id)?;
+    match Property::parse_string(property_id.clone(), value, ParserOptions::default()) {
+      Ok(Property::Unparsed(_)) | Err(_) => None,
+      Ok(property) => Some(property),
+    }
+  }
+}
+
+fn initial_value_str(property_id: &PropertyId) -> Option<&'static str> {
+  let value = match property_id.name() {
+    "background-color" => "transparent",
+    "background-position-x" | "background-position-y" | "mask-position-x" | "mask-position-y" => "0%",
+    "background-position" | "mask-position" => "0% 0%",
+    "background-size" | "mask-size" => "auto",
+    "background-repeat" | "mask-repeat" => "repeat",
+    "background-attachment" => "scroll",
+    "background-clip" | "mask-clip" | "mask-origin" => "border-box",
+    "background-origin" => "padding-box",
+    "opacity" | "fill-opacity" | "stroke-opacity" => "1",
+    "color" => "canvastext",
+    "display" => "inline",
+    "visibility" | "overflow" | "overflow-x" | "overflow-y" | "backface-visibility" | "content-visibility" => {
+      "visible"
+    }
+    "width" | "height" | "min-width" | "min-height" | "block-size" | "inline-size" | "min-block-size"
+    | "min-inline-size" | "aspect-ratio" => "auto",
+    "max-width" | "max-height" | "max-block-size" | "max-inline-size" => "none",
+    "box-sizing" => "content-box",
+    "text-overflow" => "clip",
+    "line-clamp" => "none",
+    "overlay" => "none",
+    "overscroll-behavior"
+    | "overscroll-behavior-x"
+    | "overscroll-behavior-y"
+    | "overscroll-behavior-inline"
+    | "overscroll-behavior-block" => "auto",
+    "position" => "static",
+    "top" | "bottom" | "left" | "right" | "inset-block-start" | "inset-block-end" | "inset-inline-start"
+    | "inset-inline-end" | "inset-block" | "inset-inline" | "inset" => "auto",
+    "border-spacing" => "0",
+    "border-top-color"
+    | "border-bottom-color"
+    | "border-left-color"
+    | "border-right-color"
+    | "border-block-start-color"
+    | "border-block-end-color"
+    | "border-inline-start-color"
+    | "border-inline-end-color"
+    | "border-color"
+    | "border-block-color"
+    | "border-inline-color"
+    | "outline-color"
+    | "text-decoration-color"
+    | "text-emphasis-color" => "currentcolor",
+    "border-top-style"
+    | "border-bottom-style"
+    | "border-left-style"
+    | "border-right-style"
+    | "border-block-start-style"
+    | "border-block-end-style"
+    | "border-inline-start-style"
+    | "border-inline-end-style"
+    | "border-style"
+    | "border-block-style"
+    | "border-inline-style"
+    | "outline-style" => "none",
+    "border-top-width"
+    | "border-bottom-width"
+    | "border-left-width"
+    | "border-right-width"
+    | "border-block-start-width"
+    | "border-block-end-width"
+    | "border-inline-start-width"
+    | "border-inline-end-width"
+    | "border-width"
+    | "border-block-width"
+    | "border-inline-width"
+    | "outline-width" => "medium",
+    "border"
+    | "border-top"
+    | "border-bottom"
+    | "border-left"
+    | "border-right"
+    | "border-block"
+    | "border-block-start"
+    | "border-block-end"
+    | "border-inline"
+    | "border-inline-start"
+    | "border-inline-end"
+    | "outline" => "medium none currentcolor",
+    "border-top-left-radius"
+    | "border-top-right-radius"
+    | "border-bottom-left-radius"
+    | "border-bottom-right-radius"
+    | "border-start-start-radius"
+    | "border-start-end-radius"
+    | "border-end-start-radius"
+    | "border-end-end-radius"
+    | "border-radius" => "0",
+    "border-image-outset" | "mask-border-outset" | "mask-box-image-outset" => "0",
+    "border-image-repeat" | "mask-border-repeat" | "mask-box-image-repeat" => "stretch",
+    "border-image-width" => "1",
+    "border-image-slice" => "100%",
+    "mask-border-slice" | "mask-box-image-slice" => "0",
+    "mask-border-width" | "mask-box-image-width" => "auto",
+    "flex-direction" => "row",
+    "flex-wrap" => "nowrap",
+    "flex-flow" => "row nowrap",
+    "flex-grow" | "flex-positive" | "box-flex" | "order" | "flex-order" => "0",
+    "flex-shrink" | "flex-negative" | "box-ordinal-group" | "box-flex-group" => "1",
+    "flex-basis" | "flex-preferred-size" => "auto",
+    "flex" => "0 1 auto",
+    "align-content" | "justify-content" | "place-content" | "align-items" | "row-gap" | "column-gap" | "gap" => {
+      "normal"
+    }
+    "align-self" | "justify-self" | "place-self" | "flex-item-align" => "auto",
+    "box-orient" => "inline-axis",
+    "box-direction" => "normal",
+    "box-align" | "flex-align" | "flex-line-pack" => "stretch",
+    "box-pack" | "flex-pack" => "start",
+    "box-lines" => "single",
+    "grid-template-columns" | "grid-template-rows" | "grid-template-areas" | "grid-template" | "grid" => "none",
+    "grid-auto-columns" | "grid-auto-rows" => "auto",
+    "grid-auto-flow" => "row",
+    "grid-row-start" | "grid-row-end" | "grid-column-start" | "grid-column-end" | "grid-row" | "grid-column"
+    | "grid-area" => "auto",
+    "margin-top"
+    | "margin-bottom"
+    | "margin-left"
+    | "margin-right"
+    | "margin-block-start"
+    | "margin-block-end"
+    | "margin-inline-start"
+    | "margin-inline-end"
+    | "margin-block"
+    | "margin-inline"
+    | "margin" => "0",
+    "padding-top"
+    | "padding-bottom"
+    | "padding-left"
+    | "padding-right"
+    | "padding-block-start"
+    | "padding-block-end"
+    | "padding-inline-start"
+    | "padding-inline-end"
+    | "padding-block"
+    | "padding-inline"
+    | "padding" => "0",
+    "scroll-margin-top"
+    | "scroll-margin-bottom"
+    | "scroll-margin-left"
+    | "scroll-margin-right"
+    | "scroll-margin-block-start"
+    | "scroll-margin-block-end"
+    | "scroll-margin-inline-start"
+    | "scroll-margin-inline-end"
+    | "scroll-margin-block"
+    | "scroll-margin-inline"
+    | "scroll-margin" => "0",
+    "scroll-padding-top"
+    | "scroll-padding-bottom"
+    | "scroll-padding-left"
+    | "scroll-padding-right"
+    | "scroll-padding-block-start"
+    | "scroll-padding-block-end"
+    | "scroll-padding-inline-start"
+    | "scroll-padding-inline-end"
+    | "scroll-padding-block"
+    | "scroll-padding-inline"
+    | "scroll-padding" => "auto",
+    "font-weight" | "font-stretch" | "font-style" | "font-variant-caps" | "line-height" => "normal",
+    "font-size" => "medium",
+    "vertical-align" => "baseline",
+    "transition-property" | "transition" => "all",
+    "transition-duration" | "transition-delay" | "animation-duration" | "animation-delay" => "0s",
+    "transition-timing-function" | "animation-timing-function" => "ease",
+    "animation-name" | "animation-fill-mode" | "animation" => "none",
+    "animation-iteration-count" => "1",
+    "animation-direction" | "animation-range-start" | "animation-range-end" | "animation-range" => "normal",
+    "transition-behavior" => "normal",
+    "animation-play-state" => "running",
+    "animation-composition" => "replace",
+    "animation-timeline" => "auto",
+    "transform" | "perspective" | "translate" | "rotate" | "scale" | "offset-path" | "d" => "none",
+    "transform-origin" | "perspective-origin" => "50% 50%",
+    "transform-style" => "flat",
+    "transform-box" => "view-box",
+    "text-transform" => "none",
+    "white-space-collapse" => "collapse",
+    "text-wrap-mode" | "text-wrap" => "wrap",
+    "text-wrap-style" => "auto",
+    "white-space" => "normal",
+    "tab-size" => "8",
+    "word-break" | "overflow-wrap" | "word-wrap" | "word-spacing" | "letter-spacing" | "unicode-bidi" => "normal",
+    "line-break" | "text-align-last" | "text-justify" => "auto",
+    "hyphens" => "manual",
+    "text-align" => "start",
+    "text-indent" => "0",
+    "text-decoration-line" | "text-decoration" => "none",
+    "text-decoration-style" => "solid",
+    "text-decoration-thickness" | "text-decoration-skip-ink" => "auto",
+    "text-emphasis-style" | "text-emphasis" => "none",
+    "text-emphasis-position" => "over right",
+    "text-size-adjust" => "auto",
+    "direction" => "ltr",
+    "box-decoration-break" => "slice",
+    "resize" | "appearance" => "none",
+    "cursor" | "caret-color" | "caret-shape" | "caret" | "user-select" | "accent-color" => "auto",
+    "list-style-type" => "disc",
+    "list-style-position" => "outside",
+    "list-style" => "outside none disc",
+    "marker-side" => "match-self",
+    "fill" => "black",
+    "fill-rule" | "clip-rule" => "nonzero",
+    "stroke" | "stroke-dasharray" => "none",
+    "stroke-width" => "1px",
+    "stroke-linecap" => "butt",
+    "stroke-linejoin" => "miter",
+    "stroke-miterlimit" => "4",
+    "stroke-dashoffset" => "0",
+    "marker-start" | "marker-mid" | "marker-end" | "marker" => "none",
+    "color-interpolation" => "srgb",
+    "color-interpolation-filters" => "linearrgb",
+    "color-rendering" | "shape-rendering" | "text-rendering" | "image-rendering" => "auto",
+    "background-image"
+    | "border-image-source"
+    | "border-image"
+    | "list-style-image"
+    | "clip-path"
+    | "shape-outside"
+    | "mask-image"
+    | "mask"
+    | "mask-border-source"
+    | "mask-border"
+    | "mask-box-image"
+    | "mask-box-image-source"
+    | "background" => "none",
+    "mask-mode" => "match-source",
+    "mask-composite" => "add",
+    "mask-type" => "luminance",
+    "mask-border-mode" => "alpha",
+    "-webkit-mask-composite" => "source-over",
+    "mask-source-type" => "auto",
+    "filter" | "backdrop-filter" | "contain" | "container-name" | "container" => "none",
+    "z-index" => "auto",
+    "container-type" | "color-scheme" | "view-transition-group" => "normal",
+    "view-transition-name" | "view-transition-class" => "none",
+    "print-color-adjust" => "economy",
+    _ => return None,
+  };
+
+  Some(value)
+}
+
 impl<'i, T: smallvec::Array<Item = V>, V: Parse<'i>> Parse<'i> for SmallVec<T> {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     // Copied from cssparser `parse_comma_separated` but using SmallVec instead of Vec.
@@ -1,13 +1,18 @@
 //! CSS properties related to overflow.
 
+use super::display::{Display, DisplayInside, DisplayOutside, DisplayPair};
+use super::flex::BoxOrient;
 use super::{Property, PropertyId};
-use crate::compat::Feature;
+use crate::compat;
 use crate::context::PropertyHandlerContext;
 use crate::declaration::{DeclarationBlock, DeclarationList};
 use crate::error::{ParserError, PrinterError};
 use crate::macros::{define_shorthand, enum_property};
+use crate::prefixes::Feature;
 use crate::printer::Printer;
 use crate::traits::{Parse, PropertyHandler, Shorthand, ToCss};
+use crate::values::number::CSSInteger;
+use crate::vendor_prefix::VendorPrefix;
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
 use cssparser::*;
@@ -119,7 +124,7 @@ impl<'i> PropertyHandler<'i> for OverflowHandler {
     match (x, y) {
       // Only use shorthand syntax if the x and y values are the
       // same or the two-value syntax is supported by all targets.
-      (Some(x), Some(y)) if x == y || context.targets.is_compatible(Feature::OverflowShorthand) => {
+      (Some(x), Some(y)) if x == y || context.targets.is_compatible(compat::Feature::OverflowShorthand) => {
         dest.push(Property::Overflow(Overflow { x, y }))
       }
       _ => {
@@ -134,3 +139,90 @@ impl<'i> PropertyHandler<'i> for OverflowHandler {
     }
   }
 }
+
+/// A value for the [line-clamp](https://drafts.csswg.org/css-overflow-4/#line-clamp) property.
+#[derive(Debug, Clone, Copy, PartialEq, Parse, ToCss)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum LineClamp {
+  /// Content is not clamped.
+  None,
+  /// Content is clamped after the given number of lines.
+  Integer(CSSInteger),
+}
+
+#[derive(Default)]
+pub(crate) struct LineClampHandler {
+  value: Option<(LineClamp, VendorPrefix)>,
+}
+
+impl<'i> PropertyHandler<'i> for LineClampHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::LineClamp(val, prefix) => {
+        if let Some((cur, prefixes)) = &mut self.value {
+          // If the value is the same, combine the prefixes.
+          // If the prefix is the same, then update the value.
+          if val == cur {
+            *prefixes |= *prefix;
+            return true;
+          }
+
+          if prefixes == prefix {
+            *cur = *val;
+            return true;
+          }
+
+          self.finalize(dest, context);
+        }
+
+        self.value = Some((*val, *prefix));
+      }
+      Property::Unparsed(val) if matches!(val.property_id, PropertyId::LineClamp(_)) => {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList, context: &mut PropertyHandlerContext<'i, '_>) {
+    if let Some((value, prefix)) = std::mem::take(&mut self.value) {
+      let prefixes = context.targets.prefixes(prefix, Feature::LineClamp);
+
+      // The legacy -webkit-line-clamp property only applies to -webkit-box containers
+      // with a vertical orientation. Add these unless they were already declared.
+      if prefixes.contains(VendorPrefix::WebKit)
+        && !prefix.contains(VendorPrefix::WebKit)
+        && matches!(value, LineClamp::Integer(_))
+      {
+        if !dest.iter().any(|property| matches!(property, Property::Display(_))) {
+          dest.push(Property::Display(Display::Pair(DisplayPair {
+            outside: DisplayOutside::Block,
+            inside: DisplayInside::Box(VendorPrefix::WebKit),
+            is_list_item: false,
+          })));
+        }
+
+        if !dest.iter().any(|property| matches!(property, Property::BoxOrient(..))) {
+          dest.push(Property::BoxOrient(BoxOrient::Vertical, VendorPrefix::WebKit));
+        }
+      }
+
+      dest.push(Property::LineClamp(value, prefixes));
+    }
+  }
+}
@@ -0,0 +1,151 @@
+//! CSS properties related to overscroll behavior.
+
+use super::{Property, PropertyId};
+use crate::context::PropertyHandlerContext;
+use crate::declaration::{DeclarationBlock, DeclarationList};
+use crate::error::{ParserError, PrinterError};
+use crate::macros::{define_shorthand, enum_property};
+use crate::printer::Printer;
+use crate::traits::{Parse, PropertyHandler, Shorthand, ToCss};
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use cssparser::*;
+
+enum_property! {
+  /// An [overscroll behavior](https://drafts.csswg.org/css-overscroll-1/#overscroll-behavior-properties) keyword,
+  /// as used in the `overscroll-behavior` property and its longhands.
+  pub enum OverscrollBehaviorKeyword {
+    /// Scroll chaining and overscroll effects behave as normal.
+    Auto,
+    /// Scroll chaining is prevented, but local overscroll effects are allowed.
+    Contain,
+    /// Scroll chaining and overscroll effects are both prevented.
+    None,
+  }
+}
+
+define_shorthand! {
+  /// A value for the [overscroll-behavior](https://drafts.csswg.org/css-overscroll-1/#propdef-overscroll-behavior) shorthand property.
+  pub struct OverscrollBehavior {
+    /// The overscroll behavior for the x direction.
+    x: OverscrollBehaviorX(OverscrollBehaviorKeyword),
+    /// The overscroll behavior for the y direction.
+    y: OverscrollBehaviorY(OverscrollBehaviorKeyword),
+  }
+}
+
+impl<'i> Parse<'i> for OverscrollBehavior {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let x = OverscrollBehaviorKeyword::parse(input)?;
+    let y = input.try_parse(OverscrollBehaviorKeyword::parse).unwrap_or(x);
+    Ok(OverscrollBehavior { x, y })
+  }
+}
+
+impl ToCss for OverscrollBehavior {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.x.to_css(dest)?;
+    if self.y != self.x {
+      dest.write_char(' ')?;
+      self.y.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct OverscrollBehaviorHandler {
+  x: Option<OverscrollBehaviorKeyword>,
+  y: Option<OverscrollBehaviorKeyword>,
+  inline: Option<OverscrollBehaviorKeyword>,
+  block: Option<OverscrollBehaviorKeyword>,
+}
+
+impl<'i> PropertyHandler<'i> for OverscrollBehaviorHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    use Property::*;
+
+    // Physical and logical properties may override each other depending on the writing mode,
+    // so flush the other category first in order to preserve their relative order.
+    macro_rules! physical {
+      ($key: ident, $val: expr) => {{
+        if self.inline.is_some() || self.block.is_some() {
+          self.finalize(dest, context);
+        }
+        self.$key = Some($val);
+      }};
+    }
+
+    macro_rules! logical {
+      ($key: ident, $val: expr) => {{
+        if self.x.is_some() || self.y.is_some() {
+          self.finalize(dest, context);
+        }
+        self.$key = Some($val);
+      }};
+    }
+
+    match property {
+      OverscrollBehaviorX(val) => physical!(x, *val),
+      OverscrollBehaviorY(val) => physical!(y, *val),
+      OverscrollBehavior(val) => {
+        physical!(x, val.x);
+        self.y = Some(val.y);
+      }
+      OverscrollBehaviorInline(val) => logical!(inline, *val),
+      OverscrollBehaviorBlock(val) => logical!(block, *val),
+      Unparsed(val)
+        if matches!(
+          val.property_id,
+          PropertyId::OverscrollBehavior
+            | PropertyId::OverscrollBehaviorX
+            | PropertyId::OverscrollBehaviorY
+            | PropertyId::OverscrollBehaviorInline
+            | PropertyId::OverscrollBehaviorBlock
+        ) =>
+      {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList, _: &mut PropertyHandlerContext<'i, '_>) {
+    let x = std::mem::take(&mut self.x);
+    let y = std::mem::take(&mut self.y);
+    let inline = std::mem::take(&mut self.inline);
+    let block = std::mem::take(&mut self.block);
+
+    match (x, y) {
+      (Some(x), Some(y)) => dest.push(Property::OverscrollBehavior(OverscrollBehavior { x, y })),
+      _ => {
+        if let Some(x) = x {
+          dest.push(Property::OverscrollBehaviorX(x))
+        }
+
+        if let Some(y) = y {
+          dest.push(Property::OverscrollBehaviorY(y))
+        }
+      }
+    }
+
+    if let Some(inline) = inline {
+      dest.push(Property::OverscrollBehaviorInline(inline))
+    }
+
+    if let Some(block) = block {
+      dest.push(Property::OverscrollBehaviorBlock(block))
+    }
+  }
+}
@@ -4,6 +4,7 @@ use super::Property;
 use crate::context::PropertyHandlerContext;
 use crate::declaration::DeclarationList;
 use crate::error::{ParserError, PrinterError};
+use crate::macros::enum_property;
 use crate::prefixes::Feature;
 use crate::printer::Printer;
 use crate::traits::{Parse, PropertyHandler, ToCss};
@@ -89,6 +90,16 @@ pub enum ZIndex {
   Integer(CSSInteger),
 }
 
+enum_property! {
+  /// A value for the [overlay](https://drafts.csswg.org/css-position-4/#overlay) property.
+  pub enum Overlay {
+    /// The element is not rendered in the top layer.
+    None,
+    /// The element is rendered in the top layer, if it is in the top layer.
+    Auto,
+  }
+}
+
 #[derive(Default)]
 pub(crate) struct PositionHandler {
   position: Option<Position>,
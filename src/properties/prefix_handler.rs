@@ -178,4 +178,5 @@ define_fallbacks! {
   Stroke,
   CaretColor,
   Caret,
+  AccentColor,
 }
@@ -6,6 +6,7 @@ use crate::printer::Printer;
 use crate::targets::{Browsers, Targets};
 use crate::traits::{FallbackValues, IsCompatible, Parse, ToCss};
 use crate::values::length::LengthPercentage;
+use crate::values::path::PathData;
 use crate::values::{color::CssColor, url::Url};
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
@@ -310,3 +311,48 @@ pub enum ImageRendering {
   /// The UA shall optimize quality over speed.
   OptimizeQuality,
 }
+
+/// A value for the [d](https://www.w3.org/TR/SVG2/paths.html#TheDProperty) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum SVGPath {
+  /// No path.
+  None,
+  /// A `path()` function.
+  Path(PathData),
+}
+
+impl<'i> Parse<'i> for SVGPath {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(SVGPath::None);
+    }
+
+    input.expect_function_matching("path")?;
+    let path = input.parse_nested_block(PathData::parse)?;
+    Ok(SVGPath::Path(path))
+  }
+}
+
+impl ToCss for SVGPath {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      SVGPath::None => dest.write_str("none"),
+      SVGPath::Path(path) => {
+        dest.write_str("path(")?;
+        path.to_css(dest)?;
+        dest.write_char(')')
+      }
+    }
+  }
+}
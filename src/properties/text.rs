@@ -217,20 +217,208 @@ impl ToCss for TextTransform {
 }
 
 enum_property! {
-  /// A value for the [white-space](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#white-space-property) property.
-  pub enum WhiteSpace {
-    /// Sequences of white space are collapsed into a single character.
-    "normal": Normal,
-    /// White space is not collapsed.
-    "pre": Pre,
-    /// White space is collapsed, but no line wrapping occurs.
+  /// A value for the [text-wrap-mode](https://drafts.csswg.org/css-text-4/#text-wrap-mode) property.
+  #[derive(Default)]
+  pub enum TextWrapMode {
+    /// Lines may break at allowed soft wrap opportunities.
+    #[default]
+    "wrap": Wrap,
+    /// Lines do not break at soft wrap opportunities.
     "nowrap": NoWrap,
-    /// White space is preserved, but line wrapping occurs.
-    "pre-wrap": PreWrap,
-    /// Like pre-wrap, but with different line breaking rules.
-    "break-spaces": BreakSpaces,
-    /// White space is collapsed, but with different line breaking rules.
-    "pre-line": PreLine,
+  }
+}
+
+enum_property! {
+  /// A value for the [text-wrap-style](https://drafts.csswg.org/css-text-4/#text-wrap-style) property.
+  #[derive(Default)]
+  pub enum TextWrapStyle {
+    /// The UA chooses line breaks, prioritizing speed over quality.
+    #[default]
+    Auto,
+    /// Line lengths are balanced across all lines of the block.
+    Balance,
+    /// Lines before the one being edited do not change when editing content.
+    Stable,
+    /// Line breaks are chosen to produce better layout, prioritizing quality over speed.
+    Pretty,
+  }
+}
+
+enum_property! {
+  /// A value for the [white-space-collapse](https://drafts.csswg.org/css-text-4/#white-space-collapsing) property.
+  #[derive(Default)]
+  pub enum WhiteSpaceCollapse {
+    /// Sequences of white space are collapsed into a single character.
+    #[default]
+    Collapse,
+    /// White space and segment breaks are discarded.
+    Discard,
+    /// White space and segment breaks are preserved.
+    Preserve,
+    /// Segment breaks are preserved, but other white space is collapsed.
+    PreserveBreaks,
+    /// White space is preserved, but segment breaks are collapsed.
+    PreserveSpaces,
+    /// Like preserve, but preserved white space always takes up space.
+    BreakSpaces,
+  }
+}
+
+define_shorthand! {
+  /// A value for the [white-space](https://drafts.csswg.org/css-text-4/#white-space-property) shorthand property.
+  pub struct WhiteSpace {
+    /// How white space is collapsed.
+    collapse: WhiteSpaceCollapse(WhiteSpaceCollapse),
+    /// Whether lines may wrap.
+    wrap_mode: TextWrapMode(TextWrapMode),
+  }
+}
+
+impl WhiteSpace {
+  /// Returns the legacy keyword for this value, if any. Values without a legacy keyword
+  /// can only be written with the multi-value syntax from CSS Text 4.
+  fn legacy_keyword(&self) -> Option<&'static str> {
+    match (&self.collapse, &self.wrap_mode) {
+      (WhiteSpaceCollapse::Collapse, TextWrapMode::Wrap) => Some("normal"),
+      (WhiteSpaceCollapse::Collapse, TextWrapMode::NoWrap) => Some("nowrap"),
+      (WhiteSpaceCollapse::Preserve, TextWrapMode::Wrap) => Some("pre-wrap"),
+      (WhiteSpaceCollapse::Preserve, TextWrapMode::NoWrap) => Some("pre"),
+      (WhiteSpaceCollapse::PreserveBreaks, TextWrapMode::Wrap) => Some("pre-line"),
+      (WhiteSpaceCollapse::BreakSpaces, TextWrapMode::Wrap) => Some("break-spaces"),
+      _ => None,
+    }
+  }
+}
+
+impl<'i> Parse<'i> for WhiteSpace {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    // Legacy keywords that cannot be expressed as a single longhand value.
+    if let Ok(value) = input.try_parse(|input| -> Result<_, ParseError<'i, ParserError<'i>>> {
+      let location = input.current_source_location();
+      let ident = input.expect_ident()?;
+      let (collapse, wrap_mode) = match_ignore_ascii_case! { ident,
+        "normal" => (WhiteSpaceCollapse::Collapse, TextWrapMode::Wrap),
+        "pre" => (WhiteSpaceCollapse::Preserve, TextWrapMode::NoWrap),
+        "pre-wrap" => (WhiteSpaceCollapse::Preserve, TextWrapMode::Wrap),
+        "pre-line" => (WhiteSpaceCollapse::PreserveBreaks, TextWrapMode::Wrap),
+        _ => return Err(location.new_unexpected_token_error(cssparser::Token::Ident(ident.clone())))
+      };
+      Ok(WhiteSpace { collapse, wrap_mode })
+    }) {
+      return Ok(value);
+    }
+
+    let mut collapse = None;
+    let mut wrap_mode = None;
+
+    loop {
+      if collapse.is_none() {
+        if let Ok(val) = input.try_parse(WhiteSpaceCollapse::parse) {
+          collapse = Some(val);
+          continue;
+        }
+      }
+
+      if wrap_mode.is_none() {
+        if let Ok(val) = input.try_parse(TextWrapMode::parse) {
+          wrap_mode = Some(val);
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    if collapse.is_none() && wrap_mode.is_none() {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    Ok(WhiteSpace {
+      collapse: collapse.unwrap_or_default(),
+      wrap_mode: wrap_mode.unwrap_or_default(),
+    })
+  }
+}
+
+impl ToCss for WhiteSpace {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if let Some(keyword) = self.legacy_keyword() {
+      return dest.write_str(keyword);
+    }
+
+    // All values with the initial collapse value have a legacy keyword.
+    self.collapse.to_css(dest)?;
+    if self.wrap_mode != TextWrapMode::Wrap {
+      dest.write_char(' ')?;
+      self.wrap_mode.to_css(dest)?;
+    }
+
+    Ok(())
+  }
+}
+
+define_shorthand! {
+  /// A value for the [text-wrap](https://drafts.csswg.org/css-text-4/#text-wrap) shorthand property.
+  pub struct TextWrap {
+    /// Whether lines may wrap.
+    mode: TextWrapMode(TextWrapMode),
+    /// How lines are wrapped.
+    style: TextWrapStyle(TextWrapStyle),
+  }
+}
+
+impl<'i> Parse<'i> for TextWrap {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut mode = None;
+    let mut style = None;
+
+    loop {
+      if mode.is_none() {
+        if let Ok(val) = input.try_parse(TextWrapMode::parse) {
+          mode = Some(val);
+          continue;
+        }
+      }
+
+      if style.is_none() {
+        if let Ok(val) = input.try_parse(TextWrapStyle::parse) {
+          style = Some(val);
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    if mode.is_none() && style.is_none() {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    Ok(TextWrap {
+      mode: mode.unwrap_or_default(),
+      style: style.unwrap_or_default(),
+    })
+  }
+}
+
+impl ToCss for TextWrap {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.style == TextWrapStyle::Auto {
+      return self.mode.to_css(dest);
+    }
+
+    if self.mode != TextWrapMode::Wrap {
+      self.mode.to_css(dest)?;
+      dest.write_char(' ')?;
+    }
+
+    self.style.to_css(dest)
   }
 }
 
@@ -1355,6 +1543,103 @@ impl<'i> PropertyHandler<'i> for TextDecorationHandler<'i> {
   }
 }
 
+/// Handles the `text-wrap` and `white-space` shorthands, which share the `text-wrap-mode` longhand.
+#[derive(Default)]
+pub(crate) struct TextWrapHandler {
+  collapse: Option<WhiteSpaceCollapse>,
+  mode: Option<TextWrapMode>,
+  style: Option<TextWrapStyle>,
+}
+
+impl<'i> PropertyHandler<'i> for TextWrapHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::TextWrapMode(val) => self.mode = Some(*val),
+      Property::TextWrapStyle(val) => self.style = Some(*val),
+      Property::TextWrap(val) => {
+        self.mode = Some(val.mode);
+        self.style = Some(val.style);
+      }
+      Property::WhiteSpaceCollapse(val) => self.collapse = Some(*val),
+      Property::WhiteSpace(val) => {
+        self.collapse = Some(val.collapse);
+        self.mode = Some(val.wrap_mode);
+      }
+      Property::Unparsed(val)
+        if matches!(
+          val.property_id,
+          PropertyId::TextWrapMode
+            | PropertyId::TextWrapStyle
+            | PropertyId::TextWrap
+            | PropertyId::WhiteSpaceCollapse
+            | PropertyId::WhiteSpace
+        ) =>
+      {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList, context: &mut PropertyHandlerContext<'i, '_>) {
+    let collapse = std::mem::take(&mut self.collapse);
+    let mut mode = std::mem::take(&mut self.mode);
+    let style = std::mem::take(&mut self.style);
+
+    // Older browsers support text-wrap with a style keyword, but not the text-wrap-style longhand.
+    // In that case, the mode must be kept so that the text-wrap shorthand can be written.
+    let needs_text_wrap_shorthand =
+      style.is_some() && !context.targets.is_compatible(compat::Feature::TextWrapStyle);
+
+    if let (Some(collapse), Some(wrap_mode)) = (collapse, mode) {
+      // Legacy keywords are supported everywhere. Otherwise, the multi-value
+      // syntax must be supported by all targets to use the shorthand.
+      let white_space = WhiteSpace { collapse, wrap_mode };
+      if white_space.legacy_keyword().is_some()
+        || context.targets.is_compatible(compat::Feature::WhiteSpaceShorthand)
+      {
+        dest.push(Property::WhiteSpace(white_space));
+        if !needs_text_wrap_shorthand {
+          mode = None;
+        }
+      } else {
+        dest.push(Property::WhiteSpaceCollapse(collapse));
+      }
+    } else if let Some(collapse) = collapse {
+      dest.push(Property::WhiteSpaceCollapse(collapse));
+    }
+
+    match (mode, style) {
+      // Only use the shorthand if it can be written as a single keyword,
+      // or the two value syntax is supported by all targets.
+      (Some(mode), Some(style))
+        if mode == TextWrapMode::Wrap
+          || style == TextWrapStyle::Auto
+          || context.targets.is_compatible(compat::Feature::TextWrapShorthand) =>
+      {
+        dest.push(Property::TextWrap(TextWrap { mode, style }))
+      }
+      _ => {
+        if let Some(mode) = mode {
+          dest.push(Property::TextWrapMode(mode))
+        }
+
+        if let Some(style) = style {
+          dest.push(Property::TextWrapStyle(style))
+        }
+      }
+    }
+  }
+}
+
 /// A value for the [text-shadow](https://www.w3.org/TR/2020/WD-css-text-decor-4-20200506/#text-shadow-property) property.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "visitor", derive(Visit))]
@@ -1,6 +1,8 @@
 //! CSS properties related to 2D and 3D transforms.
 
+use super::masking::GeometryBox;
 use super::{Property, PropertyId};
+use crate::compat;
 use crate::context::PropertyHandlerContext;
 use crate::declaration::DeclarationList;
 use crate::error::{ParserError, PrinterError};
@@ -13,6 +15,8 @@ use crate::values::{
   angle::Angle,
   length::{Length, LengthPercentage},
   percentage::NumberOrPercentage,
+  shape::BasicShape,
+  url::Url,
 };
 use crate::vendor_prefix::VendorPrefix;
 #[cfg(feature = "visitor")]
@@ -1504,12 +1508,20 @@ impl ToCss for Translate {
 }
 
 impl Translate {
+  fn is_identity(&self) -> bool {
+    match self {
+      Translate::None => true,
+      Translate::XYZ { x, y, z } => x.is_zero() && y.is_zero() && z.is_zero(),
+    }
+  }
+
   /// Converts the translation to a transform function.
   pub fn to_transform(&self) -> Transform {
     match self {
       Translate::None => {
         Transform::Translate3d(LengthPercentage::zero(), LengthPercentage::zero(), Length::zero())
       }
+      Translate::XYZ { x, y, z } if z.is_zero() => Transform::Translate(x.clone(), y.clone()),
       Translate::XYZ { x, y, z } => Transform::Translate3d(x.clone(), y.clone(), z.clone()),
     }
   }
@@ -1596,8 +1608,15 @@ impl ToCss for Rotate {
 }
 
 impl Rotate {
+  fn is_identity(&self) -> bool {
+    self.angle.is_zero()
+  }
+
   /// Converts the rotation to a transform function.
   pub fn to_transform(&self) -> Transform {
+    if self.x == 0.0 && self.y == 0.0 && self.z == 1.0 {
+      return Transform::Rotate(self.angle.clone());
+    }
     Transform::Rotate3d(self.x, self.y, self.z, self.angle.clone())
   }
 }
@@ -1678,6 +1697,16 @@ impl ToCss for Scale {
 }
 
 impl Scale {
+  fn is_identity(&self) -> bool {
+    match self {
+      Scale::None => true,
+      Scale::XYZ { x, y, z } => [x, y, z].into_iter().all(|v| {
+        let v: f32 = v.into();
+        v == 1.0
+      }),
+    }
+  }
+
   /// Converts the scale to a transform function.
   pub fn to_transform(&self) -> Transform {
     match self {
@@ -1686,11 +1715,94 @@ impl Scale {
         NumberOrPercentage::Number(1.0),
         NumberOrPercentage::Number(1.0),
       ),
+      Scale::XYZ { x, y, z } if Into::<f32>::into(z) == 1.0 => Transform::Scale(x.clone(), y.clone()),
       Scale::XYZ { x, y, z } => Transform::Scale3d(x.clone(), y.clone(), z.clone()),
     }
   }
 }
 
+/// A value for the [offset-path](https://drafts.fxtf.org/motion-1/#offset-path-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum OffsetPath<'i> {
+  /// The element does not follow a path.
+  None,
+  /// A url reference to an SVG shape element.
+  #[cfg_attr(feature = "serde", serde(borrow, with = "crate::serialization::ValueWrapper::<Url>"))]
+  Url(Url<'i>),
+  /// A basic shape, e.g. `path()`, positioned according to the reference box.
+  #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+  Shape {
+    /// A basic shape.
+    shape: Box<BasicShape>,
+    /// A reference box that the shape is positioned according to.
+    reference_box: GeometryBox,
+  },
+  /// A reference box, whose edges form the path.
+  #[cfg_attr(feature = "serde", serde(with = "crate::serialization::ValueWrapper::<GeometryBox>"))]
+  Box(GeometryBox),
+}
+
+impl<'i> Parse<'i> for OffsetPath<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(OffsetPath::None);
+    }
+
+    if let Ok(url) = input.try_parse(Url::parse) {
+      return Ok(OffsetPath::Url(url));
+    }
+
+    if let Ok(shape) = input.try_parse(BasicShape::parse) {
+      let b = input.try_parse(GeometryBox::parse).unwrap_or_default();
+      return Ok(OffsetPath::Shape {
+        shape: Box::new(shape),
+        reference_box: b,
+      });
+    }
+
+    let b = GeometryBox::parse(input)?;
+    if let Ok(shape) = input.try_parse(BasicShape::parse) {
+      return Ok(OffsetPath::Shape {
+        shape: Box::new(shape),
+        reference_box: b,
+      });
+    }
+    Ok(OffsetPath::Box(b))
+  }
+}
+
+impl<'i> ToCss for OffsetPath<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      OffsetPath::None => dest.write_str("none"),
+      OffsetPath::Url(url) => url.to_css(dest),
+      OffsetPath::Shape {
+        shape,
+        reference_box: b,
+      } => {
+        shape.to_css(dest)?;
+        if *b != GeometryBox::default() {
+          dest.write_char(' ')?;
+          b.to_css(dest)?;
+        }
+        Ok(())
+      }
+      OffsetPath::Box(b) => b.to_css(dest),
+    }
+  }
+}
+
 #[derive(Default)]
 pub(crate) struct TransformHandler {
   transform: Option<(TransformList, VendorPrefix)>,
@@ -1709,17 +1821,6 @@ impl<'i> PropertyHandler<'i> for TransformHandler {
   ) -> bool {
     use Property::*;
 
-    macro_rules! individual_property {
-      ($prop: ident, $val: ident) => {
-        if let Some((transform, _)) = &mut self.transform {
-          transform.0.push($val.to_transform())
-        } else {
-          self.$prop = Some($val.clone());
-          self.has_any = true;
-        }
-      };
-    }
-
     match property {
       Transform(val, vp) => {
         // If two vendor prefixes for the same property have different
@@ -1738,14 +1839,21 @@ impl<'i> PropertyHandler<'i> for TransformHandler {
           self.transform = Some((val.clone(), *vp));
           self.has_any = true;
         }
-
-        self.translate = None;
-        self.rotate = None;
-        self.scale = None;
       }
-      Translate(val) => individual_property!(translate, val),
-      Rotate(val) => individual_property!(rotate, val),
-      Scale(val) => individual_property!(scale, val),
+      // The individual transform properties are applied separately from `transform`,
+      // so they are not combined with it unless the targets don't support them.
+      Translate(val) => {
+        self.translate = Some(val.clone());
+        self.has_any = true;
+      }
+      Rotate(val) => {
+        self.rotate = Some(val.clone());
+        self.has_any = true;
+      }
+      Scale(val) => {
+        self.scale = Some(val.clone());
+        self.has_any = true;
+      }
       Unparsed(val)
         if matches!(
           val.property_id,
@@ -1780,11 +1888,30 @@ impl TransformHandler {
     self.has_any = false;
 
     let transform = std::mem::take(&mut self.transform);
-    let translate = std::mem::take(&mut self.translate);
-    let rotate = std::mem::take(&mut self.rotate);
-    let scale = std::mem::take(&mut self.scale);
+    let mut translate = std::mem::take(&mut self.translate);
+    let mut rotate = std::mem::take(&mut self.rotate);
+    let mut scale = std::mem::take(&mut self.scale);
+
+    if let Some((mut transform, prefix)) = transform {
+      // If the targets don't support the individual transform properties, and the full transform
+      // is known, prepend them to the transform list. They are applied in this order, before `transform`.
+      if (translate.is_some() || rotate.is_some() || scale.is_some())
+        && !context.targets.is_compatible(compat::Feature::IndividualTransforms)
+      {
+        let mut functions = Vec::new();
+        if let Some(translate) = translate.take().filter(|t| !t.is_identity()) {
+          functions.push(translate.to_transform());
+        }
+        if let Some(rotate) = rotate.take().filter(|r| !r.is_identity()) {
+          functions.push(rotate.to_transform());
+        }
+        if let Some(scale) = scale.take().filter(|s| !s.is_identity()) {
+          functions.push(scale.to_transform());
+        }
+        functions.append(&mut transform.0);
+        transform.0 = functions;
+      }
 
-    if let Some((transform, prefix)) = transform {
       let prefix = context.targets.prefixes(prefix, Feature::Transform);
       dest.push(Property::Transform(transform, prefix))
     }
@@ -5,7 +5,7 @@ use crate::compat;
 use crate::context::PropertyHandlerContext;
 use crate::declaration::{DeclarationBlock, DeclarationList};
 use crate::error::{ParserError, PrinterError};
-use crate::macros::define_list_shorthand;
+use crate::macros::{define_list_shorthand, enum_property};
 use crate::prefixes::Feature;
 use crate::printer::Printer;
 use crate::properties::masking::get_webkit_mask_property;
@@ -31,6 +31,20 @@ define_list_shorthand! {
     delay: TransitionDelay(Time, VendorPrefix),
     /// The easing function for the transition.
     timing_function: TransitionTimingFunction(EasingFunction, VendorPrefix),
+    /// Whether the transition applies to discretely animated properties.
+    behavior: TransitionBehavior(TransitionBehavior),
+  }
+}
+
+enum_property! {
+  /// A value for the [transition-behavior](https://drafts.csswg.org/css-transitions-2/#transition-behavior-property) property.
+  #[derive(Default)]
+  pub enum TransitionBehavior {
+    /// Transitions are not started for discretely animated properties.
+    #[default]
+    Normal,
+    /// Transitions are started for discretely animated properties.
+    AllowDiscrete,
   }
 }
 
@@ -40,6 +54,7 @@ impl<'i> Parse<'i> for Transition<'i> {
     let mut duration = None;
     let mut delay = None;
     let mut timing_function = None;
+    let mut behavior = None;
 
     loop {
       if duration.is_none() {
@@ -63,6 +78,14 @@ impl<'i> Parse<'i> for Transition<'i> {
         }
       }
 
+      // Parsed before the property, which would otherwise accept the keywords as unknown properties.
+      if behavior.is_none() {
+        if let Ok(value) = input.try_parse(TransitionBehavior::parse) {
+          behavior = Some(value);
+          continue;
+        }
+      }
+
       if property.is_none() {
         if let Ok(value) = input.try_parse(PropertyId::parse) {
           property = Some(value);
@@ -78,6 +101,7 @@ impl<'i> Parse<'i> for Transition<'i> {
       duration: duration.unwrap_or(Time::Seconds(0.0)),
       delay: delay.unwrap_or(Time::Seconds(0.0)),
       timing_function: timing_function.unwrap_or(EasingFunction::Ease),
+      behavior: behavior.unwrap_or_default(),
     })
   }
 }
@@ -103,6 +127,11 @@ impl<'i> ToCss for Transition<'i> {
       self.delay.to_css(dest)?;
     }
 
+    if self.behavior != TransitionBehavior::default() {
+      dest.write_char(' ')?;
+      self.behavior.to_css(dest)?;
+    }
+
     Ok(())
   }
 }
@@ -157,6 +186,7 @@ pub(crate) struct TransitionHandler<'i> {
   durations: Option<(SmallVec<[Time; 1]>, VendorPrefix)>,
   delays: Option<(SmallVec<[Time; 1]>, VendorPrefix)>,
   timing_functions: Option<(SmallVec<[EasingFunction; 1]>, VendorPrefix)>,
+  behaviors: Option<SmallVec<[TransitionBehavior; 1]>>,
   has_any: bool,
 }
 
@@ -206,6 +236,10 @@ impl<'i> PropertyHandler<'i> for TransitionHandler<'i> {
       TransitionDuration(val, vp) => property!(TransitionDuration, durations, val, vp),
       TransitionDelay(val, vp) => property!(TransitionDelay, delays, val, vp),
       TransitionTimingFunction(val, vp) => property!(TransitionTimingFunction, timing_functions, val, vp),
+      TransitionBehavior(val) => {
+        self.behaviors = Some(val.clone());
+        self.has_any = true;
+      }
       Transition(val, vp) => {
         let properties: SmallVec<[PropertyId; 1]> = merge_properties(val.iter().map(|b| &b.property));
         maybe_flush!(properties, &properties, vp);
@@ -220,6 +254,13 @@ impl<'i> PropertyHandler<'i> for TransitionHandler<'i> {
           val.iter().map(|b| b.timing_function.clone()).collect();
         maybe_flush!(timing_functions, &timing_functions, vp);
 
+        // Only the unprefixed transition-behavior property exists. Track it for the unprefixed shorthand,
+        // which resets it, or when a prefixed shorthand (an alias in some browsers) sets it explicitly.
+        if vp.contains(VendorPrefix::None) || val.iter().any(|b| b.behavior != self::TransitionBehavior::Normal) {
+          self.behaviors = Some(val.iter().map(|b| b.behavior).collect());
+          self.has_any = true;
+        }
+
         property!(TransitionProperty, properties, &properties, vp);
         property!(TransitionDuration, durations, &durations, vp);
         property!(TransitionDelay, delays, &delays, vp);
@@ -254,6 +295,7 @@ impl<'i> TransitionHandler<'i> {
     let mut durations = std::mem::take(&mut self.durations);
     let mut delays = std::mem::take(&mut self.delays);
     let mut timing_functions = std::mem::take(&mut self.timing_functions);
+    let mut behaviors = std::mem::take(&mut self.behaviors);
 
     let rtl_properties = if let Some((properties, _)) = &mut properties {
       expand_properties(properties, context)
@@ -273,6 +315,21 @@ impl<'i> TransitionHandler<'i> {
       // prefixes will be handled by outputting individual properties below.
       let intersection = *property_prefixes & *duration_prefixes & *delay_prefixes & *timing_prefixes;
       if !intersection.is_empty() {
+        // Prefixed transition shorthands do not support transition-behavior, and browsers without support
+        // would drop the whole shorthand. In these cases, output transition-behavior separately unless
+        // every value is the default, which the unprefixed shorthand resets to.
+        let shorthand_behaviors = match &behaviors {
+          Some(val)
+            if intersection.contains(VendorPrefix::None)
+              && (val.iter().all(|b| *b == TransitionBehavior::Normal)
+                || (intersection == VendorPrefix::None
+                  && context.targets.is_compatible(compat::Feature::TransitionBehavior))) =>
+          {
+            std::mem::take(&mut behaviors)
+          }
+          _ => None,
+        };
+
         macro_rules! get_transitions {
           ($properties: ident) => {{
             // transition-property determines the number of transitions. The values of other
@@ -281,15 +338,18 @@ impl<'i> TransitionHandler<'i> {
             let mut durations_iter = durations.iter().cycle().cloned();
             let mut delays_iter = delays.iter().cycle().cloned();
             let mut timing_iter = timing_functions.iter().cycle().cloned();
+            let mut behaviors_iter = shorthand_behaviors.iter().flatten().cycle().cloned();
             for property_id in $properties {
               let duration = durations_iter.next().unwrap_or(Time::Seconds(0.0));
               let delay = delays_iter.next().unwrap_or(Time::Seconds(0.0));
               let timing_function = timing_iter.next().unwrap_or(EasingFunction::Ease);
+              let behavior = behaviors_iter.next().unwrap_or_default();
               let transition = Transition {
                 property: property_id.clone(),
                 duration,
                 delay,
                 timing_function,
+                behavior,
               };
 
               // Expand vendor prefixes into multiple transitions.
@@ -353,6 +413,10 @@ impl<'i> TransitionHandler<'i> {
       }
     }
 
+    if let Some(behaviors) = behaviors {
+      dest.push(Property::TransitionBehavior(behaviors));
+    }
+
     self.reset();
   }
 
@@ -361,6 +425,7 @@ impl<'i> TransitionHandler<'i> {
     self.durations = None;
     self.delays = None;
     self.timing_functions = None;
+    self.behaviors = None;
   }
 }
 
@@ -371,6 +436,7 @@ fn is_transition_property(property_id: &PropertyId) -> bool {
     | PropertyId::TransitionDuration(_)
     | PropertyId::TransitionDelay(_)
     | PropertyId::TransitionTimingFunction(_)
+    | PropertyId::TransitionBehavior
     | PropertyId::Transition(_) => true,
     _ => false,
   }
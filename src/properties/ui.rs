@@ -14,7 +14,6 @@ use crate::values::string::CowArcStr;
 use crate::values::url::Url;
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
-use bitflags::bitflags;
 use cssparser::*;
 use smallvec::SmallVec;
 
@@ -414,52 +413,83 @@ impl<'a> schemars::JsonSchema for Appearance<'a> {
   }
 }
 
-bitflags! {
-  /// A value for the [color-scheme](https://drafts.csswg.org/css-color-adjust/#color-scheme-prop) property.
-  #[cfg_attr(feature = "visitor", derive(Visit))]
-  #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(from = "SerializedColorScheme", into = "SerializedColorScheme"))]
-  #[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
-  #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
-  pub struct ColorScheme: u8 {
-    /// Indicates that the element supports a light color scheme.
-    const Light    = 0b01;
-    /// Indicates that the element supports a dark color scheme.
-    const Dark     = 0b10;
-    /// Forbids the user agent from overriding the color scheme for the element.
-    const Only     = 0b100;
+enum_property! {
+  /// A color scheme keyword, as used in the [color-scheme](https://drafts.csswg.org/css-color-adjust/#color-scheme-prop) property.
+  pub enum ColorSchemeKeyword {
+    /// The light color scheme.
+    Light,
+    /// The dark color scheme.
+    Dark,
+  }
+}
+
+/// A value for the [color-scheme](https://drafts.csswg.org/css-color-adjust/#color-scheme-prop) property.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct ColorScheme {
+  /// The color schemes supported by the element, in order of preference.
+  /// An empty list represents the `normal` keyword.
+  pub schemes: SmallVec<[ColorSchemeKeyword; 2]>,
+  /// Forbids the user agent from overriding the color scheme for the element.
+  pub only: bool,
+}
+
+impl ColorScheme {
+  /// Returns whether the given color scheme is supported.
+  pub fn supports(&self, scheme: ColorSchemeKeyword) -> bool {
+    self.schemes.contains(&scheme)
   }
 }
 
 impl<'i> Parse<'i> for ColorScheme {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
-    let mut res = ColorScheme::empty();
-    let ident = input.expect_ident()?;
-    match_ignore_ascii_case! { &ident,
-      "normal" => return Ok(res),
-      "only" => res |= ColorScheme::Only,
-      "light" => res |= ColorScheme::Light,
-      "dark" => res |= ColorScheme::Dark,
-      _ => {}
-    };
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(ColorScheme::default());
+    }
+
+    let mut only = input.try_parse(|input| input.expect_ident_matching("only")).is_ok();
+    let mut schemes = SmallVec::new();
+    let mut has_name = false;
+    loop {
+      let location = input.current_source_location();
+      let ident = match input.try_parse(|input| input.expect_ident_cloned()) {
+        Ok(ident) => ident,
+        Err(_) => break,
+      };
 
-    while let Ok(ident) = input.try_parse(|input| input.expect_ident_cloned()) {
-      match_ignore_ascii_case! { &ident,
-        "normal" => return Err(input.new_custom_error(ParserError::InvalidValue)),
+      let scheme = match_ignore_ascii_case! { &ident,
+        "normal" => return Err(location.new_custom_error(ParserError::InvalidValue)),
         "only" => {
           // Only must be at the start or the end, not in the middle.
-          if res.contains(ColorScheme::Only) {
-            return Err(input.new_custom_error(ParserError::InvalidValue));
+          if only || !has_name {
+            return Err(location.new_custom_error(ParserError::InvalidValue));
           }
-          res |= ColorScheme::Only;
-          return Ok(res);
+          only = true;
+          break;
         },
-        "light" => res |= ColorScheme::Light,
-        "dark" => res |= ColorScheme::Dark,
-        _ => {}
+        "light" => Some(ColorSchemeKeyword::Light),
+        "dark" => Some(ColorSchemeKeyword::Dark),
+        // Unknown color schemes are ignored by browsers.
+        _ => None,
       };
+
+      has_name = true;
+      if let Some(scheme) = scheme {
+        // Duplicates have no effect, and the first occurrence determines the preference.
+        if !schemes.contains(&scheme) {
+          schemes.push(scheme);
+        }
+      }
     }
 
-    Ok(res)
+    if !has_name || (only && schemes.is_empty()) {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(ColorScheme { schemes, only })
   }
 }
 
@@ -468,22 +498,21 @@ impl ToCss for ColorScheme {
   where
     W: std::fmt::Write,
   {
-    if self.is_empty() {
+    if self.schemes.is_empty() {
       return dest.write_str("normal");
     }
 
-    if self.contains(ColorScheme::Light) {
-      dest.write_str("light")?;
-      if self.contains(ColorScheme::Dark) {
+    let mut first = true;
+    for scheme in &self.schemes {
+      if first {
+        first = false;
+      } else {
         dest.write_char(' ')?;
       }
+      scheme.to_css(dest)?;
     }
 
-    if self.contains(ColorScheme::Dark) {
-      dest.write_str("dark")?;
-    }
-
-    if self.contains(ColorScheme::Only) {
+    if self.only {
       dest.write_str(" only")?;
     }
 
@@ -491,50 +520,6 @@ impl ToCss for ColorScheme {
   }
 }
 
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
-struct SerializedColorScheme {
-  light: bool,
-  dark: bool,
-  only: bool,
-}
-
-impl From<ColorScheme> for SerializedColorScheme {
-  fn from(color_scheme: ColorScheme) -> Self {
-    Self {
-      light: color_scheme.contains(ColorScheme::Light),
-      dark: color_scheme.contains(ColorScheme::Dark),
-      only: color_scheme.contains(ColorScheme::Only),
-    }
-  }
-}
-
-impl From<SerializedColorScheme> for ColorScheme {
-  fn from(s: SerializedColorScheme) -> ColorScheme {
-    let mut color_scheme = ColorScheme::empty();
-    color_scheme.set(ColorScheme::Light, s.light);
-    color_scheme.set(ColorScheme::Dark, s.dark);
-    color_scheme.set(ColorScheme::Only, s.only);
-    color_scheme
-  }
-}
-
-#[cfg(feature = "jsonschema")]
-#[cfg_attr(docsrs, doc(cfg(feature = "jsonschema")))]
-impl<'a> schemars::JsonSchema for ColorScheme {
-  fn is_referenceable() -> bool {
-    true
-  }
-
-  fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
-    SerializedColorScheme::json_schema(gen)
-  }
-
-  fn schema_name() -> String {
-    "ColorScheme".into()
-  }
-}
-
 #[derive(Default)]
 pub(crate) struct ColorSchemeHandler;
 
@@ -548,15 +533,15 @@ impl<'i> PropertyHandler<'i> for ColorSchemeHandler {
     match property {
       Property::ColorScheme(color_scheme) => {
         if should_compile!(context.targets, LightDark) {
-          if color_scheme.contains(ColorScheme::Light) {
+          if color_scheme.supports(ColorSchemeKeyword::Light) {
             dest.push(define_var("--lightningcss-light", Token::Ident("initial".into())));
             dest.push(define_var("--lightningcss-dark", Token::WhiteSpace(" ".into())));
 
-            if color_scheme.contains(ColorScheme::Dark) {
+            if color_scheme.supports(ColorSchemeKeyword::Dark) {
               context.add_dark_rule(define_var("--lightningcss-light", Token::WhiteSpace(" ".into())));
               context.add_dark_rule(define_var("--lightningcss-dark", Token::Ident("initial".into())));
             }
-          } else if color_scheme.contains(ColorScheme::Dark) {
+          } else if color_scheme.supports(ColorSchemeKeyword::Dark) {
             dest.push(define_var("--lightningcss-light", Token::WhiteSpace(" ".into())));
             dest.push(define_var("--lightningcss-dark", Token::Ident("initial".into())));
           }
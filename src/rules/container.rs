@@ -497,10 +497,10 @@ impl<'a, 'i, T: ToCss> ToCss for ContainerRule<'i, T> {
 
     dest.whitespace()?;
     dest.write_char('{')?;
-    dest.indent();
+    dest.increase_indent();
     dest.newline()?;
     self.rules.to_css(dest)?;
-    dest.dedent();
+    dest.decrease_indent();
     dest.newline()?;
     dest.write_char('}')
   }
@@ -1,13 +1,20 @@
 //! The `@counter-style` rule.
 
 use super::Location;
-use crate::declaration::DeclarationBlock;
-use crate::error::PrinterError;
+use crate::error::{ParserError, PrinterError};
 use crate::printer::Printer;
-use crate::traits::ToCss;
+use crate::properties::custom::CustomProperty;
+use crate::properties::list::{CounterStyle, PredefinedCounterStyle};
+use crate::stylesheet::ParserOptions;
+use crate::traits::{Parse, ToCss};
 use crate::values::ident::CustomIdent;
+use crate::values::image::Image;
+use crate::values::number::CSSInteger;
+use crate::values::string::CSSString;
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
+use cssparser::*;
+use std::collections::HashSet;
 
 /// A [@counter-style](https://drafts.csswg.org/css-counter-styles/#the-counter-style-rule) rule.
 #[derive(Debug, PartialEq, Clone)]
@@ -19,14 +26,663 @@ pub struct CounterStyleRule<'i> {
   /// The name of the counter style to declare.
   #[cfg_attr(feature = "serde", serde(borrow))]
   pub name: CustomIdent<'i>,
-  // TODO: eventually parse these properties
   /// Declarations in the `@counter-style` rule.
-  pub declarations: DeclarationBlock<'i>,
+  pub properties: Vec<CounterStyleProperty<'i>>,
   /// The location of the rule in the source file.
   #[cfg_attr(feature = "visitor", skip_visit)]
   pub loc: Location,
 }
 
+/// A property within an `@counter-style` rule.
+///
+///  See [CounterStyleRule](CounterStyleRule).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum CounterStyleProperty<'i> {
+  /// The `system` property.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  System(CounterSystem<'i>),
+  /// The `symbols` property.
+  Symbols(Vec<CounterSymbol<'i>>),
+  /// The `additive-symbols` property.
+  AdditiveSymbols(Vec<AdditiveSymbol<'i>>),
+  /// The `negative` property.
+  Negative(Negative<'i>),
+  /// The `prefix` property.
+  Prefix(CounterSymbol<'i>),
+  /// The `suffix` property.
+  Suffix(CounterSymbol<'i>),
+  /// The `range` property.
+  Range(CounterRange),
+  /// The `pad` property.
+  Pad(Pad<'i>),
+  /// The `fallback` property.
+  Fallback(CounterStyle<'i>),
+  /// The `speak-as` property.
+  SpeakAs(SpeakAs<'i>),
+  /// An unknown or unsupported property.
+  Custom(CustomProperty<'i>),
+}
+
+/// A value for the [system](https://drafts.csswg.org/css-counter-styles/#counter-style-system)
+/// property in an `@counter-style` rule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum CounterSystem<'i> {
+  /// Cycles repeatedly through the list of symbols.
+  Cyclic,
+  /// Interprets the symbols as digits of a place-value numbering system.
+  Numeric,
+  /// Interprets the symbols as digits of an alphabetic numbering system.
+  Alphabetic,
+  /// Cycles through the symbols, doubling, tripling, etc. them on each pass.
+  Symbolic,
+  /// Represents counter values using additive tallies.
+  Additive,
+  /// Runs through the list of symbols once, starting from the given first symbol value.
+  Fixed(CSSInteger),
+  /// Uses the algorithm of another counter style, overriding some of its descriptors.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Extends(CounterStyle<'i>),
+}
+
+/// A [`<symbol>`](https://drafts.csswg.org/css-counter-styles/#typedef-symbol) within an `@counter-style` rule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum CounterSymbol<'i> {
+  /// A string.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  String(CSSString<'i>),
+  /// An identifier.
+  Ident(CustomIdent<'i>),
+  /// An image.
+  Image(Image<'i>),
+}
+
+/// A tuple within the [additive-symbols](https://drafts.csswg.org/css-counter-styles/#counter-style-symbols)
+/// property in an `@counter-style` rule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct AdditiveSymbol<'i> {
+  /// The weight of the symbol.
+  pub weight: CSSInteger,
+  /// The symbol.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub symbol: CounterSymbol<'i>,
+}
+
+/// A value for the [negative](https://drafts.csswg.org/css-counter-styles/#counter-style-negative)
+/// property in an `@counter-style` rule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct Negative<'i> {
+  /// The symbol to prepend to negative values.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub prefix: CounterSymbol<'i>,
+  /// The symbol to append to negative values.
+  pub suffix: Option<CounterSymbol<'i>>,
+}
+
+/// A value for the [range](https://drafts.csswg.org/css-counter-styles/#counter-style-range)
+/// property in an `@counter-style` rule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum CounterRange {
+  /// The range depends on the counter system.
+  Auto,
+  /// A list of ranges.
+  Ranges(Vec<CounterRangeBounds>),
+}
+
+/// A single range within the `range` property in an `@counter-style` rule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct CounterRangeBounds {
+  /// The lower bound of the range.
+  pub start: CounterRangeBound,
+  /// The upper bound of the range.
+  pub end: CounterRangeBound,
+}
+
+/// A bound of a range within the `range` property in an `@counter-style` rule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum CounterRangeBound {
+  /// Negative infinity as a lower bound, or positive infinity as an upper bound.
+  Infinite,
+  /// An integer.
+  Integer(CSSInteger),
+}
+
+/// A value for the [pad](https://drafts.csswg.org/css-counter-styles/#counter-style-pad)
+/// property in an `@counter-style` rule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct Pad<'i> {
+  /// The minimum length of the marker representation.
+  pub length: CSSInteger,
+  /// The symbol used to pad the marker representation.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub symbol: CounterSymbol<'i>,
+}
+
+/// A value for the [speak-as](https://drafts.csswg.org/css-counter-styles/#counter-style-speak-as)
+/// property in an `@counter-style` rule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum SpeakAs<'i> {
+  /// Depends on the counter system.
+  Auto,
+  /// Speaks a UA-defined phrase or audio cue for a bullet.
+  Bullets,
+  /// Speaks the numerical value of the counter.
+  Numbers,
+  /// Speaks the generated counter representation as words.
+  Words,
+  /// Spells out the generated counter representation letter by letter.
+  SpellOut,
+  /// Speaks the counter as if it used the given counter style.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  CounterStyle(CounterStyle<'i>),
+}
+
+/// Parses a [`<counter-style-name>`](https://drafts.csswg.org/css-counter-styles/#typedef-counter-style-name),
+/// i.e. a counter style other than `symbols()`.
+fn parse_counter_style_name<'i, 't>(
+  input: &mut Parser<'i, 't>,
+) -> Result<CounterStyle<'i>, ParseError<'i, ParserError<'i>>> {
+  let location = input.current_source_location();
+  match CounterStyle::parse(input)? {
+    CounterStyle::Symbols { .. } => Err(location.new_custom_error(ParserError::InvalidValue)),
+    CounterStyle::Name(name) if name.0.eq_ignore_ascii_case("none") => {
+      Err(location.new_custom_error(ParserError::InvalidValue))
+    }
+    name => Ok(name),
+  }
+}
+
+impl<'i> CounterStyleRule<'i> {
+  /// Parses the name of an `@counter-style` rule, which cannot be `none` or
+  /// one of the predefined counter styles that cannot be overridden.
+  pub(crate) fn parse_name<'t>(
+    input: &mut Parser<'i, 't>,
+  ) -> Result<CustomIdent<'i>, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let name = CustomIdent::parse(input)?;
+    match_ignore_ascii_case! { &name.0,
+      "none" | "decimal" | "disc" | "square" | "circle" | "disclosure-open" | "disclosure-closed" => {
+        Err(location.new_custom_error(ParserError::InvalidValue))
+      },
+      _ => Ok(name)
+    }
+  }
+
+  pub(crate) fn parse<'t>(
+    name: CustomIdent<'i>,
+    input: &mut Parser<'i, 't>,
+    loc: Location,
+    options: &ParserOptions<'_, 'i>,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut decl_parser = CounterStyleDeclarationParser { options };
+    let parser = RuleBodyParser::new(input, &mut decl_parser);
+    let mut properties = vec![];
+    for decl in parser {
+      match decl {
+        Ok(decl) => properties.push(decl),
+        Err((err, _)) => {
+          if options.error_recovery {
+            options.warn(err);
+            continue;
+          }
+          return Err(err);
+        }
+      }
+    }
+
+    Ok(CounterStyleRule { name, properties, loc })
+  }
+
+  pub(crate) fn minify(&mut self) {
+    // Only the last occurrence of each descriptor applies. Unknown descriptors are preserved as is.
+    let mut seen = HashSet::new();
+    self.properties.reverse();
+    self.properties.retain(|property| {
+      matches!(property, CounterStyleProperty::Custom(_)) || seen.insert(std::mem::discriminant(property))
+    });
+    self.properties.reverse();
+
+    // Descriptors omitted from a counter style that extends another are taken from the extended
+    // counter style rather than using their initial values, so they must be preserved.
+    if self
+      .properties
+      .iter()
+      .any(|property| matches!(property, CounterStyleProperty::System(CounterSystem::Extends(..))))
+    {
+      return;
+    }
+
+    self.properties.retain(|property| !property.is_initial());
+  }
+}
+
+impl<'i> CounterStyleProperty<'i> {
+  fn is_initial(&self) -> bool {
+    match self {
+      CounterStyleProperty::System(CounterSystem::Symbolic) => true,
+      CounterStyleProperty::Negative(Negative { prefix, suffix: None }) => prefix.is_string("-"),
+      CounterStyleProperty::Prefix(prefix) => prefix.is_string(""),
+      CounterStyleProperty::Suffix(suffix) => suffix.is_string(". "),
+      CounterStyleProperty::Range(CounterRange::Auto) => true,
+      CounterStyleProperty::Pad(Pad { length: 0, symbol }) => symbol.is_string(""),
+      CounterStyleProperty::Fallback(CounterStyle::Predefined(PredefinedCounterStyle::Decimal)) => true,
+      CounterStyleProperty::SpeakAs(SpeakAs::Auto) => true,
+      _ => false,
+    }
+  }
+}
+
+impl<'i> CounterSymbol<'i> {
+  fn is_string(&self, s: &str) -> bool {
+    matches!(self, CounterSymbol::String(string) if string.0.as_ref() == s)
+  }
+}
+
+pub(crate) struct CounterStyleDeclarationParser<'a, 'o, 'i> {
+  options: &'a ParserOptions<'o, 'i>,
+}
+
+impl<'a, 'o, 'i> cssparser::DeclarationParser<'i> for CounterStyleDeclarationParser<'a, 'o, 'i> {
+  type Declaration = CounterStyleProperty<'i>;
+  type Error = ParserError<'i>;
+
+  fn parse_value<'t>(
+    &mut self,
+    name: CowRcStr<'i>,
+    input: &mut cssparser::Parser<'i, 't>,
+  ) -> Result<Self::Declaration, cssparser::ParseError<'i, Self::Error>> {
+    macro_rules! property {
+      ($property: ident, $parse: expr) => {
+        if let Ok(c) = $parse(input) {
+          if input.expect_exhausted().is_ok() {
+            return Ok(CounterStyleProperty::$property(c));
+          }
+        }
+      };
+    }
+
+    let state = input.state();
+    match_ignore_ascii_case! { &name,
+      "system" => property!(System, CounterSystem::parse),
+      "symbols" => property!(Symbols, |input: &mut Parser<'i, '_>| {
+        let mut symbols = vec![CounterSymbol::parse(input)?];
+        while let Ok(symbol) = input.try_parse(CounterSymbol::parse) {
+          symbols.push(symbol);
+        }
+        Ok::<_, ParseError<'i, ParserError<'i>>>(symbols)
+      }),
+      "additive-symbols" => property!(AdditiveSymbols, |input: &mut Parser<'i, '_>| {
+        let symbols = input.parse_comma_separated(AdditiveSymbol::parse)?;
+        // Weights must be in descending order.
+        if symbols.windows(2).any(|pair| pair[0].weight <= pair[1].weight) {
+          return Err(input.new_custom_error(ParserError::InvalidValue));
+        }
+        Ok(symbols)
+      }),
+      "negative" => property!(Negative, Negative::parse),
+      "prefix" => property!(Prefix, CounterSymbol::parse),
+      "suffix" => property!(Suffix, CounterSymbol::parse),
+      "range" => property!(Range, CounterRange::parse),
+      "pad" => property!(Pad, Pad::parse),
+      "fallback" => property!(Fallback, parse_counter_style_name),
+      "speak-as" => property!(SpeakAs, SpeakAs::parse),
+      _ => {}
+    }
+
+    input.reset(&state);
+    return Ok(CounterStyleProperty::Custom(CustomProperty::parse(
+      name.into(),
+      input,
+      self.options,
+    )?));
+  }
+}
+
+/// Default methods reject all at rules.
+impl<'a, 'o, 'i> AtRuleParser<'i> for CounterStyleDeclarationParser<'a, 'o, 'i> {
+  type Prelude = ();
+  type AtRule = CounterStyleProperty<'i>;
+  type Error = ParserError<'i>;
+}
+
+impl<'a, 'o, 'i> QualifiedRuleParser<'i> for CounterStyleDeclarationParser<'a, 'o, 'i> {
+  type Prelude = ();
+  type QualifiedRule = CounterStyleProperty<'i>;
+  type Error = ParserError<'i>;
+}
+
+impl<'a, 'o, 'i> RuleBodyItemParser<'i, CounterStyleProperty<'i>, ParserError<'i>>
+  for CounterStyleDeclarationParser<'a, 'o, 'i>
+{
+  fn parse_qualified(&self) -> bool {
+    false
+  }
+
+  fn parse_declarations(&self) -> bool {
+    true
+  }
+}
+
+impl<'i> Parse<'i> for CounterSystem<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let ident = input.expect_ident_cloned()?;
+    match_ignore_ascii_case! { &*ident,
+      "cyclic" => Ok(CounterSystem::Cyclic),
+      "numeric" => Ok(CounterSystem::Numeric),
+      "alphabetic" => Ok(CounterSystem::Alphabetic),
+      "symbolic" => Ok(CounterSystem::Symbolic),
+      "additive" => Ok(CounterSystem::Additive),
+      "fixed" => Ok(CounterSystem::Fixed(input.try_parse(CSSInteger::parse).unwrap_or(1))),
+      "extends" => Ok(CounterSystem::Extends(parse_counter_style_name(input)?)),
+      _ => Err(location.new_unexpected_token_error(Token::Ident(ident)))
+    }
+  }
+}
+
+impl<'i> ToCss for CounterSystem<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      CounterSystem::Cyclic => dest.write_str("cyclic"),
+      CounterSystem::Numeric => dest.write_str("numeric"),
+      CounterSystem::Alphabetic => dest.write_str("alphabetic"),
+      CounterSystem::Symbolic => dest.write_str("symbolic"),
+      CounterSystem::Additive => dest.write_str("additive"),
+      CounterSystem::Fixed(first) => {
+        dest.write_str("fixed")?;
+        // The first symbol value defaults to 1.
+        if *first != 1 {
+          dest.write_char(' ')?;
+          first.to_css(dest)?;
+        }
+        Ok(())
+      }
+      CounterSystem::Extends(name) => {
+        dest.write_str("extends ")?;
+        name.to_css(dest)
+      }
+    }
+  }
+}
+
+impl<'i> Parse<'i> for CounterSymbol<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if let Ok(string) = input.try_parse(CSSString::parse) {
+      return Ok(CounterSymbol::String(string));
+    }
+
+    if let Ok(ident) = input.try_parse(CustomIdent::parse) {
+      return Ok(CounterSymbol::Ident(ident));
+    }
+
+    Ok(CounterSymbol::Image(Image::parse(input)?))
+  }
+}
+
+impl<'i> ToCss for CounterSymbol<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      CounterSymbol::String(string) => string.to_css(dest),
+      // Symbols are not counter style names, so they are not affected by CSS modules.
+      CounterSymbol::Ident(ident) => ident.to_css_with_options(dest, false),
+      CounterSymbol::Image(image) => image.to_css(dest),
+    }
+  }
+}
+
+/// Parses an `<integer [0,∞]> && <symbol>` pair, as used in the `additive-symbols` and `pad` properties.
+fn parse_integer_and_symbol<'i, 't>(
+  input: &mut Parser<'i, 't>,
+) -> Result<(CSSInteger, CounterSymbol<'i>), ParseError<'i, ParserError<'i>>> {
+  let location = input.current_source_location();
+  let (integer, symbol) = if let Ok(integer) = input.try_parse(CSSInteger::parse) {
+    (integer, CounterSymbol::parse(input)?)
+  } else {
+    let symbol = CounterSymbol::parse(input)?;
+    (CSSInteger::parse(input)?, symbol)
+  };
+
+  if integer < 0 {
+    return Err(location.new_custom_error(ParserError::InvalidValue));
+  }
+
+  Ok((integer, symbol))
+}
+
+impl<'i> Parse<'i> for AdditiveSymbol<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let (weight, symbol) = parse_integer_and_symbol(input)?;
+    Ok(AdditiveSymbol { weight, symbol })
+  }
+}
+
+impl<'i> ToCss for AdditiveSymbol<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.weight.to_css(dest)?;
+    dest.write_char(' ')?;
+    self.symbol.to_css(dest)
+  }
+}
+
+impl<'i> Parse<'i> for Pad<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let (length, symbol) = parse_integer_and_symbol(input)?;
+    Ok(Pad { length, symbol })
+  }
+}
+
+impl<'i> ToCss for Pad<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.length.to_css(dest)?;
+    dest.write_char(' ')?;
+    self.symbol.to_css(dest)
+  }
+}
+
+impl<'i> Parse<'i> for Negative<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let prefix = CounterSymbol::parse(input)?;
+    let suffix = input.try_parse(CounterSymbol::parse).ok();
+    Ok(Negative { prefix, suffix })
+  }
+}
+
+impl<'i> ToCss for Negative<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.prefix.to_css(dest)?;
+    if let Some(suffix) = &self.suffix {
+      dest.write_char(' ')?;
+      suffix.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+impl<'i> Parse<'i> for CounterRange {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(CounterRange::Auto);
+    }
+
+    let ranges = input.parse_comma_separated(|input| {
+      let location = input.current_source_location();
+      let start = CounterRangeBound::parse(input)?;
+      let end = CounterRangeBound::parse(input)?;
+      // The lower bound cannot be higher than the upper bound.
+      if let (CounterRangeBound::Integer(start), CounterRangeBound::Integer(end)) = (&start, &end) {
+        if start > end {
+          return Err(location.new_custom_error(ParserError::InvalidValue));
+        }
+      }
+      Ok(CounterRangeBounds { start, end })
+    })?;
+    Ok(CounterRange::Ranges(ranges))
+  }
+}
+
+impl ToCss for CounterRange {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      CounterRange::Auto => dest.write_str("auto"),
+      CounterRange::Ranges(ranges) => {
+        let mut first = true;
+        for range in ranges {
+          if first {
+            first = false;
+          } else {
+            dest.delim(',', false)?;
+          }
+          range.start.to_css(dest)?;
+          dest.write_char(' ')?;
+          range.end.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+impl<'i> Parse<'i> for CounterRangeBound {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("infinite")).is_ok() {
+      return Ok(CounterRangeBound::Infinite);
+    }
+
+    Ok(CounterRangeBound::Integer(CSSInteger::parse(input)?))
+  }
+}
+
+impl ToCss for CounterRangeBound {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      CounterRangeBound::Infinite => dest.write_str("infinite"),
+      CounterRangeBound::Integer(integer) => integer.to_css(dest),
+    }
+  }
+}
+
+impl<'i> Parse<'i> for SpeakAs<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let keyword = input.try_parse(|input| {
+      let ident = input.expect_ident().map_err(|_| ())?;
+      match_ignore_ascii_case! { ident,
+        "auto" => Ok(SpeakAs::Auto),
+        "bullets" => Ok(SpeakAs::Bullets),
+        "numbers" => Ok(SpeakAs::Numbers),
+        "words" => Ok(SpeakAs::Words),
+        "spell-out" => Ok(SpeakAs::SpellOut),
+        _ => Err(())
+      }
+    });
+
+    if let Ok(keyword) = keyword {
+      return Ok(keyword);
+    }
+
+    Ok(SpeakAs::CounterStyle(parse_counter_style_name(input)?))
+  }
+}
+
+impl<'i> ToCss for SpeakAs<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      SpeakAs::Auto => dest.write_str("auto"),
+      SpeakAs::Bullets => dest.write_str("bullets"),
+      SpeakAs::Numbers => dest.write_str("numbers"),
+      SpeakAs::Words => dest.write_str("words"),
+      SpeakAs::SpellOut => dest.write_str("spell-out"),
+      SpeakAs::CounterStyle(name) => name.to_css(dest),
+    }
+  }
+}
+
 impl<'i> ToCss for CounterStyleRule<'i> {
   fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
   where
@@ -36,6 +692,65 @@ impl<'i> ToCss for CounterStyleRule<'i> {
     dest.add_mapping(self.loc);
     dest.write_str("@counter-style ")?;
     self.name.to_css(dest)?;
-    self.declarations.to_css_block(dest)
+    dest.whitespace()?;
+    dest.write_char('{')?;
+    dest.increase_indent();
+    let len = self.properties.len();
+    for (i, prop) in self.properties.iter().enumerate() {
+      dest.newline()?;
+      prop.to_css(dest)?;
+      if i != len - 1 || !dest.minify {
+        dest.write_char(';')?;
+      }
+    }
+    dest.decrease_indent();
+    dest.newline()?;
+    dest.write_char('}')
+  }
+}
+
+impl<'i> ToCss for CounterStyleProperty<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    macro_rules! property {
+      ($prop: literal, $value: expr) => {{
+        dest.write_str($prop)?;
+        dest.delim(':', false)?;
+        $value.to_css(dest)
+      }};
+    }
+
+    match self {
+      CounterStyleProperty::System(system) => property!("system", system),
+      CounterStyleProperty::Symbols(symbols) => {
+        dest.write_str("symbols")?;
+        dest.delim(':', false)?;
+        let mut first = true;
+        for symbol in symbols {
+          if first {
+            first = false;
+          } else {
+            dest.write_char(' ')?;
+          }
+          symbol.to_css(dest)?;
+        }
+        Ok(())
+      }
+      CounterStyleProperty::AdditiveSymbols(symbols) => property!("additive-symbols", symbols),
+      CounterStyleProperty::Negative(negative) => property!("negative", negative),
+      CounterStyleProperty::Prefix(prefix) => property!("prefix", prefix),
+      CounterStyleProperty::Suffix(suffix) => property!("suffix", suffix),
+      CounterStyleProperty::Range(range) => property!("range", range),
+      CounterStyleProperty::Pad(pad) => property!("pad", pad),
+      CounterStyleProperty::Fallback(fallback) => property!("fallback", fallback),
+      CounterStyleProperty::SpeakAs(speak_as) => property!("speak-as", speak_as),
+      CounterStyleProperty::Custom(custom) => {
+        dest.write_str(custom.name.as_ref())?;
+        dest.delim(':', false)?;
+        custom.value.to_css(dest, true)
+      }
+    }
   }
 }
@@ -43,10 +43,10 @@ impl<'i, T: ToCss> ToCss for MozDocumentRule<'i, T> {
     dest.write_str("@-moz-document url-prefix()")?;
     dest.whitespace()?;
     dest.write_char('{')?;
-    dest.indent();
+    dest.increase_indent();
     dest.newline()?;
     self.rules.to_css(dest)?;
-    dest.dedent();
+    dest.decrease_indent();
     dest.newline()?;
     dest.write_char('}')
   }
@@ -1,6 +1,7 @@
 //! The `@font-face` rule.
 
 use super::Location;
+use crate::compat::Feature;
 use crate::error::{ParserError, PrinterError};
 use crate::macros::enum_property;
 use crate::printer::Printer;
@@ -58,10 +59,31 @@ pub enum FontFaceProperty<'i> {
   FontStretch(Size2D<FontStretch>),
   /// The `unicode-range` property.
   UnicodeRange(Vec<UnicodeRange>),
+  /// The `font-display` property.
+  FontDisplay(FontDisplay),
   /// An unknown or unsupported property.
   Custom(CustomProperty<'i>),
 }
 
+impl<'i> FontFaceRule<'i> {
+  pub(crate) fn minify(&mut self) {
+    // Only the last `font-display` descriptor applies. `auto` is its initial value, so it can be omitted.
+    let last = self
+      .properties
+      .iter()
+      .rposition(|property| matches!(property, FontFaceProperty::FontDisplay(_)));
+    let mut i = 0;
+    self.properties.retain(|property| {
+      let keep = match property {
+        FontFaceProperty::FontDisplay(display) => Some(i) == last && *display != FontDisplay::Auto,
+        _ => true,
+      };
+      i += 1;
+      keep
+    });
+  }
+}
+
 /// A value for the [src](https://drafts.csswg.org/css-fonts/#src-desc)
 /// property in an `@font-face` rule.
 #[derive(Debug, Clone, PartialEq)]
@@ -242,9 +264,15 @@ impl<'i> ToCss for FontFormat<'i> {
       SVG => "svg",
       String(s) => &s,
     };
-    // Browser support for keywords rather than strings is very limited.
+    // Browser support for keywords rather than strings is limited, so only use them
+    // when minifying for targets that support them. Unknown formats are always strings.
     // https://developer.mozilla.org/en-US/docs/Web/CSS/@font-face/src
-    serialize_string(&s, dest)?;
+    if dest.minify && !matches!(self, String(_)) && dest.targets.current.is_compatible(Feature::FontFormatKeywords)
+    {
+      dest.write_str(s)?;
+    } else {
+      serialize_string(&s, dest)?;
+    }
     Ok(())
   }
 }
@@ -439,10 +467,31 @@ impl ToCss for FontStyle {
   }
 }
 
-pub(crate) struct FontFaceDeclarationParser;
+enum_property! {
+  /// A value for the [font-display](https://drafts.csswg.org/css-fonts/#font-display-desc)
+  /// descriptor in an `@font-face` rule.
+  #[derive(Default)]
+  pub enum FontDisplay {
+    /// The font display policy is defined by the user agent.
+    #[default]
+    Auto,
+    /// Gives the font face a short block period and an infinite swap period.
+    Block,
+    /// Gives the font face an extremely small block period and an infinite swap period.
+    Swap,
+    /// Gives the font face an extremely small block period and a short swap period.
+    Fallback,
+    /// Gives the font face an extremely small block period and no swap period.
+    Optional,
+  }
+}
+
+pub(crate) struct FontFaceDeclarationParser<'a, 'o, 'i> {
+  pub options: &'a ParserOptions<'o, 'i>,
+}
 
 /// Parse a declaration within {} block: `color: blue`
-impl<'i> cssparser::DeclarationParser<'i> for FontFaceDeclarationParser {
+impl<'a, 'o, 'i> cssparser::DeclarationParser<'i> for FontFaceDeclarationParser<'a, 'o, 'i> {
   type Declaration = FontFaceProperty<'i>;
   type Error = ParserError<'i>;
 
@@ -464,7 +513,23 @@ impl<'i> cssparser::DeclarationParser<'i> for FontFaceDeclarationParser {
     let state = input.state();
     match_ignore_ascii_case! { &name,
       "src" => {
-        if let Ok(sources) = input.parse_comma_separated(Source::parse) {
+        // Unknown formats are preserved, but browsers will skip these sources.
+        let mut warnings = Vec::new();
+        let sources = input.parse_comma_separated(|input| {
+          input.skip_whitespace();
+          let location = input.current_source_location();
+          let source = Source::parse(input)?;
+          if let Source::Url(UrlSource { format: Some(FontFormat::String(format)), .. }) = &source {
+            warnings.push(location.new_custom_error(ParserError::UnknownFontFormat(format.clone())));
+          }
+          Ok(source)
+        });
+        if let Ok(sources) = sources {
+          if self.options.error_recovery {
+            for warning in warnings {
+              self.options.warn(warning);
+            }
+          }
           return Ok(FontFaceProperty::Source(sources))
         }
       },
@@ -473,6 +538,7 @@ impl<'i> cssparser::DeclarationParser<'i> for FontFaceDeclarationParser {
       "font-style" => property!(FontStyle, FontStyle),
       "font-stretch" => property!(FontStretch, Size2D<FontStretch>),
       "unicode-range" => property!(UnicodeRange, Vec<UnicodeRange>),
+      "font-display" => property!(FontDisplay, FontDisplay),
       _ => {}
     }
 
@@ -486,19 +552,21 @@ impl<'i> cssparser::DeclarationParser<'i> for FontFaceDeclarationParser {
 }
 
 /// Default methods reject all at rules.
-impl<'i> AtRuleParser<'i> for FontFaceDeclarationParser {
+impl<'a, 'o, 'i> AtRuleParser<'i> for FontFaceDeclarationParser<'a, 'o, 'i> {
   type Prelude = ();
   type AtRule = FontFaceProperty<'i>;
   type Error = ParserError<'i>;
 }
 
-impl<'i> QualifiedRuleParser<'i> for FontFaceDeclarationParser {
+impl<'a, 'o, 'i> QualifiedRuleParser<'i> for FontFaceDeclarationParser<'a, 'o, 'i> {
   type Prelude = ();
   type QualifiedRule = FontFaceProperty<'i>;
   type Error = ParserError<'i>;
 }
 
-impl<'i> RuleBodyItemParser<'i, FontFaceProperty<'i>, ParserError<'i>> for FontFaceDeclarationParser {
+impl<'a, 'o, 'i> RuleBodyItemParser<'i, FontFaceProperty<'i>, ParserError<'i>>
+  for FontFaceDeclarationParser<'a, 'o, 'i>
+{
   fn parse_qualified(&self) -> bool {
     false
   }
@@ -518,7 +586,7 @@ impl<'i> ToCss for FontFaceRule<'i> {
     dest.write_str("@font-face")?;
     dest.whitespace()?;
     dest.write_char('{')?;
-    dest.indent();
+    dest.increase_indent();
     let len = self.properties.len();
     for (i, prop) in self.properties.iter().enumerate() {
       dest.newline()?;
@@ -527,7 +595,7 @@ impl<'i> ToCss for FontFaceRule<'i> {
         dest.write_char(';')?;
       }
     }
-    dest.dedent();
+    dest.decrease_indent();
     dest.newline()?;
     dest.write_char('}')
   }
@@ -566,6 +634,7 @@ impl<'i> ToCss for FontFaceProperty<'i> {
       FontWeight(value) => property!("font-weight", value),
       FontStretch(value) => property!("font-stretch", value),
       UnicodeRange(value) => property!("unicode-range", value),
+      FontDisplay(value) => property!("font-display", value),
       Custom(custom) => {
         dest.write_str(custom.name.as_ref())?;
         dest.delim(':', false)?;
@@ -249,7 +249,7 @@ impl<'i> ToCss for FontFeatureSubrule<'i> {
     dest.write_char('@')?;
     self.name.to_css(dest)?;
     dest.write_char('{')?;
-    dest.indent();
+    dest.increase_indent();
     let len = self.declarations.len();
     for (i, (name, value)) in self.declarations.iter().enumerate() {
       dest.newline()?;
@@ -270,7 +270,7 @@ impl<'i> ToCss for FontFeatureSubrule<'i> {
         dest.write_char(';')?;
       }
     }
-    dest.dedent();
+    dest.decrease_indent();
     dest.newline()?;
     dest.write_char('}')
   }
@@ -367,7 +367,7 @@ impl<'i> ToCss for FontPaletteValuesRule<'i> {
     self.name.to_css(dest)?;
     dest.whitespace()?;
     dest.write_char('{')?;
-    dest.indent();
+    dest.increase_indent();
     let len = self.properties.len();
     for (i, prop) in self.properties.iter().enumerate() {
       dest.newline()?;
@@ -376,7 +376,7 @@ impl<'i> ToCss for FontPaletteValuesRule<'i> {
         dest.write_char(';')?;
       }
     }
-    dest.dedent();
+    dest.decrease_indent();
     dest.newline()?;
     dest.write_char('}')
   }
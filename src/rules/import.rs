@@ -2,9 +2,9 @@
 
 use super::layer::LayerName;
 use super::supports::SupportsCondition;
-use super::Location;
+use super::{Location, MinifyContext};
 use crate::dependencies::{Dependency, ImportDependency};
-use crate::error::PrinterError;
+use crate::error::{MinifyError, PrinterError};
 use crate::media_query::MediaList;
 use crate::printer::Printer;
 use crate::traits::ToCss;
@@ -37,6 +37,20 @@ pub struct ImportRule<'i> {
   pub loc: Location,
 }
 
+impl<'i> ImportRule<'i> {
+  pub(crate) fn minify(&mut self, context: &mut MinifyContext<'_, 'i>) -> Result<(), MinifyError> {
+    if let Some(custom_media) = &context.custom_media {
+      self.media.transform_custom_media(self.loc, custom_media)?;
+    }
+
+    self.media.transform_resolution(context.targets.current);
+    if let Some(supports) = &mut self.supports {
+      supports.set_prefixes_for_targets(&context.targets.current);
+    }
+    Ok(())
+  }
+}
+
 impl<'i> ToCss for ImportRule<'i> {
   fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
   where
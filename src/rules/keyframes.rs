@@ -7,7 +7,7 @@ use crate::context::DeclarationContext;
 use crate::declaration::DeclarationBlock;
 use crate::error::{ParserError, PrinterError};
 use crate::parser::ParserOptions;
-use crate::printer::Printer;
+use crate::printer::{KeyframeSelectorStyle, Printer};
 use crate::properties::animation::TimelineRangeName;
 use crate::properties::custom::{CustomProperty, UnparsedProperty};
 use crate::properties::Property;
@@ -16,7 +16,7 @@ use crate::traits::{Parse, ToCss};
 use crate::values::color::ColorFallbackKind;
 use crate::values::ident::CustomIdent;
 use crate::values::percentage::Percentage;
-use crate::values::string::CowArcStr;
+use crate::values::string::{is_valid_custom_ident, write_custom_ident, CowArcStr};
 use crate::vendor_prefix::VendorPrefix;
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
@@ -72,14 +72,11 @@ impl<'i> Parse<'i> for KeyframesName<'i> {
     match input.next()?.clone() {
       Token::Ident(ref s) => {
         // CSS-wide keywords without quotes throws an error.
-        match_ignore_ascii_case! { &*s,
-          "none" | "initial" | "inherit" | "unset" | "default" | "revert" | "revert-layer" => {
-            Err(input.new_unexpected_token_error(Token::Ident(s.clone())))
-          },
-          _ => {
-            Ok(KeyframesName::Ident(CustomIdent(s.into())))
-          }
+        if !is_valid_custom_ident(s, &["none"]) {
+          return Err(input.new_unexpected_token_error(Token::Ident(s.clone())));
         }
+
+        Ok(KeyframesName::Ident(CustomIdent(s.into())))
       }
 
       Token::QuotedString(ref s) => Ok(KeyframesName::Custom(s.into())),
@@ -101,21 +98,35 @@ impl<'i> ToCss for KeyframesName<'i> {
         dest.write_ident(ident.0.as_ref(), css_module_animation_enabled)?;
       }
       KeyframesName::Custom(s) => {
-        // CSS-wide keywords and `none` cannot remove quotes.
-        match_ignore_ascii_case! { &*s,
-          "none" | "initial" | "inherit" | "unset" | "default" | "revert" | "revert-layer" => {
-            serialize_string(&s, dest)?;
-          },
-          _ => {
-            dest.write_ident(s.as_ref(), css_module_animation_enabled)?;
-          }
-        }
+        // CSS-wide keywords, `none`, and other invalid identifiers keep their quotes.
+        write_custom_ident(s, &["none"], dest, css_module_animation_enabled)?;
       }
     }
     Ok(())
   }
 }
 
+impl<'i> KeyframesName<'i> {
+  /// Returns the animation name, whether it was written as an identifier or a string.
+  pub fn as_str(&self) -> &str {
+    match self {
+      KeyframesName::Ident(ident) => ident.0.as_ref(),
+      KeyframesName::Custom(string) => string.as_ref(),
+    }
+  }
+}
+
+/// Two `@keyframes` rules that define an animation with the same name.
+///
+/// See [CssRuleList::detect_keyframe_collisions](super::CssRuleList::detect_keyframe_collisions).
+#[derive(Debug, PartialEq)]
+pub struct KeyframeConflict<'a, 'i> {
+  /// The first rule that defines the animation.
+  pub first: &'a KeyframesRule<'i>,
+  /// A later rule that defines an animation with the same name.
+  pub second: &'a KeyframesRule<'i>,
+}
+
 impl<'i> KeyframesRule<'i> {
   pub(crate) fn minify(&mut self, context: &mut MinifyContext<'_, 'i>) {
     context.handler_context.context = DeclarationContext::Keyframes;
@@ -129,6 +140,20 @@ impl<'i> KeyframesRule<'i> {
     context.handler_context.context = DeclarationContext::None;
   }
 
+  /// Merges the keyframes of a later `@keyframes` rule with the same name into this one.
+  /// Keyframes in the later rule replace any keyframes in this rule at the same offset.
+  pub(crate) fn merge(&mut self, keyframes: Vec<Keyframe<'i>>) {
+    for existing in &mut self.keyframes {
+      existing.selectors.retain(|selector| {
+        !keyframes
+          .iter()
+          .any(|keyframe| keyframe.selectors.iter().any(|s| s.is_equivalent(selector)))
+      });
+    }
+    self.keyframes.retain(|keyframe| !keyframe.selectors.is_empty());
+    self.keyframes.extend(keyframes);
+  }
+
   pub(crate) fn get_fallbacks<T>(&mut self, targets: &Targets) -> Vec<CssRule<'i, T>> {
     let mut fallbacks = ColorFallbackKind::empty();
     for keyframe in &self.keyframes {
@@ -233,6 +258,7 @@ impl<'i> ToCss for KeyframesRule<'i> {
               dest.write_char('\n')?; // no indent
             }
             dest.newline()?;
+            dest.rule_separator()?;
           }
           dest.write_char('@')?;
           VendorPrefix::$prefix.to_css(dest)?;
@@ -240,7 +266,7 @@ impl<'i> ToCss for KeyframesRule<'i> {
           self.name.to_css(dest)?;
           dest.whitespace()?;
           dest.write_char('{')?;
-          dest.indent();
+          dest.increase_indent();
           let mut first = true;
           for keyframe in &self.keyframes {
             if first {
@@ -251,7 +277,7 @@ impl<'i> ToCss for KeyframesRule<'i> {
             dest.newline()?;
             keyframe.to_css(dest)?;
           }
-          dest.dedent();
+          dest.decrease_indent();
           dest.newline()?;
           dest.write_char('}')?;
         }
@@ -313,27 +339,47 @@ pub enum KeyframeSelector {
   TimelineRangePercentage(TimelineRangePercentage),
 }
 
+impl KeyframeSelector {
+  /// Returns whether this selector refers to the same offset as another, e.g. `from` and `0%`.
+  fn is_equivalent(&self, other: &KeyframeSelector) -> bool {
+    match (self, other) {
+      (KeyframeSelector::From, KeyframeSelector::Percentage(Percentage(p)))
+      | (KeyframeSelector::Percentage(Percentage(p)), KeyframeSelector::From) => *p == 0.0,
+      (KeyframeSelector::To, KeyframeSelector::Percentage(Percentage(p)))
+      | (KeyframeSelector::Percentage(Percentage(p)), KeyframeSelector::To) => *p == 1.0,
+      _ => self == other,
+    }
+  }
+}
+
 impl ToCss for KeyframeSelector {
   fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
   where
     W: std::fmt::Write,
   {
+    let explicit = dest.keyframe_selectors == KeyframeSelectorStyle::Explicit;
     match self {
       KeyframeSelector::Percentage(p) => {
-        if dest.minify && *p == Percentage(1.0) {
+        if dest.minify && !explicit && *p == Percentage(1.0) {
           dest.write_str("to")
         } else {
           p.to_css(dest)
         }
       }
       KeyframeSelector::From => {
-        if dest.minify {
+        if dest.minify || explicit {
           dest.write_str("0%")
         } else {
           dest.write_str("from")
         }
       }
-      KeyframeSelector::To => dest.write_str("to"),
+      KeyframeSelector::To => {
+        if explicit {
+          dest.write_str("100%")
+        } else {
+          dest.write_str("to")
+        }
+      }
       KeyframeSelector::TimelineRangePercentage(TimelineRangePercentage {
         name: timeline_range_name,
         percentage,
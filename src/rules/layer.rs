@@ -1,6 +1,6 @@
 //! The `@layer` rule.
 
-use super::{CssRuleList, Location, MinifyContext};
+use super::{CssRule, CssRuleList, Location, MinifyContext};
 use crate::error::{MinifyError, ParserError, PrinterError};
 use crate::parser::DefaultAtRule;
 use crate::printer::Printer;
@@ -156,11 +156,96 @@ impl<'a, 'i, T: ToCss> ToCss for LayerBlockRule<'i, T> {
 
     dest.whitespace()?;
     dest.write_char('{')?;
-    dest.indent();
+    dest.increase_indent();
     dest.newline()?;
     self.rules.to_css(dest)?;
-    dest.dedent();
+    dest.decrease_indent();
     dest.newline()?;
     dest.write_char('}')
   }
 }
+
+/// A node in the tree of declared cascade layers, used to compute the layer order.
+#[derive(Default)]
+struct LayerTree<'i> {
+  children: Vec<(CowArcStr<'i>, LayerTree<'i>)>,
+}
+
+impl<'i> LayerTree<'i> {
+  /// Declares the given layer within this one, if not already declared, and returns it.
+  fn declare(&mut self, name: &[CowArcStr<'i>]) -> &mut LayerTree<'i> {
+    let mut tree = self;
+    for part in name {
+      let index = match tree.children.iter().position(|(n, _)| n == part) {
+        Some(index) => index,
+        None => {
+          tree.children.push((part.clone(), LayerTree::default()));
+          tree.children.len() - 1
+        }
+      };
+      tree = &mut tree.children[index].1;
+    }
+    tree
+  }
+
+  fn declare_anonymous(&mut self, counter: &mut usize) -> &mut LayerTree<'i> {
+    *counter += 1;
+    let name: CowArcStr<'i> = format!("<anonymous-{}>", counter).into();
+    self.children.push((name, LayerTree::default()));
+    &mut self.children.last_mut().unwrap().1
+  }
+
+  fn flatten(&self, prefix: &mut SmallVec<[CowArcStr<'i>; 1]>, result: &mut Vec<LayerName<'i>>) {
+    for (name, tree) in &self.children {
+      prefix.push(name.clone());
+      tree.flatten(prefix, result);
+      result.push(LayerName(prefix.clone()));
+      prefix.pop();
+    }
+  }
+}
+
+fn collect_layers<'i, T>(rules: &CssRuleList<'i, T>, tree: &mut LayerTree<'i>, counter: &mut usize) {
+  for rule in &rules.0 {
+    match rule {
+      CssRule::Import(import) => match &import.layer {
+        Some(Some(name)) => {
+          tree.declare(&name.0);
+        }
+        Some(None) => {
+          tree.declare_anonymous(counter);
+        }
+        None => {}
+      },
+      CssRule::LayerStatement(statement) => {
+        for name in &statement.names {
+          tree.declare(&name.0);
+        }
+      }
+      CssRule::LayerBlock(layer) => {
+        let tree = match &layer.name {
+          Some(name) => tree.declare(&name.0),
+          None => tree.declare_anonymous(counter),
+        };
+        collect_layers(&layer.rules, tree, counter);
+      }
+      _ => {
+        if let Some(rules) = rule.nested_rules() {
+          collect_layers(rules, tree, counter);
+        }
+      }
+    }
+  }
+}
+
+/// Returns the fully qualified names of the cascade layers declared in the given rules,
+/// from lowest to highest priority. See [StyleSheet::extract_layer_order](crate::stylesheet::StyleSheet::extract_layer_order).
+pub(crate) fn extract_layer_order<'i, T>(rules: &CssRuleList<'i, T>) -> Vec<LayerName<'i>> {
+  let mut tree = LayerTree::default();
+  let mut counter = 0;
+  collect_layers(rules, &mut tree, &mut counter);
+
+  let mut result = Vec::new();
+  tree.flatten(&mut SmallVec::new(), &mut result);
+  result
+}
@@ -1,7 +1,7 @@
 //! The `@media` rule.
 
 use super::Location;
-use super::{CssRuleList, MinifyContext};
+use super::{CssRule, CssRuleList, MinifyContext};
 use crate::error::{MinifyError, PrinterError};
 use crate::media_query::MediaList;
 use crate::parser::DefaultAtRule;
@@ -39,6 +39,19 @@ impl<'i, T: Clone> MediaRule<'i, T> {
       self.query.transform_custom_media(self.loc, custom_media)?;
     }
 
+    // If the only child is another @media rule, combine the queries,
+    // e.g. `@media screen { @media (color) { … } }` => `@media screen and (color) { … }`.
+    while let [CssRule::Media(nested)] = self.rules.0.as_mut_slice() {
+      let mut query = self.query.clone();
+      if query.and(&nested.query).is_err() {
+        break;
+      }
+
+      self.query = query;
+      self.rules = CssRuleList(std::mem::take(&mut nested.rules.0));
+    }
+
+    self.query.minify(&context.targets.current);
     self.query.transform_resolution(context.targets.current);
     Ok(self.rules.0.is_empty() || self.query.never_matches())
   }
@@ -61,10 +74,10 @@ impl<'a, 'i, T: ToCss> ToCss for MediaRule<'i, T> {
     self.query.to_css(dest)?;
     dest.whitespace()?;
     dest.write_char('{')?;
-    dest.indent();
+    dest.increase_indent();
     dest.newline()?;
     self.rules.to_css(dest)?;
-    dest.dedent();
+    dest.decrease_indent();
     dest.newline()?;
     dest.write_char('}')
   }
@@ -63,17 +63,18 @@ use self::font_feature_values::FontFeatureValuesRule;
 use self::font_palette_values::FontPaletteValuesRule;
 use self::layer::{LayerBlockRule, LayerStatementRule};
 use self::property::PropertyRule;
-use crate::context::PropertyHandlerContext;
+use crate::context::{PropertyHandlerContext, PropertyHandlerFallbacks};
 use crate::declaration::{DeclarationBlock, DeclarationHandler};
 use crate::dependencies::{Dependency, ImportDependency};
-use crate::error::{MinifyError, ParserError, PrinterError, PrinterErrorKind};
+use crate::error::{MinifyError, MinifyErrorKind, ParserError, PrinterError, PrinterErrorKind};
+use crate::media_query::{KnownDimensions, Resolved};
 use crate::parser::{parse_rule_list, parse_style_block, DefaultAtRule, DefaultAtRuleParser, TopLevelRuleParser};
 use crate::prefixes::Feature;
 use crate::printer::Printer;
-use crate::rules::keyframes::KeyframesName;
+use crate::rules::keyframes::KeyframeConflict;
 use crate::selector::{is_compatible, is_equivalent, Component, Selector, SelectorList};
 use crate::stylesheet::ParserOptions;
-use crate::targets::{should_compile, TargetsWithSupportsScope};
+use crate::targets::{should_compile, Targets, TargetsWithSupportsScope};
 use crate::traits::{AtRuleParser, ToCss};
 use crate::values::string::CowArcStr;
 use crate::vendor_prefix::VendorPrefix;
@@ -447,6 +448,39 @@ impl<'i, T> CssRule<'i, T> {
     let mut parser = Parser::new(&mut input);
     Self::parse_with(&mut parser, &options, at_rule_parser)
   }
+
+  /// Returns the rules nested within a grouping rule, e.g. `@media`, or within a style rule.
+  pub(crate) fn nested_rules(&self) -> Option<&CssRuleList<'i, T>> {
+    match self {
+      CssRule::Media(media) => Some(&media.rules),
+      CssRule::Supports(supports) => Some(&supports.rules),
+      CssRule::Container(container) => Some(&container.rules),
+      CssRule::LayerBlock(layer) => Some(&layer.rules),
+      CssRule::MozDocument(document) => Some(&document.rules),
+      CssRule::Scope(scope) => Some(&scope.rules),
+      CssRule::StartingStyle(starting_style) => Some(&starting_style.rules),
+      CssRule::Style(style) => Some(&style.rules),
+      CssRule::Nesting(nesting) => Some(&nesting.style.rules),
+      _ => None,
+    }
+  }
+
+  /// Returns a mutable reference to the rules nested within a grouping rule or style rule.
+  /// See [nested_rules](CssRule::nested_rules).
+  pub(crate) fn nested_rules_mut(&mut self) -> Option<&mut CssRuleList<'i, T>> {
+    match self {
+      CssRule::Media(media) => Some(&mut media.rules),
+      CssRule::Supports(supports) => Some(&mut supports.rules),
+      CssRule::Container(container) => Some(&mut container.rules),
+      CssRule::LayerBlock(layer) => Some(&mut layer.rules),
+      CssRule::MozDocument(document) => Some(&mut document.rules),
+      CssRule::Scope(scope) => Some(&mut scope.rules),
+      CssRule::StartingStyle(starting_style) => Some(&mut starting_style.rules),
+      CssRule::Style(style) => Some(&mut style.rules),
+      CssRule::Nesting(nesting) => Some(&mut nesting.style.rules),
+      _ => None,
+    }
+  }
 }
 
 /// A list of CSS rules.
@@ -500,6 +534,252 @@ impl<'i, T> CssRuleList<'i, T> {
   }
 }
 
+impl<'i, T> CssRuleList<'i, T> {
+  /// Returns the `@keyframes` rules in the list that define an animation with the same name as an
+  /// earlier rule, recursing into nested rule lists, e.g. when concatenating separately compiled
+  /// CSS modules. Each conflicting rule is paired with the first rule that defines the name. Rules
+  /// with different vendor prefixes, e.g. `@-webkit-keyframes` and `@keyframes`, do not conflict.
+  pub fn detect_keyframe_collisions(&self) -> Vec<KeyframeConflict<'_, 'i>> {
+    let mut keyframes = Vec::new();
+    self.collect_keyframes(&mut keyframes);
+
+    let mut conflicts = Vec::new();
+    for (index, &second) in keyframes.iter().enumerate() {
+      let first = keyframes[..index].iter().find(|first| {
+        first.name.as_str() == second.name.as_str() && first.vendor_prefix.intersects(second.vendor_prefix)
+      });
+      if let Some(&first) = first {
+        conflicts.push(KeyframeConflict { first, second });
+      }
+    }
+
+    conflicts
+  }
+
+  fn collect_keyframes<'a>(&'a self, keyframes: &mut Vec<&'a KeyframesRule<'i>>) {
+    self.for_each_rule(&mut |rule| {
+      if let CssRule::Keyframes(rule) = rule {
+        keyframes.push(rule)
+      }
+    });
+  }
+
+  /// Calls the given function for each `@keyframes` rule in the list, recursing into nested rule lists.
+  pub(crate) fn for_each_keyframes<F: FnMut(&mut KeyframesRule<'i>)>(&mut self, f: &mut F) {
+    self.for_each_rule_mut(&mut |rule| {
+      if let CssRule::Keyframes(keyframes) = rule {
+        f(keyframes)
+      }
+    });
+  }
+
+  /// Calls the given function for each rule in the list, recursing into the rules nested within
+  /// grouping rules and style rules. See [CssRule::nested_rules](CssRule::nested_rules).
+  pub(crate) fn for_each_rule<'a, F: FnMut(&'a CssRule<'i, T>)>(&'a self, f: &mut F) {
+    for rule in &self.0 {
+      f(rule);
+      if let Some(rules) = rule.nested_rules() {
+        rules.for_each_rule(f);
+      }
+    }
+  }
+
+  /// Calls the given function for each rule in the list, recursing into nested rules.
+  /// See [for_each_rule](CssRuleList::for_each_rule).
+  pub(crate) fn for_each_rule_mut<F: FnMut(&mut CssRule<'i, T>)>(&mut self, f: &mut F) {
+    for rule in &mut self.0 {
+      f(rule);
+      if let Some(rules) = rule.nested_rules_mut() {
+        rules.for_each_rule_mut(f);
+      }
+    }
+  }
+
+  /// Resolves `@media` rules with `prefers-color-scheme` features for the given color scheme, i.e. `light`
+  /// or `dark`, recursing into nested rule lists. Rules that always match are unwrapped, and rules that
+  /// never match are removed.
+  pub(crate) fn resolve_color_scheme(&mut self, scheme: &str) {
+    self.resolve_color_scheme_queries(scheme);
+    self.for_each_rule_mut(&mut |rule| {
+      if let Some(rules) = rule.nested_rules_mut() {
+        rules.resolve_color_scheme_queries(scheme);
+      }
+    });
+  }
+
+  /// Resolves the `@media` rules directly within the list. The rules of an unwrapped `@media` rule
+  /// become part of the list, so they are resolved before being inserted.
+  fn resolve_color_scheme_queries(&mut self, scheme: &str) {
+    let rules = std::mem::take(&mut self.0);
+    for mut rule in rules {
+      if let CssRule::Media(media) = &mut rule {
+        match media.query.resolve_color_scheme(scheme) {
+          Resolved::Known(true) => {
+            media.rules.resolve_color_scheme_queries(scheme);
+            self.0.append(&mut media.rules.0);
+            continue;
+          }
+          Resolved::Known(false) => continue,
+          Resolved::Residual(query) => media.query = query,
+        }
+      }
+      self.0.push(rule);
+    }
+  }
+
+  /// Reports style rules with selectors that use pseudo-classes or pseudo-elements not supported by the
+  /// targets, recursing into nested rules, and removes them if `remove` is true. Rules within `@supports`
+  /// rules that test for selector support are assumed to be guarded by the author and are not checked.
+  pub(crate) fn check_selector_support(
+    &mut self,
+    targets: Targets,
+    remove: bool,
+    warnings: &mut Vec<MinifyError>,
+  ) {
+    self.0.retain_mut(|rule| {
+      match rule {
+        CssRule::Style(style) => {
+          if let Some(name) = crate::selector::find_unsupported_pseudo(&style.selectors.0, targets) {
+            warnings.push(MinifyError {
+              kind: MinifyErrorKind::UnsupportedSelector { name: name.into() },
+              loc: style.loc,
+            });
+            if remove {
+              return false;
+            }
+          }
+          style.rules.check_selector_support(targets, remove, warnings)
+        }
+        CssRule::Supports(supports) if !supports.condition.has_selector() => {
+          supports.rules.check_selector_support(targets, remove, warnings)
+        }
+        CssRule::Media(media) => media.rules.check_selector_support(targets, remove, warnings),
+        CssRule::Container(container) => container.rules.check_selector_support(targets, remove, warnings),
+        CssRule::LayerBlock(layer) => layer.rules.check_selector_support(targets, remove, warnings),
+        CssRule::MozDocument(document) => document.rules.check_selector_support(targets, remove, warnings),
+        CssRule::Scope(scope) => scope.rules.check_selector_support(targets, remove, warnings),
+        CssRule::StartingStyle(starting_style) => {
+          starting_style.rules.check_selector_support(targets, remove, warnings)
+        }
+        CssRule::Nesting(nesting) => nesting.style.rules.check_selector_support(targets, remove, warnings),
+        _ => {}
+      }
+      true
+    });
+  }
+
+  /// Adds the namespace prefixes referenced by selectors and `attr()` functions in the list to `prefixes`,
+  /// recursing into nested rules. Returns false if the list contains rules whose selectors cannot be inspected,
+  /// e.g. custom or unknown at-rules.
+  pub(crate) fn collect_namespace_prefixes(&self, prefixes: &mut HashSet<CowArcStr<'i>>) -> bool {
+    let mut complete = true;
+    self.for_each_rule(&mut |rule| match rule {
+      CssRule::Style(style) => {
+        crate::selector::collect_namespace_prefixes(&style.selectors.0, prefixes);
+        style.declarations.collect_namespace_prefixes(prefixes);
+      }
+      CssRule::Nesting(nesting) => {
+        crate::selector::collect_namespace_prefixes(&nesting.style.selectors.0, prefixes);
+        nesting.style.declarations.collect_namespace_prefixes(prefixes);
+      }
+      CssRule::NestedDeclarations(nested) => nested.declarations.collect_namespace_prefixes(prefixes),
+      CssRule::Keyframes(keyframes) => {
+        for keyframe in &keyframes.keyframes {
+          keyframe.declarations.collect_namespace_prefixes(prefixes);
+        }
+      }
+      CssRule::Page(page) => {
+        page.declarations.collect_namespace_prefixes(prefixes);
+        for margin_rule in &page.rules {
+          margin_rule.declarations.collect_namespace_prefixes(prefixes);
+        }
+      }
+      CssRule::Viewport(viewport) => viewport.declarations.collect_namespace_prefixes(prefixes),
+      CssRule::Scope(scope) => {
+        for selectors in scope.scope_start.iter().chain(scope.scope_end.iter()) {
+          crate::selector::collect_namespace_prefixes(&selectors.0, prefixes);
+        }
+      }
+      CssRule::Supports(supports) if supports.condition.has_selector() => complete = false,
+      CssRule::Unknown(..) | CssRule::Custom(..) => complete = false,
+      _ => {}
+    });
+    complete
+  }
+
+  /// Calls the given function for the source location of each rule in the list, recursing into nested rules.
+  /// Locations within custom at-rules are not visited.
+  pub(crate) fn for_each_location<F: FnMut(&mut Location)>(&mut self, f: &mut F) {
+    for rule in &mut self.0 {
+      match rule {
+        CssRule::Media(media) => {
+          f(&mut media.loc);
+          media.rules.for_each_location(f)
+        }
+        CssRule::Supports(supports) => {
+          f(&mut supports.loc);
+          supports.rules.for_each_location(f)
+        }
+        CssRule::Container(container) => {
+          f(&mut container.loc);
+          container.rules.for_each_location(f)
+        }
+        CssRule::LayerBlock(layer) => {
+          f(&mut layer.loc);
+          layer.rules.for_each_location(f)
+        }
+        CssRule::MozDocument(document) => {
+          f(&mut document.loc);
+          document.rules.for_each_location(f)
+        }
+        CssRule::Scope(scope) => {
+          f(&mut scope.loc);
+          scope.rules.for_each_location(f)
+        }
+        CssRule::StartingStyle(starting_style) => {
+          f(&mut starting_style.loc);
+          starting_style.rules.for_each_location(f)
+        }
+        CssRule::Style(style) => {
+          f(&mut style.loc);
+          style.rules.for_each_location(f)
+        }
+        CssRule::Nesting(nesting) => {
+          f(&mut nesting.loc);
+          f(&mut nesting.style.loc);
+          nesting.style.rules.for_each_location(f)
+        }
+        CssRule::Page(page) => {
+          f(&mut page.loc);
+          for margin_rule in &mut page.rules {
+            f(&mut margin_rule.loc);
+          }
+        }
+        CssRule::FontFeatureValues(font_feature_values) => {
+          f(&mut font_feature_values.loc);
+          for subrule in font_feature_values.rules.values_mut() {
+            f(&mut subrule.loc);
+          }
+        }
+        CssRule::Import(import) => f(&mut import.loc),
+        CssRule::Keyframes(keyframes) => f(&mut keyframes.loc),
+        CssRule::FontFace(font_face) => f(&mut font_face.loc),
+        CssRule::FontPaletteValues(font_palette_values) => f(&mut font_palette_values.loc),
+        CssRule::CounterStyle(counter_style) => f(&mut counter_style.loc),
+        CssRule::Namespace(namespace) => f(&mut namespace.loc),
+        CssRule::NestedDeclarations(nested) => f(&mut nested.loc),
+        CssRule::Viewport(viewport) => f(&mut viewport.loc),
+        CssRule::CustomMedia(custom_media) => f(&mut custom_media.loc),
+        CssRule::LayerStatement(layer) => f(&mut layer.loc),
+        CssRule::Property(property) => f(&mut property.loc),
+        CssRule::ViewTransition(view_transition) => f(&mut view_transition.loc),
+        CssRule::Unknown(unknown) => f(&mut unknown.loc),
+        CssRule::Ignored | CssRule::Custom(..) => {}
+      }
+    }
+  }
+}
+
 // Manually implemented to avoid circular child types.
 #[cfg(feature = "visitor")]
 #[cfg_attr(docsrs, doc(cfg(feature = "visitor")))]
@@ -525,32 +805,46 @@ pub(crate) struct MinifyContext<'a, 'i> {
   pub important_handler: &'a mut DeclarationHandler<'i>,
   pub handler_context: PropertyHandlerContext<'i, 'a>,
   pub unused_symbols: &'a HashSet<String>,
+  pub known_dimensions: Option<KnownDimensions>,
   pub custom_media: Option<HashMap<CowArcStr<'i>, CustomMediaRule<'i>>>,
   pub css_modules: bool,
   pub pure_css_modules: bool,
+  pub merge_keyframes: bool,
 }
 
+/// The result of minifying a style rule ahead of time: whether the rule is unused,
+/// and the fallback declarations collected while minifying it.
+pub(crate) type MinifiedStyleRule<'i> = Result<(bool, PropertyHandlerFallbacks<'i>), MinifyError>;
+
 impl<'i, T: Clone> CssRuleList<'i, T> {
   pub(crate) fn minify(
     &mut self,
     context: &mut MinifyContext<'_, 'i>,
     parent_is_unused: bool,
+  ) -> Result<(), MinifyError> {
+    self.minify_with(context, parent_is_unused, &mut [])
+  }
+
+  /// Minifies the rule list, using the results in `minified` for style rules that were already
+  /// minified (e.g. by `minify_style_rules_parallel`), indexed by their position in the list.
+  pub(crate) fn minify_with(
+    &mut self,
+    context: &mut MinifyContext<'_, 'i>,
+    parent_is_unused: bool,
+    minified: &mut [Option<MinifiedStyleRule<'i>>],
   ) -> Result<(), MinifyError> {
     let mut keyframe_rules = HashMap::new();
     let mut layer_rules = HashMap::new();
     let mut has_layers = false;
-    let mut property_rules = HashMap::new();
+    let mut property_rules = HashMap::<_, (usize, std::ops::Range<usize>)>::new();
     let mut font_feature_values_rules = Vec::new();
     let mut style_rules =
       HashMap::with_capacity_and_hasher(self.0.len(), BuildHasherDefault::<PrecomputedHasher>::default());
     let mut rules = Vec::new();
-    for mut rule in self.0.drain(..) {
+    for (index, mut rule) in self.0.drain(..).enumerate() {
       match &mut rule {
         CssRule::Keyframes(keyframes) => {
-          if context.unused_symbols.contains(match &keyframes.name {
-            KeyframesName::Ident(ident) => ident.0.as_ref(),
-            KeyframesName::Custom(string) => string.as_ref(),
-          }) {
+          if context.unused_symbols.contains(keyframes.name.as_str()) {
             continue;
           }
           keyframes.minify(context);
@@ -565,9 +859,14 @@ impl<'i, T: Clone> CssRuleList<'i, T> {
           // Merge @keyframes rules with the same name.
           if let Some(existing_idx) = keyframe_rules.get(&keyframes.name) {
             if let Some(CssRule::Keyframes(existing)) = &mut rules.get_mut(*existing_idx) {
-              // If the existing rule has the same vendor prefixes, replace it with this rule.
+              // If the existing rule has the same vendor prefixes, replace it with this rule,
+              // or merge the keyframes of both rules if enabled.
               if existing.vendor_prefix == keyframes.vendor_prefix {
-                *existing = keyframes.clone();
+                if context.merge_keyframes {
+                  existing.merge(std::mem::take(&mut keyframes.keyframes));
+                } else {
+                  *existing = keyframes.clone();
+                }
                 continue;
               }
               // Otherwise, if the keyframes are identical, merge the prefixes.
@@ -593,16 +892,30 @@ impl<'i, T: Clone> CssRuleList<'i, T> {
           }
         }
         CssRule::Media(media) => {
+          if media.minify(context, parent_is_unused)? {
+            continue;
+          }
+
+          // Merge with the previous rule if the queries are the same. This happens after minifying,
+          // since nested @media rules may have been combined into this one.
           if let Some(CssRule::Media(last_rule)) = rules.last_mut() {
             if last_rule.query == media.query {
-              last_rule.rules.0.extend(media.rules.0.drain(..));
+              last_rule.rules.0.append(&mut media.rules.0);
               last_rule.minify(context, parent_is_unused)?;
               continue;
             }
           }
 
-          if media.minify(context, parent_is_unused)? {
-            continue;
+          // With a known viewport size, the query may always or never match.
+          if let Some(dimensions) = &context.known_dimensions {
+            match media.query.evaluate(dimensions) {
+              Some(true) => {
+                rules.append(&mut media.rules.0);
+                continue;
+              }
+              Some(false) => continue,
+              None => {}
+            }
           }
         }
         CssRule::Supports(supports) => {
@@ -614,10 +927,26 @@ impl<'i, T: Clone> CssRuleList<'i, T> {
             }
           }
 
+          // Drop the rule if the condition never matches for the targets,
+          // or hoist its contents if it always does.
+          let result = context
+            .targets
+            .current
+            .browsers
+            .and_then(|browsers| supports.condition.evaluate(browsers));
+          if result == Some(false) {
+            continue;
+          }
+
           supports.minify(context, parent_is_unused)?;
           if supports.rules.0.is_empty() {
             continue;
           }
+
+          if result == Some(true) {
+            rules.append(&mut supports.rules.0);
+            continue;
+          }
         }
         CssRule::Container(container) => {
           if let Some(CssRule::Container(last_rule)) = rules.last_mut() {
@@ -665,7 +994,16 @@ impl<'i, T: Clone> CssRuleList<'i, T> {
         }
         CssRule::MozDocument(document) => document.minify(context)?,
         CssRule::Style(style) => {
-          if parent_is_unused || style.minify(context, parent_is_unused)? {
+          let unused = parent_is_unused
+            || match minified.get_mut(index).and_then(Option::take) {
+              Some(result) => {
+                let (unused, fallbacks) = result?;
+                context.handler_context.restore_fallbacks(fallbacks);
+                unused
+              }
+              None => style.minify(context, parent_is_unused)?,
+            };
+          if unused {
             continue;
           }
 
@@ -833,6 +1171,7 @@ impl<'i, T: Clone> CssRuleList<'i, T> {
           if context.unused_symbols.contains(counter_style.name.0.as_ref()) {
             continue;
           }
+          counter_style.minify();
         }
         CssRule::Scope(scope) => scope.minify(context)?,
         CssRule::Nesting(nesting) => {
@@ -850,6 +1189,7 @@ impl<'i, T: Clone> CssRuleList<'i, T> {
             continue;
           }
         }
+        CssRule::FontFace(font_face) => font_face.minify(),
         CssRule::FontPaletteValues(f) => {
           if context.unused_symbols.contains(f.name.0.as_ref()) {
             continue;
@@ -880,14 +1220,26 @@ impl<'i, T: Clone> CssRuleList<'i, T> {
             continue;
           }
 
-          if let Some(index) = property_rules.get(&property.name) {
+          let fallbacks = property.get_fallbacks(context.targets.current);
+          let fallback_range = rules.len() + 1..rules.len() + 1 + fallbacks.len();
+          if let Some((index, existing_fallbacks)) = property_rules.get_mut(&property.name) {
+            // The last @property rule wins, so remove the fallbacks generated for the previous one.
+            for i in existing_fallbacks.clone() {
+              rules[i] = CssRule::Ignored;
+            }
+            *existing_fallbacks = rules.len()..rules.len() + fallbacks.len();
             rules[*index] = rule;
+            rules.extend(fallbacks);
             continue;
-          } else {
-            property_rules.insert(property.name.clone(), rules.len());
           }
+
+          property_rules.insert(property.name.clone(), (rules.len(), fallback_range));
+          rules.push(rule);
+          rules.extend(fallbacks);
+          continue;
         }
-        CssRule::Import(_) => {
+        CssRule::Import(import) => {
+          import.minify(context)?;
           // @layer blocks can't be inlined into layers declared before imports.
           layer_rules.clear();
         }
@@ -958,6 +1310,68 @@ impl<'i, T: Clone> CssRuleList<'i, T> {
   }
 }
 
+#[cfg(feature = "rayon")]
+impl<'i, T: Clone + Send> CssRuleList<'i, T> {
+  /// Minifies the style rules in the list in parallel, returning the results indexed by position,
+  /// to be passed to `minify_with`.
+  ///
+  /// Style rules do not share any minification state with other rules, so their declarations
+  /// can be minified independently. Steps that depend on adjacent rules, such as merging,
+  /// are performed sequentially by `minify_with`.
+  pub(crate) fn minify_style_rules_parallel(
+    &mut self,
+    context: &MinifyContext<'_, 'i>,
+  ) -> Vec<Option<MinifiedStyleRule<'i>>> {
+    use rayon::prelude::*;
+
+    let targets = context.targets.current;
+    let handler_targets = context.handler_context.targets;
+    let unused_symbols = context.unused_symbols;
+    let known_dimensions = context.known_dimensions;
+    let custom_media = &context.custom_media;
+    let css_modules = context.css_modules;
+    let pure_css_modules = context.pure_css_modules;
+    let merge_keyframes = context.merge_keyframes;
+
+    self
+      .0
+      .par_iter_mut()
+      .map_init(
+        || (DeclarationHandler::default(), DeclarationHandler::default()),
+        |(handler, important_handler), rule| {
+          let CssRule::Style(style) = rule else {
+            return None;
+          };
+
+          let mut context = MinifyContext {
+            targets: TargetsWithSupportsScope::new(targets),
+            handler,
+            important_handler,
+            handler_context: PropertyHandlerContext::new(handler_targets, unused_symbols),
+            unused_symbols,
+            known_dimensions,
+            // Custom media queries are only needed to minify nested rules.
+            custom_media: if style.rules.0.is_empty() {
+              None
+            } else {
+              custom_media.clone()
+            },
+            css_modules,
+            pure_css_modules,
+            merge_keyframes,
+          };
+
+          Some(
+            style
+              .minify(&mut context, false)
+              .map(|unused| (unused, context.handler_context.take_fallbacks())),
+          )
+        },
+      )
+      .collect()
+  }
+}
+
 fn merge_style_rules<'i, T>(
   style: &mut StyleRule<'i, T>,
   last_style_rule: &mut StyleRule<'i, T>,
@@ -1059,6 +1473,7 @@ impl<'a, 'i, T: ToCss> ToCss for CssRuleList<'i, T> {
           dest.write_char('\n')?;
         }
         dest.newline()?;
+        dest.rule_separator()?;
       }
       rule.to_css(dest)?;
 
@@ -99,7 +99,7 @@ impl<'i> ToCss for NestedDeclarationsRule<'i> {
           dest.with_parent_context(|dest| context.selectors.to_css(dest))?;
           dest.whitespace()?;
           dest.write_char('{')?;
-          dest.indent();
+          dest.increase_indent();
           dest.newline()?;
         }
 
@@ -108,7 +108,7 @@ impl<'i> ToCss for NestedDeclarationsRule<'i> {
           .to_css_declarations(dest, false, &context.selectors, self.loc.source_index)?;
 
         if has_printable_declarations {
-          dest.dedent();
+          dest.decrease_indent();
           dest.newline()?;
           dest.write_char('}')?;
         }
@@ -5,8 +5,13 @@ use crate::declaration::{parse_declaration, DeclarationBlock};
 use crate::error::{ParserError, PrinterError};
 use crate::macros::enum_property;
 use crate::printer::Printer;
+use crate::properties::custom::TokenList;
+#[cfg(feature = "serde")]
+use crate::serialization::ValueWrapper;
 use crate::stylesheet::ParserOptions;
-use crate::traits::{Parse, ToCss};
+use crate::traits::{Parse, ToCss, TrySign};
+use crate::values::length::Length;
+use crate::values::size::Size2D;
 use crate::values::string::CowArcStr;
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
@@ -119,6 +124,168 @@ enum_property! {
   }
 }
 
+enum_property! {
+  /// A [page size](https://www.w3.org/TR/css-page-3/#typedef-page-size-page-size) keyword
+  /// in the `size` descriptor of an `@page` rule.
+  pub enum PageSizeKeyword {
+    /// 148mm x 210mm.
+    "a5": A5,
+    /// 210mm x 297mm.
+    "a4": A4,
+    /// 297mm x 420mm.
+    "a3": A3,
+    /// 176mm x 250mm.
+    "b5": B5,
+    /// 250mm x 353mm.
+    "b4": B4,
+    /// 182mm x 257mm.
+    "jis-b5": JisB5,
+    /// 257mm x 364mm.
+    "jis-b4": JisB4,
+    /// 8.5in x 11in.
+    "letter": Letter,
+    /// 8.5in x 14in.
+    "legal": Legal,
+    /// 11in x 17in.
+    "ledger": Ledger,
+  }
+}
+
+enum_property! {
+  /// A page orientation keyword in the `size` descriptor of an `@page` rule.
+  pub enum PageOrientation {
+    /// The longest edge of the page is vertical.
+    Portrait,
+    /// The longest edge of the page is horizontal.
+    Landscape,
+  }
+}
+
+/// A value for the [size](https://www.w3.org/TR/css-page-3/#page-size-prop)
+/// descriptor in an `@page` rule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum PageSize {
+  /// The page size is determined by the user agent.
+  Auto,
+  /// An explicit width and height.
+  #[cfg_attr(feature = "serde", serde(with = "ValueWrapper::<Size2D<Length>>"))]
+  Length(Size2D<Length>),
+  /// A named page size and/or orientation.
+  Keyword {
+    /// A named page size.
+    size: Option<PageSizeKeyword>,
+    /// The page orientation.
+    orientation: Option<PageOrientation>,
+  },
+}
+
+/// The value of the [size](https://www.w3.org/TR/css-page-3/#page-size-prop) descriptor
+/// in an `@page` rule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "into_owned", derive(static_self::IntoOwned))]
+pub enum PageSizeDescriptor<'i> {
+  /// A page size.
+  Size(PageSize),
+  /// A value that could not be parsed, e.g. one containing `var()`, kept as written.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Unparsed(TokenList<'i>),
+}
+
+impl<'i> ToCss for PageSizeDescriptor<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      PageSizeDescriptor::Size(size) => size.to_css(dest),
+      PageSizeDescriptor::Unparsed(tokens) => tokens.to_css(dest, false),
+    }
+  }
+}
+
+impl<'i> Parse<'i> for PageSize {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(PageSize::Auto);
+    }
+
+    if let Ok(width) = input.try_parse(Length::parse) {
+      let height = input.try_parse(Length::parse).unwrap_or_else(|_| width.clone());
+      if width.is_sign_negative() || height.is_sign_negative() {
+        return Err(input.new_custom_error(ParserError::InvalidValue));
+      }
+      return Ok(PageSize::Length(Size2D(width, height)));
+    }
+
+    let mut size = None;
+    let mut orientation = None;
+    loop {
+      if size.is_none() {
+        if let Ok(val) = input.try_parse(PageSizeKeyword::parse) {
+          size = Some(val);
+          continue;
+        }
+      }
+
+      if orientation.is_none() {
+        if let Ok(val) = input.try_parse(PageOrientation::parse) {
+          orientation = Some(val);
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    if size.is_none() && orientation.is_none() {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(PageSize::Keyword { size, orientation })
+  }
+}
+
+impl ToCss for PageSize {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      PageSize::Auto => dest.write_str("auto"),
+      PageSize::Length(size) => size.to_css(dest),
+      PageSize::Keyword { size, orientation } => {
+        if let Some(size) = size {
+          size.to_css(dest)?;
+          if orientation.is_some() {
+            dest.write_char(' ')?;
+          }
+        }
+
+        if let Some(orientation) = orientation {
+          orientation.to_css(dest)?;
+        }
+
+        Ok(())
+      }
+    }
+  }
+}
+
 /// A [page margin rule](https://www.w3.org/TR/css-page-3/#margin-at-rules) rule.
 #[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(feature = "visitor", derive(Visit))]
@@ -164,6 +331,9 @@ pub struct PageRule<'i> {
   #[cfg_attr(feature = "serde", serde(borrow))]
   #[cfg_attr(feature = "visitor", skip_visit)]
   pub selectors: Vec<PageSelector<'i>>,
+  /// The `size` descriptor, if any. When it is declared more than once, the last
+  /// declaration wins, since they would override each other.
+  pub size: Option<PageSizeDescriptor<'i>>,
   /// The declarations within the `@page` rule.
   pub declarations: DeclarationBlock<'i>,
   /// The nested margin rules.
@@ -180,9 +350,11 @@ impl<'i> PageRule<'i> {
     loc: Location,
     options: &ParserOptions<'o, 'i>,
   ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut size = None;
     let mut declarations = DeclarationBlock::new();
     let mut rules = Vec::new();
     let mut rule_parser = PageRuleParser {
+      size: &mut size,
       declarations: &mut declarations,
       rules: &mut rules,
       options: &options,
@@ -201,6 +373,7 @@ impl<'i> PageRule<'i> {
 
     Ok(PageRule {
       selectors,
+      size,
       declarations,
       rules,
       loc,
@@ -234,10 +407,21 @@ impl<'i> ToCss for PageRule<'i> {
 
     dest.whitespace()?;
     dest.write_char('{')?;
-    dest.indent();
+    dest.increase_indent();
 
     let mut i = 0;
-    let len = self.declarations.len() + self.rules.len();
+    let len = self.size.is_some() as usize + self.declarations.len() + self.rules.len();
+
+    if let Some(size) = &self.size {
+      dest.newline()?;
+      dest.write_str("size")?;
+      dest.delim(':', false)?;
+      size.to_css(dest)?;
+      if i != len - 1 || !dest.minify {
+        dest.write_char(';')?;
+      }
+      i += 1;
+    }
 
     macro_rules! write {
       ($decls: expr, $important: literal) => {
@@ -256,7 +440,7 @@ impl<'i> ToCss for PageRule<'i> {
     write!(self.declarations.important_declarations, true);
 
     if !self.rules.is_empty() {
-      if !dest.minify && self.declarations.len() > 0 {
+      if !dest.minify && (self.size.is_some() || self.declarations.len() > 0) {
         dest.write_char('\n')?;
       }
       dest.newline()?;
@@ -275,7 +459,7 @@ impl<'i> ToCss for PageRule<'i> {
       }
     }
 
-    dest.dedent();
+    dest.decrease_indent();
     dest.newline()?;
     dest.write_char('}')
   }
@@ -300,6 +484,7 @@ impl<'i> ToCss for PageSelector<'i> {
 }
 
 struct PageRuleParser<'a, 'o, 'i> {
+  size: &'a mut Option<PageSizeDescriptor<'i>>,
   declarations: &'a mut DeclarationBlock<'i>,
   rules: &'a mut Vec<PageMarginRule<'i>>,
   options: &'a ParserOptions<'o, 'i>,
@@ -314,6 +499,28 @@ impl<'a, 'o, 'i> cssparser::DeclarationParser<'i> for PageRuleParser<'a, 'o, 'i>
     name: CowRcStr<'i>,
     input: &mut cssparser::Parser<'i, 't>,
   ) -> Result<Self::Declaration, cssparser::ParseError<'i, Self::Error>> {
+    // An `!important` size is kept as a declaration, since it wins regardless of its position.
+    if name.eq_ignore_ascii_case("size") {
+      let options = self.options;
+      if let Ok(size) = input.try_parse(|input| {
+        input.parse_entirely(|input| {
+          input.parse_until_before(Delimiter::Bang, |input| {
+            if let Ok(size) = input.try_parse(|input| input.parse_entirely(PageSize::parse)) {
+              return Ok(PageSizeDescriptor::Size(size));
+            }
+            let tokens = TokenList::parse(input, options, 0)?;
+            if tokens.0.is_empty() {
+              return Err(input.new_custom_error(ParserError::InvalidValue));
+            }
+            Ok(PageSizeDescriptor::Unparsed(tokens))
+          })
+        })
+      }) {
+        *self.size = Some(size);
+        return Ok(());
+      }
+    }
+
     parse_declaration(
       name,
       input,
@@ -1,14 +1,17 @@
 //! The `@property` rule.
 
-use super::Location;
+use super::supports::SupportsRule;
+use super::{CssRule, CssRuleList, Location};
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
 use crate::{
   error::{ParserError, PrinterError},
   printer::Printer,
   properties::custom::TokenList,
+  targets::Targets,
   traits::{Parse, ToCss},
   values::{
+    color::ColorFallbackKind,
     ident::DashedIdent,
     syntax::{ParsedComponent, SyntaxString},
   },
@@ -109,6 +112,51 @@ impl<'i> PropertyRule<'i> {
   }
 }
 
+impl<'i> PropertyRule<'i> {
+  pub(crate) fn get_fallbacks<T>(&mut self, targets: Targets) -> Vec<CssRule<'i, T>> {
+    // Get fallbacks for colors in the initial value. The lowest one replaces the original
+    // value, and the rest generate @supports rules containing duplicate @property rules.
+    let mut fallbacks = match &self.initial_value {
+      Some(initial_value) => initial_value.get_necessary_fallbacks(targets),
+      None => return Vec::new(),
+    };
+
+    let mut res = Vec::new();
+    let lowest_fallback = fallbacks.lowest();
+    fallbacks.remove(lowest_fallback);
+
+    if fallbacks.contains(ColorFallbackKind::P3) {
+      res.push(self.get_fallback(ColorFallbackKind::P3));
+    }
+
+    if fallbacks.contains(ColorFallbackKind::LAB) {
+      res.push(self.get_fallback(ColorFallbackKind::LAB));
+    }
+
+    if !lowest_fallback.is_empty() {
+      if let Some(initial_value) = &mut self.initial_value {
+        *initial_value = initial_value.get_fallback(lowest_fallback);
+      }
+    }
+
+    res
+  }
+
+  fn get_fallback<T>(&self, kind: ColorFallbackKind) -> CssRule<'i, T> {
+    CssRule::Supports(SupportsRule {
+      condition: kind.supports_condition(),
+      rules: CssRuleList(vec![CssRule::Property(PropertyRule {
+        name: self.name.clone(),
+        syntax: self.syntax.clone(),
+        inherits: self.inherits,
+        initial_value: self.initial_value.as_ref().map(|v| v.get_fallback(kind)),
+        loc: self.loc,
+      })]),
+      loc: self.loc,
+    })
+  }
+}
+
 impl<'i> ToCss for PropertyRule<'i> {
   fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
   where
@@ -120,7 +168,7 @@ impl<'i> ToCss for PropertyRule<'i> {
     self.name.to_css(dest)?;
     dest.whitespace()?;
     dest.write_char('{')?;
-    dest.indent();
+    dest.increase_indent();
     dest.newline()?;
 
     dest.write_str("syntax:")?;
@@ -149,7 +197,7 @@ impl<'i> ToCss for PropertyRule<'i> {
       }
     }
 
-    dest.dedent();
+    dest.decrease_indent();
     dest.newline()?;
     dest.write_char('}')
   }
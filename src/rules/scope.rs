@@ -94,13 +94,13 @@ impl<'i, T: ToCss> ToCss for ScopeRule<'i, T> {
       dest.whitespace()?;
     }
     dest.write_char('{')?;
-    dest.indent();
+    dest.increase_indent();
     dest.newline()?;
     // Nested style rules within @scope are implicitly relative to the <scope-start>
     // so clear our style context while printing them to avoid replacing & ourselves.
     // https://drafts.csswg.org/css-cascade-6/#scoped-rules
     dest.with_cleared_context(|dest| self.rules.to_css(dest))?;
-    dest.dedent();
+    dest.decrease_indent();
     dest.newline()?;
     dest.write_char('}')
   }
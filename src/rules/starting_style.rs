@@ -45,10 +45,10 @@ impl<'i, T: ToCss> ToCss for StartingStyleRule<'i, T> {
     dest.write_str("@starting-style")?;
     dest.whitespace()?;
     dest.write_char('{')?;
-    dest.indent();
+    dest.increase_indent();
     dest.newline()?;
     self.rules.to_css(dest)?;
-    dest.dedent();
+    dest.decrease_indent();
     dest.newline()?;
     dest.write_char('}')
   }
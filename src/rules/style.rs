@@ -228,6 +228,7 @@ impl<'a, 'i, T: ToCss> ToCss for StyleRule<'i, T> {
             dest.write_char('\n')?; // no indent
           }
           dest.newline()?;
+          dest.rule_separator()?;
         }
         dest.vendor_prefix = prefix;
         self.to_css_base(dest)?;
@@ -255,7 +256,7 @@ impl<'a, 'i, T: ToCss> StyleRule<'i, T> {
       self.selectors.to_css(dest)?;
       dest.whitespace()?;
       dest.write_char('{')?;
-      dest.indent();
+      dest.increase_indent();
       if len > 0 {
         dest.newline()?;
       }
@@ -282,7 +283,7 @@ impl<'a, 'i, T: ToCss> StyleRule<'i, T> {
     macro_rules! end {
       () => {
         if has_declarations {
-          dest.dedent();
+          dest.decrease_indent();
           dest.newline()?;
           dest.write_char('}')?;
         }
@@ -297,6 +298,9 @@ impl<'a, 'i, T: ToCss> StyleRule<'i, T> {
     } else {
       end!();
       newline!();
+      if has_declarations {
+        dest.rule_separator()?;
+      }
       dest.with_context(&self.selectors, |dest| self.rules.to_css(dest))?;
     }
 
@@ -4,13 +4,16 @@ use std::collections::HashMap;
 
 use super::Location;
 use super::{CssRuleList, MinifyContext};
+use crate::compat::Feature;
 use crate::error::{MinifyError, ParserError, PrinterError};
 use crate::parser::DefaultAtRule;
 use crate::printer::Printer;
+use crate::properties::contain::{ContainerType, ContentVisibility};
 use crate::properties::custom::TokenList;
 use crate::properties::PropertyId;
-use crate::targets::{Features, FeaturesIterator, Targets};
-use crate::traits::{Parse, ToCss};
+use crate::targets::{Browsers, Features, FeaturesIterator, Targets};
+use crate::traits::{IsCompatible, Parse, ToCss};
+use crate::values::color::CssColor;
 use crate::values::string::CowArcStr;
 use crate::vendor_prefix::VendorPrefix;
 #[cfg(feature = "visitor")]
@@ -70,7 +73,7 @@ impl<'a, 'i, T: ToCss> ToCss for SupportsRule<'i, T> {
     self.condition.to_css(dest)?;
     dest.whitespace()?;
     dest.write_char('{')?;
-    dest.indent();
+    dest.increase_indent();
     dest.newline()?;
 
     let inserted = dest.targets.enter_supports(self.condition.get_supported_features());
@@ -79,7 +82,7 @@ impl<'a, 'i, T: ToCss> ToCss for SupportsRule<'i, T> {
       dest.targets.exit_supports();
     }
 
-    dest.dedent();
+    dest.decrease_indent();
     dest.newline()?;
     dest.write_char('}')
   }
@@ -150,7 +153,17 @@ impl<'i> SupportsCondition<'i> {
     }
   }
 
-  fn set_prefixes_for_targets(&mut self, targets: &Targets) {
+  /// Returns whether this condition tests support for a selector via `selector()`.
+  pub(crate) fn has_selector(&self) -> bool {
+    match self {
+      SupportsCondition::Not(cond) => cond.has_selector(),
+      SupportsCondition::And(items) | SupportsCondition::Or(items) => items.iter().any(|item| item.has_selector()),
+      SupportsCondition::Selector(_) => true,
+      _ => false,
+    }
+  }
+
+  pub(crate) fn set_prefixes_for_targets(&mut self, targets: &Targets) {
     match self {
       SupportsCondition::Not(cond) => cond.set_prefixes_for_targets(targets),
       SupportsCondition::And(items) | SupportsCondition::Or(items) => {
@@ -189,6 +202,89 @@ impl<'i> SupportsCondition<'i> {
 
     get_supported_features_internal(self).unwrap_or(Features::empty())
   }
+
+  /// Statically evaluates the condition for the given browser targets.
+  ///
+  /// Returns `Some(true)` if the condition is known to match in all of the targets,
+  /// `Some(false)` if it is known to never match, and `None` otherwise.
+  /// Only a small set of declarations can be evaluated, and selectors are never evaluated.
+  /// Conditions are never evaluated if any of the targets do not support `@supports` rules.
+  pub fn evaluate(&self, browsers: Browsers) -> Option<bool> {
+    if !Feature::SupportsRule.is_compatible(browsers) {
+      return None;
+    }
+
+    match self {
+      SupportsCondition::Not(condition) => condition.evaluate(browsers).map(|result| !result),
+      SupportsCondition::And(conditions) => {
+        let mut result = Some(true);
+        for condition in conditions {
+          match condition.evaluate(browsers) {
+            Some(false) => return Some(false),
+            Some(true) => {}
+            None => result = None,
+          }
+        }
+        result
+      }
+      SupportsCondition::Or(conditions) => {
+        let mut result = Some(false);
+        for condition in conditions {
+          match condition.evaluate(browsers) {
+            Some(true) => return Some(true),
+            Some(false) => {}
+            None => result = None,
+          }
+        }
+        result
+      }
+      SupportsCondition::Declaration { property_id, value } => {
+        // Targets are minimum versions, so a declaration is never known to be unsupported,
+        // only to be supported by all of the targets.
+        if browsers == Browsers::default() {
+          return None;
+        }
+
+        let mut input = ParserInput::new(value);
+        let mut parser = Parser::new(&mut input);
+        let is_compatible = match property_id {
+          PropertyId::Display => parser
+            .parse_entirely(|input| -> Result<Feature, ParseError<'_, ParserError<'_>>> {
+              let location = input.current_source_location();
+              let ident = input.expect_ident()?;
+              match_ignore_ascii_case! { ident,
+                "flex" | "inline-flex" => Ok(Feature::DisplayFlex),
+                "grid" | "inline-grid" => Ok(Feature::DisplayGrid),
+                _ => Err(location.new_unexpected_token_error(Token::Ident(ident.clone())))
+              }
+            })
+            .ok()?
+            .is_compatible(browsers),
+          PropertyId::ContentVisibility => {
+            parser.parse_entirely(ContentVisibility::parse).ok()?;
+            Feature::ContentVisibility.is_compatible(browsers)
+          }
+          PropertyId::ContainerType => match parser.parse_entirely(ContainerType::parse).ok()? {
+            ContainerType::Normal | ContainerType::InlineSize | ContainerType::Size => {
+              Feature::ContainerQueries.is_compatible(browsers)
+            }
+            ContainerType::ScrollState => return None,
+          },
+          PropertyId::Color | PropertyId::BackgroundColor => {
+            parser.parse_entirely(CssColor::parse).ok()?.is_compatible(browsers)
+          }
+          _ => return None,
+        };
+
+        if is_compatible {
+          Some(true)
+        } else {
+          None
+        }
+      }
+      SupportsCondition::Selector(_) | SupportsCondition::Unknown(_) => None,
+    }
+  }
 }
 
 impl<'i> Parse<'i> for SupportsCondition<'i> {
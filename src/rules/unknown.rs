@@ -47,10 +47,10 @@ impl<'i> ToCss for UnknownAtRule<'i> {
     if let Some(block) = &self.block {
       dest.whitespace()?;
       dest.write_char('{')?;
-      dest.indent();
+      dest.increase_indent();
       dest.newline()?;
       block.to_css(dest, false)?;
-      dest.dedent();
+      dest.decrease_indent();
       dest.newline()?;
       dest.write_char('}')
     } else {
@@ -128,16 +128,19 @@ impl<'i> RuleBodyItemParser<'i, ViewTransitionProperty<'i>, ParserError<'i>> for
 }
 
 impl<'i> ViewTransitionRule<'i> {
-  pub(crate) fn parse<'t>(
+  pub(crate) fn parse<'t, 'o>(
     input: &mut Parser<'i, 't>,
     loc: Location,
+    options: &ParserOptions<'o, 'i>,
   ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     let mut decl_parser = ViewTransitionDeclarationParser;
     let mut parser = RuleBodyParser::new(input, &mut decl_parser);
     let mut properties = vec![];
     while let Some(decl) = parser.next() {
-      if let Ok(decl) = decl {
-        properties.push(decl);
+      match decl {
+        Ok(decl) => properties.push(decl),
+        // Unknown descriptors are ignored by browsers, so drop them with a warning.
+        Err((err, _)) => options.warn(err),
       }
     }
 
@@ -155,7 +158,7 @@ impl<'i> ToCss for ViewTransitionRule<'i> {
     dest.write_str("@view-transition")?;
     dest.whitespace()?;
     dest.write_char('{')?;
-    dest.indent();
+    dest.increase_indent();
     let len = self.properties.len();
     for (i, prop) in self.properties.iter().enumerate() {
       dest.newline()?;
@@ -164,7 +167,7 @@ impl<'i> ToCss for ViewTransitionRule<'i> {
         dest.write_char(';')?;
       }
     }
-    dest.dedent();
+    dest.decrease_indent();
     dest.newline()?;
     dest.write_char('}')
   }
@@ -18,11 +18,11 @@ use crate::{macros::enum_property, values::string::CowArcStr};
 use cssparser::*;
 use parcel_selectors::parser::{NthType, SelectorParseErrorKind};
 use parcel_selectors::{
-  attr::{AttrSelectorOperator, ParsedAttrSelectorOperation, ParsedCaseSensitivity},
+  attr::{AttrSelectorOperator, NamespaceConstraint, ParsedAttrSelectorOperation, ParsedCaseSensitivity},
   parser::SelectorImpl,
 };
 use smallvec::SmallVec;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 
 #[cfg(feature = "serde")]
@@ -61,8 +61,12 @@ pub type SelectorList<'i> = parcel_selectors::SelectorList<'i, Selectors>;
 pub type Selector<'i> = parcel_selectors::parser::Selector<'i, Selectors>;
 /// An individual component within a selector.
 pub type Component<'i> = parcel_selectors::parser::Component<'i, Selectors>;
+/// Partitions selectors into buckets by specificity.
+pub use parcel_selectors::parser::group_by_specificity;
 /// A combinator.
 pub use parcel_selectors::parser::Combinator;
+/// The specificity of a selector.
+pub use parcel_selectors::Specificity;
 
 impl<'i> SelectorImpl<'i> for Selectors {
   type AttrValue = CSSString<'i>;
@@ -88,6 +92,22 @@ impl<'i> SelectorImpl<'i> for Selectors {
 pub(crate) struct SelectorParser<'a, 'o, 'i> {
   pub is_nesting_allowed: bool,
   pub options: &'a ParserOptions<'o, 'i>,
+  namespaces: Option<&'a HashMap<CowArcStr<'i>, CowArcStr<'i>>>,
+}
+
+impl<'a, 'o, 'i> SelectorParser<'a, 'o, 'i> {
+  /// Creates a selector parser. When `namespaces` is `Some`, namespace prefixes must be
+  /// declared in it, otherwise any prefix is accepted.
+  pub fn new(
+    options: &'a ParserOptions<'o, 'i>,
+    namespaces: Option<&'a HashMap<CowArcStr<'i>, CowArcStr<'i>>>,
+  ) -> Self {
+    SelectorParser {
+      is_nesting_allowed: true,
+      options,
+      namespaces,
+    }
+  }
 }
 
 impl<'a, 'o, 'i> parcel_selectors::parser::Parser<'i> for SelectorParser<'a, 'o, 'i> {
@@ -367,7 +387,10 @@ impl<'a, 'o, 'i> parcel_selectors::parser::Parser<'i> for SelectorParser<'a, 'o,
   }
 
   fn namespace_for_prefix(&self, prefix: &Ident<'i>) -> Option<CowArcStr<'i>> {
-    Some(prefix.0.clone())
+    match self.namespaces {
+      Some(namespaces) => namespaces.get(&*prefix.0).cloned(),
+      None => Some(prefix.0.clone()),
+    }
   }
 
   #[inline]
@@ -1428,8 +1451,13 @@ where
 
     // Skip implicit :scope in relative selectors (e.g. :has(:scope > foo) -> :has(> foo))
     if is_relative && matches!(compound.get(0), Some(Component::Scope)) {
-      if let Some(combinator) = combinators.next() {
-        combinator.to_css(dest)?;
+      // The leading combinator is not preceded by whitespace.
+      match combinators.next() {
+        Some(Combinator::Child) => dest.delim('>', false)?,
+        Some(Combinator::NextSibling) => dest.delim('+', false)?,
+        Some(Combinator::LaterSibling) => dest.delim('~', false)?,
+        Some(combinator) => combinator.to_css(dest)?,
+        None => {}
       }
       compound = &compound[1..];
       is_relative = false;
@@ -1998,6 +2026,117 @@ pub(crate) fn is_compatible(selectors: &[Selector], targets: Targets) -> bool {
   true
 }
 
+/// Returns the name of the first pseudo-class or pseudo-element in the given selectors that is not
+/// supported by the targets, and cannot be compiled or prefixed for them.
+pub(crate) fn find_unsupported_pseudo(selectors: &[Selector], targets: Targets) -> Option<&'static str> {
+  for selector in selectors {
+    for component in selector.iter_raw_match_order() {
+      let (feature, name) = match component {
+        Component::Has(selectors) => {
+          if !targets.is_compatible(Feature::HasSelector) {
+            return Some(":has()");
+          }
+          if let Some(name) = find_unsupported_pseudo(selectors, targets) {
+            return Some(name);
+          }
+          continue;
+        }
+        Component::Is(selectors) | Component::Where(selectors) | Component::Negation(selectors) => {
+          if let Some(name) = find_unsupported_pseudo(selectors, targets) {
+            return Some(name);
+          }
+          continue;
+        }
+        Component::NthOf(n) => {
+          if !targets.is_compatible(Feature::NthChildOf) {
+            return Some(":nth-child(of)");
+          }
+          if let Some(name) = find_unsupported_pseudo(n.selectors(), targets) {
+            return Some(name);
+          }
+          continue;
+        }
+
+        Component::NonTSPseudoClass(pseudo) => match pseudo {
+          PseudoClass::FocusVisible => (Feature::FocusVisible, ":focus-visible"),
+          PseudoClass::FocusWithin => (Feature::FocusWithin, ":focus-within"),
+          PseudoClass::Indeterminate => (Feature::IndeterminatePseudo, ":indeterminate"),
+          PseudoClass::Default => (Feature::DefaultPseudo, ":default"),
+          PseudoClass::Optional => (Feature::OptionalPseudo, ":optional"),
+          PseudoClass::Valid => (Feature::FormValidation, ":valid"),
+          PseudoClass::Invalid => (Feature::FormValidation, ":invalid"),
+          PseudoClass::Required => (Feature::FormValidation, ":required"),
+          PseudoClass::InRange => (Feature::InOutOfRange, ":in-range"),
+          PseudoClass::OutOfRange => (Feature::InOutOfRange, ":out-of-range"),
+          PseudoClass::State { .. } => (Feature::StatePseudoClass, ":state()"),
+          _ => continue,
+        },
+
+        Component::PseudoElement(pseudo) => match pseudo {
+          PseudoElement::Marker => (Feature::MarkerPseudo, "::marker"),
+          PseudoElement::TargetText => (Feature::TargetText, "::target-text"),
+          PseudoElement::DetailsContent => (Feature::DetailsContent, "::details-content"),
+          PseudoElement::Cue => (Feature::Cue, "::cue"),
+          PseudoElement::CueFunction { .. } => (Feature::CueFunction, "::cue()"),
+          PseudoElement::ViewTransition
+          | PseudoElement::ViewTransitionNew { .. }
+          | PseudoElement::ViewTransitionOld { .. }
+          | PseudoElement::ViewTransitionGroup { .. }
+          | PseudoElement::ViewTransitionImagePair { .. } => (Feature::ViewTransition, "::view-transition"),
+          PseudoElement::PickerFunction { .. } => (Feature::Picker, "::picker()"),
+          PseudoElement::PickerIcon => (Feature::PickerIcon, "::picker-icon"),
+          PseudoElement::Checkmark => (Feature::Checkmark, "::checkmark"),
+          PseudoElement::GrammarError => (Feature::GrammarError, "::grammar-error"),
+          PseudoElement::SpellingError => (Feature::SpellingError, "::spelling-error"),
+          _ => continue,
+        },
+
+        _ => continue,
+      };
+
+      if !targets.is_compatible(feature) {
+        return Some(name);
+      }
+    }
+  }
+
+  None
+}
+
+/// Adds the namespace prefixes referenced by the given selectors, including within nested selectors, to `prefixes`.
+pub(crate) fn collect_namespace_prefixes<'i>(selectors: &[Selector<'i>], prefixes: &mut HashSet<CowArcStr<'i>>) {
+  for selector in selectors {
+    for component in selector.iter_raw_match_order() {
+      match component {
+        Component::Namespace(prefix, _) => {
+          prefixes.insert(prefix.0.clone());
+        }
+        Component::AttributeOther(attr) => {
+          if let Some(NamespaceConstraint::Specific((prefix, _))) = &attr.namespace {
+            prefixes.insert(prefix.0.clone());
+          }
+        }
+        Component::Is(selectors)
+        | Component::Where(selectors)
+        | Component::Negation(selectors)
+        | Component::Has(selectors)
+        | Component::Any(_, selectors) => collect_namespace_prefixes(selectors, prefixes),
+        Component::NthOf(n) => collect_namespace_prefixes(n.selectors(), prefixes),
+        Component::NonTSPseudoClass(PseudoClass::Global { selector } | PseudoClass::Local { selector }) => {
+          collect_namespace_prefixes(std::slice::from_ref(&**selector), prefixes)
+        }
+        Component::Slotted(selector) | Component::Host(Some(selector)) => {
+          collect_namespace_prefixes(std::slice::from_ref(selector), prefixes)
+        }
+        Component::PseudoElement(
+          PseudoElement::CueFunction { selector } | PseudoElement::CueRegionFunction { selector },
+        ) => collect_namespace_prefixes(std::slice::from_ref(&**selector), prefixes),
+        _ => {}
+      }
+    }
+  }
+}
+
 /// Returns whether two selector lists are equivalent, i.e. the same minus any vendor prefix differences.
 pub(crate) fn is_equivalent<'i>(selectors: &[Selector<'i>], other: &[Selector<'i>]) -> bool {
   if selectors.len() != other.len() {
@@ -2276,13 +2415,7 @@ impl<'i> ParseWithOptions<'i> for Selector<'i> {
     input: &mut Parser<'i, 't>,
     options: &ParserOptions<'_, 'i>,
   ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
-    Selector::parse(
-      &SelectorParser {
-        is_nesting_allowed: true,
-        options: &options,
-      },
-      input,
-    )
+    Selector::parse(&SelectorParser::new(&options, None), input)
   }
 }
 
@@ -2292,10 +2425,7 @@ impl<'i> ParseWithOptions<'i> for SelectorList<'i> {
     options: &ParserOptions<'_, 'i>,
   ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     SelectorList::parse(
-      &SelectorParser {
-        is_nesting_allowed: true,
-        options: &options,
-      },
+      &SelectorParser::new(&options, None),
       input,
       parcel_selectors::parser::ParseErrorRecovery::DiscardList,
       parcel_selectors::parser::NestingRequirement::None,
@@ -3,27 +3,45 @@
 //! A [StyleSheet](StyleSheet) represents a `.css` file or `<style>` element in HTML.
 //! A [StyleAttribute](StyleAttribute) represents an inline `style` attribute in HTML.
 
+use crate::compat::Feature;
 use crate::context::{DeclarationContext, PropertyHandlerContext};
 use crate::css_modules::{hash, CssModule, CssModuleExports, CssModuleReferences};
 use crate::declaration::{DeclarationBlock, DeclarationHandler};
 use crate::dependencies::Dependency;
-use crate::error::{Error, ErrorLocation, MinifyErrorKind, ParserError, PrinterError, PrinterErrorKind};
-use crate::parser::{DefaultAtRule, DefaultAtRuleParser, TopLevelRuleParser};
+use crate::diff::Change;
+use crate::error::{
+  Error, ErrorLocation, MinifyError, MinifyErrorKind, ParserError, PrinterError, PrinterErrorKind,
+};
+use crate::font_usage::FontUsage;
+use crate::lint::LintWarning;
+use crate::parser::{
+  parse_charset, starts_with_ignore_ascii_case, DefaultAtRule, DefaultAtRuleParser, TopLevelRuleParser,
+};
 use crate::printer::Printer;
-use crate::rules::{CssRule, CssRuleList, MinifyContext};
+use crate::properties::custom::{Token, TokenList, TokenOrValue};
+use crate::rules::keyframes::KeyframesRule;
+use crate::rules::layer::LayerName;
+use crate::rules::namespace::NamespaceRule;
+use crate::rules::unknown::UnknownAtRule;
+use crate::rules::{CssRule, CssRuleList, Location, MinifiedStyleRule, MinifyContext};
 use crate::targets::{should_compile, Targets, TargetsWithSupportsScope};
 use crate::traits::{AtRuleParser, ToCss};
 use crate::values::string::CowArcStr;
+use crate::vendor_prefix::VendorPrefix;
 #[cfg(feature = "visitor")]
 use crate::visitor::{Visit, VisitTypes, Visitor};
-use cssparser::{Parser, ParserInput, StyleSheetParser};
+use cssparser::{Parser, ParserInput, SourceLocation, StyleSheetParser};
 #[cfg(feature = "sourcemap")]
 use parcel_sourcemap::SourceMap;
 use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+use std::sync::{Arc, RwLock};
 
+pub use crate::media_query::KnownDimensions;
 pub use crate::parser::{ParserFlags, ParserOptions};
 pub use crate::printer::PrinterOptions;
 pub use crate::printer::PseudoClasses;
+pub use crate::printer::{Indent, KeyframeSelectorStyle, LineEnding};
 
 /// A CSS style sheet, representing a `.css` file or inline `<style>` element.
 ///
@@ -79,17 +97,61 @@ pub struct StyleSheet<'i, 'o, T = DefaultAtRule> {
   pub sources: Vec<String>,
   /// The source map URL extracted from the original style sheet.
   pub(crate) source_map_urls: Vec<Option<String>>,
+  /// The original source text for each source file, if known.
+  #[cfg(feature = "sourcemap")]
+  #[cfg_attr(feature = "serde", serde(skip))]
+  pub(crate) source_contents: Vec<Option<&'i str>>,
   /// The license comments that appeared at the start of the file.
   pub license_comments: Vec<CowArcStr<'i>>,
   /// A list of content hashes for all source files included within the style sheet.
   /// This is only set if CSS modules are enabled and the pattern includes [content-hash].
   #[cfg_attr(feature = "serde", serde(skip))]
   pub(crate) content_hashes: Option<Vec<String>>,
+  /// The source range of each top-level rule, used for incremental re-parsing.
+  #[cfg_attr(feature = "serde", serde(skip))]
+  rule_ranges: Vec<RuleRange>,
   #[cfg_attr(feature = "serde", serde(skip))]
   /// The options the style sheet was originally parsed with.
   options: ParserOptions<'o, 'i>,
 }
 
+/// The version of the JSON AST schema produced by [StyleSheet::to_ast_json].
+///
+/// This is incremented whenever the serialized representation of the AST changes
+/// in a way that is not backward compatible, e.g. a field or type tag is renamed.
+pub const AST_SCHEMA_VERSION: u32 = 2;
+
+/// The versioned JSON AST produced by [StyleSheet::to_ast_json].
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AstJson<'a, 'i, 'o, T> {
+  schema_version: u32,
+  stylesheet: &'a StyleSheet<'i, 'o, T>,
+}
+
+/// The source range of a top-level rule within a style sheet.
+#[derive(Debug, Clone)]
+struct RuleRange {
+  /// The byte range of the rule, including any whitespace and invalid rules before it.
+  bytes: Range<usize>,
+  /// The source location at the start of the byte range.
+  start: SourceLocation,
+}
+
+/// A change to the source text of a [StyleSheet](StyleSheet), applied
+/// using [apply_patch](StyleSheet::apply_patch).
+#[derive(Debug, Clone, PartialEq)]
+pub enum StyleSheetPatch<'a> {
+  /// A range of the previous source text was replaced.
+  Changed {
+    /// The byte range of the replaced text within the previous source.
+    byte_range: Range<usize>,
+    /// The text that replaced it.
+    new_text: &'a str,
+  },
+}
+
 /// Options for the `minify` function of a [StyleSheet](StyleSheet)
 /// or [StyleAttribute](StyleAttribute).
 #[derive(Default)]
@@ -99,6 +161,59 @@ pub struct MinifyOptions {
   /// A list of known unused symbols, including CSS class names,
   /// ids, and `@keyframe` names. The declarations of these will be removed.
   pub unused_symbols: HashSet<String>,
+  /// The known dimensions of the viewport. When set, `@media` rules whose queries
+  /// always match are replaced by their contents, and rules that never match are removed.
+  pub known_dimensions: Option<KnownDimensions>,
+  /// What to do with style rules whose selectors use pseudo-classes or pseudo-elements
+  /// that are not supported by the targets, e.g. `:has()`.
+  pub unsupported_selectors: UnsupportedSelectorPolicy,
+  /// A list that will be appended to when a warning occurs, e.g. for unsupported selectors.
+  pub warnings: Option<Arc<RwLock<Vec<Error<MinifyErrorKind>>>>>,
+  /// When set, statistics about the changes made by minification are written here.
+  /// Collecting them requires walking and serializing the style sheet before and after
+  /// minifying, so this is disabled by default.
+  pub stats: Option<Arc<RwLock<MinifyStats>>>,
+  /// Whether to merge `@keyframes` rules with the same name and vendor prefix into a single rule
+  /// containing the keyframes of both, rather than keeping only the last rule. Keyframes in later
+  /// rules replace those at the same offset in earlier rules. Note that browsers only use the last
+  /// rule, so this changes the animation when an earlier rule defines offsets that a later one does not.
+  pub merge_keyframes: bool,
+}
+
+/// Statistics about the changes made by [StyleSheet::minify](StyleSheet::minify).
+/// See [MinifyOptions](MinifyOptions).
+///
+/// The counts are net differences between the style sheet before and after minifying,
+/// e.g. a rule that was merged into another counts as removed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MinifyStats {
+  /// The number of rules removed, including nested rules.
+  pub rules_removed: usize,
+  /// The number of declarations removed, e.g. overridden or merged into shorthands.
+  pub declarations_removed: usize,
+  /// The number of shorthand declarations formed from longhands.
+  pub shorthands_formed: usize,
+  /// The number of vendor prefixed declarations added for the targets.
+  pub prefixes_added: usize,
+  /// The number of vendor prefixed declarations removed because the targets don't need them.
+  pub prefixes_removed: usize,
+  /// The size in bytes of the minified output before minifying, i.e. only removing whitespace.
+  pub bytes_before: usize,
+  /// The size in bytes of the minified output after minifying.
+  pub bytes_after: usize,
+}
+
+/// A policy for style rules with selectors that are not supported by the targets.
+/// See [MinifyOptions](MinifyOptions).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UnsupportedSelectorPolicy {
+  /// Keep the rules as is.
+  #[default]
+  Keep,
+  /// Keep the rules, and report a warning for each.
+  Warn,
+  /// Remove the rules, and report a warning for each.
+  Remove,
 }
 
 /// A result returned from `to_css`, including the serialize CSS
@@ -123,6 +238,49 @@ impl<'i, 'o> StyleSheet<'i, 'o, DefaultAtRule> {
   pub fn parse(code: &'i str, options: ParserOptions<'o, 'i>) -> Result<Self, Error<ParserError<'i>>> {
     Self::parse_with(code, options, &mut DefaultAtRuleParser)
   }
+
+  /// Parse a style sheet from UTF-8 encoded bytes, e.g. as read from a file.
+  ///
+  /// A leading byte order mark is removed. If the input is not valid UTF-8, an error
+  /// pointing at the first invalid byte is returned.
+  pub fn from_bytes(bytes: &'i [u8], options: ParserOptions<'o, 'i>) -> Result<Self, Error<ParserError<'i>>> {
+    let bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes);
+    let code = std::str::from_utf8(bytes).map_err(|e| {
+      // Compute the location the same way as the tokenizer: lines start from 0,
+      // and columns start from 1 and are counted in UTF-16 code units.
+      let valid = std::str::from_utf8(&bytes[..e.valid_up_to()]).unwrap_or_default();
+      let mut line = 0;
+      let mut column = 1;
+      let mut chars = valid.chars().peekable();
+      while let Some(c) = chars.next() {
+        match c {
+          '\r' if chars.peek() == Some(&'\n') => {}
+          '\n' | '\r' | '\x0C' => {
+            line += 1;
+            column = 1;
+          }
+          _ => column += c.len_utf16() as u32,
+        }
+      }
+
+      Error {
+        kind: ParserError::InvalidUtf8,
+        loc: Some(ErrorLocation {
+          filename: options.filename.clone(),
+          line,
+          column,
+        }),
+      }
+    })?;
+
+    Self::parse(code, options)
+  }
+
+  /// Applies a change to the source text, re-parsing only the affected rules.
+  /// See [apply_patch_with](StyleSheet::apply_patch_with) for details.
+  pub fn apply_patch(&mut self, code: &'i str, patch: StyleSheetPatch) -> Result<(), Error<ParserError<'i>>> {
+    self.apply_patch_with(code, patch, &mut DefaultAtRuleParser)
+  }
 }
 
 impl<'i, 'o, T> StyleSheet<'i, 'o, T>
@@ -138,8 +296,11 @@ where
     StyleSheet {
       sources,
       source_map_urls: Vec::new(),
+      #[cfg(feature = "sourcemap")]
+      source_contents: Vec::new(),
       license_comments: Vec::new(),
       content_hashes: None,
+      rule_ranges: Vec::new(),
       rules,
       options,
     }
@@ -155,15 +316,7 @@ where
     let mut parser = Parser::new(&mut input);
     let mut license_comments = Vec::new();
 
-    let mut content_hashes = None;
-    if let Some(config) = &options.css_modules {
-      if config.pattern.has_content_hash() {
-        content_hashes = Some(vec![hash(
-          &code,
-          matches!(config.pattern.segments[0], crate::css_modules::Segment::ContentHash),
-        )]);
-      }
-    }
+    let content_hashes = content_hashes(code, &options);
 
     let mut state = parser.state();
     while let Ok(token) = parser.next_including_whitespace_and_comments() {
@@ -181,37 +334,238 @@ where
       }
       state = parser.state();
     }
+
     parser.reset(&state);
 
+    // A @charset rule at the start of the style sheet is skipped by the rule list parser. Encodings other
+    // than UTF-8 are kept, since the file may be served without a content type that declares its encoding.
     let mut rules = CssRuleList(vec![]);
+    if matches!(parser.next(), Ok(cssparser::Token::AtKeyword(name)) if name.eq_ignore_ascii_case("charset")) {
+      if let Ok(Some(charset)) = parse_charset(&mut parser, &options) {
+        let loc = state.source_location();
+        rules.0.push(CssRule::Unknown(UnknownAtRule {
+          name: "charset".into(),
+          prelude: TokenList(vec![TokenOrValue::Token(Token::String(charset))]),
+          block: None,
+          loc: Location {
+            source_index: options.source_index,
+            line: loc.line,
+            column: loc.column,
+          },
+        }));
+      }
+    }
+    parser.reset(&state);
+
+    let mut rule_ranges = Vec::new();
+    let mut rule_start = (parser.position().byte_index(), parser.current_source_location());
     let mut rule_parser = TopLevelRuleParser::new(&mut options, at_rule_parser, &mut rules);
     let mut rule_list_parser = StyleSheetParser::new(&mut parser, &mut rule_parser);
 
     while let Some(rule) = rule_list_parser.next() {
-      match rule {
-        Ok(()) => {}
-        Err((e, _)) => {
-          let options = &mut rule_list_parser.parser.options;
-          if options.error_recovery {
-            options.warn(e);
-            continue;
-          }
-
+      if let Err((e, _)) = rule {
+        let options = &mut rule_list_parser.parser.options;
+        if !options.error_recovery {
           return Err(Error::from(e, options.filename.clone()));
         }
+
+        options.warn(e);
       }
+
+      record_rule_range(
+        &mut rule_ranges,
+        &mut rule_start,
+        rule_list_parser.parser.rule_count(),
+        rule_list_parser.input,
+      );
     }
 
+    extend_last_rule_range(&mut rule_ranges, code.len());
+
     Ok(StyleSheet {
       sources: vec![options.filename.clone()],
       source_map_urls: vec![parser.current_source_map_url().map(|s| s.to_owned())],
+      #[cfg(feature = "sourcemap")]
+      source_contents: vec![Some(code)],
       content_hashes,
+      rule_ranges,
       rules,
       license_comments,
       options,
     })
   }
 
+  /// Applies a change to the source text of the style sheet, re-parsing only the top-level rules it affects.
+  ///
+  /// `code` is the complete source text after the patch has been applied, and `patch` describes
+  /// how it differs from the text the style sheet was previously parsed from. Rules before the change
+  /// are kept as is, and rules after it are kept with their source locations shifted accordingly. The
+  /// result is the same as parsing `code` from scratch, except for locations within custom at-rules.
+  ///
+  /// The whole style sheet is re-parsed if the affected rules cannot be determined, e.g. when it was
+  /// minified, was not parsed from a single source, or when the change affects `@import` or `@namespace` rules.
+  /// If an error is returned, the style sheet is left unchanged.
+  pub fn apply_patch_with<P: AtRuleParser<'i, AtRule = T>>(
+    &mut self,
+    code: &'i str,
+    patch: StyleSheetPatch,
+    at_rule_parser: &mut P,
+  ) -> Result<(), Error<ParserError<'i>>> {
+    let StyleSheetPatch::Changed { byte_range, new_text } = patch;
+    let old_len = self.rule_ranges.last().map_or(0, |range| range.bytes.end);
+    let can_patch = self.rule_ranges.len() == self.rules.0.len()
+      && self.sources.len() == 1
+      && byte_range.start <= byte_range.end
+      && byte_range.end <= old_len
+      && self
+        .rule_ranges
+        .first()
+        .is_some_and(|range| range.bytes.start < byte_range.start)
+      && code.len() + byte_range.len() == old_len + new_text.len()
+      && code.get(byte_range.start..byte_range.start + new_text.len()) == Some(new_text);
+    if !can_patch {
+      *self = Self::parse_with(code, self.options.clone(), at_rule_parser)?;
+      return Ok(());
+    }
+
+    // Start re-parsing at the first rule that overlaps the change. Parsing stops once a rule ends at the
+    // start of a rule after the change, since the remaining source text is the same as before.
+    let delta = new_text.len() as isize - byte_range.len() as isize;
+    let index = self
+      .rule_ranges
+      .iter()
+      .position(|range| range.bytes.end > byte_range.start)
+      .unwrap_or(self.rule_ranges.len() - 1);
+    let start_byte = self.rule_ranges[index].bytes.start;
+    let start = self.rule_ranges.partition_point(|range| range.bytes.start < start_byte);
+    let start_loc = self.rule_ranges[start].start;
+
+    // A leading @charset rule is only handled when parsing the whole style sheet.
+    if start == 0 && starts_with_ignore_ascii_case(&code[start_byte..], "@charset") {
+      *self = Self::parse_with(code, self.options.clone(), at_rule_parser)?;
+      return Ok(());
+    }
+    let warning_count = self
+      .options
+      .warnings
+      .as_ref()
+      .and_then(|warnings| warnings.read().ok().map(|warnings| warnings.len()));
+
+    // Re-parsed locations are relative to the start of the rule, and need to be made absolute.
+    let to_absolute = |line: &mut u32, column: &mut u32| {
+      if *line == 0 {
+        *column += start_loc.column - 1;
+      }
+      *line += start_loc.line;
+    };
+
+    let mut input = ParserInput::new(&code[start_byte..]);
+    let mut parser = Parser::new(&mut input);
+    let mut rules = CssRuleList(vec![]);
+    let mut rule_ranges = Vec::new();
+    let mut rule_start = (0, parser.current_source_location());
+    let mut end = None;
+    let mut rule_parser =
+      TopLevelRuleParser::new(&self.options, at_rule_parser, &mut rules).resume_after(&self.rules.0[..start]);
+    let mut rule_list_parser = StyleSheetParser::new(&mut parser, &mut rule_parser);
+
+    while let Some(rule) = rule_list_parser.next() {
+      if let Err((e, _)) = rule {
+        let options = &rule_list_parser.parser.options;
+        if !options.error_recovery {
+          let mut error = Error::from(e, options.filename.clone());
+          if let Some(loc) = &mut error.loc {
+            to_absolute(&mut loc.line, &mut loc.column);
+          }
+          return Err(error);
+        }
+
+        options.warn(e);
+      }
+
+      let rule_count = rule_list_parser.parser.rule_count();
+      if record_rule_range(&mut rule_ranges, &mut rule_start, rule_count, rule_list_parser.input) {
+        let old_pos = (start_byte + rule_start.0) as isize - delta;
+        if old_pos >= byte_range.end as isize {
+          let old_pos = old_pos as usize;
+          let next = self.rule_ranges.partition_point(|range| range.bytes.start < old_pos);
+          if next < self.rule_ranges.len() && self.rule_ranges[next].bytes.start == old_pos {
+            end = Some((next, rule_start.1));
+            break;
+          }
+        }
+      }
+    }
+
+    let source_map_url = parser.current_source_map_url().map(|url| url.to_owned());
+    let mut tail = CssRuleList(self.rules.0.split_off(match end {
+      Some((end, _)) => end,
+      None => self.rules.0.len(),
+    }));
+    let end = match end {
+      Some(_)
+        if tail
+          .0
+          .iter()
+          .any(|rule| matches!(rule, CssRule::Import(..) | CssRule::Namespace(..))) =>
+      {
+        self.rules.0.append(&mut tail.0);
+        *self = Self::parse_with(code, self.options.clone(), at_rule_parser)?;
+        return Ok(());
+      }
+      Some((end, mut end_loc)) => {
+        to_absolute(&mut end_loc.line, &mut end_loc.column);
+        let prev_loc = self.rule_ranges[end].start;
+        let shift = |line: &mut u32, column: &mut u32| {
+          if *line == prev_loc.line {
+            *column = *column + end_loc.column - prev_loc.column;
+          }
+          *line = *line + end_loc.line - prev_loc.line;
+        };
+
+        for range in &mut self.rule_ranges[end..] {
+          range.bytes = (range.bytes.start as isize + delta) as usize..(range.bytes.end as isize + delta) as usize;
+          shift(&mut range.start.line, &mut range.start.column);
+        }
+        tail.for_each_location(&mut |loc| shift(&mut loc.line, &mut loc.column));
+        end
+      }
+      None => {
+        extend_last_rule_range(&mut rule_ranges, code.len() - start_byte);
+        self.rules.0.len()
+      }
+    };
+
+    rules.for_each_location(&mut |loc| to_absolute(&mut loc.line, &mut loc.column));
+    for range in &mut rule_ranges {
+      range.bytes = range.bytes.start + start_byte..range.bytes.end + start_byte;
+      to_absolute(&mut range.start.line, &mut range.start.column);
+    }
+    if let (Some(warnings), Some(warning_count)) = (&self.options.warnings, warning_count) {
+      if let Ok(mut warnings) = warnings.write() {
+        for warning in &mut warnings[warning_count..] {
+          if let Some(loc) = &mut warning.loc {
+            to_absolute(&mut loc.line, &mut loc.column);
+          }
+        }
+      }
+    }
+
+    self.rules.0.truncate(start);
+    self.rules.0.append(&mut rules.0);
+    self.rules.0.append(&mut tail.0);
+    self.rule_ranges.splice(start..end, rule_ranges);
+    if source_map_url.is_some() {
+      self.source_map_urls = vec![source_map_url];
+    }
+    #[cfg(feature = "sourcemap")]
+    {
+      self.source_contents = vec![Some(code)];
+    }
+    self.content_hashes = content_hashes(code, &self.options);
+    Ok(())
+  }
+
   /// Returns the source map URL for the source at the given index.
   pub fn source_map_url(&self, source_index: usize) -> Option<&String> {
     self.source_map_urls.get(source_index)?.as_ref()
@@ -224,8 +578,152 @@ where
     SourceMap::from_data_url("/", self.source_map_url(source_index)?).ok()
   }
 
+  /// Calls the given function for each `@keyframes` rule in the style sheet, including
+  /// rules nested inside other rules such as `@media` and `@supports`.
+  ///
+  /// Rules are visited depth first, in source order. This is independent of the `visitor`
+  /// API: if both are used, they run in the order they are called.
+  /// Call this before [minify](StyleSheet::minify) so that the transformed rules are normalized,
+  /// merged, and prefixed along with the rest of the style sheet.
+  pub fn transform_keyframes<F: FnMut(&mut KeyframesRule<'i>)>(&mut self, mut f: F) {
+    self.rules.for_each_keyframes(&mut f)
+  }
+
+  /// Reports vendor prefixed properties, e.g. `-webkit-transform`, that are not needed for
+  /// the given browser targets because they all support the unprefixed property.
+  ///
+  /// Declarations do not store their own locations, so each warning points at the rule
+  /// containing the property. Call this before [minify](StyleSheet::minify), which merges
+  /// prefixed and unprefixed declarations. If no browser targets are given, nothing is reported.
+  pub fn lint_vendor_prefixes(&self, targets: Targets) -> Vec<LintWarning> {
+    let mut warnings = Vec::new();
+    if targets.browsers.is_some() {
+      crate::lint::lint_vendor_prefixes(&self.rules, targets, &self.sources, &mut warnings);
+    }
+    warnings
+  }
+
+  /// Collects the font families referenced by `font-family` and `font` declarations,
+  /// and the font faces declared by `@font-face` rules, including those nested in
+  /// conditional rules. This is useful to determine which fonts need to be subset.
+  pub fn collect_used_fonts(&self) -> FontUsage<'i> {
+    let mut usage = FontUsage::default();
+    crate::font_usage::collect_used_fonts(&self.rules, &mut usage);
+    usage
+  }
+
+  /// Returns the cascade layers declared by `@layer` and `@import` rules, in their final
+  /// cascade order from lowest to highest priority.
+  ///
+  /// Layers are ordered by their first declaration, and nested layers are returned by their
+  /// fully qualified name, e.g. `a.b`, before their parent layer. Anonymous layers are given
+  /// a placeholder name such as `<anonymous-1>`, numbered in source order. Layers declared
+  /// inside conditional rules such as `@media` are included regardless of the condition.
+  pub fn extract_layer_order(&self) -> Vec<LayerName<'i>> {
+    crate::rules::layer::extract_layer_order(&self.rules)
+  }
+
+  /// Splits the style sheet into separate light and dark style sheets, without any
+  /// `@media (prefers-color-scheme: …)` wrappers, e.g. for email clients that don't support `@media`.
+  ///
+  /// `@media` rules that always match the color scheme are unwrapped, and rules that never match it are
+  /// removed. Media queries that also depend on other features keep only those features. Rules outside of
+  /// any `prefers-color-scheme` media query appear in both style sheets.
+  pub fn split_color_schemes(self) -> (StyleSheet<'i, 'o, T>, StyleSheet<'i, 'o, T>) {
+    let mut light = self;
+    // The rules no longer correspond to their source ranges, so incremental re-parsing is not possible.
+    light.rule_ranges.clear();
+    let mut dark = StyleSheet {
+      rules: light.rules.clone(),
+      sources: light.sources.clone(),
+      source_map_urls: light.source_map_urls.clone(),
+      #[cfg(feature = "sourcemap")]
+      source_contents: light.source_contents.clone(),
+      license_comments: light.license_comments.clone(),
+      content_hashes: light.content_hashes.clone(),
+      rule_ranges: Vec::new(),
+      options: light.options.clone(),
+    };
+    light.rules.resolve_color_scheme("light");
+    dark.rules.resolve_color_scheme("dark");
+    (light, dark)
+  }
+
+  /// Compares this style sheet with a newer version of it, and returns the rules and declarations
+  /// that were added, removed, or changed, e.g. to show what a transform did.
+  ///
+  /// Rules are matched by their prelude, e.g. the selector or media query, and the number of
+  /// preceding sibling rules with the same prelude. Declarations are matched by property name
+  /// within a rule, and compared by their serialized values. Keyframes are compared individually.
+  /// Returns an error if a prelude or value cannot be serialized.
+  pub fn diff(&self, new: &StyleSheet<'i, 'o, T>) -> Result<Vec<Change>, PrinterError> {
+    crate::diff::diff(&self.rules, &new.rules)
+  }
+
   /// Minify and transform the style sheet for the provided browser targets.
   pub fn minify(&mut self, options: MinifyOptions) -> Result<(), Error<MinifyErrorKind>> {
+    self.minify_with(options, |_, _| Vec::new())
+  }
+
+  /// Minify and transform the style sheet for the provided browser targets, using a thread pool.
+  ///
+  /// The declarations of each top-level style rule are minified in parallel, and the remaining steps,
+  /// such as merging adjacent rules, are performed sequentially afterward. The result is the same as
+  /// [minify](StyleSheet::minify), but can be faster for large style sheets, e.g. bundles of many files.
+  #[cfg(feature = "rayon")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+  pub fn minify_parallel(&mut self, options: MinifyOptions) -> Result<(), Error<MinifyErrorKind>>
+  where
+    T: Send,
+  {
+    self.minify_with(options, CssRuleList::minify_style_rules_parallel)
+  }
+
+  fn minify_with<F>(&mut self, options: MinifyOptions, minify_style_rules: F) -> Result<(), Error<MinifyErrorKind>>
+  where
+    F: FnOnce(&mut CssRuleList<'i, T>, &MinifyContext<'_, 'i>) -> Vec<Option<MinifiedStyleRule<'i>>>,
+  {
+    // Rules are merged and removed, so their source ranges no longer apply.
+    self.rule_ranges.clear();
+
+    let before = options
+      .stats
+      .as_ref()
+      .map(|_| (RuleCounts::new(&self.rules), self.minified_len(options.targets)));
+
+    let mut warnings = Vec::new();
+    if options.unsupported_selectors != UnsupportedSelectorPolicy::Keep {
+      self.rules.check_selector_support(
+        options.targets,
+        options.unsupported_selectors == UnsupportedSelectorPolicy::Remove,
+        &mut warnings,
+      );
+    }
+
+    // @view-transition rules are kept for targets that do not support them, since they are ignored there.
+    if options.warnings.is_some() && !options.targets.is_compatible(Feature::ViewTransitionAtRule) {
+      self.rules.for_each_rule(&mut |rule| {
+        if let CssRule::ViewTransition(view_transition) = rule {
+          warnings.push(MinifyError {
+            kind: MinifyErrorKind::UnsupportedAtRule {
+              name: "@view-transition".into(),
+            },
+            loc: view_transition.loc,
+          });
+        }
+      });
+    }
+
+    if let Some(Ok(mut dest)) = options.warnings.as_ref().map(|warnings| warnings.write()) {
+      dest.extend(warnings.into_iter().map(|warning| Error {
+        kind: warning.kind,
+        loc: Some(ErrorLocation::new(
+          warning.loc,
+          self.sources[warning.loc.source_index as usize].clone(),
+        )),
+      }));
+    }
+
     let context = PropertyHandlerContext::new(options.targets, &options.unused_symbols);
     let mut handler = DeclarationHandler::default();
     let mut important_handler = DeclarationHandler::default();
@@ -252,12 +750,15 @@ where
       important_handler: &mut important_handler,
       handler_context: context,
       unused_symbols: &options.unused_symbols,
+      known_dimensions: options.known_dimensions,
       custom_media,
       css_modules: self.options.css_modules.is_some(),
       pure_css_modules: self.options.css_modules.as_ref().map(|c| c.pure).unwrap_or_default(),
+      merge_keyframes: options.merge_keyframes,
     };
 
-    self.rules.minify(&mut ctx, false).map_err(|e| Error {
+    let mut minified = minify_style_rules(&mut self.rules, &ctx);
+    self.rules.minify_with(&mut ctx, false, &mut minified).map_err(|e| Error {
       kind: e.kind,
       loc: Some(ErrorLocation::new(
         e.loc,
@@ -265,15 +766,83 @@ where
       )),
     })?;
 
+    // Remove @namespace rules whose prefix is not referenced by any selector or `attr()` function.
+    // The default namespace applies to unprefixed selectors implicitly, so it is always kept.
+    let has_prefixed_namespace = self
+      .rules
+      .0
+      .iter()
+      .any(|rule| matches!(rule, CssRule::Namespace(NamespaceRule { prefix: Some(..), .. })));
+    let mut prefixes = HashSet::new();
+    if has_prefixed_namespace && self.rules.collect_namespace_prefixes(&mut prefixes) {
+      self.rules.0.retain(|rule| match rule {
+        CssRule::Namespace(NamespaceRule {
+          prefix: Some(prefix), ..
+        }) => prefixes.contains(&prefix.0),
+        _ => true,
+      });
+    }
+
+    if let (Some((before, bytes_before)), Some(Ok(mut stats))) =
+      (before, options.stats.as_ref().map(|stats| stats.write()))
+    {
+      let after = RuleCounts::new(&self.rules);
+      *stats = MinifyStats {
+        rules_removed: before.rules.saturating_sub(after.rules),
+        declarations_removed: before.declarations.saturating_sub(after.declarations),
+        shorthands_formed: RuleCounts::increase(&before.shorthands, &after.shorthands),
+        prefixes_added: RuleCounts::increase(&before.prefixed, &after.prefixed),
+        prefixes_removed: RuleCounts::increase(&after.prefixed, &before.prefixed),
+        bytes_before,
+        bytes_after: self.minified_len(options.targets),
+      };
+    }
+
     Ok(())
   }
 
+  /// Returns the length of the minified output, or zero if it cannot be printed.
+  fn minified_len(&self, targets: Targets) -> usize {
+    struct CountingWriter(usize);
+    impl std::fmt::Write for CountingWriter {
+      fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.0 += s.len();
+        Ok(())
+      }
+    }
+
+    let mut writer = CountingWriter(0);
+    let options = PrinterOptions {
+      minify: true,
+      targets,
+      ..PrinterOptions::default()
+    };
+    match self.to_css_with_writer(&mut writer, options) {
+      Ok(_) => writer.0,
+      Err(_) => 0,
+    }
+  }
+
   /// Serialize the style sheet to a CSS string.
   pub fn to_css(&self, options: PrinterOptions) -> Result<ToCssResult, Error<PrinterErrorKind>> {
     // Make sure we always have capacity > 0: https://github.com/napi-rs/napi-rs/issues/1124.
     let mut dest = String::with_capacity(1);
+    let mut res = self.to_css_with_writer(&mut dest, options)?;
+    res.code = dest;
+    Ok(res)
+  }
+
+  /// Serialize the style sheet into the given writer, without allocating an intermediate string.
+  ///
+  /// The `code` field of the returned result is empty, since the CSS is written to `writer` instead.
+  /// All other fields are the same as the result of [to_css](StyleSheet::to_css).
+  pub fn to_css_with_writer<W: std::fmt::Write>(
+    &self,
+    mut writer: W,
+    options: PrinterOptions,
+  ) -> Result<ToCssResult, Error<PrinterErrorKind>> {
     let project_root = options.project_root.clone();
-    let mut printer = Printer::new(&mut dest, options);
+    let mut printer = Printer::new(&mut writer, options);
 
     #[cfg(feature = "sourcemap")]
     {
@@ -285,6 +854,28 @@ where
       printer.source_maps = self.sources.iter().enumerate().map(|(i, _)| self.source_map(i)).collect();
     }
 
+    #[cfg(feature = "sourcemap")]
+    if printer.embed_sources {
+      if let Some(map) = &mut printer.source_map {
+        for (i, source) in self.sources.iter().enumerate() {
+          // Sources with an input source map are remapped to their original sources instead.
+          if matches!(printer.source_maps.get(i), Some(Some(_))) {
+            continue;
+          }
+
+          if let Some(Some(content)) = self.source_contents.get(i) {
+            let source_index = map.add_source(source);
+            map
+              .set_source_content(source_index as usize, content)
+              .map_err(|_| PrinterError {
+                kind: PrinterErrorKind::SourceMapError,
+                loc: None,
+              })?;
+          }
+        }
+      }
+    }
+
     for comment in &self.license_comments {
       printer.write_str("/*")?;
       printer.write_str_with_newlines(comment)?;
@@ -292,7 +883,7 @@ where
     }
 
     if let Some(config) = &self.options.css_modules {
-      let mut references = HashMap::new();
+      let mut references = CssModuleReferences::new();
       printer.css_module = Some(CssModule::new(
         config,
         &self.sources,
@@ -304,26 +895,82 @@ where
       self.rules.to_css(&mut printer)?;
       printer.newline()?;
 
+      #[cfg(feature = "sourcemap")]
+      printer.write_source_map_comment()?;
+
       Ok(ToCssResult {
         dependencies: printer.dependencies,
         exports: Some(std::mem::take(
           &mut printer.css_module.unwrap().exports_by_source_index[0],
         )),
-        code: dest,
+        code: String::new(),
         references: Some(references),
       })
     } else {
       self.rules.to_css(&mut printer)?;
       printer.newline()?;
 
+      #[cfg(feature = "sourcemap")]
+      printer.write_source_map_comment()?;
+
       Ok(ToCssResult {
         dependencies: printer.dependencies,
-        code: dest,
+        code: String::new(),
         exports: None,
         references: None,
       })
     }
   }
+
+  /// Serialize the style sheet to a CSS string, returning it together with the
+  /// generated source map as JSON.
+  ///
+  /// If no `source_map` is provided in the options, a new one is created containing the
+  /// style sheet's sources. Enable the `append_source_map_comment` option to also
+  /// reference the map from the CSS.
+  #[cfg(feature = "sourcemap")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "sourcemap")))]
+  pub fn to_css_with_source_map(&self, mut options: PrinterOptions) -> Result<(String, String), PrinterError> {
+    let mut new_source_map;
+    let source_map = match options.source_map.take() {
+      Some(source_map) => source_map,
+      None => {
+        new_source_map = SourceMap::new(options.project_root.unwrap_or("/"));
+        for source in &self.sources {
+          new_source_map.add_source(source);
+        }
+        &mut new_source_map
+      }
+    };
+
+    let source_root = options.source_root.clone();
+    let res = self.to_css(PrinterOptions {
+      source_map: Some(&mut *source_map),
+      ..options
+    })?;
+    let json = source_map.to_json(source_root.as_deref()).map_err(|_| PrinterError {
+      kind: PrinterErrorKind::SourceMapError,
+      loc: None,
+    })?;
+    Ok((res.code, json))
+  }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'i, 'o, T: serde::Serialize> StyleSheet<'i, 'o, T> {
+  /// Serializes the style sheet to a JSON AST, e.g. for consumers written in other languages.
+  ///
+  /// The result is an object with a `schemaVersion` field, set to [AST_SCHEMA_VERSION],
+  /// and a `stylesheet` field containing the serialized style sheet. The schema of the
+  /// `stylesheet` field can be generated using the `jsonschema` feature. Consumers should
+  /// check the version before reading the rest of the AST.
+  pub fn to_ast_json(&self) -> Result<String, serde_json::Error> {
+    serde_json::to_string(&AstJson {
+      schema_version: AST_SCHEMA_VERSION,
+      stylesheet: self,
+    })
+  }
 }
 
 #[cfg(feature = "visitor")]
@@ -344,6 +991,114 @@ where
   }
 }
 
+/// Counts of rules and declarations in a style sheet, used to compute [MinifyStats](MinifyStats).
+///
+/// Shorthands and prefixed declarations are counted per property name, so that e.g. a prefix
+/// removed from one property and added to another are both reported.
+#[derive(Default)]
+struct RuleCounts {
+  rules: usize,
+  declarations: usize,
+  shorthands: HashMap<String, usize>,
+  prefixed: HashMap<String, usize>,
+}
+
+impl RuleCounts {
+  fn new<T>(rules: &CssRuleList<T>) -> Self {
+    let mut counts = RuleCounts::default();
+    counts.add_rules(rules);
+    counts
+  }
+
+  fn add_rules<T>(&mut self, rules: &CssRuleList<T>) {
+    rules.for_each_rule(&mut |rule| {
+      if matches!(rule, CssRule::Ignored) {
+        return;
+      }
+
+      self.rules += 1;
+      match rule {
+        CssRule::Style(style) => self.add_declarations(&style.declarations),
+        CssRule::Nesting(nesting) => self.add_declarations(&nesting.style.declarations),
+        CssRule::NestedDeclarations(nested) => self.add_declarations(&nested.declarations),
+        CssRule::Keyframes(keyframes) => {
+          for keyframe in &keyframes.keyframes {
+            self.add_declarations(&keyframe.declarations);
+          }
+        }
+        CssRule::Page(page) => self.add_declarations(&page.declarations),
+        _ => {}
+      }
+    });
+  }
+
+  /// Returns the total increase in count of each name from `a` to `b`.
+  fn increase(a: &HashMap<String, usize>, b: &HashMap<String, usize>) -> usize {
+    b.iter()
+      .map(|(name, count)| count.saturating_sub(a.get(name).copied().unwrap_or(0)))
+      .sum()
+  }
+
+  fn add_declarations(&mut self, declarations: &DeclarationBlock) {
+    for (property, _) in declarations.iter() {
+      let property_id = property.property_id();
+      // A single property may be printed once per vendor prefix.
+      let prefix = property_id.prefix();
+      let prefixed = (prefix - VendorPrefix::None).bits().count_ones() as usize;
+      let count = (prefixed + prefix.contains(VendorPrefix::None) as usize).max(1);
+      self.declarations += count;
+      if prefixed > 0 {
+        *self.prefixed.entry(property_id.name().to_owned()).or_default() += prefixed;
+      }
+      if property_id.is_shorthand() {
+        *self.shorthands.entry(property_id.name().to_owned()).or_default() += count;
+      }
+    }
+  }
+}
+
+fn content_hashes(code: &str, options: &ParserOptions) -> Option<Vec<String>> {
+  match &options.css_modules {
+    Some(config) if config.pattern.has_content_hash() => Some(vec![hash(
+      code,
+      matches!(config.pattern.segments[0], crate::css_modules::Segment::ContentHash),
+    )]),
+    _ => None,
+  }
+}
+
+/// Records the source range of any rules added by the last call to `StyleSheetParser::next`,
+/// and returns whether there were any. Rules added by the same call share a range.
+fn record_rule_range(
+  rule_ranges: &mut Vec<RuleRange>,
+  rule_start: &mut (usize, SourceLocation),
+  rule_count: usize,
+  input: &Parser,
+) -> bool {
+  if rule_count == rule_ranges.len() {
+    return false;
+  }
+
+  let end = input.position().byte_index();
+  let range = RuleRange {
+    bytes: rule_start.0..end,
+    start: rule_start.1,
+  };
+  rule_ranges.resize(rule_count, range);
+  *rule_start = (end, input.current_source_location());
+  true
+}
+
+/// Extends the range of the last rules to the end of the source, so trailing whitespace
+/// and invalid rules are covered.
+fn extend_last_rule_range(rule_ranges: &mut [RuleRange], len: usize) {
+  if let Some(last) = rule_ranges.last().map(|range| range.bytes.start) {
+    for range in rule_ranges.iter_mut().rev().take_while(|range| range.bytes.start == last) {
+      range.bytes.end = len;
+    }
+  }
+}
+
 /// An inline style attribute, as in HTML or SVG.
 ///
 /// Style attributes can be parsed from a string, minified and transformed
@@ -41,6 +41,128 @@ pub struct Browsers {
   pub samsung: Option<u32>,
 }
 
+/// A browser that can be included in [Browsers](Browsers).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(any(feature = "serde", feature = "nodejs"), derive(Serialize, Deserialize))]
+#[cfg_attr(any(feature = "serde", feature = "nodejs"), serde(rename_all = "snake_case"))]
+pub enum BrowserName {
+  Android,
+  Chrome,
+  Edge,
+  Firefox,
+  Ie,
+  IosSaf,
+  Opera,
+  Safari,
+  Samsung,
+}
+
+impl std::fmt::Display for BrowserName {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(match self {
+      BrowserName::Android => "android",
+      BrowserName::Chrome => "chrome",
+      BrowserName::Edge => "edge",
+      BrowserName::Firefox => "firefox",
+      BrowserName::Ie => "ie",
+      BrowserName::IosSaf => "ios_saf",
+      BrowserName::Opera => "opera",
+      BrowserName::Safari => "safari",
+      BrowserName::Samsung => "samsung",
+    })
+  }
+}
+
+/// A browser version, decoded from the 24-bit integer representation used by [Browsers](Browsers).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(any(feature = "serde", feature = "nodejs"), derive(Serialize, Deserialize))]
+pub struct BrowserVersion {
+  /// The major version.
+  pub major: u8,
+  /// The minor version.
+  pub minor: u8,
+  /// The patch version.
+  pub patch: u8,
+}
+
+impl From<u32> for BrowserVersion {
+  fn from(version: u32) -> Self {
+    BrowserVersion {
+      major: ((version >> 16) & 0xff) as u8,
+      minor: ((version >> 8) & 0xff) as u8,
+      patch: (version & 0xff) as u8,
+    }
+  }
+}
+
+impl From<BrowserVersion> for u32 {
+  fn from(version: BrowserVersion) -> Self {
+    (version.major as u32) << 16 | (version.minor as u32) << 8 | version.patch as u32
+  }
+}
+
+impl std::fmt::Display for BrowserVersion {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.major)?;
+    if self.minor > 0 || self.patch > 0 {
+      write!(f, ".{}", self.minor)?;
+    }
+    if self.patch > 0 {
+      write!(f, ".{}", self.patch)?;
+    }
+    Ok(())
+  }
+}
+
+impl Browsers {
+  /// Returns the browser and version in the targets that supports the fewest features.
+  ///
+  /// Version numbers are not comparable between browsers, so each target is ranked by the
+  /// number of features known to Lightning CSS that it does not support. If several browsers
+  /// are tied, the first in alphabetical order is returned. Returns `None` if no browsers are set.
+  pub fn minimum_browser(&self) -> Option<(BrowserName, BrowserVersion)> {
+    let browsers = [
+      (BrowserName::Android, self.android),
+      (BrowserName::Chrome, self.chrome),
+      (BrowserName::Edge, self.edge),
+      (BrowserName::Firefox, self.firefox),
+      (BrowserName::Ie, self.ie),
+      (BrowserName::IosSaf, self.ios_saf),
+      (BrowserName::Opera, self.opera),
+      (BrowserName::Safari, self.safari),
+      (BrowserName::Samsung, self.samsung),
+    ];
+
+    let mut result = None;
+    let mut max_unsupported = 0;
+    for (name, version) in browsers.into_iter().filter_map(|(name, v)| v.map(|v| (name, v))) {
+      let mut single = Browsers::default();
+      match name {
+        BrowserName::Android => single.android = Some(version),
+        BrowserName::Chrome => single.chrome = Some(version),
+        BrowserName::Edge => single.edge = Some(version),
+        BrowserName::Firefox => single.firefox = Some(version),
+        BrowserName::Ie => single.ie = Some(version),
+        BrowserName::IosSaf => single.ios_saf = Some(version),
+        BrowserName::Opera => single.opera = Some(version),
+        BrowserName::Safari => single.safari = Some(version),
+        BrowserName::Samsung => single.samsung = Some(version),
+      }
+
+      let unsupported = crate::compat::Feature::ALL
+        .iter()
+        .filter(|feature| !feature.is_compatible(single))
+        .count();
+      if result.is_none() || unsupported > max_unsupported {
+        result = Some((name, version.into()));
+        max_unsupported = unsupported;
+      }
+    }
+
+    result
+  }
+}
+
 #[cfg(feature = "browserslist")]
 pub use browserslist::Opts as BrowserslistConfig;
 
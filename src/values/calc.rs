@@ -384,6 +384,16 @@ impl<
         })?;
 
         // According to the spec, the minimum should "win" over the maximum if they are in the wrong order.
+        // If the minimum is known to be greater than or equal to the maximum, the center doesn't matter.
+        let cmp = match (&min, &max) {
+          (Some(Calc::Value(min_val)), Some(Calc::Value(max_val))) => min_val.partial_cmp(max_val),
+          (Some(Calc::Number(min_val)), Some(Calc::Number(max_val))) => min_val.partial_cmp(max_val),
+          _ => None,
+        };
+        if matches!(cmp, Some(std::cmp::Ordering::Greater | std::cmp::Ordering::Equal)) {
+          return Ok(min.unwrap());
+        }
+
         let cmp = match (&max, &center) {
           (Some(Calc::Value(max_val)), Calc::Value(center_val)) => center_val.partial_cmp(&max_val),
           (Some(Calc::Number(max_val)), Calc::Number(center_val)) => center_val.partial_cmp(max_val),
@@ -770,24 +770,28 @@ impl<'i> ColorParser<'i> for RelativeComponentParser {
       });
     }
 
-    if let Ok(value) = input.try_parse(|input| -> Result<AngleOrNumber, ParseError<'i, ParserError<'i>>> {
-      match Calc::parse_with(input, |ident| {
-        self
-          .get_ident(ident, ChannelType::Angle | ChannelType::Number)
-          .map(|(value, ty)| match ty {
-            ChannelType::Angle => Calc::Value(Box::new(Angle::Deg(value))),
-            ChannelType::Number => Calc::Number(value),
-            _ => unreachable!(),
-          })
+    // Hue channel keywords resolve to an angle in older versions of the spec, e.g. calc(h + 180deg),
+    // but to a number of degrees in the current one, e.g. calc(h + 180). Support both.
+    for hue_as_number in [false, true] {
+      if let Ok(value) = input.try_parse(|input| -> Result<AngleOrNumber, ParseError<'i, ParserError<'i>>> {
+        match Calc::parse_with(input, |ident| {
+          self
+            .get_ident(ident, ChannelType::Angle | ChannelType::Number)
+            .map(|(value, ty)| match ty {
+              ChannelType::Angle if !hue_as_number => Calc::Value(Box::new(Angle::Deg(value))),
+              ChannelType::Angle | ChannelType::Number => Calc::Number(value),
+              _ => unreachable!(),
+            })
+        }) {
+          Ok(Calc::Value(v)) => Ok(AngleOrNumber::Angle {
+            degrees: v.to_degrees(),
+          }),
+          Ok(Calc::Number(v)) => Ok(AngleOrNumber::Number { value: v }),
+          _ => Err(input.new_custom_error(ParserError::InvalidValue)),
+        }
       }) {
-        Ok(Calc::Value(v)) => Ok(AngleOrNumber::Angle {
-          degrees: v.to_degrees(),
-        }),
-        Ok(Calc::Number(v)) => Ok(AngleOrNumber::Number { value: v }),
-        _ => Err(input.new_custom_error(ParserError::InvalidValue)),
+        return Ok(value);
       }
-    }) {
-      return Ok(value);
     }
 
     Err(input.new_error_for_next_token())
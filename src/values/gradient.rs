@@ -256,7 +256,7 @@ impl LinearGradient {
     } else {
       LineDirection::Vertical(VerticalPositionKeyword::Bottom)
     };
-    let items = parse_items(input)?;
+    let items = parse_items(input, LengthPercentage::parse)?;
     Ok(LinearGradient {
       direction,
       items,
@@ -381,7 +381,7 @@ impl<'i> RadialGradient {
       input.expect_comma()?;
     }
 
-    let items = parse_items(input)?;
+    let items = parse_items(input, LengthPercentage::parse)?;
     Ok(RadialGradient {
       shape: shape.unwrap_or_default(),
       position: position.unwrap_or(Position::center()),
@@ -821,7 +821,13 @@ impl ConicGradient {
       input.expect_comma()?;
     }
 
-    let items = parse_items(input)?;
+    // Color stop angles also allow unitless zero.
+    // https://w3c.github.io/csswg-drafts/css-images-4/#typedef-color-stop-angle
+    let items = parse_items(input, |input| {
+      input
+        .try_parse(AnglePercentage::parse)
+        .or_else(|_| Angle::parse_with_unitless_zero(input).map(AnglePercentage::Dimension))
+    })?;
     Ok(ConicGradient {
       angle: angle.unwrap_or(Angle::Deg(0.0)),
       position: position.unwrap_or(Position::center()),
@@ -977,25 +983,33 @@ impl<D> IsCompatible for GradientItem<D> {
   }
 }
 
-fn parse_items<'i, 't, D: Parse<'i>>(
+fn parse_items<'i, 't, D, F>(
   input: &mut Parser<'i, 't>,
-) -> Result<Vec<GradientItem<D>>, ParseError<'i, ParserError<'i>>> {
+  parse_position: F,
+) -> Result<Vec<GradientItem<D>>, ParseError<'i, ParserError<'i>>>
+where
+  F: Fn(&mut Parser<'i, '_>) -> Result<D, ParseError<'i, ParserError<'i>>> + Copy,
+{
   let mut items = Vec::new();
   let mut seen_stop = false;
 
   loop {
     input.parse_until_before(Delimiter::Comma, |input| {
       if seen_stop {
-        if let Ok(hint) = input.try_parse(D::parse) {
+        if let Ok(hint) = input.try_parse(parse_position) {
           seen_stop = false;
           items.push(GradientItem::Hint(hint));
           return Ok(());
         }
       }
 
-      let stop = ColorStop::parse(input)?;
+      let color = CssColor::parse(input)?;
+      let stop = ColorStop {
+        color,
+        position: input.try_parse(parse_position).ok(),
+      };
 
-      if let Ok(position) = input.try_parse(D::parse) {
+      if let Ok(position) = input.try_parse(parse_position) {
         let color = stop.color.clone();
         items.push(GradientItem::ColorStop(stop));
 
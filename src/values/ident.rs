@@ -4,7 +4,7 @@ use crate::error::{ParserError, PrinterError};
 use crate::printer::Printer;
 use crate::properties::css_modules::Specifier;
 use crate::traits::{Parse, ParseWithOptions, ToCss};
-use crate::values::string::CowArcStr;
+use crate::values::string::{is_valid_custom_ident, CowArcStr};
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
 use cssparser::*;
@@ -31,12 +31,7 @@ impl<'i> Parse<'i> for CustomIdent<'i> {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     let location = input.current_source_location();
     let ident = input.expect_ident()?;
-    let valid = match_ignore_ascii_case! { &ident,
-      "initial" | "inherit" | "unset" | "default" | "revert" | "revert-layer" => false,
-      _ => true
-    };
-
-    if !valid {
+    if !is_valid_custom_ident(ident, &[]) {
       return Err(location.new_unexpected_token_error(Token::Ident(ident.clone())));
     }
 
@@ -81,6 +81,17 @@ impl<'i> Image<'i> {
     }
   }
 
+  /// Returns a plain image to use as a fallback for an `image-set()` in browsers that do not support it,
+  /// if needed for the given targets.
+  pub fn get_image_set_fallback(&self, targets: Targets) -> Option<Image<'i>> {
+    match self {
+      Image::ImageSet(image_set) if matches!(targets.browsers, Some(browsers) if !compat::Feature::ImageSet.is_compatible(browsers)) => {
+        image_set.get_fallback_image().cloned()
+      }
+      _ => None,
+    }
+  }
+
   /// Returns a legacy `-webkit-gradient()` value for the image.
   ///
   /// May return an error in case the gradient cannot be converted.
@@ -170,6 +181,11 @@ impl<'i> FallbackValues for Image<'i> {
     let fallbacks = self.get_necessary_fallbacks(targets);
     let mut res = Vec::new();
 
+    // Plain image for browsers without image-set() support.
+    if let Some(fallback) = self.get_image_set_fallback(targets) {
+      res.push(fallback);
+    }
+
     // Get RGB fallbacks if needed.
     let rgb = if fallbacks.contains(ColorFallbackKind::RGB) {
       Some(self.get_fallback(ColorFallbackKind::RGB))
@@ -250,6 +266,21 @@ impl<'i, T: ImageFallback<'i>> FallbackValues for SmallVec<[T; 1]> {
     // Prefixed properties only support RGB.
     let prefix_images = rgb.as_ref().unwrap_or(&self);
 
+    // Plain images for browsers without image-set() support.
+    if prefix_images
+      .iter()
+      .any(|item| item.get_image().get_image_set_fallback(targets).is_some())
+    {
+      let images = prefix_images
+        .iter()
+        .map(|item| match item.get_image().get_image_set_fallback(targets) {
+          Some(image) => item.with_image(image),
+          None => item.with_image(item.get_image().clone()),
+        })
+        .collect();
+      res.push(images)
+    }
+
     // Legacy -webkit-gradient()
     if prefixes.contains(VendorPrefix::WebKit) && targets.browsers.map(is_webkit_gradient).unwrap_or(false) {
       let images: SmallVec<[T; 1]> = prefix_images
@@ -341,6 +372,19 @@ impl<'i> ImageSet<'i> {
     targets.prefixes(self.vendor_prefix, Feature::ImageSet)
   }
 
+  /// Returns the image to use in browsers that do not support `image-set()`.
+  ///
+  /// This is the `1x` option if there is one, or otherwise the option with the lowest resolution.
+  /// Options with a `type()` are skipped, since the browser may not support the file format.
+  pub fn get_fallback_image(&self) -> Option<&Image<'i>> {
+    let options = self.options.iter().filter(|option| option.file_type.is_none());
+    options
+      .clone()
+      .find(|option| option.resolution.to_dppx() == 1.0)
+      .or_else(|| options.min_by(|a, b| a.resolution.to_dppx().total_cmp(&b.resolution.to_dppx())))
+      .map(|option| &option.image)
+  }
+
   /// Returns the `image-set()` value with the given vendor prefix.
   pub fn get_prefixed(&self, prefix: VendorPrefix) -> ImageSet<'i> {
     ImageSet {
@@ -473,14 +517,17 @@ impl<'i> ImageSetOption<'i> {
     // TODO: Throwing an error when `self.resolution = Resolution::Dppx(0.0)`
     // TODO: -webkit-image-set() does not support `<image()> | <image-set()> |
     // <cross-fade()> | <element()> | <gradient>` and `type(<string>)`.
-    dest.write_char(' ')?;
-
-    // Safari only supports the x resolution unit in image-set().
-    // In other places, x was added as an alias later.
-    // Temporarily ignore the targets while printing here.
-    let targets = std::mem::take(&mut dest.targets.current);
-    self.resolution.to_css(dest)?;
-    dest.targets.current = targets;
+    // The resolution defaults to 1x, but the prefixed syntax requires it.
+    if !dest.minify || is_prefixed || self.resolution.to_dppx() != 1.0 {
+      dest.write_char(' ')?;
+
+      // Safari only supports the x resolution unit in image-set().
+      // In other places, x was added as an alias later.
+      // Temporarily ignore the targets while printing here.
+      let targets = std::mem::take(&mut dest.targets.current);
+      self.resolution.to_css(dest)?;
+      dest.targets.current = targets;
+    }
 
     if let Some(file_type) = &self.file_type {
       dest.write_str(" type(")?;
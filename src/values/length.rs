@@ -6,7 +6,7 @@ use super::number::CSSNumber;
 use super::percentage::DimensionPercentage;
 use crate::error::{ParserError, PrinterError};
 use crate::printer::Printer;
-use crate::targets::Browsers;
+use crate::targets::{Browsers, Targets};
 use crate::traits::{
   private::{AddInternal, TryAdd},
   Map, Parse, Sign, ToCss, TryMap, TryOp, Zero,
@@ -81,6 +81,18 @@ const PX_PER_Q: f32 = PX_PER_CM / 40.0;
 const PX_PER_PT: f32 = PX_PER_IN / 72.0;
 const PX_PER_PC: f32 = PX_PER_IN / 6.0;
 
+/// Absolute length units, and the number of pixels in each. When converting lengths,
+/// earlier units are preferred over later ones with the same serialized length.
+const ABSOLUTE_UNITS: [(&str, f32); 7] = [
+  ("px", 1.0),
+  ("in", PX_PER_IN),
+  ("cm", PX_PER_CM),
+  ("mm", PX_PER_MM),
+  ("pt", PX_PER_PT),
+  ("pc", PX_PER_PC),
+  ("q", PX_PER_Q),
+];
+
 macro_rules! define_length_units {
   (
     $(
@@ -467,6 +479,12 @@ impl ToCss for LengthValue {
       return dest.write_char('0');
     }
 
+    if dest.minify && dest.convert_length_units {
+      if let Some((value, unit)) = self.to_shortest_absolute_unit(&dest.targets.current) {
+        return serialize_dimension(value, unit, dest);
+      }
+    }
+
     serialize_dimension(value, unit, dest)
   }
 }
@@ -484,6 +502,39 @@ impl LengthValue {
 }
 
 pub(crate) fn serialize_dimension<W>(value: f32, unit: &str, dest: &mut Printer<W>) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  write_dimension(value, unit, dest)?;
+  Ok(())
+}
+
+/// Serializes a dimension that was converted from another unit, and returns the value that is read
+/// back when parsing the serialization, along with its length. Returns `None` unless `to_base`
+/// converts the parsed value back to exactly `base`, i.e. the printed value is an exact conversion.
+pub(crate) fn exact_dimension(
+  value: f32,
+  unit: &str,
+  base: f32,
+  to_base: impl Fn(f32) -> f32,
+) -> Option<(f32, usize)> {
+  let mut s = String::new();
+  write_dimension(value, unit, &mut s).ok()?;
+  let mut input = ParserInput::new(&s);
+  let mut parser = Parser::new(&mut input);
+  let printed = match parser.next() {
+    Ok(Token::Dimension { value, .. }) => *value,
+    _ => return None,
+  };
+
+  if to_base(printed) == base {
+    Some((printed, s.len()))
+  } else {
+    None
+  }
+}
+
+pub(crate) fn write_dimension<W>(value: f32, unit: &str, dest: &mut W) -> std::fmt::Result
 where
   W: std::fmt::Write,
 {
@@ -505,8 +556,7 @@ where
       dest.write_str(s.trim_start_matches('0'))
     }
   } else {
-    token.to_css(dest)?;
-    Ok(())
+    token.to_css(dest)
   }
 }
 
@@ -526,6 +576,36 @@ impl LengthValue {
       _ => None,
     }
   }
+
+  /// Returns the value converted to the absolute unit with the shortest serialization, if it is
+  /// an absolute length and the conversion is exact and shorter than the original.
+  fn to_shortest_absolute_unit(&self, targets: &Targets) -> Option<(CSSNumber, &'static str)> {
+    fn serialized_len(value: CSSNumber, unit: &str) -> usize {
+      let mut s = String::new();
+      let _ = write_dimension(value, unit, &mut s);
+      s.len()
+    }
+
+    let px = self.to_px()?;
+    let (value, unit) = self.to_unit_value();
+    let mut shortest = serialized_len(value, unit);
+    let mut result = None;
+    for (unit, px_per_unit) in ABSOLUTE_UNITS {
+      if unit == "q" && !targets.is_compatible(crate::compat::Feature::QUnit) {
+        continue;
+      }
+
+      // Skip units that can't represent the value exactly once printed.
+      if let Some((converted, len)) = exact_dimension(px / px_per_unit, unit, px, |v| v * px_per_unit) {
+        if len < shortest {
+          shortest = len;
+          result = Some((converted, unit));
+        }
+      }
+    }
+
+    result
+  }
 }
 
 /// A CSS [`<length>`](https://www.w3.org/TR/css-values-4/#lengths) value, with support for `calc()`.
@@ -37,6 +37,7 @@ pub mod ident;
 pub mod image;
 pub mod length;
 pub mod number;
+pub mod path;
 pub mod percentage;
 pub mod position;
 pub mod ratio;
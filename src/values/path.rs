@@ -0,0 +1,798 @@
+//! CSS path data values.
+
+use crate::error::{ParserError, PrinterError};
+use crate::printer::Printer;
+use crate::traits::{Parse, ToCss};
+#[cfg(feature = "visitor")]
+use crate::visitor::Visit;
+use cssparser::*;
+
+/// SVG [path data](https://www.w3.org/TR/SVG2/paths.html#PathData), as used in the `path()`
+/// function and the `d` property.
+///
+/// When minifying, path data is compacted by removing redundant whitespace and command letters,
+/// and choosing the shortest of the absolute and relative forms of each command. Numbers are
+/// stored in double precision, so that coordinates are preserved exactly as written.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct PathData {
+  /// The commands that make up the path.
+  pub commands: Vec<PathCommand>,
+}
+
+/// A command within [PathData](PathData).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum PathCommand {
+  /// The `M` command, which starts a new subpath.
+  MoveTo {
+    /// Whether the coordinates are relative to the current point.
+    relative: bool,
+    /// The start point of the subpath.
+    point: PathPoint,
+  },
+  /// The `L` command, which draws a straight line.
+  LineTo {
+    /// Whether the coordinates are relative to the current point.
+    relative: bool,
+    /// The end point of the line.
+    point: PathPoint,
+  },
+  /// The `H` command, which draws a horizontal line.
+  HorizontalLineTo {
+    /// Whether the coordinate is relative to the current point.
+    relative: bool,
+    /// The x coordinate of the end of the line.
+    x: f64,
+  },
+  /// The `V` command, which draws a vertical line.
+  VerticalLineTo {
+    /// Whether the coordinate is relative to the current point.
+    relative: bool,
+    /// The y coordinate of the end of the line.
+    y: f64,
+  },
+  /// The `C` command, which draws a cubic Bézier curve.
+  CurveTo {
+    /// Whether the coordinates are relative to the current point.
+    relative: bool,
+    /// The control point at the start of the curve.
+    control1: PathPoint,
+    /// The control point at the end of the curve.
+    control2: PathPoint,
+    /// The end point of the curve.
+    point: PathPoint,
+  },
+  /// The `S` command, which draws a cubic Bézier curve whose first control point
+  /// is the reflection of the previous curve's second control point.
+  SmoothCurveTo {
+    /// Whether the coordinates are relative to the current point.
+    relative: bool,
+    /// The control point at the end of the curve.
+    control2: PathPoint,
+    /// The end point of the curve.
+    point: PathPoint,
+  },
+  /// The `Q` command, which draws a quadratic Bézier curve.
+  QuadraticCurveTo {
+    /// Whether the coordinates are relative to the current point.
+    relative: bool,
+    /// The control point of the curve.
+    control1: PathPoint,
+    /// The end point of the curve.
+    point: PathPoint,
+  },
+  /// The `T` command, which draws a quadratic Bézier curve whose control point
+  /// is the reflection of the previous curve's control point.
+  SmoothQuadraticCurveTo {
+    /// Whether the coordinates are relative to the current point.
+    relative: bool,
+    /// The end point of the curve.
+    point: PathPoint,
+  },
+  /// The `A` command, which draws an elliptical arc.
+  #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+  Arc {
+    /// Whether the end point is relative to the current point.
+    relative: bool,
+    /// The x and y radii of the ellipse.
+    radii: PathPoint,
+    /// The rotation of the ellipse's x axis, in degrees.
+    angle: f64,
+    /// Whether the larger of the two possible arcs is drawn.
+    large_arc: bool,
+    /// Whether the arc is drawn in the positive-angle direction.
+    sweep: bool,
+    /// The end point of the arc.
+    point: PathPoint,
+  },
+  /// The `Z` command, which closes the current subpath.
+  ClosePath,
+}
+
+/// A coordinate pair within [PathData](PathData).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct PathPoint {
+  /// The x coordinate.
+  pub x: f64,
+  /// The y coordinate.
+  pub y: f64,
+}
+
+impl PathPoint {
+  fn add(&self, other: PathPoint) -> PathPoint {
+    PathPoint {
+      x: self.x + other.x,
+      y: self.y + other.y,
+    }
+  }
+}
+
+/// A coordinate of a command, in both its absolute and relative forms. The form the
+/// command was written in is always available, while the other form is only available
+/// if it resolves to exactly the same position.
+#[derive(Clone, Copy)]
+struct Coordinate {
+  absolute: Option<f64>,
+  relative: Option<f64>,
+  /// The resolved absolute position, used as the origin of following relative commands.
+  resolved: f64,
+}
+
+impl Coordinate {
+  fn new(value: f64, relative: bool, origin: f64) -> Coordinate {
+    if relative {
+      let absolute = exact_sum(origin, value);
+      Coordinate {
+        absolute,
+        relative: Some(value),
+        resolved: absolute.unwrap_or(origin + value),
+      }
+    } else {
+      Coordinate {
+        absolute: Some(value),
+        relative: exact_sum(value, -origin),
+        resolved: value,
+      }
+    }
+  }
+}
+
+/// Returns the sum of `a` and `b`, if it can be represented without rounding.
+fn exact_sum(a: f64, b: f64) -> Option<f64> {
+  let sum = a + b;
+  if sum - a == b && sum - b == a {
+    Some(sum)
+  } else {
+    None
+  }
+}
+
+impl PathData {
+  /// Returns an equivalent path where every command uses absolute coordinates.
+  ///
+  /// Horizontal and vertical line commands are converted to regular line commands.
+  pub fn to_absolute(&self) -> PathData {
+    let mut current = PathPoint::default();
+    let mut subpath_start = PathPoint::default();
+    let commands = self
+      .commands
+      .iter()
+      .map(|command| {
+        let resolve = |relative: bool, point: PathPoint| {
+          if relative {
+            current.add(point)
+          } else {
+            point
+          }
+        };
+
+        let command = match *command {
+          PathCommand::MoveTo { relative, point } => {
+            let point = resolve(relative, point);
+            subpath_start = point;
+            PathCommand::MoveTo { relative: false, point }
+          }
+          PathCommand::LineTo { relative, point } => PathCommand::LineTo {
+            relative: false,
+            point: resolve(relative, point),
+          },
+          PathCommand::HorizontalLineTo { relative, x } => PathCommand::LineTo {
+            relative: false,
+            point: PathPoint {
+              x: if relative { current.x + x } else { x },
+              y: current.y,
+            },
+          },
+          PathCommand::VerticalLineTo { relative, y } => PathCommand::LineTo {
+            relative: false,
+            point: PathPoint {
+              x: current.x,
+              y: if relative { current.y + y } else { y },
+            },
+          },
+          PathCommand::CurveTo {
+            relative,
+            control1,
+            control2,
+            point,
+          } => PathCommand::CurveTo {
+            relative: false,
+            control1: resolve(relative, control1),
+            control2: resolve(relative, control2),
+            point: resolve(relative, point),
+          },
+          PathCommand::SmoothCurveTo {
+            relative,
+            control2,
+            point,
+          } => PathCommand::SmoothCurveTo {
+            relative: false,
+            control2: resolve(relative, control2),
+            point: resolve(relative, point),
+          },
+          PathCommand::QuadraticCurveTo {
+            relative,
+            control1,
+            point,
+          } => PathCommand::QuadraticCurveTo {
+            relative: false,
+            control1: resolve(relative, control1),
+            point: resolve(relative, point),
+          },
+          PathCommand::SmoothQuadraticCurveTo { relative, point } => PathCommand::SmoothQuadraticCurveTo {
+            relative: false,
+            point: resolve(relative, point),
+          },
+          PathCommand::Arc {
+            relative,
+            radii,
+            angle,
+            large_arc,
+            sweep,
+            point,
+          } => PathCommand::Arc {
+            relative: false,
+            radii,
+            angle,
+            large_arc,
+            sweep,
+            point: resolve(relative, point),
+          },
+          PathCommand::ClosePath => PathCommand::ClosePath,
+        };
+
+        current = match command {
+          PathCommand::MoveTo { point, .. }
+          | PathCommand::LineTo { point, .. }
+          | PathCommand::CurveTo { point, .. }
+          | PathCommand::SmoothCurveTo { point, .. }
+          | PathCommand::QuadraticCurveTo { point, .. }
+          | PathCommand::SmoothQuadraticCurveTo { point, .. }
+          | PathCommand::Arc { point, .. } => point,
+          PathCommand::ClosePath => subpath_start,
+          PathCommand::HorizontalLineTo { .. } | PathCommand::VerticalLineTo { .. } => unreachable!(),
+        };
+
+        command
+      })
+      .collect();
+
+    PathData { commands }
+  }
+
+  /// Writes the path in its most compact form. Each command keeps the form it was written in,
+  /// unless the other form resolves to exactly the same points and is shorter.
+  fn write_minified(&self, dest: &mut String) {
+    let mut writer = PathWriter::default();
+    let mut current = PathPoint::default();
+    let mut subpath_start = PathPoint::default();
+    for command in &self.commands {
+      let resolve = |relative: bool, point: PathPoint| {
+        [
+          Coordinate::new(point.x, relative, current.x),
+          Coordinate::new(point.y, relative, current.y),
+        ]
+      };
+
+      current = match *command {
+        PathCommand::MoveTo { relative, point } => {
+          let [x, y] = resolve(relative, point);
+          writer.write_shortest('M', &[], &[x, y]);
+          subpath_start = PathPoint {
+            x: x.resolved,
+            y: y.resolved,
+          };
+          subpath_start
+        }
+        PathCommand::LineTo { relative, point } => {
+          let [x, y] = resolve(relative, point);
+          if y.relative == Some(0.0) {
+            writer.write_shortest('H', &[], &[x]);
+          } else if x.relative == Some(0.0) {
+            writer.write_shortest('V', &[], &[y]);
+          } else {
+            writer.write_shortest('L', &[], &[x, y]);
+          }
+          PathPoint {
+            x: x.resolved,
+            y: y.resolved,
+          }
+        }
+        PathCommand::HorizontalLineTo { relative, x } => {
+          let x = Coordinate::new(x, relative, current.x);
+          writer.write_shortest('H', &[], &[x]);
+          PathPoint {
+            x: x.resolved,
+            y: current.y,
+          }
+        }
+        PathCommand::VerticalLineTo { relative, y } => {
+          let y = Coordinate::new(y, relative, current.y);
+          writer.write_shortest('V', &[], &[y]);
+          PathPoint {
+            x: current.x,
+            y: y.resolved,
+          }
+        }
+        PathCommand::CurveTo {
+          relative,
+          control1,
+          control2,
+          point,
+        } => {
+          let [x1, y1] = resolve(relative, control1);
+          let [x2, y2] = resolve(relative, control2);
+          let [x, y] = resolve(relative, point);
+          writer.write_shortest('C', &[], &[x1, y1, x2, y2, x, y]);
+          PathPoint {
+            x: x.resolved,
+            y: y.resolved,
+          }
+        }
+        PathCommand::SmoothCurveTo {
+          relative,
+          control2,
+          point,
+        } => {
+          let [x2, y2] = resolve(relative, control2);
+          let [x, y] = resolve(relative, point);
+          writer.write_shortest('S', &[], &[x2, y2, x, y]);
+          PathPoint {
+            x: x.resolved,
+            y: y.resolved,
+          }
+        }
+        PathCommand::QuadraticCurveTo {
+          relative,
+          control1,
+          point,
+        } => {
+          let [x1, y1] = resolve(relative, control1);
+          let [x, y] = resolve(relative, point);
+          writer.write_shortest('Q', &[], &[x1, y1, x, y]);
+          PathPoint {
+            x: x.resolved,
+            y: y.resolved,
+          }
+        }
+        PathCommand::SmoothQuadraticCurveTo { relative, point } => {
+          let [x, y] = resolve(relative, point);
+          writer.write_shortest('T', &[], &[x, y]);
+          PathPoint {
+            x: x.resolved,
+            y: y.resolved,
+          }
+        }
+        PathCommand::Arc {
+          relative,
+          radii,
+          angle,
+          large_arc,
+          sweep,
+          point,
+        } => {
+          let flag = |flag: bool| if flag { 1.0 } else { 0.0 };
+          let args = [radii.x, radii.y, angle, flag(large_arc), flag(sweep)];
+          let [x, y] = resolve(relative, point);
+          writer.write_shortest('A', &args, &[x, y]);
+          PathPoint {
+            x: x.resolved,
+            y: y.resolved,
+          }
+        }
+        PathCommand::ClosePath => {
+          writer.state.write_command(&mut writer.output, 'Z', &[]);
+          subpath_start
+        }
+      };
+    }
+
+    dest.push_str(&writer.output);
+  }
+}
+
+/// Serializes path commands while omitting unnecessary separators and command letters.
+#[derive(Default)]
+struct PathWriter {
+  output: String,
+  state: PathWriterState,
+}
+
+/// The state needed to decide which separators and command letters the next command needs.
+#[derive(Default, Clone, Copy)]
+struct PathWriterState {
+  /// The command letter that is implied if the next command omits its letter.
+  implicit_command: Option<char>,
+  /// Whether the output ends with a number, meaning that a following number may need a separator.
+  ends_with_number: bool,
+  /// Whether the last number written contains a decimal point, meaning that a
+  /// following number starting with a decimal point needs no separator.
+  last_number_has_decimal: bool,
+}
+
+impl PathWriter {
+  /// Writes the command with either absolute or relative coordinates, whichever is shorter.
+  /// Each candidate is written to a separate segment first, so that only the new command is
+  /// measured rather than the whole output.
+  fn write_shortest(&mut self, command: char, args: &[f64], coordinates: &[Coordinate]) {
+    let absolute = coordinates.iter().map(|c| c.absolute).collect::<Option<Vec<_>>>();
+    let relative = coordinates.iter().map(|c| c.relative).collect::<Option<Vec<_>>>();
+    let candidates = [(command, absolute), (command.to_ascii_lowercase(), relative)];
+
+    let mut shortest: Option<(String, PathWriterState)> = None;
+    for (command, coordinates) in candidates {
+      let Some(coordinates) = coordinates else { continue };
+      let mut values = args.to_vec();
+      values.extend(coordinates);
+
+      let mut segment = String::new();
+      let mut state = self.state;
+      state.write_command(&mut segment, command, &values);
+      match &shortest {
+        Some((shortest, _)) if shortest.len() <= segment.len() => {}
+        _ => shortest = Some((segment, state)),
+      }
+    }
+
+    // The form the command was written in is always available.
+    let (segment, state) = shortest.unwrap();
+    self.output.push_str(&segment);
+    self.state = state;
+  }
+}
+
+impl PathWriterState {
+  fn write_command(&mut self, dest: &mut String, command: char, values: &[f64]) {
+    if self.implicit_command != Some(command) || values.is_empty() {
+      dest.push(command);
+      self.ends_with_number = false;
+      self.last_number_has_decimal = false;
+    }
+
+    self.implicit_command = match command {
+      'M' => Some('L'),
+      'm' => Some('l'),
+      'Z' | 'z' => None,
+      c => Some(c),
+    };
+
+    for value in values {
+      self.write_number(dest, *value);
+    }
+  }
+
+  fn write_number(&mut self, dest: &mut String, value: f64) {
+    let number = serialize_number(value);
+    let needs_separator = self.ends_with_number
+      && !(number.starts_with('-') || (number.starts_with('.') && self.last_number_has_decimal));
+
+    if needs_separator {
+      dest.push(' ');
+    }
+
+    self.ends_with_number = true;
+    self.last_number_has_decimal = number.contains('.') && !number.contains('e');
+    dest.push_str(&number);
+  }
+}
+
+/// Serializes a number using the shortest representation that parses back to exactly the same value.
+/// Unlike other CSS numbers, path coordinates are not rounded, since relative commands would
+/// accumulate the rounding error along the path.
+fn serialize_number(value: f64) -> String {
+  // Avoid serializing negative zero as "-0".
+  let value = if value == 0.0 { 0.0 } else { value };
+  let mut s = value.to_string();
+  if s.starts_with("0.") {
+    s.remove(0);
+  } else if s.starts_with("-0.") {
+    s.remove(1);
+  }
+  s
+}
+
+impl<'i> Parse<'i> for PathData {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let string = input.expect_string()?;
+    PathParser::new(string.as_ref())
+      .parse()
+      .ok_or_else(|| location.new_custom_error(ParserError::InvalidValue))
+  }
+}
+
+impl ToCss for PathData {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    let mut s = String::new();
+    if dest.minify {
+      self.write_minified(&mut s);
+    } else {
+      for command in &self.commands {
+        if !s.is_empty() {
+          s.push(' ');
+        }
+        command.write(&mut s);
+      }
+    }
+
+    serialize_string(&s, dest)?;
+    Ok(())
+  }
+}
+
+impl PathCommand {
+  fn write(&self, dest: &mut String) {
+    let mut write = |command: char, relative: bool, values: &[f64]| {
+      dest.push(if relative {
+        command.to_ascii_lowercase()
+      } else {
+        command
+      });
+      for value in values {
+        dest.push(' ');
+        dest.push_str(&serialize_number(*value));
+      }
+    };
+
+    match *self {
+      PathCommand::MoveTo { relative, point } => write('M', relative, &[point.x, point.y]),
+      PathCommand::LineTo { relative, point } => write('L', relative, &[point.x, point.y]),
+      PathCommand::HorizontalLineTo { relative, x } => write('H', relative, &[x]),
+      PathCommand::VerticalLineTo { relative, y } => write('V', relative, &[y]),
+      PathCommand::CurveTo {
+        relative,
+        control1,
+        control2,
+        point,
+      } => write(
+        'C',
+        relative,
+        &[control1.x, control1.y, control2.x, control2.y, point.x, point.y],
+      ),
+      PathCommand::SmoothCurveTo {
+        relative,
+        control2,
+        point,
+      } => write('S', relative, &[control2.x, control2.y, point.x, point.y]),
+      PathCommand::QuadraticCurveTo {
+        relative,
+        control1,
+        point,
+      } => write('Q', relative, &[control1.x, control1.y, point.x, point.y]),
+      PathCommand::SmoothQuadraticCurveTo { relative, point } => write('T', relative, &[point.x, point.y]),
+      PathCommand::Arc {
+        relative,
+        radii,
+        angle,
+        large_arc,
+        sweep,
+        point,
+      } => write(
+        'A',
+        relative,
+        &[
+          radii.x,
+          radii.y,
+          angle,
+          if large_arc { 1.0 } else { 0.0 },
+          if sweep { 1.0 } else { 0.0 },
+          point.x,
+          point.y,
+        ],
+      ),
+      PathCommand::ClosePath => write('Z', false, &[]),
+    }
+  }
+}
+
+/// A parser for the [SVG path data grammar](https://www.w3.org/TR/SVG2/paths.html#PathDataBNF).
+struct PathParser<'a> {
+  bytes: &'a [u8],
+  pos: usize,
+}
+
+impl<'a> PathParser<'a> {
+  fn new(s: &'a str) -> Self {
+    PathParser {
+      bytes: s.as_bytes(),
+      pos: 0,
+    }
+  }
+
+  fn parse(mut self) -> Option<PathData> {
+    let mut commands = Vec::new();
+    self.skip_whitespace();
+    while let Some(&c) = self.bytes.get(self.pos) {
+      self.pos += 1;
+
+      // Paths must start with a move command.
+      if commands.is_empty() && !matches!(c, b'M' | b'm') {
+        return None;
+      }
+
+      let relative = c.is_ascii_lowercase();
+      if matches!(c, b'Z' | b'z') {
+        commands.push(PathCommand::ClosePath);
+        self.skip_whitespace();
+        continue;
+      }
+
+      // Each command letter may be followed by multiple sets of arguments.
+      // Additional sets of arguments after a move command are treated as lines.
+      let mut first = true;
+      loop {
+        self.skip_whitespace();
+        let command = match c.to_ascii_uppercase() {
+          b'M' if first => PathCommand::MoveTo {
+            relative,
+            point: self.parse_point()?,
+          },
+          b'M' | b'L' => PathCommand::LineTo {
+            relative,
+            point: self.parse_point()?,
+          },
+          b'H' => PathCommand::HorizontalLineTo {
+            relative,
+            x: self.parse_number()?,
+          },
+          b'V' => PathCommand::VerticalLineTo {
+            relative,
+            y: self.parse_number()?,
+          },
+          b'C' => PathCommand::CurveTo {
+            relative,
+            control1: self.parse_point()?,
+            control2: self.parse_point()?,
+            point: self.parse_point()?,
+          },
+          b'S' => PathCommand::SmoothCurveTo {
+            relative,
+            control2: self.parse_point()?,
+            point: self.parse_point()?,
+          },
+          b'Q' => PathCommand::QuadraticCurveTo {
+            relative,
+            control1: self.parse_point()?,
+            point: self.parse_point()?,
+          },
+          b'T' => PathCommand::SmoothQuadraticCurveTo {
+            relative,
+            point: self.parse_point()?,
+          },
+          b'A' => PathCommand::Arc {
+            relative,
+            radii: PathPoint {
+              x: self.parse_number()?,
+              y: self.parse_number()?,
+            },
+            angle: self.parse_number()?,
+            large_arc: self.parse_flag()?,
+            sweep: self.parse_flag()?,
+            point: self.parse_point()?,
+          },
+          _ => return None,
+        };
+
+        commands.push(command);
+        first = false;
+        self.skip_whitespace();
+        if !matches!(self.bytes.get(self.pos), Some(b'0'..=b'9' | b'-' | b'+' | b'.')) {
+          break;
+        }
+      }
+    }
+
+    Some(PathData { commands })
+  }
+
+  fn skip_whitespace(&mut self) {
+    while matches!(self.bytes.get(self.pos), Some(b' ' | b'\t' | b'\n' | b'\r' | b'\x0C')) {
+      self.pos += 1;
+    }
+  }
+
+  /// Skips the optional comma and whitespace that may separate arguments.
+  fn skip_separator(&mut self) {
+    self.skip_whitespace();
+    if self.bytes.get(self.pos) == Some(&b',') {
+      self.pos += 1;
+      self.skip_whitespace();
+    }
+  }
+
+  fn parse_point(&mut self) -> Option<PathPoint> {
+    let x = self.parse_number()?;
+    let y = self.parse_number()?;
+    Some(PathPoint { x, y })
+  }
+
+  fn parse_number(&mut self) -> Option<f64> {
+    let start = self.pos;
+    let digits = |parser: &mut Self| {
+      let start = parser.pos;
+      while matches!(parser.bytes.get(parser.pos), Some(b'0'..=b'9')) {
+        parser.pos += 1;
+      }
+      parser.pos > start
+    };
+
+    if matches!(self.bytes.get(self.pos), Some(b'-' | b'+')) {
+      self.pos += 1;
+    }
+
+    let mut has_digits = digits(self);
+    if self.bytes.get(self.pos) == Some(&b'.') {
+      self.pos += 1;
+      has_digits |= digits(self);
+    }
+
+    if !has_digits {
+      return None;
+    }
+
+    if matches!(self.bytes.get(self.pos), Some(b'e' | b'E')) {
+      let mantissa_end = self.pos;
+      self.pos += 1;
+      if matches!(self.bytes.get(self.pos), Some(b'-' | b'+')) {
+        self.pos += 1;
+      }
+      if !digits(self) {
+        self.pos = mantissa_end;
+      }
+    }
+
+    let number = std::str::from_utf8(&self.bytes[start..self.pos]).ok()?.parse::<f64>().ok()?;
+    if !number.is_finite() {
+      return None;
+    }
+
+    self.skip_separator();
+    Some(number)
+  }
+
+  fn parse_flag(&mut self) -> Option<bool> {
+    let flag = match self.bytes.get(self.pos)? {
+      b'0' => false,
+      b'1' => true,
+      _ => return None,
+    };
+    self.pos += 1;
+    self.skip_separator();
+    Some(flag)
+  }
+}
@@ -46,6 +46,27 @@ impl Position {
   pub fn is_zero(&self) -> bool {
     self.x.is_zero() && self.y.is_zero()
   }
+
+  /// Returns an equivalent position that can be serialized in the shorter two-value syntax,
+  /// e.g. `right 0 bottom 20%` => `100% 80%`. Positions that have no two-value equivalent,
+  /// e.g. `right 10px bottom 20px`, are returned unchanged.
+  pub fn to_minimized_form(&self) -> Position {
+    let mut x = self.x.to_minimized_form();
+    let mut y = self.y.to_minimized_form();
+    if x.has_side_offset() || y.has_side_offset() {
+      return self.clone();
+    }
+
+    // A side keyword next to a length is longer than the equivalent percentage, e.g. `right 20px` => `100% 20px`.
+    if let (PositionComponent::Side { side, offset: None }, PositionComponent::Length(_)) = (&x, &y) {
+      x = PositionComponent::Length((*side).into());
+    }
+    if let (PositionComponent::Length(_), PositionComponent::Side { side, offset: None }) = (&x, &y) {
+      y = PositionComponent::Length((*side).into());
+    }
+
+    Position { x, y }
+  }
 }
 
 impl Default for Position {
@@ -172,6 +193,13 @@ impl ToCss for Position {
   where
     W: std::fmt::Write,
   {
+    if dest.minify {
+      let minimized = self.to_minimized_form();
+      if minimized != *self {
+        return minimized.to_css(dest);
+      }
+    }
+
     match (&self.x, &self.y) {
       (x_pos @ &HorizontalPosition::Side { side, offset: Some(_) }, &VerticalPosition::Length(ref y_lp))
         if side != HorizontalPositionKeyword::Left =>
@@ -343,6 +371,42 @@ impl<S> PositionComponent<S> {
   }
 }
 
+impl<S: Clone + Into<LengthPercentage>> PositionComponent<S> {
+  /// Converts a side keyword with an offset into an equivalent length or percentage where possible.
+  /// Offsets from the end side (e.g. `right`) can only be converted when they are zero or a percentage.
+  fn to_minimized_form(&self) -> Self {
+    if let PositionComponent::Side {
+      side,
+      offset: Some(offset),
+    } = self
+    {
+      let start: LengthPercentage = side.clone().into();
+      if start.is_zero() {
+        return PositionComponent::Length(offset.clone());
+      }
+
+      match offset {
+        offset if offset.is_zero() => {
+          return PositionComponent::Side {
+            side: side.clone(),
+            offset: None,
+          }
+        }
+        LengthPercentage::Percentage(Percentage(p)) => {
+          return PositionComponent::Length(LengthPercentage::Percentage(Percentage(1.0 - p)))
+        }
+        _ => {}
+      }
+    }
+
+    self.clone()
+  }
+
+  fn has_side_offset(&self) -> bool {
+    matches!(self, PositionComponent::Side { offset: Some(_), .. })
+  }
+}
+
 impl<'i, S: Parse<'i>> Parse<'i> for PositionComponent<S> {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     if input.try_parse(|i| i.expect_ident_matching("center")).is_ok() {
@@ -2,7 +2,7 @@
 
 use super::number::CSSNumber;
 use crate::error::{ParserError, PrinterError};
-use crate::printer::Printer;
+use crate::printer::{Printer, PrinterOptions};
 use crate::traits::{Parse, ToCss};
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
@@ -39,6 +39,59 @@ impl Ratio {
     let second = CSSNumber::parse(input)?;
     Ok(Ratio(first, second))
   }
+
+  /// Returns an equivalent ratio with the smallest integer terms, e.g. `1 / 2` for `2 / 4` or `1.5 / 3`,
+  /// if it can be computed exactly and is not longer than the original when minified.
+  fn reduce(&self) -> Option<Ratio> {
+    // Degenerate ratios (e.g. `0 / 1`) are left alone.
+    if !(self.0 > 0.0 && self.1 > 0.0) || self.1 == 1.0 {
+      return None;
+    }
+
+    let mut scale = 1.0;
+    while (self.0 * scale).fract() != 0.0 || (self.1 * scale).fract() != 0.0 {
+      scale *= 10.0;
+      if scale > 1e6 {
+        return None;
+      }
+    }
+
+    // Integers above 2^24 cannot be represented exactly as an f32, so a term of 2^24 or more
+    // may already have been rounded when it was parsed, e.g. `16777217` is stored as `16777216`.
+    let (a, b) = (self.0 * scale, self.1 * scale);
+    if a >= 16777216.0 || b >= 16777216.0 {
+      return None;
+    }
+
+    let (a, b) = (a as u32, b as u32);
+    let divisor = gcd(a, b);
+    let reduced = Ratio((a / divisor) as f32, (b / divisor) as f32);
+    if reduced == *self || reduced.minified_len()? > self.minified_len()? {
+      return None;
+    }
+
+    Some(reduced)
+  }
+
+  fn minified_len(&self) -> Option<usize> {
+    let options = || PrinterOptions {
+      minify: true,
+      ..PrinterOptions::default()
+    };
+    let first = self.0.to_css_string(options()).ok()?;
+    if self.1 == 1.0 {
+      return Some(first.len());
+    }
+
+    Some(first.len() + 1 + self.1.to_css_string(options()).ok()?.len())
+  }
+}
+
+fn gcd(mut a: u32, mut b: u32) -> u32 {
+  while b != 0 {
+    (a, b) = (b, a % b);
+  }
+  a
 }
 
 impl ToCss for Ratio {
@@ -46,6 +99,12 @@ impl ToCss for Ratio {
   where
     W: std::fmt::Write,
   {
+    if dest.minify {
+      if let Some(reduced) = self.reduce() {
+        return reduced.to_css(dest);
+      }
+    }
+
     self.0.to_css(dest)?;
     if self.1 != 1.0 {
       dest.delim('/', true)?;
@@ -1,10 +1,11 @@
 //! CSS resolution values.
 
-use super::length::serialize_dimension;
+use super::length::{exact_dimension, serialize_dimension, write_dimension};
 use super::number::CSSNumber;
 use crate::compat::Feature;
 use crate::error::{ParserError, PrinterError};
 use crate::printer::Printer;
+use crate::targets::Targets;
 use crate::traits::{Parse, ToCss};
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
@@ -85,6 +86,65 @@ impl ToCss for Resolution {
   }
 }
 
+impl Resolution {
+  /// Returns the resolution in dots per px.
+  pub fn to_dppx(&self) -> CSSNumber {
+    match self {
+      Resolution::Dpi(dpi) => dpi / 96.0,
+      Resolution::Dpcm(dpcm) => dpcm * 2.54 / 96.0,
+      Resolution::Dppx(dppx) => *dppx,
+    }
+  }
+
+  /// Returns the equivalent resolution in the unit with the shortest serialization.
+  /// Units that cannot represent the value exactly are skipped, and `dppx` is preferred on ties.
+  /// Values are only converted to `dppx` if all targets support it.
+  pub(crate) fn to_shortest_unit(&self, targets: &Targets) -> Resolution {
+    let dppx_unit = if targets.is_compatible(Feature::XResolutionUnit) {
+      "x"
+    } else {
+      "dppx"
+    };
+
+    let serialized_len = |value: CSSNumber, unit: &str| {
+      let mut s = String::new();
+      let _ = write_dimension(value, unit, &mut s);
+      s.len()
+    };
+
+    let (value, unit) = match self {
+      Resolution::Dpi(dpi) => (*dpi, "dpi"),
+      Resolution::Dpcm(dpcm) => (*dpcm, "dpcm"),
+      Resolution::Dppx(dppx) => (*dppx, dppx_unit),
+    };
+
+    let dppx = self.to_dppx();
+    let mut result = self.clone();
+    let mut shortest = serialized_len(value, unit);
+    let resolution = |unit: &str, value: CSSNumber| match unit {
+      "dpi" => Resolution::Dpi(value),
+      "dpcm" => Resolution::Dpcm(value),
+      _ => Resolution::Dppx(value),
+    };
+
+    let supports_dppx = targets.is_compatible(Feature::DppxResolutionUnit);
+    for (unit, converted) in [(dppx_unit, dppx), ("dpi", dppx * 96.0), ("dpcm", dppx * 96.0 / 2.54)] {
+      if unit == dppx_unit && !supports_dppx {
+        continue;
+      }
+
+      if let Some((converted, len)) = exact_dimension(converted, unit, dppx, |v| resolution(unit, v).to_dppx()) {
+        if len < shortest || (len == shortest && unit == dppx_unit) {
+          shortest = len;
+          result = resolution(unit, converted);
+        }
+      }
+    }
+
+    result
+  }
+}
+
 impl std::ops::Add<CSSNumber> for Resolution {
   type Output = Self;
 
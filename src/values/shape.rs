@@ -1,13 +1,15 @@
 //! CSS shape values for masking and clipping.
 
+use super::angle::Angle;
 use super::length::LengthPercentage;
+use super::path::PathData;
 use super::position::Position;
 use super::rect::Rect;
 use crate::error::{ParserError, PrinterError};
 use crate::macros::enum_property;
 use crate::printer::Printer;
 use crate::properties::border_radius::BorderRadius;
-use crate::traits::{Parse, ToCss};
+use crate::traits::{Parse, ToCss, Zero};
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
 use cssparser::*;
@@ -31,6 +33,10 @@ pub enum BasicShape {
   Ellipse(Ellipse),
   /// A polygon.
   Polygon(Polygon),
+  /// A path.
+  Path(Path),
+  /// A shape built from a list of commands.
+  Shape(Shape),
 }
 
 /// An [`inset()`](https://www.w3.org/TR/css-shapes-1/#funcdef-inset) rectangle shape.
@@ -110,7 +116,149 @@ pub struct Polygon {
   pub points: Vec<Point>,
 }
 
-/// A point within a `polygon()` shape.
+/// A [`path()`](https://www.w3.org/TR/css-shapes-1/#funcdef-basic-shape-path) shape.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct Path {
+  /// The fill rule used to determine the interior of the path.
+  pub fill_rule: FillRule,
+  /// The path data.
+  pub path: PathData,
+}
+
+/// A [`shape()`](https://drafts.csswg.org/css-shapes-2/#shape-function) shape.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct Shape {
+  /// The fill rule used to determine the interior of the shape.
+  pub fill_rule: FillRule,
+  /// The starting point of the shape.
+  pub start: Point,
+  /// The commands that make up the shape.
+  pub commands: Vec<ShapeCommand>,
+}
+
+/// A command within a `shape()` shape.
+///
+/// See [Shape](Shape).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "visitor", derive(Visit))]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub enum ShapeCommand {
+  /// The `move` command, which starts a new subpath.
+  Move {
+    /// Whether the point is relative to the current point (`by`) rather than the reference box (`to`).
+    relative: bool,
+    /// The start point of the subpath.
+    point: Point,
+  },
+  /// The `line` command, which draws a straight line.
+  Line {
+    /// Whether the point is relative to the current point.
+    relative: bool,
+    /// The end point of the line.
+    point: Point,
+  },
+  /// The `hline` command, which draws a horizontal line.
+  #[cfg_attr(feature = "serde", serde(rename = "hline"))]
+  HorizontalLine {
+    /// Whether the coordinate is relative to the current point.
+    relative: bool,
+    /// The x coordinate of the end of the line.
+    x: LengthPercentage,
+  },
+  /// The `vline` command, which draws a vertical line.
+  #[cfg_attr(feature = "serde", serde(rename = "vline"))]
+  VerticalLine {
+    /// Whether the coordinate is relative to the current point.
+    relative: bool,
+    /// The y coordinate of the end of the line.
+    y: LengthPercentage,
+  },
+  /// The `curve` command, which draws a quadratic or cubic Bézier curve.
+  Curve {
+    /// Whether the end point is relative to the current point.
+    relative: bool,
+    /// The end point of the curve.
+    point: Point,
+    /// The first control point of the curve.
+    control1: Point,
+    /// The second control point of the curve, for cubic curves.
+    control2: Option<Point>,
+  },
+  /// The `smooth` command, which draws a Bézier curve whose first control point
+  /// is the reflection of the previous curve's last control point.
+  Smooth {
+    /// Whether the end point is relative to the current point.
+    relative: bool,
+    /// The end point of the curve.
+    point: Point,
+    /// The second control point of the curve, for cubic curves.
+    control: Option<Point>,
+  },
+  /// The `arc` command, which draws an elliptical arc.
+  Arc {
+    /// Whether the end point is relative to the current point.
+    relative: bool,
+    /// The end point of the arc.
+    point: Point,
+    /// The x radius of the ellipse.
+    radius_x: LengthPercentage,
+    /// The y radius of the ellipse.
+    radius_y: LengthPercentage,
+    /// The direction in which the arc is drawn.
+    sweep: ArcSweep,
+    /// Which of the two possible arcs is drawn.
+    size: ArcSize,
+    /// The rotation of the ellipse's x axis.
+    rotate: Angle,
+  },
+  /// The `close` command, which closes the current subpath.
+  Close,
+}
+
+enum_property! {
+  /// The direction of an `arc` command within a `shape()`.
+  #[derive(Default)]
+  pub enum ArcSweep {
+    /// The arc is drawn clockwise.
+    Cw,
+    /// The arc is drawn counter-clockwise.
+    #[default]
+    Ccw,
+  }
+}
+
+enum_property! {
+  /// The size of an `arc` command within a `shape()`.
+  #[derive(Default)]
+  pub enum ArcSize {
+    /// The larger of the two possible arcs is drawn.
+    Large,
+    /// The smaller of the two possible arcs is drawn.
+    #[default]
+    Small,
+  }
+}
+
+/// A point within a `polygon()` or `shape()` shape.
 ///
 /// See [Polygon](Polygon).
 #[derive(Debug, Clone, PartialEq)]
@@ -119,9 +267,9 @@ pub struct Polygon {
 #[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
 pub struct Point {
   /// The x position of the point.
-  x: LengthPercentage,
+  pub x: LengthPercentage,
   /// the y position of the point.
-  y: LengthPercentage,
+  pub y: LengthPercentage,
 }
 
 enum_property! {
@@ -152,6 +300,8 @@ impl<'i> Parse<'i> for BasicShape {
       "circle" => Ok(BasicShape::Circle(input.parse_nested_block(Circle::parse)?)),
       "ellipse" => Ok(BasicShape::Ellipse(input.parse_nested_block(Ellipse::parse)?)),
       "polygon" => Ok(BasicShape::Polygon(input.parse_nested_block(Polygon::parse)?)),
+      "path" => Ok(BasicShape::Path(input.parse_nested_block(Path::parse)?)),
+      "shape" => Ok(BasicShape::Shape(input.parse_nested_block(Shape::parse)?)),
       _ => Err(location.new_unexpected_token_error(Token::Ident(f.clone()))),
     }
   }
@@ -225,6 +375,150 @@ impl<'i> Parse<'i> for Polygon {
   }
 }
 
+impl<'i> Parse<'i> for Path {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let fill_rule = input.try_parse(FillRule::parse);
+    if fill_rule.is_ok() {
+      input.expect_comma()?;
+    }
+
+    let path = PathData::parse(input)?;
+    Ok(Path {
+      fill_rule: fill_rule.unwrap_or_default(),
+      path,
+    })
+  }
+}
+
+impl<'i> Parse<'i> for Shape {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let fill_rule = input.try_parse(FillRule::parse).unwrap_or_default();
+    input.expect_ident_matching("from")?;
+    let start = Point::parse(input)?;
+    input.expect_comma()?;
+    let commands = input.parse_comma_separated(ShapeCommand::parse)?;
+    Ok(Shape {
+      fill_rule,
+      start,
+      commands,
+    })
+  }
+}
+
+/// Parses the `to` or `by` keyword before the end point of a shape command,
+/// returning whether the end point is relative.
+fn parse_relative<'i, 't>(input: &mut Parser<'i, 't>) -> Result<bool, ParseError<'i, ParserError<'i>>> {
+  let location = input.current_source_location();
+  let ident = input.expect_ident()?;
+  match_ignore_ascii_case! { &ident,
+    "to" => Ok(false),
+    "by" => Ok(true),
+    _ => Err(location.new_unexpected_token_error(Token::Ident(ident.clone()))),
+  }
+}
+
+impl<'i> Parse<'i> for ShapeCommand {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let ident = input.expect_ident_cloned()?;
+    match_ignore_ascii_case! { &ident,
+      "close" => Ok(ShapeCommand::Close),
+      "move" => {
+        let relative = parse_relative(input)?;
+        let point = Point::parse(input)?;
+        Ok(ShapeCommand::Move { relative, point })
+      },
+      "line" => {
+        let relative = parse_relative(input)?;
+        let point = Point::parse(input)?;
+        Ok(ShapeCommand::Line { relative, point })
+      },
+      "hline" => {
+        let relative = parse_relative(input)?;
+        let x = LengthPercentage::parse(input)?;
+        Ok(ShapeCommand::HorizontalLine { relative, x })
+      },
+      "vline" => {
+        let relative = parse_relative(input)?;
+        let y = LengthPercentage::parse(input)?;
+        Ok(ShapeCommand::VerticalLine { relative, y })
+      },
+      "curve" => {
+        let relative = parse_relative(input)?;
+        let point = Point::parse(input)?;
+        input.expect_ident_matching("with")?;
+        let control1 = Point::parse(input)?;
+        let control2 = input.try_parse(|input| {
+          input.expect_delim('/')?;
+          Point::parse(input)
+        }).ok();
+        Ok(ShapeCommand::Curve { relative, point, control1, control2 })
+      },
+      "smooth" => {
+        let relative = parse_relative(input)?;
+        let point = Point::parse(input)?;
+        let control = input.try_parse(|input| {
+          input.expect_ident_matching("with")?;
+          Point::parse(input)
+        }).ok();
+        Ok(ShapeCommand::Smooth { relative, point, control })
+      },
+      "arc" => {
+        let relative = parse_relative(input)?;
+        let point = Point::parse(input)?;
+        let mut radii = None;
+        let mut sweep = None;
+        let mut size = None;
+        let mut rotate = None;
+        loop {
+          if radii.is_none() && input.try_parse(|input| input.expect_ident_matching("of")).is_ok() {
+            let x = LengthPercentage::parse(input)?;
+            let y = input.try_parse(LengthPercentage::parse).unwrap_or_else(|_| x.clone());
+            radii = Some((x, y));
+            continue;
+          }
+
+          if sweep.is_none() {
+            if let Ok(value) = input.try_parse(ArcSweep::parse) {
+              sweep = Some(value);
+              continue;
+            }
+          }
+
+          if size.is_none() {
+            if let Ok(value) = input.try_parse(ArcSize::parse) {
+              size = Some(value);
+              continue;
+            }
+          }
+
+          if rotate.is_none() && input.try_parse(|input| input.expect_ident_matching("rotate")).is_ok() {
+            rotate = Some(Angle::parse(input)?);
+            continue;
+          }
+
+          break;
+        }
+
+        if let Some((radius_x, radius_y)) = radii {
+          Ok(ShapeCommand::Arc {
+            relative,
+            point,
+            radius_x,
+            radius_y,
+            sweep: sweep.unwrap_or_default(),
+            size: size.unwrap_or_default(),
+            rotate: rotate.unwrap_or(Angle::Deg(0.0)),
+          })
+        } else {
+          Err(input.new_custom_error(ParserError::InvalidValue))
+        }
+      },
+      _ => Err(location.new_unexpected_token_error(Token::Ident(ident))),
+    }
+  }
+}
+
 impl<'i> Parse<'i> for Point {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     let x = LengthPercentage::parse(input)?;
@@ -259,6 +553,16 @@ impl ToCss for BasicShape {
         poly.to_css(dest)?;
         dest.write_char(')')
       }
+      BasicShape::Path(path) => {
+        dest.write_str("path(")?;
+        path.to_css(dest)?;
+        dest.write_char(')')
+      }
+      BasicShape::Shape(shape) => {
+        dest.write_str("shape(")?;
+        shape.to_css(dest)?;
+        dest.write_char(')')
+      }
     }
   }
 }
@@ -349,6 +653,124 @@ impl ToCss for Polygon {
   }
 }
 
+impl ToCss for Path {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.fill_rule != FillRule::default() {
+      self.fill_rule.to_css(dest)?;
+      dest.delim(',', false)?;
+    }
+
+    self.path.to_css(dest)
+  }
+}
+
+impl ToCss for Shape {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.fill_rule != FillRule::default() {
+      self.fill_rule.to_css(dest)?;
+      dest.write_char(' ')?;
+    }
+
+    dest.write_str("from ")?;
+    self.start.to_css(dest)?;
+    for command in &self.commands {
+      dest.delim(',', false)?;
+      command.to_css(dest)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl ToCss for ShapeCommand {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    fn write_end<W: std::fmt::Write, T: ToCss>(
+      name: &str,
+      relative: bool,
+      point: &T,
+      dest: &mut Printer<W>,
+    ) -> Result<(), PrinterError> {
+      dest.write_str(name)?;
+      dest.write_str(if relative { " by " } else { " to " })?;
+      point.to_css(dest)
+    }
+
+    match self {
+      ShapeCommand::Move { relative, point } => write_end("move", *relative, point, dest),
+      ShapeCommand::Line { relative, point } => write_end("line", *relative, point, dest),
+      ShapeCommand::HorizontalLine { relative, x } => write_end("hline", *relative, x, dest),
+      ShapeCommand::VerticalLine { relative, y } => write_end("vline", *relative, y, dest),
+      ShapeCommand::Curve {
+        relative,
+        point,
+        control1,
+        control2,
+      } => {
+        write_end("curve", *relative, point, dest)?;
+        dest.write_str(" with ")?;
+        control1.to_css(dest)?;
+        if let Some(control2) = control2 {
+          dest.delim('/', true)?;
+          control2.to_css(dest)?;
+        }
+        Ok(())
+      }
+      ShapeCommand::Smooth {
+        relative,
+        point,
+        control,
+      } => {
+        write_end("smooth", *relative, point, dest)?;
+        if let Some(control) = control {
+          dest.write_str(" with ")?;
+          control.to_css(dest)?;
+        }
+        Ok(())
+      }
+      ShapeCommand::Arc {
+        relative,
+        point,
+        radius_x,
+        radius_y,
+        sweep,
+        size,
+        rotate,
+      } => {
+        write_end("arc", *relative, point, dest)?;
+        dest.write_str(" of ")?;
+        radius_x.to_css(dest)?;
+        if radius_y != radius_x {
+          dest.write_char(' ')?;
+          radius_y.to_css(dest)?;
+        }
+        if *sweep != ArcSweep::default() {
+          dest.write_char(' ')?;
+          sweep.to_css(dest)?;
+        }
+        if *size != ArcSize::default() {
+          dest.write_char(' ')?;
+          size.to_css(dest)?;
+        }
+        if !rotate.is_zero() {
+          dest.write_str(" rotate ")?;
+          rotate.to_css(dest)?;
+        }
+        Ok(())
+      }
+      ShapeCommand::Close => dest.write_str("close"),
+    }
+  }
+}
+
 impl ToCss for Point {
   fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
   where
@@ -1,5 +1,7 @@
 //! Types used to represent strings.
 
+use crate::error::PrinterError;
+use crate::printer::Printer;
 use crate::traits::{Parse, ToCss};
 #[cfg(feature = "visitor")]
 use crate::visitor::{Visit, VisitTypes, Visitor};
@@ -356,6 +358,66 @@ impl<'i> cssparser::ToCss for CSSString<'i> {
   }
 }
 
+/// Returns whether the given string may be used as a [`<custom-ident>`](https://www.w3.org/TR/css-values-4/#custom-idents).
+///
+/// The string must be non-empty, and must not be a CSS-wide keyword, `default`,
+/// or one of the additional `excluded` keywords (compared ASCII case-insensitively).
+pub fn is_valid_custom_ident(s: &str, excluded: &[&str]) -> bool {
+  if s.is_empty() {
+    return false;
+  }
+
+  let reserved = cssparser::match_ignore_ascii_case! { s,
+    "initial" | "inherit" | "unset" | "default" | "revert" | "revert-layer" => true,
+    _ => false
+  };
+
+  !reserved && !excluded.iter().any(|e| s.eq_ignore_ascii_case(e))
+}
+
+/// Returns whether the string can be serialized as an identifier without any escapes.
+fn is_unescaped_ident(s: &str) -> bool {
+  let is_name_char = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '-' || !c.is_ascii();
+  let is_start_char = |c: char| c.is_ascii_alphabetic() || c == '_' || !c.is_ascii();
+
+  let rest = if let Some(rest) = s.strip_prefix("--") {
+    rest
+  } else {
+    let s = s.strip_prefix('-').unwrap_or(s);
+    match s.chars().next() {
+      Some(c) if is_start_char(c) => &s[c.len_utf8()..],
+      _ => return false,
+    }
+  };
+
+  rest.chars().all(is_name_char)
+}
+
+/// Writes a string as a [`<custom-ident>`](https://www.w3.org/TR/css-values-4/#custom-idents) if possible,
+/// otherwise as a quoted `<string>`.
+///
+/// Strings that are not valid custom identifiers (see [is_valid_custom_ident]) are always quoted.
+/// Strings that would require escapes are also kept quoted since that is shorter, unless the name
+/// must be written as an identifier so that it can be scoped to a CSS module.
+pub fn write_custom_ident<W>(
+  s: &str,
+  excluded: &[&str],
+  dest: &mut Printer<W>,
+  handle_css_module: bool,
+) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  let as_ident = is_valid_custom_ident(s, excluded)
+    && ((handle_css_module && dest.css_module.is_some()) || is_unescaped_ident(s));
+  if as_ident {
+    dest.write_ident(s, handle_css_module)
+  } else {
+    serialize_string(s, dest)?;
+    Ok(())
+  }
+}
+
 macro_rules! impl_string_type {
   ($t: ident) => {
     impl<'i> From<CowRcStr<'i>> for $t<'i> {
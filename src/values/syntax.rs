@@ -6,8 +6,10 @@ use crate::error::{ParserError, PrinterError};
 use crate::printer::Printer;
 use crate::properties::custom::TokenList;
 use crate::stylesheet::ParserOptions;
+use crate::targets::Targets;
 use crate::traits::{Parse, ToCss};
 use crate::values;
+use crate::values::color::ColorFallbackKind;
 #[cfg(feature = "visitor")]
 use crate::visitor::Visit;
 use cssparser::*;
@@ -463,6 +465,35 @@ impl ToCss for SyntaxComponentKind {
   }
 }
 
+impl<'i> ParsedComponent<'i> {
+  /// Returns the possible color fallbacks for any colors within the component.
+  pub(crate) fn get_necessary_fallbacks(&self, targets: Targets) -> ColorFallbackKind {
+    match self {
+      ParsedComponent::Color(color) => color.get_possible_fallbacks(targets),
+      ParsedComponent::Repeated { components, .. } => {
+        components.iter().fold(ColorFallbackKind::empty(), |fallbacks, component| {
+          fallbacks | component.get_necessary_fallbacks(targets)
+        })
+      }
+      ParsedComponent::TokenList(tokens) => tokens.get_necessary_fallbacks(targets),
+      _ => ColorFallbackKind::empty(),
+    }
+  }
+
+  /// Returns a copy of the component with all colors converted to the given fallback type.
+  pub(crate) fn get_fallback(&self, kind: ColorFallbackKind) -> Self {
+    match self {
+      ParsedComponent::Color(color) => ParsedComponent::Color(color.get_fallback(kind)),
+      ParsedComponent::Repeated { components, multiplier } => ParsedComponent::Repeated {
+        components: components.iter().map(|component| component.get_fallback(kind)).collect(),
+        multiplier: multiplier.clone(),
+      },
+      ParsedComponent::TokenList(tokens) => ParsedComponent::TokenList(tokens.get_fallback(kind)),
+      _ => self.clone(),
+    }
+  }
+}
+
 impl<'i> ToCss for ParsedComponent<'i> {
   fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
   where
@@ -101,9 +101,11 @@ impl ToCss for Time {
   {
     // 0.1s is shorter than 100ms
     // anything smaller is longer
+    // Round when comparing so floating point error (e.g. from calc()) doesn't affect the unit.
+    let ms = (self.to_ms() * 1000.0).round() / 1000.0;
     match self {
       Time::Seconds(s) => {
-        if *s > 0.0 && *s < 0.1 {
+        if *s > 0.0 && ms < 100.0 {
           (*s * 1000.0).to_css(dest)?;
           dest.write_str("ms")
         } else {
@@ -111,12 +113,12 @@ impl ToCss for Time {
           dest.write_str("s")
         }
       }
-      Time::Milliseconds(ms) => {
-        if *ms == 0.0 || *ms >= 100.0 {
-          (*ms / 1000.0).to_css(dest)?;
+      Time::Milliseconds(value) => {
+        if *value == 0.0 || ms >= 100.0 {
+          (*value / 1000.0).to_css(dest)?;
           dest.write_str("s")
         } else {
-          ms.to_css(dest)?;
+          value.to_css(dest)?;
           dest.write_str("ms")
         }
       }
@@ -63,31 +63,7 @@ impl<'i> ToCss for Url<'i> {
       return Ok(());
     }
 
-    use cssparser::ToCss;
-    if dest.minify {
-      let mut buf = String::new();
-      Token::UnquotedUrl(CowRcStr::from(self.url.as_ref())).to_css(&mut buf)?;
-
-      // If the unquoted url is longer than it would be quoted (e.g. `url("...")`)
-      // then serialize as a string and choose the shorter version.
-      if buf.len() > self.url.len() + 7 {
-        let mut buf2 = String::new();
-        serialize_string(&self.url, &mut buf2)?;
-        if buf2.len() + 5 < buf.len() {
-          dest.write_str("url(")?;
-          dest.write_str(&buf2)?;
-          return dest.write_char(')');
-        }
-      }
-
-      dest.write_str(&buf)?;
-    } else {
-      dest.write_str("url(")?;
-      serialize_string(&self.url, dest)?;
-      dest.write_char(')')?;
-    }
-
-    Ok(())
+    dest.write_url(&self.url)
   }
 }
 
@@ -424,6 +424,7 @@ impl_visit!(u16);
 impl_visit!(u32);
 impl_visit!(i32);
 impl_visit!(f32);
+impl_visit!(f64);
 impl_visit!(bool);
 impl_visit!(char);
 impl_visit!(str);
@@ -1,5 +1,7 @@
+use std::collections::HashMap;
+
 use lightningcss::{
-  declaration::DeclarationBlock,
+  declaration::{DeclarationBlock, MergeMode},
   properties::{Property, PropertyId},
   stylesheet::{ParserOptions, PrinterOptions},
   traits::ToCss,
@@ -506,3 +508,147 @@ fn test_remove() {
     "-webkit-flex-wrap: wrap",
   );
 }
+
+fn to_object_test(decls: &str, expected: &[(&str, &str)]) {
+  let decls = DeclarationBlock::parse_string(decls, ParserOptions::default()).unwrap();
+  let object = decls.to_object(PrinterOptions::default()).unwrap();
+  assert_eq!(
+    object,
+    expected
+      .iter()
+      .map(|(name, value)| (name.to_string(), value.to_string()))
+      .collect::<HashMap<_, _>>()
+  );
+}
+
+#[test]
+fn test_to_object() {
+  to_object_test("color: red; width: 10px", &[("color", "red"), ("width", "10px")]);
+  to_object_test("color: red; color: green", &[("color", "green")]);
+  to_object_test("color: red !important; color: green", &[("color", "red")]);
+  to_object_test(
+    "margin: 5px 6px; -webkit-transition: opacity 200ms",
+    &[("margin", "5px 6px"), ("-webkit-transition", "opacity .2s")],
+  );
+  to_object_test(
+    "--foo: calc(1px + 2px); background: var(--bg, blue)",
+    &[("--foo", "calc(1px + 2px)"), ("background", "var(--bg, blue)")],
+  );
+  to_object_test("", &[]);
+}
+
+fn merge_test(orig: &str, other: &str, mode: MergeMode, expected: &str) {
+  let mut decls = DeclarationBlock::parse_string(orig, ParserOptions::default()).unwrap();
+  let other = DeclarationBlock::parse_string(other, ParserOptions::default()).unwrap();
+  decls.merge_from(&other, mode);
+  assert_eq!(decls.to_css_string(PrinterOptions::default()).unwrap(), expected);
+}
+
+#[test]
+fn test_merge_from() {
+  merge_test(
+    "color: red; width: 10px",
+    "color: blue; height: 5px",
+    MergeMode::Replace,
+    "width: 10px; color: #00f; height: 5px",
+  );
+  merge_test(
+    "color: red; width: 10px",
+    "color: blue; height: 5px",
+    MergeMode::Fallback,
+    "height: 5px; color: red; width: 10px",
+  );
+  merge_test(
+    "background-color: red; color: red",
+    "background: url(foo.png)",
+    MergeMode::Replace,
+    "color: red; background: url(\"foo.png\")",
+  );
+  merge_test(
+    "background: url(foo.png)",
+    "background-color: red",
+    MergeMode::Replace,
+    "background: url(\"foo.png\"); background-color: red",
+  );
+  merge_test(
+    "background-color: red",
+    "background: url(foo.png); background-color: blue",
+    MergeMode::Fallback,
+    "background: url(\"foo.png\"); background-color: red",
+  );
+  merge_test(
+    "margin: 10px",
+    "margin-top: 5px; padding: 5px",
+    MergeMode::Fallback,
+    "padding: 5px; margin: 10px",
+  );
+  merge_test(
+    "color: red !important; width: 10px",
+    "color: blue; width: 5px !important",
+    MergeMode::Replace,
+    "width: 10px; color: #00f; color: red !important; width: 5px !important",
+  );
+  merge_test(
+    "color: red !important",
+    "color: blue !important; width: 5px",
+    MergeMode::Fallback,
+    "width: 5px; color: red !important",
+  );
+}
+
+#[test]
+fn test_longhands() {
+  assert!(PropertyId::Margin.is_shorthand());
+  assert!(!PropertyId::MarginTop.is_shorthand());
+  assert!(!PropertyId::from("--foo").is_shorthand());
+  assert_eq!(PropertyId::MarginTop.longhands(), None);
+  assert_eq!(
+    PropertyId::Margin.longhands(),
+    Some(vec![
+      PropertyId::MarginTop,
+      PropertyId::MarginRight,
+      PropertyId::MarginBottom,
+      PropertyId::MarginLeft
+    ])
+  );
+  assert_eq!(
+    PropertyId::FlexFlow(VendorPrefix::WebKit).longhands(),
+    Some(vec![
+      PropertyId::FlexDirection(VendorPrefix::WebKit),
+      PropertyId::FlexWrap(VendorPrefix::WebKit)
+    ])
+  );
+  let border = PropertyId::Border.longhands().unwrap();
+  assert_eq!(border.len(), 12);
+  assert!(border.contains(&PropertyId::BorderTopWidth));
+  assert!(border.iter().all(|longhand| !longhand.is_shorthand()));
+}
+
+#[test]
+fn test_initial_value() {
+  fn initial(name: &str) -> Option<String> {
+    Property::initial_value(&PropertyId::from(name))
+      .map(|property| property.to_css_string(false, PrinterOptions::default()).unwrap())
+  }
+
+  assert_eq!(initial("display"), Some("display: inline".into()));
+  assert_eq!(initial("margin-top"), Some("margin-top: 0".into()));
+  assert_eq!(initial("margin"), Some("margin: 0".into()));
+  assert_eq!(initial("border"), Some("border: none".into()));
+  assert_eq!(
+    initial("border-top-color"),
+    Some("border-top-color: currentColor".into())
+  );
+  assert_eq!(initial("border-image"), Some("border-image: none".into()));
+  assert_eq!(initial("flex"), Some("flex: 0 auto".into()));
+  assert_eq!(initial("-webkit-flex"), Some("-webkit-flex: 0 auto".into()));
+  assert_eq!(initial("white-space"), Some("white-space: normal".into()));
+  assert_eq!(initial("transition-duration"), Some("transition-duration: 0s".into()));
+  assert_eq!(initial("fill"), Some("fill: #000".into()));
+  assert_eq!(initial("font-family"), None);
+  assert_eq!(initial("--foo"), None);
+  assert_eq!(initial("unknown"), None);
+
+  let property = Property::initial_value(&PropertyId::BoxSizing(VendorPrefix::WebKit)).unwrap();
+  assert_eq!(property.property_id(), PropertyId::BoxSizing(VendorPrefix::WebKit));
+}
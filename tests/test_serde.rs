@@ -18,3 +18,49 @@ fn test_serde() {
   let deserialized: StyleSheet = serde_json::from_str(&json).unwrap();
   assert_eq!(&deserialized.rules, &stylesheet.rules);
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_ast_json() {
+  use lightningcss::stylesheet::AST_SCHEMA_VERSION;
+
+  // Pins the serialized schema. If this changes in a backward incompatible way,
+  // AST_SCHEMA_VERSION must be incremented.
+  let stylesheet =
+    StyleSheet::parse(".foo { color: red; animation-name: bar }", ParserOptions::default()).unwrap();
+  let json: serde_json::Value = serde_json::from_str(&stylesheet.to_ast_json().unwrap()).unwrap();
+  assert_eq!(AST_SCHEMA_VERSION, 2);
+  assert_eq!(
+    json,
+    serde_json::json!({
+      "schemaVersion": 2,
+      "stylesheet": {
+        "rules": [{
+          "type": "style",
+          "value": {
+            "selectors": [[{ "type": "class", "name": "foo" }]],
+            "declarations": {
+              "importantDeclarations": [],
+              "declarations": [
+                {
+                  "property": "color",
+                  "value": { "type": "rgb", "r": 255.0, "g": 0.0, "b": 0.0, "alpha": 1.0 }
+                },
+                {
+                  "property": "animation-name",
+                  "vendorPrefix": [],
+                  "value": [{ "type": "ident", "value": "bar" }]
+                }
+              ]
+            },
+            "rules": [],
+            "loc": { "source_index": 0, "line": 0, "column": 1 }
+          }
+        }],
+        "sources": [""],
+        "sourceMapUrls": [null],
+        "licenseComments": []
+      }
+    })
+  );
+}
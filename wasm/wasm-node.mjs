@@ -28,6 +28,10 @@ export function transform(options) {
   return wasm.transform(options);
 }
 
+export function transformJson(options) {
+  return wasm.transformJson(options);
+}
+
 export function transformStyleAttribute(options) {
   return wasm.transformStyleAttribute(options);
 }